@@ -0,0 +1,88 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Measure a full `disassemble_64` of each instruction in a buffer of
+//! code, and compare `instruction_length` against it when splitting the
+//! buffer into instructions.
+//!
+//! Run with `cargo bench --bench length`.
+
+extern crate burst;
+
+use burst::x86::*;
+use std::hint::black_box;
+use std::mem;
+use std::time::{Duration, Instant};
+
+// A typical function prologue, body and epilogue.
+static CODE: &[u8] = &[
+    0x55,                                     // push rbp
+    0x48, 0x89, 0xe5,                         // mov rbp, rsp
+    0x41, 0x57,                               // push r15
+    0x53,                                     // push rbx
+    0x48, 0x83, 0xec, 0x28,                   // sub rsp, 0x28
+    0x48, 0x8b, 0x05, 0x10, 0x20, 0x00, 0x00, // mov rax, [rip+0x2010]
+    0x48, 0x89, 0x45, 0xe8,                   // mov [rbp-0x18], rax
+    0x8b, 0x44, 0x24, 0x08,                   // mov eax, [rsp+0x8]
+    0x48, 0x63, 0xd0,                         // movsxd rdx, eax
+    0x48, 0x8d, 0x0c, 0x95, 0x00, 0x10, 0x00, 0x00, // lea rcx, [rdx*4+0x1000]
+    0x85, 0xc0,                               // test eax, eax
+    0x74, 0x12,                               // je +0x12
+    0x0f, 0xb6, 0x0f,                         // movzx ecx, byte [rdi]
+    0x48, 0xb8, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, // mov rax, imm64
+    0xe8, 0x00, 0x01, 0x00, 0x00,             // call +0x100
+    0x0f, 0x84, 0x80, 0x00, 0x00, 0x00,       // je +0x80
+    0x66, 0x0f, 0x6f, 0x06,                   // movdqa xmm0, [rsi]
+    0x0f, 0x29, 0x07,                         // movaps [rdi], xmm0
+    0xf3, 0x0f, 0x10, 0x4e, 0x10,             // movss xmm1, [rsi+0x10]
+    0xc7, 0x45, 0xf0, 0x01, 0x00, 0x00, 0x00, // mov dword [rbp-0x10], 1
+    0x31, 0xc0,                               // xor eax, eax
+    0x48, 0x83, 0xc4, 0x28,                   // add rsp, 0x28
+    0x5b,                                     // pop rbx
+    0x41, 0x5f,                               // pop r15
+    0x5d,                                     // pop rbp
+    0xc3,                                     // ret
+    0x0f, 0x1f, 0x44, 0x00, 0x00,             // nop dword [rax+rax]
+    0x66, 0x2e, 0x0f, 0x1f, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00, // nop word cs:[rax+rax]
+];
+
+const ROUNDS: usize = 200_000;
+
+fn walk<F: Fn(&[u8]) -> usize>(measure: F) -> (usize, Duration) {
+    let start = Instant::now();
+    let mut count = 0;
+    for _ in 0..ROUNDS {
+        let mut offset = 0;
+        while offset < CODE.len() {
+            offset += measure(black_box(&CODE[offset..]));
+            count += 1;
+        }
+    }
+    (count, start.elapsed())
+}
+
+fn main() {
+    // Both must agree on every instruction boundary.
+    let mut offset = 0;
+    while offset < CODE.len() {
+        let bytes = &CODE[offset..];
+        let expected = disassemble_64(bytes, 0, bytes.len()).unwrap().length;
+        assert_eq!(instruction_length(X86Mode::Bits64, bytes), Ok(expected));
+        offset += expected;
+    }
+
+    // Keep the whole instruction, so that none of the work of building
+    // it can be skipped.
+    let (count, full) = walk(|bytes| {
+        let instr = black_box(disassemble_64(bytes, 0, bytes.len()).unwrap());
+        instr.length
+    });
+    let (_, fast) = walk(|bytes| instruction_length(X86Mode::Bits64, bytes).unwrap());
+    let per_instruction = |elapsed: Duration| elapsed.as_nanos() as f64 / count as f64;
+    println!("disassemble_64:     {:6.2} ns/instruction", per_instruction(full));
+    println!("instruction_length: {:6.2} ns/instruction", per_instruction(fast));
+    println!("speedup:            {:6.2}x", full.as_secs_f64() / fast.as_secs_f64());
+    println!("X86Instruction:     {:6} bytes", mem::size_of::<X86Instruction>());
+}
@@ -0,0 +1,13 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate burst;
+
+use burst::x86::*;
+
+fuzz_target!(|data: &[u8]| {
+    for &mode in &[X86Mode::Bits16, X86Mode::Bits32, X86Mode::Bits64] {
+        let expected = disassemble(mode, data, 0).map(|instr| instr.length);
+        assert_eq!(instruction_length(mode, data), expected);
+    }
+});
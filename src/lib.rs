@@ -40,14 +40,33 @@
 //! # fn main() {}
 //! ```
 //!
+//! ## Features
+//!
+//! The `std` feature is enabled by default. Without it, Burst only
+//! depends upon `core` and can be used in `#![no_std]` environments
+//! like bootloaders and kernels:
+//!
+//! ```toml
+//! [dependencies]
+//! burst = { version = "0.0.3", default-features = false }
+//! ```
+//!
+//! Formatting instructions works through `core::fmt::Write` in
+//! either case.
+//!
 //! ## Contributions
 //!
 //! Contributions are welcome.
 //!
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![deny(trivial_numeric_casts, unstable_features,
         unused_import_braces, unused_qualifications)]
+#![forbid(unsafe_code)]
+
+#[cfg(feature = "std")]
+extern crate core;
 
 pub mod x86;
 
@@ -0,0 +1,66 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{disassemble, DecodeError, InstructionIter, InvalidBytePolicy, X86Instruction, X86Mode};
+
+/// Options that control how an [`X86Decoder`] decodes instructions.
+///
+/// [`X86Decoder`]: struct.X86Decoder.html
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct X86DecoderOptions {
+    /// What iterators created by the decoder do with bytes that do
+    /// not decode to a valid instruction.
+    pub invalid_bytes: InvalidBytePolicy,
+}
+
+/// A reusable decoder configuration.
+///
+/// This allows the mode and other options to be chosen at runtime and
+/// then used to decode any number of instructions.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// let decoder = X86Decoder {
+///     mode: X86Mode::Bits32,
+///     options: X86DecoderOptions {
+///         invalid_bytes: InvalidBytePolicy::Skip,
+///     },
+/// };
+///
+/// // push ebp; mov ebp, esp
+/// let data = [0x55u8, 0x89, 0xe5];
+/// let instr = decoder.decode(&data, 0x400000).unwrap();
+/// assert_eq!(instr.operation, InstructionOperation::PUSH);
+/// assert_eq!(decoder.iter(&data, 0x400000).count(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct X86Decoder {
+    /// The processor mode to decode instructions for.
+    pub mode: X86Mode,
+    /// Additional options for decoding.
+    pub options: X86DecoderOptions,
+}
+
+impl X86Decoder {
+    /// Create a decoder for the given mode with the default options.
+    pub fn new(mode: X86Mode) -> Self {
+        X86Decoder {
+            mode,
+            options: X86DecoderOptions::default(),
+        }
+    }
+
+    /// Decode a single instruction located at `addr`.
+    pub fn decode(&self, opcode: &[u8], addr: u64) -> Result<X86Instruction, DecodeError> {
+        disassemble(self.mode, opcode, addr)
+    }
+
+    /// Create an iterator over the instructions in `bytes`, which are
+    /// located at `addr`.
+    pub fn iter<'a>(&self, bytes: &'a [u8], addr: u64) -> InstructionIter<'a> {
+        InstructionIter::new(self.mode, bytes, addr).with_policy(self.options.invalid_bytes)
+    }
+}
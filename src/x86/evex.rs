@@ -0,0 +1,103 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::OperandType;
+
+/// A static rounding mode, given by an EVEX prefix for a register
+/// to register instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X86Rounding {
+    /// Round to nearest, `{rn-sae}`.
+    Nearest,
+    /// Round down, toward negative infinity, `{rd-sae}`.
+    Down,
+    /// Round up, toward positive infinity, `{ru-sae}`.
+    Up,
+    /// Round toward zero, `{rz-sae}`.
+    TowardZero,
+}
+
+impl X86Rounding {
+    pub(crate) fn from_bits(bits: u8) -> Self {
+        match bits & 3 {
+            0 => X86Rounding::Nearest,
+            1 => X86Rounding::Down,
+            2 => X86Rounding::Up,
+            _ => X86Rounding::TowardZero,
+        }
+    }
+
+    /// The name of the rounding mode as it is written in assembly,
+    /// such as `rn-sae`.
+    pub fn name(self) -> &'static str {
+        match self {
+            X86Rounding::Nearest => "rn-sae",
+            X86Rounding::Down => "rd-sae",
+            X86Rounding::Up => "ru-sae",
+            X86Rounding::TowardZero => "rz-sae",
+        }
+    }
+}
+
+/// The AVX-512 and APX features that an EVEX prefix adds to an
+/// instruction.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // vaddps zmm0{k1}{z}, zmm1, dword [rax+0x40]{1to16}
+/// let data = [0x62u8, 0xf1, 0x74, 0xd9, 0x58, 0x40, 0x10];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// let evex = instr.evex.unwrap();
+/// assert_eq!(evex.mask, Some(OperandType::REG_K1));
+/// assert!(evex.zeroing);
+/// assert_eq!(evex.broadcast, Some(16));
+/// // The 8 bit displacement is scaled by the size of the element.
+/// assert_eq!(evex.disp8_scale, 4);
+/// let mut out = String::new();
+/// format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+/// assert_eq!(out, "vaddps zmm0{k1}{z}, zmm1, dword [rax+0x40]{1to16}");
+///
+/// // vaddps zmm0, zmm1, zmm2, {rz-sae}
+/// let data = [0x62u8, 0xf1, 0x74, 0x78, 0x58, 0xc2];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.evex.unwrap().rounding, Some(X86Rounding::TowardZero));
+///
+/// // {nf} add ecx, ebx, eax
+/// let data = [0x62u8, 0xf4, 0x74, 0x1c, 0x01, 0xc3];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert!(instr.evex.unwrap().no_flags);
+/// let mut out = String::new();
+/// format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+/// assert_eq!(out, "{nf} add ecx, ebx, eax");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct X86Evex {
+    /// The opmask register that selects which elements of the
+    /// destination are written. This is `None` for `k0`, which means
+    /// that every element is written.
+    pub mask: Option<OperandType>,
+    /// Whether the elements that the mask doesn't select are zeroed
+    /// rather than left unchanged.
+    pub zeroing: bool,
+    /// The number of times that a single element from memory is
+    /// repeated to fill the vector, as in `{1to16}`.
+    pub broadcast: Option<u8>,
+    /// The static rounding mode, which also suppresses exceptions.
+    pub rounding: Option<X86Rounding>,
+    /// Whether floating point exceptions are suppressed, as with
+    /// `{sae}` or a static rounding mode.
+    pub sae: bool,
+    /// What an 8 bit displacement was multiplied by to get the
+    /// displacement of the memory operand, or `0` when there is no
+    /// memory operand.
+    pub disp8_scale: u8,
+    /// Whether an APX instruction leaves the flags unchanged, as with
+    /// `{nf}`.
+    pub no_flags: bool,
+    /// Whether an APX instruction writes its result to a new register,
+    /// which is the first operand, rather than to its first source.
+    pub new_data_dest: bool,
+}
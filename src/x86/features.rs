@@ -0,0 +1,6435 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::{BitOr, BitOrAssign};
+
+use super::{InstructionIter, InstructionOperation, InvalidBytePolicy, OperandType};
+use super::{X86Instruction, X86Mode, X86Operand};
+
+/// An ISA extension, as reported by `CPUID`, that an instruction
+/// needs.
+///
+/// The name of a feature is the one that Rust uses for it in
+/// `target_feature` and `is_x86_feature_detected!`, where there is
+/// one.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// assert_eq!(X86Feature::SSE4_1.name(), "sse4.1");
+/// assert_eq!(X86Feature::SSE4_1.level(), Some(X86Level::V2));
+/// assert_eq!(X86Feature::BMI1.level(), Some(X86Level::V3));
+/// // AES isn't part of any level.
+/// assert_eq!(X86Feature::AES.level(), None);
+/// ```
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum X86Feature {
+    /// The x87 floating point unit.
+    X87,
+    /// `CMOVcc`, `FCMOVcc` and `FCOMI`.
+    CMOV,
+    /// `CMPXCHG8B`.
+    CX8,
+    /// `FXSAVE` and `FXRSTOR`.
+    FXSR,
+    /// `SYSCALL` and `SYSRET`.
+    SYSCALL,
+    /// MMX.
+    MMX,
+    /// SSE.
+    SSE,
+    /// SSE2.
+    SSE2,
+    /// `CMPXCHG16B`.
+    CX16,
+    /// `LAHF` and `SAHF` in 64 bit mode.
+    LAHF_SAHF,
+    /// `POPCNT`.
+    POPCNT,
+    /// SSE3.
+    SSE3,
+    /// Supplemental SSE3.
+    SSSE3,
+    /// SSE4.1.
+    SSE4_1,
+    /// SSE4.2.
+    SSE4_2,
+    /// AVX.
+    AVX,
+    /// AVX2.
+    AVX2,
+    /// Bit manipulation instructions, such as `ANDN` and `TZCNT`.
+    BMI1,
+    /// More bit manipulation instructions, such as `PDEP` and `SHLX`.
+    BMI2,
+    /// Conversions between half and single precision.
+    F16C,
+    /// Fused multiply and add with three operands.
+    FMA,
+    /// `LZCNT`.
+    LZCNT,
+    /// `MOVBE`.
+    MOVBE,
+    /// `XSAVE`, `XRSTOR`, `XGETBV` and `XSETBV`.
+    XSAVE,
+    /// The AVX-512 foundation.
+    AVX512F,
+    /// AVX-512 byte and word instructions.
+    AVX512BW,
+    /// AVX-512 conflict detection.
+    AVX512CD,
+    /// AVX-512 doubleword and quadword instructions.
+    AVX512DQ,
+    /// AVX-512 instructions on 128 and 256 bit vectors.
+    AVX512VL,
+    /// `SYSENTER` and `SYSEXIT`.
+    SEP,
+    /// `RDTSC`.
+    TSC,
+    /// `RDMSR` and `WRMSR`.
+    MSR,
+    /// `CLFLUSH`.
+    CLFSH,
+    /// `MONITOR` and `MWAIT`.
+    MONITOR,
+    /// AMD 3DNow!
+    AMD3DNOW,
+    /// The extensions to AMD 3DNow!
+    AMD3DNOWEXT,
+    /// `PREFETCH` and `PREFETCHW`.
+    PRFCHW,
+    /// `PREFETCHWT1`.
+    PREFETCHWT1,
+    /// AES rounds.
+    AES,
+    /// Carry-less multiplication.
+    PCLMULQDQ,
+    /// SHA-1 and SHA-256.
+    SHA,
+    /// Galois field instructions.
+    GFNI,
+    /// AES rounds on 256 and 512 bit vectors.
+    VAES,
+    /// Carry-less multiplication on 256 and 512 bit vectors.
+    VPCLMULQDQ,
+    /// AMD fused multiply and add with four operands.
+    FMA4,
+    /// AMD extended operations.
+    XOP,
+    /// `ADCX` and `ADOX`.
+    ADX,
+    /// `RDRAND`.
+    RDRAND,
+    /// `RDSEED`.
+    RDSEED,
+    /// `RDPID`.
+    RDPID,
+    /// `XSAVEOPT`.
+    XSAVEOPT,
+    /// `XSAVEC`.
+    XSAVEC,
+    /// `XSAVES` and `XRSTORS`.
+    XSAVES,
+    /// `CLFLUSHOPT`.
+    CLFLUSHOPT,
+    /// `CLWB`.
+    CLWB,
+    /// Restricted transactional memory.
+    RTM,
+    /// CET indirect branch tracking.
+    CET_IBT,
+    /// CET shadow stacks.
+    CET_SS,
+    /// Intel virtualization.
+    VMX,
+    /// AMD virtualization.
+    SVM,
+    /// Safer mode extensions.
+    SMX,
+    /// AMX tile configuration, loads and stores.
+    AMX_TILE,
+    /// AMX dot products of bytes.
+    AMX_INT8,
+    /// AMX dot products of bfloat16 values.
+    AMX_BF16,
+    /// AMX dot products of half precision values.
+    AMX_FP16,
+    /// AMX complex multiplication.
+    AMX_COMPLEX,
+    /// The APX foundation, including the extended general purpose registers.
+    APX_F,
+}
+
+impl X86Feature {
+    /// The name of the feature, such as `avx512f`.
+    pub fn name(self) -> &'static str {
+        FEATURE_INFO[self as usize].name
+    }
+
+    /// The lowest microarchitecture level that includes this feature,
+    /// or `None` when no level includes it.
+    pub fn level(self) -> Option<X86Level> {
+        FEATURE_INFO[self as usize].level
+    }
+}
+
+/// An x86-64 microarchitecture level, as defined by the x86-64 psABI.
+///
+/// Each level includes the features of the levels below it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum X86Level {
+    /// The baseline: x87, `CMOV`, `CMPXCHG8B`, `FXSR`, `SYSCALL`,
+    /// MMX, SSE and SSE2.
+    V1,
+    /// Adds `CMPXCHG16B`, `LAHF` and `SAHF`, `POPCNT`, SSE3, SSSE3,
+    /// SSE4.1 and SSE4.2.
+    V2,
+    /// Adds AVX, AVX2, BMI1, BMI2, F16C, FMA, `LZCNT`, `MOVBE` and
+    /// `XSAVE`.
+    V3,
+    /// Adds AVX512F, AVX512BW, AVX512CD, AVX512DQ and AVX512VL.
+    V4,
+}
+
+impl X86Level {
+    /// The name of the level, such as `x86-64-v2`.
+    pub fn name(self) -> &'static str {
+        match self {
+            X86Level::V1 => "x86-64",
+            X86Level::V2 => "x86-64-v2",
+            X86Level::V3 => "x86-64-v3",
+            X86Level::V4 => "x86-64-v4",
+        }
+    }
+
+    /// The features that a processor at this level has.
+    pub fn features(self) -> X86FeatureSet {
+        let mut features = X86FeatureSet::NONE;
+        for info in FEATURE_INFO.iter() {
+            if info.level.is_some_and(|level| level <= self) {
+                features.insert(info.value);
+            }
+        }
+        features
+    }
+}
+
+/// A set of features.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// let mut features = X86FeatureSet::new();
+/// assert_eq!(features.level(), Some(X86Level::V1));
+/// features.insert(X86Feature::POPCNT);
+/// features |= X86Feature::SSE4_2.into();
+/// assert!(features.contains(X86Feature::SSE4_2));
+/// assert_eq!(features.level(), Some(X86Level::V2));
+/// assert_eq!(format!("{:?}", features), r#"{"popcnt", "sse4.2"}"#);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct X86FeatureSet(u128);
+
+impl X86FeatureSet {
+    /// No features.
+    pub const NONE: X86FeatureSet = X86FeatureSet(0);
+
+    /// Create an empty set.
+    pub fn new() -> Self {
+        X86FeatureSet::default()
+    }
+
+    /// Add a feature to the set.
+    pub fn insert(&mut self, feature: X86Feature) {
+        self.0 |= bit(feature);
+    }
+
+    /// Remove a feature from the set.
+    pub fn remove(&mut self, feature: X86Feature) {
+        self.0 &= !bit(feature);
+    }
+
+    /// Whether the set holds this feature.
+    pub fn contains(&self, feature: X86Feature) -> bool {
+        self.0 & bit(feature) != 0
+    }
+
+    /// Whether every feature in this set is also in `other`.
+    pub fn is_subset(&self, other: X86FeatureSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The number of features in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// The features in the set, in the order of `X86Feature`.
+    pub fn iter(&self) -> impl Iterator<Item = X86Feature> {
+        let bits = self.0;
+        FEATURE_INFO
+            .iter()
+            .map(|info| info.value)
+            .filter(move |&feature| bits & bit(feature) != 0)
+    }
+
+    /// The lowest microarchitecture level that has all of these
+    /// features, or `None` when a feature is not part of any level.
+    /// An empty set is `X86Level::V1`.
+    pub fn level(&self) -> Option<X86Level> {
+        self.iter()
+            .try_fold(X86Level::V1, |level, feature| feature.level().map(|l| l.max(level)))
+    }
+}
+
+impl BitOr for X86Feature {
+    type Output = X86FeatureSet;
+
+    fn bitor(self, other: X86Feature) -> X86FeatureSet {
+        X86FeatureSet(bit(self) | bit(other))
+    }
+}
+
+impl From<X86Feature> for X86FeatureSet {
+    fn from(feature: X86Feature) -> Self {
+        X86FeatureSet(bit(feature))
+    }
+}
+
+impl BitOr for X86FeatureSet {
+    type Output = X86FeatureSet;
+
+    fn bitor(self, other: X86FeatureSet) -> X86FeatureSet {
+        X86FeatureSet(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for X86FeatureSet {
+    fn bitor_assign(&mut self, other: X86FeatureSet) {
+        self.0 |= other.0;
+    }
+}
+
+impl fmt::Debug for X86FeatureSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter().map(X86Feature::name)).finish()
+    }
+}
+
+const fn bit(feature: X86Feature) -> u128 {
+    1 << feature as u32
+}
+
+const fn set(bits: u128) -> X86FeatureSet {
+    X86FeatureSet(bits)
+}
+
+struct FeatureInfo {
+    value: X86Feature,
+    name: &'static str,
+    level: Option<X86Level>,
+}
+
+/// The features that an operation needs in each of its encodings.
+#[derive(Clone, Copy)]
+struct Requirement {
+    /// The legacy, VEX or XOP form.
+    features: X86FeatureSet,
+    /// The form on MMX registers, when it differs.
+    mmx: X86FeatureSet,
+    /// The 256 bit VEX form, when it differs.
+    wide: X86FeatureSet,
+    /// The EVEX form, or nothing when there is none.
+    evex: X86FeatureSet,
+    /// Whether the EVEX form also needs AVX512VL for vectors of less
+    /// than 512 bits.
+    vector: bool,
+}
+
+struct FeatureEntry {
+    operation: InstructionOperation,
+    requirement: Requirement,
+}
+
+fn find_requirement(operation: InstructionOperation) -> Option<&'static Requirement> {
+    FEATURE_REQUIREMENTS
+        .binary_search_by_key(&(operation as i32), |entry| entry.operation as i32)
+        .ok()
+        .map(|i| &FEATURE_REQUIREMENTS[i].requirement)
+}
+
+fn is_vector(reg: OperandType) -> bool {
+    reg.family() >= OperandType::REG_ZMM0 && reg.family() <= OperandType::REG_ZMM31
+}
+
+fn is_mmx(reg: OperandType) -> bool {
+    reg >= OperandType::REG_MM0 && reg <= OperandType::REG_MM7
+}
+
+fn is_extended_gpr(reg: OperandType) -> bool {
+    reg.family() >= OperandType::REG_R16 && reg.family() <= OperandType::REG_R31
+}
+
+impl X86Instruction {
+    /// The features that the processor needs to run this instruction.
+    ///
+    /// This depends on how the instruction is encoded as well as on
+    /// its operation: `PADDD` needs MMX on MMX registers and SSE2 on
+    /// XMM registers, a VEX encoded integer instruction needs AVX2 on
+    /// YMM registers, and an EVEX encoded instruction needs AVX512VL
+    /// on vectors shorter than 512 bits. Any use of APX, such as a
+    /// REX2 prefix or one of the registers `r16` to `r31`, adds
+    /// `X86Feature::APX_F`.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// // popcnt eax, ecx
+    /// let data = [0xf3u8, 0x0f, 0xb8, 0xc1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert_eq!(instr.features(), X86Feature::POPCNT.into());
+    /// assert_eq!(instr.features().level(), Some(X86Level::V2));
+    ///
+    /// // paddd mm0, mm1
+    /// let data = [0x0fu8, 0xfe, 0xc1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert_eq!(instr.features(), X86Feature::MMX.into());
+    ///
+    /// // vpaddd ymm0, ymm0, ymm1
+    /// let data = [0xc5u8, 0xfd, 0xfe, 0xc1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert_eq!(instr.features(), X86Feature::AVX2.into());
+    ///
+    /// // vpaddd xmm0, xmm0, xmm1 with an EVEX prefix
+    /// let data = [0x62u8, 0xf1, 0x7d, 0x08, 0xfe, 0xc1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert_eq!(instr.features(), X86Feature::AVX512F | X86Feature::AVX512VL);
+    /// assert_eq!(instr.features().level(), Some(X86Level::V4));
+    ///
+    /// // aesenc xmm0, xmm1
+    /// let data = [0x66u8, 0x0f, 0x38, 0xdc, 0xc1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert_eq!(instr.features().level(), None);
+    /// ```
+    pub fn features(&self) -> X86FeatureSet {
+        let requirement = if self.is_sse_string_alias() {
+            Some(&SSE2)
+        } else {
+            find_requirement(self.operation)
+        };
+        let mut features = match requirement {
+            Some(requirement) => self.encoded_features(requirement),
+            None => X86FeatureSet::NONE,
+        };
+        let promoted = self.evex.is_some() && requirement.is_none_or(|r| r.evex.is_empty());
+        if promoted || self.uses_extended_gprs() {
+            features.insert(X86Feature::APX_F);
+        }
+        features
+    }
+
+    fn encoded_features(&self, requirement: &Requirement) -> X86FeatureSet {
+        let operands = self.operands();
+        match self.operation {
+            InstructionOperation::LAHF | InstructionOperation::SAHF
+                if self.mode != X86Mode::Bits64 =>
+            {
+                return X86FeatureSet::NONE;
+            }
+            // The form that stores to memory came with SSE4.1.
+            InstructionOperation::PEXTRW
+                if operands.iter().any(|operand| matches!(operand, X86Operand::Memory(_))) =>
+            {
+                return X86Feature::SSE4_1.into();
+            }
+            // The form that broadcasts from a register came with AVX2.
+            InstructionOperation::VBROADCASTSS | InstructionOperation::VBROADCASTSD
+                if self.evex.is_none() &&
+                    matches!(operands.get(1), Some(X86Operand::Register(_))) =>
+            {
+                return X86Feature::AVX2.into();
+            }
+            _ => {}
+        }
+        let has_reg = |test: fn(OperandType) -> bool| {
+            operands.iter().any(|operand| match *operand {
+                X86Operand::Register(reg) => test(reg.register),
+                _ => false,
+            })
+        };
+        if self.evex.is_some() && !requirement.evex.is_empty() {
+            let mut features = requirement.evex;
+            if requirement.vector && self.vector_size() < 64 {
+                features.insert(X86Feature::AVX512VL);
+            }
+            features
+        } else if !requirement.mmx.is_empty() && has_reg(is_mmx) {
+            requirement.mmx
+        } else if !requirement.wide.is_empty() &&
+            has_reg(|reg| is_vector(reg) && reg.size() == 32)
+        {
+            requirement.wide
+        } else {
+            requirement.features
+        }
+    }
+
+    /// The size in bytes of the widest vector that this instruction
+    /// uses, including a broadcast from memory and the index of a
+    /// gather or scatter.
+    fn vector_size(&self) -> u16 {
+        let broadcast = self.evex.and_then(|evex| evex.broadcast).unwrap_or(1);
+        self.operands()
+            .iter()
+            .map(|operand| match *operand {
+                X86Operand::Register(reg) if is_vector(reg.register) => reg.size,
+                X86Operand::Memory(mem) => {
+                    let index = mem.index.filter(|&index| is_vector(index));
+                    (mem.size * u16::from(broadcast)).max(index.map_or(0, OperandType::size))
+                }
+                _ => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether this instruction has a REX2 prefix or uses one of the
+    /// registers `r16` to `r31`.
+    fn uses_extended_gprs(&self) -> bool {
+        let rex2 = self.prefix_bytes().contains(&0xd5);
+        rex2 ||
+            self.operands().iter().any(|operand| match *operand {
+                X86Operand::Register(reg) => is_extended_gpr(reg.register),
+                X86Operand::Memory(mem) => {
+                    mem.base.is_some_and(is_extended_gpr) || mem.index.is_some_and(is_extended_gpr)
+                }
+                _ => false,
+            })
+    }
+}
+
+/// The features used by the instructions in a buffer, along with the
+/// address of the first instruction to use each one.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // popcnt eax, ecx; pminsb xmm0, xmm1; ret
+/// let data = [0xf3u8, 0x0f, 0xb8, 0xc1, 0x66, 0x0f, 0x38, 0x38, 0xc1, 0xc3];
+/// let summary = scan_features(X86Mode::Bits64, &data, 0x1000);
+/// assert_eq!(summary.features(), X86Feature::POPCNT | X86Feature::SSE4_1);
+/// assert_eq!(summary.level(), Some(X86Level::V2));
+/// assert_eq!(summary.first_use(X86Feature::SSE4_1), Some(0x1004));
+/// assert_eq!(summary.first_use(X86Feature::AVX), None);
+///
+/// // A release gate for x86-64-v2.
+/// assert!(summary.features().is_subset(X86Level::V2.features()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct X86FeatureSummary {
+    features: X86FeatureSet,
+    first_use: [Option<u64>; X86_FEATURE_COUNT],
+}
+
+impl Default for X86FeatureSummary {
+    fn default() -> Self {
+        X86FeatureSummary {
+            features: X86FeatureSet::NONE,
+            first_use: [None; X86_FEATURE_COUNT],
+        }
+    }
+}
+
+impl X86FeatureSummary {
+    /// Create an empty summary.
+    pub fn new() -> Self {
+        X86FeatureSummary::default()
+    }
+
+    /// Add the features of an instruction at `addr`.
+    pub fn add(&mut self, addr: u64, instr: &X86Instruction) {
+        let features = instr.features();
+        for feature in features.iter() {
+            self.first_use[feature as usize].get_or_insert(addr);
+        }
+        self.features |= features;
+    }
+
+    /// Every feature that the instructions use.
+    pub fn features(&self) -> X86FeatureSet {
+        self.features
+    }
+
+    /// The lowest microarchitecture level that can run all of the
+    /// instructions, or `None` when one needs a feature that is not
+    /// part of any level.
+    pub fn level(&self) -> Option<X86Level> {
+        self.features.level()
+    }
+
+    /// The address of the first instruction that uses `feature`.
+    pub fn first_use(&self, feature: X86Feature) -> Option<u64> {
+        self.first_use[feature as usize]
+    }
+}
+
+impl Extend<(u64, X86Instruction)> for X86FeatureSummary {
+    fn extend<I: IntoIterator<Item = (u64, X86Instruction)>>(&mut self, iter: I) {
+        for (addr, instr) in iter {
+            self.add(addr, &instr);
+        }
+    }
+}
+
+impl FromIterator<(u64, X86Instruction)> for X86FeatureSummary {
+    fn from_iter<I: IntoIterator<Item = (u64, X86Instruction)>>(iter: I) -> Self {
+        let mut summary = X86FeatureSummary::new();
+        summary.extend(iter);
+        summary
+    }
+}
+
+/// Summarize the features used by the instructions in `bytes`, which
+/// are located at `addr`, decoding them in the given `mode`.
+///
+/// The bytes are decoded one instruction after another, skipping any
+/// byte that does not decode. Data mixed in with the code may decode
+/// as instructions and add features. To choose how invalid bytes are
+/// handled, collect an [`InstructionIter`] into an
+/// [`X86FeatureSummary`] instead.
+///
+/// [`InstructionIter`]: struct.InstructionIter.html
+/// [`X86FeatureSummary`]: struct.X86FeatureSummary.html
+pub fn scan_features(mode: X86Mode, bytes: &[u8], addr: u64) -> X86FeatureSummary {
+    InstructionIter::new(mode, bytes, addr)
+        .with_policy(InvalidBytePolicy::Skip)
+        .collect()
+}
+
+/// The number of `X86Feature` values.
+const X86_FEATURE_COUNT: usize = 67;
+
+static FEATURE_INFO: [FeatureInfo; X86_FEATURE_COUNT] = [
+    FeatureInfo {
+        value: X86Feature::X87,
+        name: "x87",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::CMOV,
+        name: "cmov",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::CX8,
+        name: "cmpxchg8b",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::FXSR,
+        name: "fxsr",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::SYSCALL,
+        name: "syscall",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::MMX,
+        name: "mmx",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::SSE,
+        name: "sse",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::SSE2,
+        name: "sse2",
+        level: Some(X86Level::V1),
+    },
+    FeatureInfo {
+        value: X86Feature::CX16,
+        name: "cmpxchg16b",
+        level: Some(X86Level::V2),
+    },
+    FeatureInfo {
+        value: X86Feature::LAHF_SAHF,
+        name: "sahf",
+        level: Some(X86Level::V2),
+    },
+    FeatureInfo {
+        value: X86Feature::POPCNT,
+        name: "popcnt",
+        level: Some(X86Level::V2),
+    },
+    FeatureInfo {
+        value: X86Feature::SSE3,
+        name: "sse3",
+        level: Some(X86Level::V2),
+    },
+    FeatureInfo {
+        value: X86Feature::SSSE3,
+        name: "ssse3",
+        level: Some(X86Level::V2),
+    },
+    FeatureInfo {
+        value: X86Feature::SSE4_1,
+        name: "sse4.1",
+        level: Some(X86Level::V2),
+    },
+    FeatureInfo {
+        value: X86Feature::SSE4_2,
+        name: "sse4.2",
+        level: Some(X86Level::V2),
+    },
+    FeatureInfo {
+        value: X86Feature::AVX,
+        name: "avx",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::AVX2,
+        name: "avx2",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::BMI1,
+        name: "bmi1",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::BMI2,
+        name: "bmi2",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::F16C,
+        name: "f16c",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::FMA,
+        name: "fma",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::LZCNT,
+        name: "lzcnt",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::MOVBE,
+        name: "movbe",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::XSAVE,
+        name: "xsave",
+        level: Some(X86Level::V3),
+    },
+    FeatureInfo {
+        value: X86Feature::AVX512F,
+        name: "avx512f",
+        level: Some(X86Level::V4),
+    },
+    FeatureInfo {
+        value: X86Feature::AVX512BW,
+        name: "avx512bw",
+        level: Some(X86Level::V4),
+    },
+    FeatureInfo {
+        value: X86Feature::AVX512CD,
+        name: "avx512cd",
+        level: Some(X86Level::V4),
+    },
+    FeatureInfo {
+        value: X86Feature::AVX512DQ,
+        name: "avx512dq",
+        level: Some(X86Level::V4),
+    },
+    FeatureInfo {
+        value: X86Feature::AVX512VL,
+        name: "avx512vl",
+        level: Some(X86Level::V4),
+    },
+    FeatureInfo {
+        value: X86Feature::SEP,
+        name: "sep",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::TSC,
+        name: "tsc",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::MSR,
+        name: "msr",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::CLFSH,
+        name: "clflush",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::MONITOR,
+        name: "monitor",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AMD3DNOW,
+        name: "3dnow",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AMD3DNOWEXT,
+        name: "3dnowa",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::PRFCHW,
+        name: "prfchw",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::PREFETCHWT1,
+        name: "prefetchwt1",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AES,
+        name: "aes",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::PCLMULQDQ,
+        name: "pclmulqdq",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::SHA,
+        name: "sha",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::GFNI,
+        name: "gfni",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::VAES,
+        name: "vaes",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::VPCLMULQDQ,
+        name: "vpclmulqdq",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::FMA4,
+        name: "fma4",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::XOP,
+        name: "xop",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::ADX,
+        name: "adx",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::RDRAND,
+        name: "rdrand",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::RDSEED,
+        name: "rdseed",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::RDPID,
+        name: "rdpid",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::XSAVEOPT,
+        name: "xsaveopt",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::XSAVEC,
+        name: "xsavec",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::XSAVES,
+        name: "xsaves",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::CLFLUSHOPT,
+        name: "clflushopt",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::CLWB,
+        name: "clwb",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::RTM,
+        name: "rtm",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::CET_IBT,
+        name: "ibt",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::CET_SS,
+        name: "shstk",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::VMX,
+        name: "vmx",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::SVM,
+        name: "svm",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::SMX,
+        name: "smx",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AMX_TILE,
+        name: "amx-tile",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AMX_INT8,
+        name: "amx-int8",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AMX_BF16,
+        name: "amx-bf16",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AMX_FP16,
+        name: "amx-fp16",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::AMX_COMPLEX,
+        name: "amx-complex",
+        level: None,
+    },
+    FeatureInfo {
+        value: X86Feature::APX_F,
+        name: "apxf",
+        level: None,
+    },
+];
+
+const X87: Requirement = Requirement {
+    features: set(bit(X86Feature::X87)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CMOV: Requirement = Requirement {
+    features: set(bit(X86Feature::CMOV)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CX8: Requirement = Requirement {
+    features: set(bit(X86Feature::CX8)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CX16: Requirement = Requirement {
+    features: set(bit(X86Feature::CX16)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const FXSR: Requirement = Requirement {
+    features: set(bit(X86Feature::FXSR)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SYSCALL: Requirement = Requirement {
+    features: set(bit(X86Feature::SYSCALL)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SEP: Requirement = Requirement {
+    features: set(bit(X86Feature::SEP)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const TSC: Requirement = Requirement {
+    features: set(bit(X86Feature::TSC)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const MSR: Requirement = Requirement {
+    features: set(bit(X86Feature::MSR)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CLFSH: Requirement = Requirement {
+    features: set(bit(X86Feature::CLFSH)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const MONITOR: Requirement = Requirement {
+    features: set(bit(X86Feature::MONITOR)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const MMX: Requirement = Requirement {
+    features: set(bit(X86Feature::MMX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SSE: Requirement = Requirement {
+    features: set(bit(X86Feature::SSE)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SSE2: Requirement = Requirement {
+    features: set(bit(X86Feature::SSE2)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SSE3: Requirement = Requirement {
+    features: set(bit(X86Feature::SSE3)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SSSE3: Requirement = Requirement {
+    features: set(bit(X86Feature::SSSE3)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SSE4_1: Requirement = Requirement {
+    features: set(bit(X86Feature::SSE4_1)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SSE4_2: Requirement = Requirement {
+    features: set(bit(X86Feature::SSE4_2)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const LAHF_SAHF: Requirement = Requirement {
+    features: set(bit(X86Feature::LAHF_SAHF)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const POPCNT: Requirement = Requirement {
+    features: set(bit(X86Feature::POPCNT)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const LZCNT: Requirement = Requirement {
+    features: set(bit(X86Feature::LZCNT)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const MOVBE: Requirement = Requirement {
+    features: set(bit(X86Feature::MOVBE)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const BMI1: Requirement = Requirement {
+    features: set(bit(X86Feature::BMI1)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const BMI2: Requirement = Requirement {
+    features: set(bit(X86Feature::BMI2)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const ADX: Requirement = Requirement {
+    features: set(bit(X86Feature::ADX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const XSAVE: Requirement = Requirement {
+    features: set(bit(X86Feature::XSAVE)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const XSAVEOPT: Requirement = Requirement {
+    features: set(bit(X86Feature::XSAVEOPT)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const XSAVEC: Requirement = Requirement {
+    features: set(bit(X86Feature::XSAVEC)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const XSAVES: Requirement = Requirement {
+    features: set(bit(X86Feature::XSAVES)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AMD3DNOW: Requirement = Requirement {
+    features: set(bit(X86Feature::AMD3DNOW)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AMD3DNOWEXT: Requirement = Requirement {
+    features: set(bit(X86Feature::AMD3DNOWEXT)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const PRFCHW: Requirement = Requirement {
+    features: set(bit(X86Feature::PRFCHW)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const PREFETCHWT1: Requirement = Requirement {
+    features: set(bit(X86Feature::PREFETCHWT1)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AES: Requirement = Requirement {
+    features: set(bit(X86Feature::AES)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const PCLMULQDQ: Requirement = Requirement {
+    features: set(bit(X86Feature::PCLMULQDQ)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SHA: Requirement = Requirement {
+    features: set(bit(X86Feature::SHA)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const GFNI: Requirement = Requirement {
+    features: set(bit(X86Feature::GFNI)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const RDRAND: Requirement = Requirement {
+    features: set(bit(X86Feature::RDRAND)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const RDSEED: Requirement = Requirement {
+    features: set(bit(X86Feature::RDSEED)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const RDPID: Requirement = Requirement {
+    features: set(bit(X86Feature::RDPID)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CLFLUSHOPT: Requirement = Requirement {
+    features: set(bit(X86Feature::CLFLUSHOPT)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CLWB: Requirement = Requirement {
+    features: set(bit(X86Feature::CLWB)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const RTM: Requirement = Requirement {
+    features: set(bit(X86Feature::RTM)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CET_IBT: Requirement = Requirement {
+    features: set(bit(X86Feature::CET_IBT)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const CET_SS: Requirement = Requirement {
+    features: set(bit(X86Feature::CET_SS)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const VMX: Requirement = Requirement {
+    features: set(bit(X86Feature::VMX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SVM: Requirement = Requirement {
+    features: set(bit(X86Feature::SVM)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SMX: Requirement = Requirement {
+    features: set(bit(X86Feature::SMX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AMX_TILE: Requirement = Requirement {
+    features: set(bit(X86Feature::AMX_TILE)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AMX_INT8: Requirement = Requirement {
+    features: set(bit(X86Feature::AMX_INT8)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AMX_BF16: Requirement = Requirement {
+    features: set(bit(X86Feature::AMX_BF16)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AMX_FP16: Requirement = Requirement {
+    features: set(bit(X86Feature::AMX_FP16)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AMX_COMPLEX: Requirement = Requirement {
+    features: set(bit(X86Feature::AMX_COMPLEX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const APX_F: Requirement = Requirement {
+    features: set(bit(X86Feature::APX_F)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const FMA4: Requirement = Requirement {
+    features: set(bit(X86Feature::FMA4)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const XOP: Requirement = Requirement {
+    features: set(bit(X86Feature::XOP)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const X87_CMOV: Requirement = Requirement {
+    features: set(bit(X86Feature::X87) | bit(X86Feature::CMOV)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const MMX_SSE2: Requirement = Requirement {
+    features: set(bit(X86Feature::SSE2)),
+    mmx: set(bit(X86Feature::MMX)),
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const SSE_SSE2: Requirement = Requirement {
+    features: set(bit(X86Feature::SSE2)),
+    mmx: set(bit(X86Feature::SSE)),
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AVX: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AVX_F: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const AVX_F_SCALAR: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: false,
+};
+const AVX_DQ: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512DQ)),
+    vector: true,
+};
+const AVX_DQ_SCALAR: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512DQ)),
+    vector: false,
+};
+const AVX_BW_SCALAR: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512BW)),
+    vector: false,
+};
+const AVX_AVX2: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: set(bit(X86Feature::AVX2)),
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AVX_AVX2_F: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: set(bit(X86Feature::AVX2)),
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const AVX_AVX2_BW: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: set(bit(X86Feature::AVX2)),
+    evex: set(bit(X86Feature::AVX512BW)),
+    vector: true,
+};
+const AVX2: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX2)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AVX2_F: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX2)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const AVX2_BW: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX2)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512BW)),
+    vector: true,
+};
+const F16C_F: Requirement = Requirement {
+    features: set(bit(X86Feature::F16C)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const FMA_F: Requirement = Requirement {
+    features: set(bit(X86Feature::FMA)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const FMA_F_SCALAR: Requirement = Requirement {
+    features: set(bit(X86Feature::FMA)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: false,
+};
+const AES_AVX: Requirement = Requirement {
+    features: set(bit(X86Feature::AES) | bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: X86FeatureSet::NONE,
+    vector: false,
+};
+const AES_VAES: Requirement = Requirement {
+    features: set(bit(X86Feature::AES) | bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: set(bit(X86Feature::VAES)),
+    evex: set(bit(X86Feature::VAES) | bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const PCLMULQDQ_VPCLMULQDQ: Requirement = Requirement {
+    features: set(bit(X86Feature::PCLMULQDQ) | bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: set(bit(X86Feature::VPCLMULQDQ)),
+    evex: set(bit(X86Feature::VPCLMULQDQ) | bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const GFNI_AVX: Requirement = Requirement {
+    features: set(bit(X86Feature::GFNI) | bit(X86Feature::AVX)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::GFNI) | bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const AVX512F: Requirement = Requirement {
+    features: X86FeatureSet::NONE,
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: true,
+};
+const AVX512F_SCALAR: Requirement = Requirement {
+    features: X86FeatureSet::NONE,
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: false,
+};
+const AVX512BW: Requirement = Requirement {
+    features: X86FeatureSet::NONE,
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512BW)),
+    vector: true,
+};
+const AVX512DQ: Requirement = Requirement {
+    features: X86FeatureSet::NONE,
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512DQ)),
+    vector: true,
+};
+const AVX512DQ_SCALAR: Requirement = Requirement {
+    features: X86FeatureSet::NONE,
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512DQ)),
+    vector: false,
+};
+const AVX512CD: Requirement = Requirement {
+    features: X86FeatureSet::NONE,
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512CD)),
+    vector: true,
+};
+const MASK_F: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX512F)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512F)),
+    vector: false,
+};
+const MASK_BW: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX512BW)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512BW)),
+    vector: false,
+};
+const MASK_DQ: Requirement = Requirement {
+    features: set(bit(X86Feature::AVX512DQ)),
+    mmx: X86FeatureSet::NONE,
+    wide: X86FeatureSet::NONE,
+    evex: set(bit(X86Feature::AVX512DQ)),
+    vector: false,
+};
+
+static FEATURE_REQUIREMENTS: [FeatureEntry; 1216] = [
+    FeatureEntry {
+        operation: InstructionOperation::BLENDPD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BLENDPS,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BLENDVPD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BLENDVPS,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CLFLUSH,
+        requirement: CLFSH,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMPXCH16B,
+        requirement: CX16,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMPXCH8B,
+        requirement: CX8,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CRC32,
+        requirement: SSE4_2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::DPPD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::DPPS,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::EMMS,
+        requirement: MMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::F2XM1,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FABS,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FADD,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FADDP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FBLD,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FBSTP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCHS,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCLEX,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVB,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVBE,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVE,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVNB,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVNBE,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVNE,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVNU,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCMOVU,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCOM,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCOMI,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCOMIP,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCOMP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCOMPP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FCOS,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FDECSTP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FDISI,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FDIV,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FDIVP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FDIVR,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FDIVRP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FEMMS,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FENI,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FFREE,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FFREEP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FIADD,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FICOM,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FICOMP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FIDIV,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FIDIVR,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FILD,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FIMUL,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FINCSTP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FINIT,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FIST,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FISTP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FISTTP,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FISUB,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FISUBR,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLD,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLD1,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDCW,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDENV,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDL2E,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDL2T,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDLG2,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDLN2,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDPI,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FLDZ,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FMUL,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FMULP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FNOP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FPATAN,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FPREM,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FPREM1,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FPTAN,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FRICHOP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FRINEAR,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FRINT2,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FRNDINT,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FRSTOR,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FRSTPM,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSAVE,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSCALE,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSETPM,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSIN,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSINCOS,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSQRT,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FST,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSTCW,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSTDW,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSTENV,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSTP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSTSG,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSTSW,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSUB,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSUBP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSUBR,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FSUBRP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FTST,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FUCOM,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FUCOMI,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FUCOMIP,
+        requirement: X87_CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FUCOMP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FUCOMPP,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FWAIT,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FXAM,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FXCH,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FXRSTOR,
+        requirement: FXSR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FXSAVE,
+        requirement: FXSR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FXTRACT,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FYL2X,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::FYL2XP1,
+        requirement: X87,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::GETSEC,
+        requirement: SMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::LAHF,
+        requirement: LAHF_SAHF,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::LDMXCSR,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::LFENCE,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MFENCE,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVNTI,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVUPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVUPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MPSADBW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PACKSSDW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PACKSSWB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PACKUSDW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PACKUSWB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PABSB,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PABSD,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PABSW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDSB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDSW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDUSB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PADDUSW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PALIGNR,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PAND,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PANDN,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PAVGB,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PAVGUSB,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PAVGW,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PBLENDVB,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PBLENDW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPEQB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPEQD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPEQQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPEQW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPESTRI,
+        requirement: SSE4_2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPESTRM,
+        requirement: SSE4_2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPGTB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPGTD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPGTQ,
+        requirement: SSE4_2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPGTW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPISTRI,
+        requirement: SSE4_2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCMPISTRM,
+        requirement: SSE4_2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PF2ID,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PF2IW,
+        requirement: AMD3DNOWEXT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFACC,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFADD,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFCMPEQ,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFCMPGE,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFCMPGT,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFMAX,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFMIN,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFMUL,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFNACC,
+        requirement: AMD3DNOWEXT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFPNACC,
+        requirement: AMD3DNOWEXT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFRCP,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFRCPIT1,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFRCPIT2,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFRCPV,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFRSQIT1,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFRSQRT,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFRSQRTV,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFSUB,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PFSUBR,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PHADDD,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PHADDSW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PHADDW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PHMINPOSUW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PHSUBD,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PHSUBSW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PHSUBW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PI2FD,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PI2FW,
+        requirement: AMD3DNOWEXT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMADDWD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMADDUBSW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMAXSB,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMAXSD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMAXSW,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMAXUB,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMAXUD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMAXUW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMINSB,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMINSD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMINSW,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMINUB,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMINUD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMINUW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULDQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULHRSW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULHRW,
+        requirement: AMD3DNOW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULHUW,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULHW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULLD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULLW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMULUDQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::POPCNT,
+        requirement: POPCNT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::POR,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSADBW,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSHUFB,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSIGNB,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSIGND,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSIGNW,
+        requirement: SSSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSLLD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSLLDQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSLLQ,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSLLW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSRAD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSRAW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSRLD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSRLDQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSRLQ,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSRLW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBSB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBSW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBUSB,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSUBUSW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSWAPD,
+        requirement: AMD3DNOWEXT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PTEST,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKHBW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKHDQ,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKHQDQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKHWD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKLQDQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PXOR,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RDMSR,
+        requirement: MSR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RDTSC,
+        requirement: TSC,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ROUNDPS,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ROUNDPD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SAHF,
+        requirement: LAHF_SAHF,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SFENCE,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::STMXCSR,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SYSCALL,
+        requirement: SYSCALL,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SYSENTER,
+        requirement: SEP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SYSEXIT,
+        requirement: SEP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SYSRET,
+        requirement: SYSCALL,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMREAD,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMWRITE,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::WRMSR,
+        requirement: MSR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XRSTOR,
+        requirement: XSAVE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XSAVE,
+        requirement: XSAVE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADDPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADDPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADDSD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADDSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADDSUBPD,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADDSUBPS,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ANDNPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ANDNPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ANDPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ANDPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVO,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVNO,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVB,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVAE,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVE,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVNE,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVBE,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVA,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVS,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVNS,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVPE,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVPO,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVL,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVGE,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVLE,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMOVG,
+        requirement: CMOV,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::DIVPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::DIVPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::DIVSD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::DIVSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MAXPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MAXPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MAXSD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MAXSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MINPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MINPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MINSD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MINSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVQ,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MULPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MULPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MULSD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MULSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ORPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ORPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PEXTRD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PEXTRQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PINSRD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PINSRQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RCPPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RCPSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RSQRTPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RSQRTSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SQRTPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SQRTPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SQRTSD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SQRTSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SUBPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SUBPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SUBSD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SUBSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XORPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XORPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMPPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMPPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CMPSS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::COMISD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::COMISS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTDQ2PD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTDQ2PS,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPD2DQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPD2PI,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPD2PS,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPI2PD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPI2PS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPS2DQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPS2PD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTPS2PI,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTSD2SI,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTSD2SS,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTSI2SD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTSI2SS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTSS2SD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTSS2SI,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTTPD2DQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTTPD2PI,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTTPS2DQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTTPS2PI,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTTSD2SI,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CVTTSS2SI,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::EXTRACTPS,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::HADDPD,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::HADDPS,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::HSUBPD,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::HSUBPS,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::INSERTPS,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::LDDQU,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MASKMOVQ,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MASKMOVDQU,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MONITOR,
+        requirement: MONITOR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVAPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVAPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVDDUP,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVDQ2Q,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVDQA,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVDQU,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVHLPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVHPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVHPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVSHDUP,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVSLDUP,
+        requirement: SSE3,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVLHPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVLPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVLPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVMSKPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVMSKPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVNTDQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVNTDQA,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVNTPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVNTPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVNTQ,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVQ2DQ,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MWAIT,
+        requirement: MONITOR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PINSRB,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PINSRW,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PEXTRB,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PEXTRW,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVMSKB,
+        requirement: SSE_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVSXBD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVSXBQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVSXDQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVSXBW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVSXWD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVSXWQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVZXBD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVZXBQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVZXDQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVZXBW,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVZXWD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PMOVZXWQ,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PREFETCH,
+        requirement: PRFCHW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PREFETCHNTA,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PREFETCHT0,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PREFETCHT1,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PREFETCHT2,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PREFETCHW,
+        requirement: PRFCHW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSHUFD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSHUFHW,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSHUFLW,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PSHUFW,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKLBW,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKLDQ,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUNPCKLWD,
+        requirement: MMX_SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ROUNDSD,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ROUNDSS,
+        requirement: SSE4_1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHUFPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHUFPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::UCOMISD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::UCOMISS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::UNPCKHPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::UNPCKHPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::UNPCKLPD,
+        requirement: SSE2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::UNPCKLPS,
+        requirement: SSE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMCALL,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMCLEAR,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMLAUNCH,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMPTRLD,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMPTRST,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMRESUME,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMXOFF,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMXON,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XGETBV,
+        requirement: XSAVE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XSETBV,
+        requirement: XSAVE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBLENDPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBLENDPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VDPPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VDPPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VLDMXCSR,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVUPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVUPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMPSADBW,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPACKSSDW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPACKSSWB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPACKUSDW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPACKUSWB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPABSB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPABSD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPABSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDSB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDUSB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPADDUSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPALIGNR,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPAND,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPANDN,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPAVGB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPAVGW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBLENDW,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPEQB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPEQD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPEQQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPEQW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPESTRI,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPESTRM,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPGTB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPGTD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPGTQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPGTW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPISTRI,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPISTRM,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDD,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDSW,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDW,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHMINPOSUW,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHSUBD,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHSUBSW,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHSUBW,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMADDWD,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMADDUBSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXSB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXSD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXUB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXUD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXUW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINSB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINSD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINUB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINUD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINUW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULHRSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULHUW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULHW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULLD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULLW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULUDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPOR,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSADBW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHUFB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSIGNB,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSIGND,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSIGNW,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSLLD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSLLDQ,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSLLQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSLLW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRAD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRAW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRLD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRLDQ,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRLQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRLW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBSB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBUSB,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSUBUSW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTEST,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKHBW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKHDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKHQDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKHWD,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKLQDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPXOR,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VROUNDPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VROUNDPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSTMXCSR,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VADDPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VADDPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VADDSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VADDSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VADDSUBPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VADDSUBPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VANDNPS,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VANDNPD,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VANDPS,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VANDPD,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCMPSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VDIVPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VDIVPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VDIVSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VDIVSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMAXPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMAXPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMAXSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMAXSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMINPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMINPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMINSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMINSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMULPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMULPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMULSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMULSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VORPS,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VORPD,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPEXTRD,
+        requirement: AVX_DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPEXTRQ,
+        requirement: AVX_DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPINSRD,
+        requirement: AVX_DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPINSRQ,
+        requirement: AVX_DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRCPPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRCPSS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRSQRTPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRSQRTSS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSQRTPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSQRTPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSQRTSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSQRTSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSUBPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSUBPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSUBSD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSUBSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VXORPS,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VXORPD,
+        requirement: AVX_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCMPPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCMPPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCMPSS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCOMISD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCOMISS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTDQ2PD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTDQ2PS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPD2DQ,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPD2PS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPS2DQ,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPS2PD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSD2SI,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSD2SS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSI2SD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSI2SS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSS2SD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSS2SI,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPD2DQ,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPS2DQ,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTSD2SI,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTSS2SI,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTPS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VHADDPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VHADDPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VHSUBPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VHSUBPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTPS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VLDDQU,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMASKMOVDQU,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVAPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVAPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDDUP,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQA,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQU,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVHLPS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVHPD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVHPS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVSHDUP,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVSLDUP,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVLHPS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVLPD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVLPS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVMSKPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVMSKPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVNTDQ,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVNTDQA,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVNTPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVNTPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPINSRB,
+        requirement: AVX_BW_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPINSRW,
+        requirement: AVX_BW_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPEXTRB,
+        requirement: AVX_BW_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPEXTRW,
+        requirement: AVX_BW_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVMSKB,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSXBD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSXBQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSXDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSXBW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSXWD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSXWQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVZXBD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVZXBQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVZXDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVZXBW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVZXWD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVZXWQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHUFD,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHUFHW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHUFLW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKLBW,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKLDQ,
+        requirement: AVX_AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPUNPCKLWD,
+        requirement: AVX_AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VROUNDSD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VROUNDSS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSHUFPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSHUFPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VUCOMISD,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VUCOMISS,
+        requirement: AVX_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VUNPCKHPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VUNPCKHPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VUNPCKLPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VUNPCKLPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VZEROUPPER,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VZEROALL,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMILPS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMILPD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VTESTPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VTESTPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPH2PS,
+        requirement: F16C_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPS2PH,
+        requirement: F16C_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMPS,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMQ,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMPD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTSS,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTSD,
+        requirement: AVX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTF128,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTI128,
+        requirement: AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBROADCASTB,
+        requirement: AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBROADCASTW,
+        requirement: AVX2_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBROADCASTD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBROADCASTQ,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMASKMOVPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMASKMOVPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMASKMOVD,
+        requirement: AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMASKMOVQ,
+        requirement: AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRLVD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRLVQ,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRAVD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSLLVD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSLLVQ,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPGATHERDD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPGATHERDQ,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPGATHERQD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPGATHERQQ,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGATHERDPS,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGATHERDPD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGATHERQPS,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGATHERQPD,
+        requirement: AVX2_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBLENDD,
+        requirement: AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERM2F128,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERM2I128,
+        requirement: AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTF128,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTF128,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTI128,
+        requirement: AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTI128,
+        requirement: AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBLENDVPS,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBLENDVPD,
+        requirement: AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBLENDVB,
+        requirement: AVX_AVX2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTQQ2PS,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQA32,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQA64,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQU32,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQU64,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRAQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPANDD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPANDQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPANDND,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPANDNQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPORD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPORQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPXORD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPXORQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINSQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMINUQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXSQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMAXUQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMULLQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTQQ2PD,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRNDSCALEPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRNDSCALEPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRNDSCALESS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRNDSCALESD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VDBPSADBW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQU8,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMOVDQU16,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPS2UDQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPD2UDQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPS2UQQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPD2UQQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTSD2USI,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTSS2USI,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPS2UDQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPD2UDQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPS2UQQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPD2UQQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSD2USI,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTSS2USI,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPS2QQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTTPD2QQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTUDQ2PS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTUQQ2PS,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTUDQ2PD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTUQQ2PD,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPS2QQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTPD2QQ,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTUSI2SD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCVTUSI2SS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRLVW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVUSWB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRAVW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVUSDB,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSLLVW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVUSQB,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVUSDW,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPRORVD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPRORVQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVUSQW,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROLVD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROLVQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVUSQD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTF32X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTF32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTF64X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTF32X8,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTF64X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPABSQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSWB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSDB,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSQB,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSDW,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSQW,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVSQD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTMB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTMW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTNMB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTNMW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTMD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTMQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTNMD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTESTNMQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVM2B,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVM2W,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVB2M,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVW2M,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBROADCASTMB2Q,
+        requirement: AVX512CD,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCALEFPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCALEFPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCALEFSS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCALEFSD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVWB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVDB,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVQB,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVDW,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVQW,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVQD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVM2D,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVM2Q,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVD2M,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMOVQ2M,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBROADCASTMW2D,
+        requirement: AVX512CD,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETEXPPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETEXPPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETEXPSS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETEXPSD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPLZCNTD,
+        requirement: AVX512CD,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPLZCNTQ,
+        requirement: AVX512CD,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSRAVQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRCP14PS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRCP14PD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRCP14SS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRCP14SD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRSQRT14PS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRSQRT14PD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRSQRT14SS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRSQRT14SD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTI32X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTI32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTI64X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTI32X8,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBROADCASTI64X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBLENDMD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBLENDMQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBLENDMPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VBLENDMPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBLENDMB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPBLENDMW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMI2W,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMI2D,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMI2Q,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMI2PS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMI2PD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMT2W,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMT2D,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMT2Q,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMT2PS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMT2PD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXPANDPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXPANDPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPEXPANDD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPEXPANDQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCOMPRESSPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VCOMPRESSPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMPRESSD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMPRESSQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPERMW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSCATTERDD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSCATTERDQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSCATTERQD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSCATTERQQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCATTERDPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCATTERDPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCATTERQPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSCATTERQPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCONFLICTD,
+        requirement: AVX512CD,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCONFLICTQ,
+        requirement: AVX512CD,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VALIGND,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VALIGNQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTF32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTF64X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTF32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTF64X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTF32X8,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTF64X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTF32X8,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTF64X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPUD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPUQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSHUFF32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSHUFF64X2,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTERNLOGD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPTERNLOGQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETMANTPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETMANTPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETMANTSS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGETMANTSD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTI32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTI64X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTI32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTI64X2,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTI32X8,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VINSERTI64X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTI32X8,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VEXTRACTI64X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPUB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPUW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPB,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMPW,
+        requirement: AVX512BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSHUFI32X4,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VSHUFI64X2,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRANGEPS,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRANGEPD,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRANGESS,
+        requirement: AVX512DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VRANGESD,
+        requirement: AVX512DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFIXUPIMMPS,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFIXUPIMMPD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFIXUPIMMSS,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFIXUPIMMSD,
+        requirement: AVX512F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VREDUCEPS,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VREDUCEPD,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VREDUCESS,
+        requirement: AVX512DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VREDUCESD,
+        requirement: AVX512DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFPCLASSPS,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFPCLASSPD,
+        requirement: AVX512DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFPCLASSSS,
+        requirement: AVX512DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFPCLASSSD,
+        requirement: AVX512DQ_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPRORD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPRORQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROLD,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROLQ,
+        requirement: AVX512F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDNW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDNQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDNB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KANDND,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXNORW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXNORQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXNORB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXNORD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXORW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXORQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXORB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KXORD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KADDW,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KADDQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KADDB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KADDD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KNOTW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KNOTQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KNOTB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KNOTD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KUNPCKWD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KUNPCKDQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KUNPCKBW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KMOVW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KMOVQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KMOVB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KMOVD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORTESTW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORTESTQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORTESTB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KORTESTD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KTESTW,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KTESTQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KTESTB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KTESTD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTRB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTRW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTRD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTRQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTLB,
+        requirement: MASK_DQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTLW,
+        requirement: MASK_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTLD,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::KSHIFTLQ,
+        requirement: MASK_BW,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUB132PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUB132PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADD132PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADD132PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD132PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD132PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD132SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD132SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB132PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB132PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB132SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB132SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD132PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD132PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD132SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD132SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB132PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB132PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB132SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB132SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUB213PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUB213PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADD213PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADD213PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD213PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD213PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD213SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD213SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB213PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB213PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB213SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB213SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD213PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD213PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD213SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD213SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB213PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB213PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB213SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB213SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUB231PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUB231PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADD231PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADD231PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD231PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD231PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD231SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADD231SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB231PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB231PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB231SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUB231SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD231PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD231PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD231SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADD231SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB231PS,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB231PD,
+        requirement: FMA_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB231SS,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUB231SD,
+        requirement: FMA_F_SCALAR,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUBPS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSUBPD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADDPS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBADDPD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDPS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDPD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMADDSD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBPS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBPD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBSS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFMSUBSD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADDPS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADDPD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADDSS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMADDSD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUBPS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUBPD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUBSS,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFNMSUBSD,
+        requirement: FMA4,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSSWW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSSWD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSSDQL,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSSDD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSSDQH,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSWW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSWD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSDQL,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSDD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMACSDQH,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMADCSSWD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPMADCSWD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCMOV,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPPERM,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROTB,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROTW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROTD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPROTQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMB,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMUB,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMUW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMUD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCOMUQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFRCZPS,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFRCZPD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFRCZSS,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VFRCZSD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHLB,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHLW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHLD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHLQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHAB,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHAW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHAD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPSHAQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDBW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDBD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDBQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDWD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDWQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDDQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDUBW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDUBD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDUBQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDUWD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDUWQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHADDUDQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHSUBBW,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHSUBWD,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPHSUBDQ,
+        requirement: XOP,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TZCNT,
+        requirement: BMI1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::LZCNT,
+        requirement: LZCNT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MOVBE,
+        requirement: MOVBE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADCX,
+        requirement: ADX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ADOX,
+        requirement: ADX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ANDN,
+        requirement: BMI1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BEXTR,
+        requirement: BMI1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BLSR,
+        requirement: BMI1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BLSMSK,
+        requirement: BMI1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BLSI,
+        requirement: BMI1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::BZHI,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::MULX,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PDEP,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PEXT,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RORX,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SARX,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHLX,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHRX,
+        requirement: BMI2,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::AESIMC,
+        requirement: AES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::AESENC,
+        requirement: AES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::AESENCLAST,
+        requirement: AES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::AESDEC,
+        requirement: AES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::AESDECLAST,
+        requirement: AES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::AESKEYGENASSIST,
+        requirement: AES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PCLMULQDQ,
+        requirement: PCLMULQDQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHA1RNDS4,
+        requirement: SHA,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHA1NEXTE,
+        requirement: SHA,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHA1MSG1,
+        requirement: SHA,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHA1MSG2,
+        requirement: SHA,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHA256RNDS2,
+        requirement: SHA,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHA256MSG1,
+        requirement: SHA,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SHA256MSG2,
+        requirement: SHA,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::GF2P8MULB,
+        requirement: GFNI,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::GF2P8AFFINEQB,
+        requirement: GFNI,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::GF2P8AFFINEINVQB,
+        requirement: GFNI,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VAESIMC,
+        requirement: AES_AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VAESENC,
+        requirement: AES_VAES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VAESENCLAST,
+        requirement: AES_VAES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VAESDEC,
+        requirement: AES_VAES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VAESDECLAST,
+        requirement: AES_VAES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VAESKEYGENASSIST,
+        requirement: AES_AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VPCLMULQDQ,
+        requirement: PCLMULQDQ_VPCLMULQDQ,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGF2P8MULB,
+        requirement: GFNI_AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGF2P8AFFINEQB,
+        requirement: GFNI_AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VGF2P8AFFINEINVQB,
+        requirement: GFNI_AVX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ENDBR32,
+        requirement: CET_IBT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::ENDBR64,
+        requirement: CET_IBT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::INCSSPD,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::INCSSPQ,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RDSSPD,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RDSSPQ,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SAVEPREVSSP,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RSTORSSP,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::WRSSD,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::WRSSQ,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::WRUSSD,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::WRUSSQ,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SETSSBSY,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CLRSSBSY,
+        requirement: CET_SS,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XBEGIN,
+        requirement: RTM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XEND,
+        requirement: RTM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XABORT,
+        requirement: RTM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XTEST,
+        requirement: RTM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RDRAND,
+        requirement: RDRAND,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RDSEED,
+        requirement: RDSEED,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::RDPID,
+        requirement: RDPID,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XSAVEOPT,
+        requirement: XSAVEOPT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XSAVEC,
+        requirement: XSAVEC,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XSAVES,
+        requirement: XSAVES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::XRSTORS,
+        requirement: XSAVES,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CLFLUSHOPT,
+        requirement: CLFLUSHOPT,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CLWB,
+        requirement: CLWB,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PREFETCHWT1,
+        requirement: PREFETCHWT1,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::INVEPT,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::INVVPID,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMFUNC,
+        requirement: VMX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMRUN,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMMCALL,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMLOAD,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::VMSAVE,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::STGI,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::CLGI,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::SKINIT,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::INVLPGA,
+        requirement: SVM,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::JMPABS,
+        requirement: APX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUSHP,
+        requirement: APX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::POPP,
+        requirement: APX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUSH2,
+        requirement: APX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::PUSH2P,
+        requirement: APX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::POP2,
+        requirement: APX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::POP2P,
+        requirement: APX_F,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::LDTILECFG,
+        requirement: AMX_TILE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::STTILECFG,
+        requirement: AMX_TILE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TILERELEASE,
+        requirement: AMX_TILE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TILEZERO,
+        requirement: AMX_TILE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TILELOADD,
+        requirement: AMX_TILE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TILELOADDT1,
+        requirement: AMX_TILE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TILESTORED,
+        requirement: AMX_TILE,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TDPBSSD,
+        requirement: AMX_INT8,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TDPBSUD,
+        requirement: AMX_INT8,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TDPBUSD,
+        requirement: AMX_INT8,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TDPBUUD,
+        requirement: AMX_INT8,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TDPBF16PS,
+        requirement: AMX_BF16,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TDPFP16PS,
+        requirement: AMX_FP16,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TCMMIMFP16PS,
+        requirement: AMX_COMPLEX,
+    },
+    FeatureEntry {
+        operation: InstructionOperation::TCMMRLFP16PS,
+        requirement: AMX_COMPLEX,
+    },
+];
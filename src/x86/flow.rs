@@ -0,0 +1,257 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{InstructionOperation, Rflags, X86Instruction, X86Operand};
+
+/// The condition of a conditional branch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum X86Condition {
+    /// `OF` is set, as for `JO`.
+    Overflow,
+    /// `OF` is clear, as for `JNO`.
+    NotOverflow,
+    /// `CF` is set, as for `JB`.
+    Below,
+    /// `CF` is clear, as for `JAE`.
+    AboveOrEqual,
+    /// `ZF` is set, as for `JE`.
+    Equal,
+    /// `ZF` is clear, as for `JNE`.
+    NotEqual,
+    /// `CF` or `ZF` is set, as for `JBE`.
+    BelowOrEqual,
+    /// `CF` and `ZF` are clear, as for `JA`.
+    Above,
+    /// `SF` is set, as for `JS`.
+    Sign,
+    /// `SF` is clear, as for `JNS`.
+    NotSign,
+    /// `PF` is set, as for `JPE`.
+    ParityEven,
+    /// `PF` is clear, as for `JPO`.
+    ParityOdd,
+    /// `SF` differs from `OF`, as for `JL`.
+    Less,
+    /// `SF` equals `OF`, as for `JGE`.
+    GreaterOrEqual,
+    /// `ZF` is set or `SF` differs from `OF`, as for `JLE`.
+    LessOrEqual,
+    /// `ZF` is clear and `SF` equals `OF`, as for `JG`.
+    Greater,
+    /// The counter register is zero, as for `JCXZ`, `JECXZ` and
+    /// `JRCXZ`.
+    CounterZero,
+    /// The counter register is not zero after it is decremented, as
+    /// for `LOOP`.
+    CounterNotZero,
+    /// The counter register is not zero after it is decremented and
+    /// `ZF` is set, as for `LOOPE`.
+    CounterNotZeroAndEqual,
+    /// The counter register is not zero after it is decremented and
+    /// `ZF` is clear, as for `LOOPNE`.
+    CounterNotZeroAndNotEqual,
+    /// A transaction started by `XBEGIN` aborts, which branches to
+    /// the fallback code.
+    TransactionAbort,
+}
+
+impl X86Condition {
+    /// The flags that the condition tests.
+    pub fn flags(self) -> Rflags {
+        match self {
+            X86Condition::Overflow | X86Condition::NotOverflow => Rflags::OF,
+            X86Condition::Below | X86Condition::AboveOrEqual => Rflags::CF,
+            X86Condition::Equal |
+            X86Condition::NotEqual |
+            X86Condition::CounterNotZeroAndEqual |
+            X86Condition::CounterNotZeroAndNotEqual => Rflags::ZF,
+            X86Condition::BelowOrEqual | X86Condition::Above => Rflags::CF | Rflags::ZF,
+            X86Condition::Sign | X86Condition::NotSign => Rflags::SF,
+            X86Condition::ParityEven | X86Condition::ParityOdd => Rflags::PF,
+            X86Condition::Less | X86Condition::GreaterOrEqual => Rflags::SF | Rflags::OF,
+            X86Condition::LessOrEqual | X86Condition::Greater => {
+                Rflags::ZF | Rflags::SF | Rflags::OF
+            }
+            X86Condition::CounterZero |
+            X86Condition::CounterNotZero |
+            X86Condition::TransactionAbort => Rflags::NONE,
+        }
+    }
+}
+
+/// Where execution goes after an instruction.
+///
+/// A far call or jump to a constant segment and offset is indirect,
+/// as the address that it reaches depends on the segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum X86Flow {
+    /// The next instruction.
+    Fallthrough,
+    /// An unconditional jump to a known address.
+    Jump {
+        /// The address of the jump's target.
+        target: u64,
+    },
+    /// A jump to a known address when a condition holds, and to the
+    /// next instruction otherwise.
+    ConditionalJump {
+        /// The address of the jump's target.
+        target: u64,
+        /// When the jump is taken.
+        condition: X86Condition,
+    },
+    /// A call to a known address, which usually returns to the next
+    /// instruction.
+    Call {
+        /// The address of the called function.
+        target: u64,
+    },
+    /// A jump to an address held in a register or in memory, or the
+    /// jump to the fallback code of a transaction by `XABORT`.
+    IndirectJump,
+    /// A call to an address held in a register or in memory.
+    IndirectCall,
+    /// A return to the address on the stack, or to the code that made
+    /// a system call or was interrupted. This includes `RSM`, which
+    /// leaves system management mode.
+    Return {
+        /// The number of bytes of arguments that are removed from the
+        /// stack after the return address.
+        pop_bytes: u16,
+    },
+    /// A software interrupt or an instruction that always raises an
+    /// exception, such as `UD2`. Execution usually resumes at the next
+    /// instruction once the handler returns.
+    Interrupt,
+    /// A call into the operating system or hypervisor, which returns
+    /// to the next instruction. This includes `VMLAUNCH` and
+    /// `VMRESUME`, which only continue at the next instruction when
+    /// they fail to enter the guest.
+    SystemCall,
+    /// The processor stops until an interrupt arrives.
+    Halt,
+}
+
+impl X86Instruction {
+    /// Where execution goes after this instruction.
+    ///
+    /// The targets of relative branches are the absolute addresses
+    /// that were computed from the `addr` given when the instruction
+    /// was decoded.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// // jbe 0x1010
+    /// let data = [0x76u8, 0x0e];
+    /// let instr = disassemble_64(&data, 0x1000, data.len()).unwrap();
+    /// assert_eq!(instr.flow(), X86Flow::ConditionalJump {
+    ///     target: 0x1010,
+    ///     condition: X86Condition::BelowOrEqual,
+    /// });
+    ///
+    /// // call 0x2000
+    /// let data = [0xe8u8, 0xfb, 0x0f, 0x00, 0x00];
+    /// let instr = disassemble_64(&data, 0x1000, data.len()).unwrap();
+    /// assert_eq!(instr.flow(), X86Flow::Call { target: 0x2000 });
+    ///
+    /// // jmp qword [rax*8+0x4000]
+    /// let data = [0xffu8, 0x24, 0xc5, 0x00, 0x40, 0x00, 0x00];
+    /// let instr = disassemble_64(&data, 0x1000, data.len()).unwrap();
+    /// assert_eq!(instr.flow(), X86Flow::IndirectJump);
+    ///
+    /// // ret 0x8
+    /// let data = [0xc2u8, 0x08, 0x00];
+    /// let instr = disassemble_64(&data, 0x1000, data.len()).unwrap();
+    /// assert_eq!(instr.flow(), X86Flow::Return { pop_bytes: 8 });
+    ///
+    /// // None of these continue at the next instruction.
+    /// let flow = |data: &[u8]| disassemble_64(data, 0x1000, data.len()).unwrap().flow();
+    /// assert_eq!(flow(&[0x0f, 0xaa]), X86Flow::Return { pop_bytes: 0 }); // rsm
+    /// assert_eq!(flow(&[0x0f, 0x01, 0xc2]), X86Flow::SystemCall); // vmlaunch
+    /// assert_eq!(flow(&[0x0f, 0x01, 0xc3]), X86Flow::SystemCall); // vmresume
+    /// assert_eq!(flow(&[0xc6, 0xf8, 0x05]), X86Flow::IndirectJump); // xabort 0x5
+    /// assert_eq!(flow(&[0x0f, 0x0b]), X86Flow::Interrupt); // ud2
+    /// assert_eq!(flow(&[0x0f, 0xb9, 0xc1]), X86Flow::Interrupt); // ud1 eax, ecx
+    /// assert_eq!(flow(&[0x0f, 0xff, 0xc1]), X86Flow::Interrupt); // ud0 eax, ecx
+    /// ```
+    pub fn flow(&self) -> X86Flow {
+        let target = self.operands().iter().find_map(|operand| match *operand {
+            X86Operand::RelativeTarget(target) => Some(target),
+            _ => None,
+        });
+        let condition = match self.operation {
+            InstructionOperation::JO => X86Condition::Overflow,
+            InstructionOperation::JNO => X86Condition::NotOverflow,
+            InstructionOperation::JB => X86Condition::Below,
+            InstructionOperation::JAE => X86Condition::AboveOrEqual,
+            InstructionOperation::JE => X86Condition::Equal,
+            InstructionOperation::JNE => X86Condition::NotEqual,
+            InstructionOperation::JBE => X86Condition::BelowOrEqual,
+            InstructionOperation::JA => X86Condition::Above,
+            InstructionOperation::JS => X86Condition::Sign,
+            InstructionOperation::JNS => X86Condition::NotSign,
+            InstructionOperation::JPE => X86Condition::ParityEven,
+            InstructionOperation::JPO => X86Condition::ParityOdd,
+            InstructionOperation::JL => X86Condition::Less,
+            InstructionOperation::JGE => X86Condition::GreaterOrEqual,
+            InstructionOperation::JLE => X86Condition::LessOrEqual,
+            InstructionOperation::JG => X86Condition::Greater,
+            InstructionOperation::JCXZ |
+            InstructionOperation::JECXZ |
+            InstructionOperation::JRCXZ => X86Condition::CounterZero,
+            InstructionOperation::LOOP => X86Condition::CounterNotZero,
+            InstructionOperation::LOOPE => X86Condition::CounterNotZeroAndEqual,
+            InstructionOperation::LOOPNE => X86Condition::CounterNotZeroAndNotEqual,
+            InstructionOperation::XBEGIN => X86Condition::TransactionAbort,
+            _ => return self.unconditional_flow(target),
+        };
+        match target {
+            Some(target) => X86Flow::ConditionalJump { target, condition },
+            None => X86Flow::Fallthrough,
+        }
+    }
+
+    fn unconditional_flow(&self, target: Option<u64>) -> X86Flow {
+        match self.operation {
+            InstructionOperation::JMP | InstructionOperation::JMPABS => match target {
+                Some(target) => X86Flow::Jump { target },
+                None => X86Flow::IndirectJump,
+            },
+            InstructionOperation::JMPF | InstructionOperation::XABORT => X86Flow::IndirectJump,
+            InstructionOperation::CALL => match target {
+                Some(target) => X86Flow::Call { target },
+                None => X86Flow::IndirectCall,
+            },
+            InstructionOperation::CALLF => X86Flow::IndirectCall,
+            InstructionOperation::RETN | InstructionOperation::RETF => {
+                let pop_bytes = match self.operands().first() {
+                    Some(&X86Operand::Immediate(imm)) => imm.value as u16,
+                    _ => 0,
+                };
+                X86Flow::Return { pop_bytes }
+            }
+            InstructionOperation::IRET |
+            InstructionOperation::RSM |
+            InstructionOperation::SYSRET |
+            InstructionOperation::SYSEXIT => X86Flow::Return { pop_bytes: 0 },
+            InstructionOperation::INT |
+            InstructionOperation::INT1 |
+            InstructionOperation::INT3 |
+            InstructionOperation::INTO |
+            InstructionOperation::UD0 |
+            InstructionOperation::UD1 |
+            InstructionOperation::UD2 => X86Flow::Interrupt,
+            InstructionOperation::SYSCALL |
+            InstructionOperation::SYSENTER |
+            InstructionOperation::VMCALL |
+            InstructionOperation::VMMCALL |
+            InstructionOperation::VMLAUNCH |
+            InstructionOperation::VMRESUME => X86Flow::SystemCall,
+            InstructionOperation::HLT => X86Flow::Halt,
+            _ => X86Flow::Fallthrough,
+        }
+    }
+}
@@ -6,9 +6,10 @@
 use Operation;
 
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 #[repr(i32)]
 pub enum InstructionOperation {
+    #[default]
     INVALID = 0i32,
     AAA,
     AAD,
@@ -630,6 +631,793 @@ pub enum InstructionOperation {
     VMXON,
     XGETBV,
     XSETBV,
+    VBLENDPD,
+    VBLENDPS,
+    VDPPD,
+    VDPPS,
+    VLDMXCSR,
+    VMOVSS,
+    VMOVUPD,
+    VMOVUPS,
+    VMPSADBW,
+    VPACKSSDW,
+    VPACKSSWB,
+    VPACKUSDW,
+    VPACKUSWB,
+    VPABSB,
+    VPABSD,
+    VPABSW,
+    VPADDB,
+    VPADDD,
+    VPADDQ,
+    VPADDW,
+    VPADDSB,
+    VPADDSW,
+    VPADDUSB,
+    VPADDUSW,
+    VPALIGNR,
+    VPAND,
+    VPANDN,
+    VPAVGB,
+    VPAVGW,
+    VPBLENDW,
+    VPCMPEQB,
+    VPCMPEQD,
+    VPCMPEQQ,
+    VPCMPEQW,
+    VPCMPESTRI,
+    VPCMPESTRM,
+    VPCMPGTB,
+    VPCMPGTD,
+    VPCMPGTQ,
+    VPCMPGTW,
+    VPCMPISTRI,
+    VPCMPISTRM,
+    VPHADDD,
+    VPHADDSW,
+    VPHADDW,
+    VPHMINPOSUW,
+    VPHSUBD,
+    VPHSUBSW,
+    VPHSUBW,
+    VPMADDWD,
+    VPMADDUBSW,
+    VPMAXSB,
+    VPMAXSD,
+    VPMAXSW,
+    VPMAXUB,
+    VPMAXUD,
+    VPMAXUW,
+    VPMINSB,
+    VPMINSD,
+    VPMINSW,
+    VPMINUB,
+    VPMINUD,
+    VPMINUW,
+    VPMULDQ,
+    VPMULHRSW,
+    VPMULHUW,
+    VPMULHW,
+    VPMULLD,
+    VPMULLW,
+    VPMULUDQ,
+    VPOR,
+    VPSADBW,
+    VPSHUFB,
+    VPSIGNB,
+    VPSIGND,
+    VPSIGNW,
+    VPSLLD,
+    VPSLLDQ,
+    VPSLLQ,
+    VPSLLW,
+    VPSRAD,
+    VPSRAW,
+    VPSRLD,
+    VPSRLDQ,
+    VPSRLQ,
+    VPSRLW,
+    VPSUBB,
+    VPSUBD,
+    VPSUBQ,
+    VPSUBW,
+    VPSUBSB,
+    VPSUBSW,
+    VPSUBUSB,
+    VPSUBUSW,
+    VPTEST,
+    VPUNPCKHBW,
+    VPUNPCKHDQ,
+    VPUNPCKHQDQ,
+    VPUNPCKHWD,
+    VPUNPCKLQDQ,
+    VPXOR,
+    VROUNDPS,
+    VROUNDPD,
+    VSTMXCSR,
+    VADDPS,
+    VADDPD,
+    VADDSD,
+    VADDSS,
+    VADDSUBPD,
+    VADDSUBPS,
+    VANDNPS,
+    VANDNPD,
+    VANDPS,
+    VANDPD,
+    VCMPSD,
+    VDIVPS,
+    VDIVPD,
+    VDIVSD,
+    VDIVSS,
+    VMAXPS,
+    VMAXPD,
+    VMAXSD,
+    VMAXSS,
+    VMINPS,
+    VMINPD,
+    VMINSD,
+    VMINSS,
+    VMOVD,
+    VMOVQ,
+    VMOVSD,
+    VMULPS,
+    VMULPD,
+    VMULSD,
+    VMULSS,
+    VORPS,
+    VORPD,
+    VPEXTRD,
+    VPEXTRQ,
+    VPINSRD,
+    VPINSRQ,
+    VRCPPS,
+    VRCPSS,
+    VRSQRTPS,
+    VRSQRTSS,
+    VSQRTPS,
+    VSQRTPD,
+    VSQRTSD,
+    VSQRTSS,
+    VSUBPS,
+    VSUBPD,
+    VSUBSD,
+    VSUBSS,
+    VXORPS,
+    VXORPD,
+    VCMPPD,
+    VCMPPS,
+    VCMPSS,
+    VCOMISD,
+    VCOMISS,
+    VCVTDQ2PD,
+    VCVTDQ2PS,
+    VCVTPD2DQ,
+    VCVTPD2PS,
+    VCVTPS2DQ,
+    VCVTPS2PD,
+    VCVTSD2SI,
+    VCVTSD2SS,
+    VCVTSI2SD,
+    VCVTSI2SS,
+    VCVTSS2SD,
+    VCVTSS2SI,
+    VCVTTPD2DQ,
+    VCVTTPS2DQ,
+    VCVTTSD2SI,
+    VCVTTSS2SI,
+    VEXTRACTPS,
+    VHADDPD,
+    VHADDPS,
+    VHSUBPD,
+    VHSUBPS,
+    VINSERTPS,
+    VLDDQU,
+    VMASKMOVDQU,
+    VMOVAPD,
+    VMOVAPS,
+    VMOVDDUP,
+    VMOVDQA,
+    VMOVDQU,
+    VMOVHLPS,
+    VMOVHPD,
+    VMOVHPS,
+    VMOVSHDUP,
+    VMOVSLDUP,
+    VMOVLHPS,
+    VMOVLPD,
+    VMOVLPS,
+    VMOVMSKPD,
+    VMOVMSKPS,
+    VMOVNTDQ,
+    VMOVNTDQA,
+    VMOVNTPD,
+    VMOVNTPS,
+    VPINSRB,
+    VPINSRW,
+    VPEXTRB,
+    VPEXTRW,
+    VPMOVMSKB,
+    VPMOVSXBD,
+    VPMOVSXBQ,
+    VPMOVSXDQ,
+    VPMOVSXBW,
+    VPMOVSXWD,
+    VPMOVSXWQ,
+    VPMOVZXBD,
+    VPMOVZXBQ,
+    VPMOVZXDQ,
+    VPMOVZXBW,
+    VPMOVZXWD,
+    VPMOVZXWQ,
+    VPSHUFD,
+    VPSHUFHW,
+    VPSHUFLW,
+    VPUNPCKLBW,
+    VPUNPCKLDQ,
+    VPUNPCKLWD,
+    VROUNDSD,
+    VROUNDSS,
+    VSHUFPD,
+    VSHUFPS,
+    VUCOMISD,
+    VUCOMISS,
+    VUNPCKHPD,
+    VUNPCKHPS,
+    VUNPCKLPD,
+    VUNPCKLPS,
+    VZEROUPPER,
+    VZEROALL,
+    VPERMILPS,
+    VPERMILPD,
+    VTESTPS,
+    VTESTPD,
+    VCVTPH2PS,
+    VCVTPS2PH,
+    VPERMPS,
+    VPERMD,
+    VPERMQ,
+    VPERMPD,
+    VBROADCASTSS,
+    VBROADCASTSD,
+    VBROADCASTF128,
+    VBROADCASTI128,
+    VPBROADCASTB,
+    VPBROADCASTW,
+    VPBROADCASTD,
+    VPBROADCASTQ,
+    VMASKMOVPS,
+    VMASKMOVPD,
+    VPMASKMOVD,
+    VPMASKMOVQ,
+    VPSRLVD,
+    VPSRLVQ,
+    VPSRAVD,
+    VPSLLVD,
+    VPSLLVQ,
+    VPGATHERDD,
+    VPGATHERDQ,
+    VPGATHERQD,
+    VPGATHERQQ,
+    VGATHERDPS,
+    VGATHERDPD,
+    VGATHERQPS,
+    VGATHERQPD,
+    VPBLENDD,
+    VPERM2F128,
+    VPERM2I128,
+    VINSERTF128,
+    VEXTRACTF128,
+    VINSERTI128,
+    VEXTRACTI128,
+    VBLENDVPS,
+    VBLENDVPD,
+    VPBLENDVB,
+    VCVTQQ2PS,
+    VMOVDQA32,
+    VMOVDQA64,
+    VMOVDQU32,
+    VMOVDQU64,
+    VPSRAQ,
+    VPANDD,
+    VPANDQ,
+    VPANDND,
+    VPANDNQ,
+    VPORD,
+    VPORQ,
+    VPXORD,
+    VPXORQ,
+    VPMINSQ,
+    VPMINUQ,
+    VPMAXSQ,
+    VPMAXUQ,
+    VPMULLQ,
+    VCVTQQ2PD,
+    VRNDSCALEPS,
+    VRNDSCALEPD,
+    VRNDSCALESS,
+    VRNDSCALESD,
+    VDBPSADBW,
+    VMOVDQU8,
+    VMOVDQU16,
+    VCVTTPS2UDQ,
+    VCVTTPD2UDQ,
+    VCVTTPS2UQQ,
+    VCVTTPD2UQQ,
+    VCVTTSD2USI,
+    VCVTTSS2USI,
+    VCVTPS2UDQ,
+    VCVTPD2UDQ,
+    VCVTPS2UQQ,
+    VCVTPD2UQQ,
+    VCVTSD2USI,
+    VCVTSS2USI,
+    VCVTTPS2QQ,
+    VCVTTPD2QQ,
+    VCVTUDQ2PS,
+    VCVTUQQ2PS,
+    VCVTUDQ2PD,
+    VCVTUQQ2PD,
+    VCVTPS2QQ,
+    VCVTPD2QQ,
+    VCVTUSI2SD,
+    VCVTUSI2SS,
+    VPSRLVW,
+    VPMOVUSWB,
+    VPSRAVW,
+    VPMOVUSDB,
+    VPSLLVW,
+    VPMOVUSQB,
+    VPMOVUSDW,
+    VPRORVD,
+    VPRORVQ,
+    VPMOVUSQW,
+    VPROLVD,
+    VPROLVQ,
+    VPMOVUSQD,
+    VBROADCASTF32X2,
+    VBROADCASTF32X4,
+    VBROADCASTF64X2,
+    VBROADCASTF32X8,
+    VBROADCASTF64X4,
+    VPABSQ,
+    VPMOVSWB,
+    VPMOVSDB,
+    VPMOVSQB,
+    VPMOVSDW,
+    VPMOVSQW,
+    VPMOVSQD,
+    VPTESTMB,
+    VPTESTMW,
+    VPTESTNMB,
+    VPTESTNMW,
+    VPTESTMD,
+    VPTESTMQ,
+    VPTESTNMD,
+    VPTESTNMQ,
+    VPMOVM2B,
+    VPMOVM2W,
+    VPMOVB2M,
+    VPMOVW2M,
+    VPBROADCASTMB2Q,
+    VSCALEFPS,
+    VSCALEFPD,
+    VSCALEFSS,
+    VSCALEFSD,
+    VPMOVWB,
+    VPMOVDB,
+    VPMOVQB,
+    VPMOVDW,
+    VPMOVQW,
+    VPMOVQD,
+    VPMOVM2D,
+    VPMOVM2Q,
+    VPMOVD2M,
+    VPMOVQ2M,
+    VPBROADCASTMW2D,
+    VGETEXPPS,
+    VGETEXPPD,
+    VGETEXPSS,
+    VGETEXPSD,
+    VPLZCNTD,
+    VPLZCNTQ,
+    VPSRAVQ,
+    VRCP14PS,
+    VRCP14PD,
+    VRCP14SS,
+    VRCP14SD,
+    VRSQRT14PS,
+    VRSQRT14PD,
+    VRSQRT14SS,
+    VRSQRT14SD,
+    VBROADCASTI32X2,
+    VBROADCASTI32X4,
+    VBROADCASTI64X2,
+    VBROADCASTI32X8,
+    VBROADCASTI64X4,
+    VPBLENDMD,
+    VPBLENDMQ,
+    VBLENDMPS,
+    VBLENDMPD,
+    VPBLENDMB,
+    VPBLENDMW,
+    VPERMI2W,
+    VPERMI2D,
+    VPERMI2Q,
+    VPERMI2PS,
+    VPERMI2PD,
+    VPERMT2W,
+    VPERMT2D,
+    VPERMT2Q,
+    VPERMT2PS,
+    VPERMT2PD,
+    VEXPANDPS,
+    VEXPANDPD,
+    VPEXPANDD,
+    VPEXPANDQ,
+    VCOMPRESSPS,
+    VCOMPRESSPD,
+    VPCOMPRESSD,
+    VPCOMPRESSQ,
+    VPERMW,
+    VPSCATTERDD,
+    VPSCATTERDQ,
+    VPSCATTERQD,
+    VPSCATTERQQ,
+    VSCATTERDPS,
+    VSCATTERDPD,
+    VSCATTERQPS,
+    VSCATTERQPD,
+    VPCONFLICTD,
+    VPCONFLICTQ,
+    VALIGND,
+    VALIGNQ,
+    VINSERTF32X4,
+    VINSERTF64X2,
+    VEXTRACTF32X4,
+    VEXTRACTF64X2,
+    VINSERTF32X8,
+    VINSERTF64X4,
+    VEXTRACTF32X8,
+    VEXTRACTF64X4,
+    VPCMPUD,
+    VPCMPUQ,
+    VPCMPD,
+    VPCMPQ,
+    VSHUFF32X4,
+    VSHUFF64X2,
+    VPTERNLOGD,
+    VPTERNLOGQ,
+    VGETMANTPS,
+    VGETMANTPD,
+    VGETMANTSS,
+    VGETMANTSD,
+    VINSERTI32X4,
+    VINSERTI64X2,
+    VEXTRACTI32X4,
+    VEXTRACTI64X2,
+    VINSERTI32X8,
+    VINSERTI64X4,
+    VEXTRACTI32X8,
+    VEXTRACTI64X4,
+    VPCMPUB,
+    VPCMPUW,
+    VPCMPB,
+    VPCMPW,
+    VSHUFI32X4,
+    VSHUFI64X2,
+    VRANGEPS,
+    VRANGEPD,
+    VRANGESS,
+    VRANGESD,
+    VFIXUPIMMPS,
+    VFIXUPIMMPD,
+    VFIXUPIMMSS,
+    VFIXUPIMMSD,
+    VREDUCEPS,
+    VREDUCEPD,
+    VREDUCESS,
+    VREDUCESD,
+    VFPCLASSPS,
+    VFPCLASSPD,
+    VFPCLASSSS,
+    VFPCLASSSD,
+    VPRORD,
+    VPRORQ,
+    VPROLD,
+    VPROLQ,
+    KANDW,
+    KANDQ,
+    KANDB,
+    KANDD,
+    KANDNW,
+    KANDNQ,
+    KANDNB,
+    KANDND,
+    KORW,
+    KORQ,
+    KORB,
+    KORD,
+    KXNORW,
+    KXNORQ,
+    KXNORB,
+    KXNORD,
+    KXORW,
+    KXORQ,
+    KXORB,
+    KXORD,
+    KADDW,
+    KADDQ,
+    KADDB,
+    KADDD,
+    KNOTW,
+    KNOTQ,
+    KNOTB,
+    KNOTD,
+    KUNPCKWD,
+    KUNPCKDQ,
+    KUNPCKBW,
+    KMOVW,
+    KMOVQ,
+    KMOVB,
+    KMOVD,
+    KORTESTW,
+    KORTESTQ,
+    KORTESTB,
+    KORTESTD,
+    KTESTW,
+    KTESTQ,
+    KTESTB,
+    KTESTD,
+    KSHIFTRB,
+    KSHIFTRW,
+    KSHIFTRD,
+    KSHIFTRQ,
+    KSHIFTLB,
+    KSHIFTLW,
+    KSHIFTLD,
+    KSHIFTLQ,
+    VFMADDSUB132PS,
+    VFMADDSUB132PD,
+    VFMSUBADD132PS,
+    VFMSUBADD132PD,
+    VFMADD132PS,
+    VFMADD132PD,
+    VFMADD132SS,
+    VFMADD132SD,
+    VFMSUB132PS,
+    VFMSUB132PD,
+    VFMSUB132SS,
+    VFMSUB132SD,
+    VFNMADD132PS,
+    VFNMADD132PD,
+    VFNMADD132SS,
+    VFNMADD132SD,
+    VFNMSUB132PS,
+    VFNMSUB132PD,
+    VFNMSUB132SS,
+    VFNMSUB132SD,
+    VFMADDSUB213PS,
+    VFMADDSUB213PD,
+    VFMSUBADD213PS,
+    VFMSUBADD213PD,
+    VFMADD213PS,
+    VFMADD213PD,
+    VFMADD213SS,
+    VFMADD213SD,
+    VFMSUB213PS,
+    VFMSUB213PD,
+    VFMSUB213SS,
+    VFMSUB213SD,
+    VFNMADD213PS,
+    VFNMADD213PD,
+    VFNMADD213SS,
+    VFNMADD213SD,
+    VFNMSUB213PS,
+    VFNMSUB213PD,
+    VFNMSUB213SS,
+    VFNMSUB213SD,
+    VFMADDSUB231PS,
+    VFMADDSUB231PD,
+    VFMSUBADD231PS,
+    VFMSUBADD231PD,
+    VFMADD231PS,
+    VFMADD231PD,
+    VFMADD231SS,
+    VFMADD231SD,
+    VFMSUB231PS,
+    VFMSUB231PD,
+    VFMSUB231SS,
+    VFMSUB231SD,
+    VFNMADD231PS,
+    VFNMADD231PD,
+    VFNMADD231SS,
+    VFNMADD231SD,
+    VFNMSUB231PS,
+    VFNMSUB231PD,
+    VFNMSUB231SS,
+    VFNMSUB231SD,
+    VFMADDSUBPS,
+    VFMADDSUBPD,
+    VFMSUBADDPS,
+    VFMSUBADDPD,
+    VFMADDPS,
+    VFMADDPD,
+    VFMADDSS,
+    VFMADDSD,
+    VFMSUBPS,
+    VFMSUBPD,
+    VFMSUBSS,
+    VFMSUBSD,
+    VFNMADDPS,
+    VFNMADDPD,
+    VFNMADDSS,
+    VFNMADDSD,
+    VFNMSUBPS,
+    VFNMSUBPD,
+    VFNMSUBSS,
+    VFNMSUBSD,
+    VPMACSSWW,
+    VPMACSSWD,
+    VPMACSSDQL,
+    VPMACSSDD,
+    VPMACSSDQH,
+    VPMACSWW,
+    VPMACSWD,
+    VPMACSDQL,
+    VPMACSDD,
+    VPMACSDQH,
+    VPMADCSSWD,
+    VPMADCSWD,
+    VPCMOV,
+    VPPERM,
+    VPROTB,
+    VPROTW,
+    VPROTD,
+    VPROTQ,
+    VPCOMB,
+    VPCOMW,
+    VPCOMD,
+    VPCOMQ,
+    VPCOMUB,
+    VPCOMUW,
+    VPCOMUD,
+    VPCOMUQ,
+    VFRCZPS,
+    VFRCZPD,
+    VFRCZSS,
+    VFRCZSD,
+    VPSHLB,
+    VPSHLW,
+    VPSHLD,
+    VPSHLQ,
+    VPSHAB,
+    VPSHAW,
+    VPSHAD,
+    VPSHAQ,
+    VPHADDBW,
+    VPHADDBD,
+    VPHADDBQ,
+    VPHADDWD,
+    VPHADDWQ,
+    VPHADDDQ,
+    VPHADDUBW,
+    VPHADDUBD,
+    VPHADDUBQ,
+    VPHADDUWD,
+    VPHADDUWQ,
+    VPHADDUDQ,
+    VPHSUBBW,
+    VPHSUBWD,
+    VPHSUBDQ,
+    TZCNT,
+    LZCNT,
+    MOVBE,
+    ADCX,
+    ADOX,
+    ANDN,
+    BEXTR,
+    BLSR,
+    BLSMSK,
+    BLSI,
+    BZHI,
+    MULX,
+    PDEP,
+    PEXT,
+    RORX,
+    SARX,
+    SHLX,
+    SHRX,
+    AESIMC,
+    AESENC,
+    AESENCLAST,
+    AESDEC,
+    AESDECLAST,
+    AESKEYGENASSIST,
+    PCLMULQDQ,
+    SHA1RNDS4,
+    SHA1NEXTE,
+    SHA1MSG1,
+    SHA1MSG2,
+    SHA256RNDS2,
+    SHA256MSG1,
+    SHA256MSG2,
+    GF2P8MULB,
+    GF2P8AFFINEQB,
+    GF2P8AFFINEINVQB,
+    VAESIMC,
+    VAESENC,
+    VAESENCLAST,
+    VAESDEC,
+    VAESDECLAST,
+    VAESKEYGENASSIST,
+    VPCLMULQDQ,
+    VGF2P8MULB,
+    VGF2P8AFFINEQB,
+    VGF2P8AFFINEINVQB,
+    ENDBR32,
+    ENDBR64,
+    INCSSPD,
+    INCSSPQ,
+    RDSSPD,
+    RDSSPQ,
+    SAVEPREVSSP,
+    RSTORSSP,
+    WRSSD,
+    WRSSQ,
+    WRUSSD,
+    WRUSSQ,
+    SETSSBSY,
+    CLRSSBSY,
+    XBEGIN,
+    XEND,
+    XABORT,
+    XTEST,
+    RDRAND,
+    RDSEED,
+    RDPID,
+    XSAVEOPT,
+    XSAVEC,
+    XSAVES,
+    XRSTORS,
+    CLFLUSHOPT,
+    CLWB,
+    PREFETCHWT1,
+    INVEPT,
+    INVVPID,
+    VMFUNC,
+    VMRUN,
+    VMMCALL,
+    VMLOAD,
+    VMSAVE,
+    STGI,
+    CLGI,
+    SKINIT,
+    INVLPGA,
+    JMPABS,
+    PUSHP,
+    POPP,
+    PUSH2,
+    PUSH2P,
+    POP2,
+    POP2P,
+    LDTILECFG,
+    STTILECFG,
+    TILERELEASE,
+    TILEZERO,
+    TILELOADD,
+    TILELOADDT1,
+    TILESTORED,
+    TDPBSSD,
+    TDPBSUD,
+    TDPBUSD,
+    TDPBUUD,
+    TDPBF16PS,
+    TDPFP16PS,
+    TCMMIMFP16PS,
+    TCMMRLFP16PS,
+    UD0,
+    UD1,
 }
 
 impl InstructionOperation {
@@ -645,18 +1433,13 @@ impl Operation for InstructionOperation {
     }
 }
 
-impl Default for InstructionOperation {
-    fn default() -> Self {
-        InstructionOperation::INVALID
-    }
-}
 
 struct InstructionOperationInfo {
     pub name: &'static str,
     pub value: InstructionOperation,
 }
 
-static INSTRUCTION_OPERATION_TABLE: [InstructionOperationInfo; 621] = [
+static INSTRUCTION_OPERATION_TABLE: [InstructionOperationInfo; 1408] = [
     InstructionOperationInfo {
         name: "",
         value: InstructionOperation::INVALID,
@@ -3141,4 +3924,3152 @@ static INSTRUCTION_OPERATION_TABLE: [InstructionOperationInfo; 621] = [
         name: "xsetbv",
         value: InstructionOperation::XSETBV,
     },
+    InstructionOperationInfo {
+        name: "vblendpd",
+        value: InstructionOperation::VBLENDPD,
+    },
+    InstructionOperationInfo {
+        name: "vblendps",
+        value: InstructionOperation::VBLENDPS,
+    },
+    InstructionOperationInfo {
+        name: "vdppd",
+        value: InstructionOperation::VDPPD,
+    },
+    InstructionOperationInfo {
+        name: "vdpps",
+        value: InstructionOperation::VDPPS,
+    },
+    InstructionOperationInfo {
+        name: "vldmxcsr",
+        value: InstructionOperation::VLDMXCSR,
+    },
+    InstructionOperationInfo {
+        name: "vmovss",
+        value: InstructionOperation::VMOVSS,
+    },
+    InstructionOperationInfo {
+        name: "vmovupd",
+        value: InstructionOperation::VMOVUPD,
+    },
+    InstructionOperationInfo {
+        name: "vmovups",
+        value: InstructionOperation::VMOVUPS,
+    },
+    InstructionOperationInfo {
+        name: "vmpsadbw",
+        value: InstructionOperation::VMPSADBW,
+    },
+    InstructionOperationInfo {
+        name: "vpackssdw",
+        value: InstructionOperation::VPACKSSDW,
+    },
+    InstructionOperationInfo {
+        name: "vpacksswb",
+        value: InstructionOperation::VPACKSSWB,
+    },
+    InstructionOperationInfo {
+        name: "vpackusdw",
+        value: InstructionOperation::VPACKUSDW,
+    },
+    InstructionOperationInfo {
+        name: "vpackuswb",
+        value: InstructionOperation::VPACKUSWB,
+    },
+    InstructionOperationInfo {
+        name: "vpabsb",
+        value: InstructionOperation::VPABSB,
+    },
+    InstructionOperationInfo {
+        name: "vpabsd",
+        value: InstructionOperation::VPABSD,
+    },
+    InstructionOperationInfo {
+        name: "vpabsw",
+        value: InstructionOperation::VPABSW,
+    },
+    InstructionOperationInfo {
+        name: "vpaddb",
+        value: InstructionOperation::VPADDB,
+    },
+    InstructionOperationInfo {
+        name: "vpaddd",
+        value: InstructionOperation::VPADDD,
+    },
+    InstructionOperationInfo {
+        name: "vpaddq",
+        value: InstructionOperation::VPADDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpaddw",
+        value: InstructionOperation::VPADDW,
+    },
+    InstructionOperationInfo {
+        name: "vpaddsb",
+        value: InstructionOperation::VPADDSB,
+    },
+    InstructionOperationInfo {
+        name: "vpaddsw",
+        value: InstructionOperation::VPADDSW,
+    },
+    InstructionOperationInfo {
+        name: "vpaddusb",
+        value: InstructionOperation::VPADDUSB,
+    },
+    InstructionOperationInfo {
+        name: "vpaddusw",
+        value: InstructionOperation::VPADDUSW,
+    },
+    InstructionOperationInfo {
+        name: "vpalignr",
+        value: InstructionOperation::VPALIGNR,
+    },
+    InstructionOperationInfo {
+        name: "vpand",
+        value: InstructionOperation::VPAND,
+    },
+    InstructionOperationInfo {
+        name: "vpandn",
+        value: InstructionOperation::VPANDN,
+    },
+    InstructionOperationInfo {
+        name: "vpavgb",
+        value: InstructionOperation::VPAVGB,
+    },
+    InstructionOperationInfo {
+        name: "vpavgw",
+        value: InstructionOperation::VPAVGW,
+    },
+    InstructionOperationInfo {
+        name: "vpblendw",
+        value: InstructionOperation::VPBLENDW,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpeqb",
+        value: InstructionOperation::VPCMPEQB,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpeqd",
+        value: InstructionOperation::VPCMPEQD,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpeqq",
+        value: InstructionOperation::VPCMPEQQ,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpeqw",
+        value: InstructionOperation::VPCMPEQW,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpestri",
+        value: InstructionOperation::VPCMPESTRI,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpestrm",
+        value: InstructionOperation::VPCMPESTRM,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpgtb",
+        value: InstructionOperation::VPCMPGTB,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpgtd",
+        value: InstructionOperation::VPCMPGTD,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpgtq",
+        value: InstructionOperation::VPCMPGTQ,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpgtw",
+        value: InstructionOperation::VPCMPGTW,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpistri",
+        value: InstructionOperation::VPCMPISTRI,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpistrm",
+        value: InstructionOperation::VPCMPISTRM,
+    },
+    InstructionOperationInfo {
+        name: "vphaddd",
+        value: InstructionOperation::VPHADDD,
+    },
+    InstructionOperationInfo {
+        name: "vphaddsw",
+        value: InstructionOperation::VPHADDSW,
+    },
+    InstructionOperationInfo {
+        name: "vphaddw",
+        value: InstructionOperation::VPHADDW,
+    },
+    InstructionOperationInfo {
+        name: "vphminposuw",
+        value: InstructionOperation::VPHMINPOSUW,
+    },
+    InstructionOperationInfo {
+        name: "vphsubd",
+        value: InstructionOperation::VPHSUBD,
+    },
+    InstructionOperationInfo {
+        name: "vphsubsw",
+        value: InstructionOperation::VPHSUBSW,
+    },
+    InstructionOperationInfo {
+        name: "vphsubw",
+        value: InstructionOperation::VPHSUBW,
+    },
+    InstructionOperationInfo {
+        name: "vpmaddwd",
+        value: InstructionOperation::VPMADDWD,
+    },
+    InstructionOperationInfo {
+        name: "vpmaddubsw",
+        value: InstructionOperation::VPMADDUBSW,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxsb",
+        value: InstructionOperation::VPMAXSB,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxsd",
+        value: InstructionOperation::VPMAXSD,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxsw",
+        value: InstructionOperation::VPMAXSW,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxub",
+        value: InstructionOperation::VPMAXUB,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxud",
+        value: InstructionOperation::VPMAXUD,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxuw",
+        value: InstructionOperation::VPMAXUW,
+    },
+    InstructionOperationInfo {
+        name: "vpminsb",
+        value: InstructionOperation::VPMINSB,
+    },
+    InstructionOperationInfo {
+        name: "vpminsd",
+        value: InstructionOperation::VPMINSD,
+    },
+    InstructionOperationInfo {
+        name: "vpminsw",
+        value: InstructionOperation::VPMINSW,
+    },
+    InstructionOperationInfo {
+        name: "vpminub",
+        value: InstructionOperation::VPMINUB,
+    },
+    InstructionOperationInfo {
+        name: "vpminud",
+        value: InstructionOperation::VPMINUD,
+    },
+    InstructionOperationInfo {
+        name: "vpminuw",
+        value: InstructionOperation::VPMINUW,
+    },
+    InstructionOperationInfo {
+        name: "vpmuldq",
+        value: InstructionOperation::VPMULDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmulhrsw",
+        value: InstructionOperation::VPMULHRSW,
+    },
+    InstructionOperationInfo {
+        name: "vpmulhuw",
+        value: InstructionOperation::VPMULHUW,
+    },
+    InstructionOperationInfo {
+        name: "vpmulhw",
+        value: InstructionOperation::VPMULHW,
+    },
+    InstructionOperationInfo {
+        name: "vpmulld",
+        value: InstructionOperation::VPMULLD,
+    },
+    InstructionOperationInfo {
+        name: "vpmullw",
+        value: InstructionOperation::VPMULLW,
+    },
+    InstructionOperationInfo {
+        name: "vpmuludq",
+        value: InstructionOperation::VPMULUDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpor",
+        value: InstructionOperation::VPOR,
+    },
+    InstructionOperationInfo {
+        name: "vpsadbw",
+        value: InstructionOperation::VPSADBW,
+    },
+    InstructionOperationInfo {
+        name: "vpshufb",
+        value: InstructionOperation::VPSHUFB,
+    },
+    InstructionOperationInfo {
+        name: "vpsignb",
+        value: InstructionOperation::VPSIGNB,
+    },
+    InstructionOperationInfo {
+        name: "vpsignd",
+        value: InstructionOperation::VPSIGND,
+    },
+    InstructionOperationInfo {
+        name: "vpsignw",
+        value: InstructionOperation::VPSIGNW,
+    },
+    InstructionOperationInfo {
+        name: "vpslld",
+        value: InstructionOperation::VPSLLD,
+    },
+    InstructionOperationInfo {
+        name: "vpslldq",
+        value: InstructionOperation::VPSLLDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsllq",
+        value: InstructionOperation::VPSLLQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsllw",
+        value: InstructionOperation::VPSLLW,
+    },
+    InstructionOperationInfo {
+        name: "vpsrad",
+        value: InstructionOperation::VPSRAD,
+    },
+    InstructionOperationInfo {
+        name: "vpsraw",
+        value: InstructionOperation::VPSRAW,
+    },
+    InstructionOperationInfo {
+        name: "vpsrld",
+        value: InstructionOperation::VPSRLD,
+    },
+    InstructionOperationInfo {
+        name: "vpsrldq",
+        value: InstructionOperation::VPSRLDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsrlq",
+        value: InstructionOperation::VPSRLQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsrlw",
+        value: InstructionOperation::VPSRLW,
+    },
+    InstructionOperationInfo {
+        name: "vpsubb",
+        value: InstructionOperation::VPSUBB,
+    },
+    InstructionOperationInfo {
+        name: "vpsubd",
+        value: InstructionOperation::VPSUBD,
+    },
+    InstructionOperationInfo {
+        name: "vpsubq",
+        value: InstructionOperation::VPSUBQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsubw",
+        value: InstructionOperation::VPSUBW,
+    },
+    InstructionOperationInfo {
+        name: "vpsubsb",
+        value: InstructionOperation::VPSUBSB,
+    },
+    InstructionOperationInfo {
+        name: "vpsubsw",
+        value: InstructionOperation::VPSUBSW,
+    },
+    InstructionOperationInfo {
+        name: "vpsubusb",
+        value: InstructionOperation::VPSUBUSB,
+    },
+    InstructionOperationInfo {
+        name: "vpsubusw",
+        value: InstructionOperation::VPSUBUSW,
+    },
+    InstructionOperationInfo {
+        name: "vptest",
+        value: InstructionOperation::VPTEST,
+    },
+    InstructionOperationInfo {
+        name: "vpunpckhbw",
+        value: InstructionOperation::VPUNPCKHBW,
+    },
+    InstructionOperationInfo {
+        name: "vpunpckhdq",
+        value: InstructionOperation::VPUNPCKHDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpunpckhqdq",
+        value: InstructionOperation::VPUNPCKHQDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpunpckhwd",
+        value: InstructionOperation::VPUNPCKHWD,
+    },
+    InstructionOperationInfo {
+        name: "vpunpcklqdq",
+        value: InstructionOperation::VPUNPCKLQDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpxor",
+        value: InstructionOperation::VPXOR,
+    },
+    InstructionOperationInfo {
+        name: "vroundps",
+        value: InstructionOperation::VROUNDPS,
+    },
+    InstructionOperationInfo {
+        name: "vroundpd",
+        value: InstructionOperation::VROUNDPD,
+    },
+    InstructionOperationInfo {
+        name: "vstmxcsr",
+        value: InstructionOperation::VSTMXCSR,
+    },
+    InstructionOperationInfo {
+        name: "vaddps",
+        value: InstructionOperation::VADDPS,
+    },
+    InstructionOperationInfo {
+        name: "vaddpd",
+        value: InstructionOperation::VADDPD,
+    },
+    InstructionOperationInfo {
+        name: "vaddsd",
+        value: InstructionOperation::VADDSD,
+    },
+    InstructionOperationInfo {
+        name: "vaddss",
+        value: InstructionOperation::VADDSS,
+    },
+    InstructionOperationInfo {
+        name: "vaddsubpd",
+        value: InstructionOperation::VADDSUBPD,
+    },
+    InstructionOperationInfo {
+        name: "vaddsubps",
+        value: InstructionOperation::VADDSUBPS,
+    },
+    InstructionOperationInfo {
+        name: "vandnps",
+        value: InstructionOperation::VANDNPS,
+    },
+    InstructionOperationInfo {
+        name: "vandnpd",
+        value: InstructionOperation::VANDNPD,
+    },
+    InstructionOperationInfo {
+        name: "vandps",
+        value: InstructionOperation::VANDPS,
+    },
+    InstructionOperationInfo {
+        name: "vandpd",
+        value: InstructionOperation::VANDPD,
+    },
+    InstructionOperationInfo {
+        name: "vcmpsd",
+        value: InstructionOperation::VCMPSD,
+    },
+    InstructionOperationInfo {
+        name: "vdivps",
+        value: InstructionOperation::VDIVPS,
+    },
+    InstructionOperationInfo {
+        name: "vdivpd",
+        value: InstructionOperation::VDIVPD,
+    },
+    InstructionOperationInfo {
+        name: "vdivsd",
+        value: InstructionOperation::VDIVSD,
+    },
+    InstructionOperationInfo {
+        name: "vdivss",
+        value: InstructionOperation::VDIVSS,
+    },
+    InstructionOperationInfo {
+        name: "vmaxps",
+        value: InstructionOperation::VMAXPS,
+    },
+    InstructionOperationInfo {
+        name: "vmaxpd",
+        value: InstructionOperation::VMAXPD,
+    },
+    InstructionOperationInfo {
+        name: "vmaxsd",
+        value: InstructionOperation::VMAXSD,
+    },
+    InstructionOperationInfo {
+        name: "vmaxss",
+        value: InstructionOperation::VMAXSS,
+    },
+    InstructionOperationInfo {
+        name: "vminps",
+        value: InstructionOperation::VMINPS,
+    },
+    InstructionOperationInfo {
+        name: "vminpd",
+        value: InstructionOperation::VMINPD,
+    },
+    InstructionOperationInfo {
+        name: "vminsd",
+        value: InstructionOperation::VMINSD,
+    },
+    InstructionOperationInfo {
+        name: "vminss",
+        value: InstructionOperation::VMINSS,
+    },
+    InstructionOperationInfo {
+        name: "vmovd",
+        value: InstructionOperation::VMOVD,
+    },
+    InstructionOperationInfo {
+        name: "vmovq",
+        value: InstructionOperation::VMOVQ,
+    },
+    InstructionOperationInfo {
+        name: "vmovsd",
+        value: InstructionOperation::VMOVSD,
+    },
+    InstructionOperationInfo {
+        name: "vmulps",
+        value: InstructionOperation::VMULPS,
+    },
+    InstructionOperationInfo {
+        name: "vmulpd",
+        value: InstructionOperation::VMULPD,
+    },
+    InstructionOperationInfo {
+        name: "vmulsd",
+        value: InstructionOperation::VMULSD,
+    },
+    InstructionOperationInfo {
+        name: "vmulss",
+        value: InstructionOperation::VMULSS,
+    },
+    InstructionOperationInfo {
+        name: "vorps",
+        value: InstructionOperation::VORPS,
+    },
+    InstructionOperationInfo {
+        name: "vorpd",
+        value: InstructionOperation::VORPD,
+    },
+    InstructionOperationInfo {
+        name: "vpextrd",
+        value: InstructionOperation::VPEXTRD,
+    },
+    InstructionOperationInfo {
+        name: "vpextrq",
+        value: InstructionOperation::VPEXTRQ,
+    },
+    InstructionOperationInfo {
+        name: "vpinsrd",
+        value: InstructionOperation::VPINSRD,
+    },
+    InstructionOperationInfo {
+        name: "vpinsrq",
+        value: InstructionOperation::VPINSRQ,
+    },
+    InstructionOperationInfo {
+        name: "vrcpps",
+        value: InstructionOperation::VRCPPS,
+    },
+    InstructionOperationInfo {
+        name: "vrcpss",
+        value: InstructionOperation::VRCPSS,
+    },
+    InstructionOperationInfo {
+        name: "vrsqrtps",
+        value: InstructionOperation::VRSQRTPS,
+    },
+    InstructionOperationInfo {
+        name: "vrsqrtss",
+        value: InstructionOperation::VRSQRTSS,
+    },
+    InstructionOperationInfo {
+        name: "vsqrtps",
+        value: InstructionOperation::VSQRTPS,
+    },
+    InstructionOperationInfo {
+        name: "vsqrtpd",
+        value: InstructionOperation::VSQRTPD,
+    },
+    InstructionOperationInfo {
+        name: "vsqrtsd",
+        value: InstructionOperation::VSQRTSD,
+    },
+    InstructionOperationInfo {
+        name: "vsqrtss",
+        value: InstructionOperation::VSQRTSS,
+    },
+    InstructionOperationInfo {
+        name: "vsubps",
+        value: InstructionOperation::VSUBPS,
+    },
+    InstructionOperationInfo {
+        name: "vsubpd",
+        value: InstructionOperation::VSUBPD,
+    },
+    InstructionOperationInfo {
+        name: "vsubsd",
+        value: InstructionOperation::VSUBSD,
+    },
+    InstructionOperationInfo {
+        name: "vsubss",
+        value: InstructionOperation::VSUBSS,
+    },
+    InstructionOperationInfo {
+        name: "vxorps",
+        value: InstructionOperation::VXORPS,
+    },
+    InstructionOperationInfo {
+        name: "vxorpd",
+        value: InstructionOperation::VXORPD,
+    },
+    InstructionOperationInfo {
+        name: "vcmppd",
+        value: InstructionOperation::VCMPPD,
+    },
+    InstructionOperationInfo {
+        name: "vcmpps",
+        value: InstructionOperation::VCMPPS,
+    },
+    InstructionOperationInfo {
+        name: "vcmpss",
+        value: InstructionOperation::VCMPSS,
+    },
+    InstructionOperationInfo {
+        name: "vcomisd",
+        value: InstructionOperation::VCOMISD,
+    },
+    InstructionOperationInfo {
+        name: "vcomiss",
+        value: InstructionOperation::VCOMISS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtdq2pd",
+        value: InstructionOperation::VCVTDQ2PD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtdq2ps",
+        value: InstructionOperation::VCVTDQ2PS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtpd2dq",
+        value: InstructionOperation::VCVTPD2DQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtpd2ps",
+        value: InstructionOperation::VCVTPD2PS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtps2dq",
+        value: InstructionOperation::VCVTPS2DQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtps2pd",
+        value: InstructionOperation::VCVTPS2PD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtsd2si",
+        value: InstructionOperation::VCVTSD2SI,
+    },
+    InstructionOperationInfo {
+        name: "vcvtsd2ss",
+        value: InstructionOperation::VCVTSD2SS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtsi2sd",
+        value: InstructionOperation::VCVTSI2SD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtsi2ss",
+        value: InstructionOperation::VCVTSI2SS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtss2sd",
+        value: InstructionOperation::VCVTSS2SD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtss2si",
+        value: InstructionOperation::VCVTSS2SI,
+    },
+    InstructionOperationInfo {
+        name: "vcvttpd2dq",
+        value: InstructionOperation::VCVTTPD2DQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvttps2dq",
+        value: InstructionOperation::VCVTTPS2DQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvttsd2si",
+        value: InstructionOperation::VCVTTSD2SI,
+    },
+    InstructionOperationInfo {
+        name: "vcvttss2si",
+        value: InstructionOperation::VCVTTSS2SI,
+    },
+    InstructionOperationInfo {
+        name: "vextractps",
+        value: InstructionOperation::VEXTRACTPS,
+    },
+    InstructionOperationInfo {
+        name: "vhaddpd",
+        value: InstructionOperation::VHADDPD,
+    },
+    InstructionOperationInfo {
+        name: "vhaddps",
+        value: InstructionOperation::VHADDPS,
+    },
+    InstructionOperationInfo {
+        name: "vhsubpd",
+        value: InstructionOperation::VHSUBPD,
+    },
+    InstructionOperationInfo {
+        name: "vhsubps",
+        value: InstructionOperation::VHSUBPS,
+    },
+    InstructionOperationInfo {
+        name: "vinsertps",
+        value: InstructionOperation::VINSERTPS,
+    },
+    InstructionOperationInfo {
+        name: "vlddqu",
+        value: InstructionOperation::VLDDQU,
+    },
+    InstructionOperationInfo {
+        name: "vmaskmovdqu",
+        value: InstructionOperation::VMASKMOVDQU,
+    },
+    InstructionOperationInfo {
+        name: "vmovapd",
+        value: InstructionOperation::VMOVAPD,
+    },
+    InstructionOperationInfo {
+        name: "vmovaps",
+        value: InstructionOperation::VMOVAPS,
+    },
+    InstructionOperationInfo {
+        name: "vmovddup",
+        value: InstructionOperation::VMOVDDUP,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqa",
+        value: InstructionOperation::VMOVDQA,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqu",
+        value: InstructionOperation::VMOVDQU,
+    },
+    InstructionOperationInfo {
+        name: "vmovhlps",
+        value: InstructionOperation::VMOVHLPS,
+    },
+    InstructionOperationInfo {
+        name: "vmovhpd",
+        value: InstructionOperation::VMOVHPD,
+    },
+    InstructionOperationInfo {
+        name: "vmovhps",
+        value: InstructionOperation::VMOVHPS,
+    },
+    InstructionOperationInfo {
+        name: "vmovshdup",
+        value: InstructionOperation::VMOVSHDUP,
+    },
+    InstructionOperationInfo {
+        name: "vmovsldup",
+        value: InstructionOperation::VMOVSLDUP,
+    },
+    InstructionOperationInfo {
+        name: "vmovlhps",
+        value: InstructionOperation::VMOVLHPS,
+    },
+    InstructionOperationInfo {
+        name: "vmovlpd",
+        value: InstructionOperation::VMOVLPD,
+    },
+    InstructionOperationInfo {
+        name: "vmovlps",
+        value: InstructionOperation::VMOVLPS,
+    },
+    InstructionOperationInfo {
+        name: "vmovmskpd",
+        value: InstructionOperation::VMOVMSKPD,
+    },
+    InstructionOperationInfo {
+        name: "vmovmskps",
+        value: InstructionOperation::VMOVMSKPS,
+    },
+    InstructionOperationInfo {
+        name: "vmovntdq",
+        value: InstructionOperation::VMOVNTDQ,
+    },
+    InstructionOperationInfo {
+        name: "vmovntdqa",
+        value: InstructionOperation::VMOVNTDQA,
+    },
+    InstructionOperationInfo {
+        name: "vmovntpd",
+        value: InstructionOperation::VMOVNTPD,
+    },
+    InstructionOperationInfo {
+        name: "vmovntps",
+        value: InstructionOperation::VMOVNTPS,
+    },
+    InstructionOperationInfo {
+        name: "vpinsrb",
+        value: InstructionOperation::VPINSRB,
+    },
+    InstructionOperationInfo {
+        name: "vpinsrw",
+        value: InstructionOperation::VPINSRW,
+    },
+    InstructionOperationInfo {
+        name: "vpextrb",
+        value: InstructionOperation::VPEXTRB,
+    },
+    InstructionOperationInfo {
+        name: "vpextrw",
+        value: InstructionOperation::VPEXTRW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovmskb",
+        value: InstructionOperation::VPMOVMSKB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsxbd",
+        value: InstructionOperation::VPMOVSXBD,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsxbq",
+        value: InstructionOperation::VPMOVSXBQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsxdq",
+        value: InstructionOperation::VPMOVSXDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsxbw",
+        value: InstructionOperation::VPMOVSXBW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsxwd",
+        value: InstructionOperation::VPMOVSXWD,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsxwq",
+        value: InstructionOperation::VPMOVSXWQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovzxbd",
+        value: InstructionOperation::VPMOVZXBD,
+    },
+    InstructionOperationInfo {
+        name: "vpmovzxbq",
+        value: InstructionOperation::VPMOVZXBQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovzxdq",
+        value: InstructionOperation::VPMOVZXDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovzxbw",
+        value: InstructionOperation::VPMOVZXBW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovzxwd",
+        value: InstructionOperation::VPMOVZXWD,
+    },
+    InstructionOperationInfo {
+        name: "vpmovzxwq",
+        value: InstructionOperation::VPMOVZXWQ,
+    },
+    InstructionOperationInfo {
+        name: "vpshufd",
+        value: InstructionOperation::VPSHUFD,
+    },
+    InstructionOperationInfo {
+        name: "vpshufhw",
+        value: InstructionOperation::VPSHUFHW,
+    },
+    InstructionOperationInfo {
+        name: "vpshuflw",
+        value: InstructionOperation::VPSHUFLW,
+    },
+    InstructionOperationInfo {
+        name: "vpunpcklbw",
+        value: InstructionOperation::VPUNPCKLBW,
+    },
+    InstructionOperationInfo {
+        name: "vpunpckldq",
+        value: InstructionOperation::VPUNPCKLDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpunpcklwd",
+        value: InstructionOperation::VPUNPCKLWD,
+    },
+    InstructionOperationInfo {
+        name: "vroundsd",
+        value: InstructionOperation::VROUNDSD,
+    },
+    InstructionOperationInfo {
+        name: "vroundss",
+        value: InstructionOperation::VROUNDSS,
+    },
+    InstructionOperationInfo {
+        name: "vshufpd",
+        value: InstructionOperation::VSHUFPD,
+    },
+    InstructionOperationInfo {
+        name: "vshufps",
+        value: InstructionOperation::VSHUFPS,
+    },
+    InstructionOperationInfo {
+        name: "vucomisd",
+        value: InstructionOperation::VUCOMISD,
+    },
+    InstructionOperationInfo {
+        name: "vucomiss",
+        value: InstructionOperation::VUCOMISS,
+    },
+    InstructionOperationInfo {
+        name: "vunpckhpd",
+        value: InstructionOperation::VUNPCKHPD,
+    },
+    InstructionOperationInfo {
+        name: "vunpckhps",
+        value: InstructionOperation::VUNPCKHPS,
+    },
+    InstructionOperationInfo {
+        name: "vunpcklpd",
+        value: InstructionOperation::VUNPCKLPD,
+    },
+    InstructionOperationInfo {
+        name: "vunpcklps",
+        value: InstructionOperation::VUNPCKLPS,
+    },
+    InstructionOperationInfo {
+        name: "vzeroupper",
+        value: InstructionOperation::VZEROUPPER,
+    },
+    InstructionOperationInfo {
+        name: "vzeroall",
+        value: InstructionOperation::VZEROALL,
+    },
+    InstructionOperationInfo {
+        name: "vpermilps",
+        value: InstructionOperation::VPERMILPS,
+    },
+    InstructionOperationInfo {
+        name: "vpermilpd",
+        value: InstructionOperation::VPERMILPD,
+    },
+    InstructionOperationInfo {
+        name: "vtestps",
+        value: InstructionOperation::VTESTPS,
+    },
+    InstructionOperationInfo {
+        name: "vtestpd",
+        value: InstructionOperation::VTESTPD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtph2ps",
+        value: InstructionOperation::VCVTPH2PS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtps2ph",
+        value: InstructionOperation::VCVTPS2PH,
+    },
+    InstructionOperationInfo {
+        name: "vpermps",
+        value: InstructionOperation::VPERMPS,
+    },
+    InstructionOperationInfo {
+        name: "vpermd",
+        value: InstructionOperation::VPERMD,
+    },
+    InstructionOperationInfo {
+        name: "vpermq",
+        value: InstructionOperation::VPERMQ,
+    },
+    InstructionOperationInfo {
+        name: "vpermpd",
+        value: InstructionOperation::VPERMPD,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastss",
+        value: InstructionOperation::VBROADCASTSS,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastsd",
+        value: InstructionOperation::VBROADCASTSD,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastf128",
+        value: InstructionOperation::VBROADCASTF128,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcasti128",
+        value: InstructionOperation::VBROADCASTI128,
+    },
+    InstructionOperationInfo {
+        name: "vpbroadcastb",
+        value: InstructionOperation::VPBROADCASTB,
+    },
+    InstructionOperationInfo {
+        name: "vpbroadcastw",
+        value: InstructionOperation::VPBROADCASTW,
+    },
+    InstructionOperationInfo {
+        name: "vpbroadcastd",
+        value: InstructionOperation::VPBROADCASTD,
+    },
+    InstructionOperationInfo {
+        name: "vpbroadcastq",
+        value: InstructionOperation::VPBROADCASTQ,
+    },
+    InstructionOperationInfo {
+        name: "vmaskmovps",
+        value: InstructionOperation::VMASKMOVPS,
+    },
+    InstructionOperationInfo {
+        name: "vmaskmovpd",
+        value: InstructionOperation::VMASKMOVPD,
+    },
+    InstructionOperationInfo {
+        name: "vpmaskmovd",
+        value: InstructionOperation::VPMASKMOVD,
+    },
+    InstructionOperationInfo {
+        name: "vpmaskmovq",
+        value: InstructionOperation::VPMASKMOVQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsrlvd",
+        value: InstructionOperation::VPSRLVD,
+    },
+    InstructionOperationInfo {
+        name: "vpsrlvq",
+        value: InstructionOperation::VPSRLVQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsravd",
+        value: InstructionOperation::VPSRAVD,
+    },
+    InstructionOperationInfo {
+        name: "vpsllvd",
+        value: InstructionOperation::VPSLLVD,
+    },
+    InstructionOperationInfo {
+        name: "vpsllvq",
+        value: InstructionOperation::VPSLLVQ,
+    },
+    InstructionOperationInfo {
+        name: "vpgatherdd",
+        value: InstructionOperation::VPGATHERDD,
+    },
+    InstructionOperationInfo {
+        name: "vpgatherdq",
+        value: InstructionOperation::VPGATHERDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpgatherqd",
+        value: InstructionOperation::VPGATHERQD,
+    },
+    InstructionOperationInfo {
+        name: "vpgatherqq",
+        value: InstructionOperation::VPGATHERQQ,
+    },
+    InstructionOperationInfo {
+        name: "vgatherdps",
+        value: InstructionOperation::VGATHERDPS,
+    },
+    InstructionOperationInfo {
+        name: "vgatherdpd",
+        value: InstructionOperation::VGATHERDPD,
+    },
+    InstructionOperationInfo {
+        name: "vgatherqps",
+        value: InstructionOperation::VGATHERQPS,
+    },
+    InstructionOperationInfo {
+        name: "vgatherqpd",
+        value: InstructionOperation::VGATHERQPD,
+    },
+    InstructionOperationInfo {
+        name: "vpblendd",
+        value: InstructionOperation::VPBLENDD,
+    },
+    InstructionOperationInfo {
+        name: "vperm2f128",
+        value: InstructionOperation::VPERM2F128,
+    },
+    InstructionOperationInfo {
+        name: "vperm2i128",
+        value: InstructionOperation::VPERM2I128,
+    },
+    InstructionOperationInfo {
+        name: "vinsertf128",
+        value: InstructionOperation::VINSERTF128,
+    },
+    InstructionOperationInfo {
+        name: "vextractf128",
+        value: InstructionOperation::VEXTRACTF128,
+    },
+    InstructionOperationInfo {
+        name: "vinserti128",
+        value: InstructionOperation::VINSERTI128,
+    },
+    InstructionOperationInfo {
+        name: "vextracti128",
+        value: InstructionOperation::VEXTRACTI128,
+    },
+    InstructionOperationInfo {
+        name: "vblendvps",
+        value: InstructionOperation::VBLENDVPS,
+    },
+    InstructionOperationInfo {
+        name: "vblendvpd",
+        value: InstructionOperation::VBLENDVPD,
+    },
+    InstructionOperationInfo {
+        name: "vpblendvb",
+        value: InstructionOperation::VPBLENDVB,
+    },
+    InstructionOperationInfo {
+        name: "vcvtqq2ps",
+        value: InstructionOperation::VCVTQQ2PS,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqa32",
+        value: InstructionOperation::VMOVDQA32,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqa64",
+        value: InstructionOperation::VMOVDQA64,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqu32",
+        value: InstructionOperation::VMOVDQU32,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqu64",
+        value: InstructionOperation::VMOVDQU64,
+    },
+    InstructionOperationInfo {
+        name: "vpsraq",
+        value: InstructionOperation::VPSRAQ,
+    },
+    InstructionOperationInfo {
+        name: "vpandd",
+        value: InstructionOperation::VPANDD,
+    },
+    InstructionOperationInfo {
+        name: "vpandq",
+        value: InstructionOperation::VPANDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpandnd",
+        value: InstructionOperation::VPANDND,
+    },
+    InstructionOperationInfo {
+        name: "vpandnq",
+        value: InstructionOperation::VPANDNQ,
+    },
+    InstructionOperationInfo {
+        name: "vpord",
+        value: InstructionOperation::VPORD,
+    },
+    InstructionOperationInfo {
+        name: "vporq",
+        value: InstructionOperation::VPORQ,
+    },
+    InstructionOperationInfo {
+        name: "vpxord",
+        value: InstructionOperation::VPXORD,
+    },
+    InstructionOperationInfo {
+        name: "vpxorq",
+        value: InstructionOperation::VPXORQ,
+    },
+    InstructionOperationInfo {
+        name: "vpminsq",
+        value: InstructionOperation::VPMINSQ,
+    },
+    InstructionOperationInfo {
+        name: "vpminuq",
+        value: InstructionOperation::VPMINUQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxsq",
+        value: InstructionOperation::VPMAXSQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmaxuq",
+        value: InstructionOperation::VPMAXUQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmullq",
+        value: InstructionOperation::VPMULLQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtqq2pd",
+        value: InstructionOperation::VCVTQQ2PD,
+    },
+    InstructionOperationInfo {
+        name: "vrndscaleps",
+        value: InstructionOperation::VRNDSCALEPS,
+    },
+    InstructionOperationInfo {
+        name: "vrndscalepd",
+        value: InstructionOperation::VRNDSCALEPD,
+    },
+    InstructionOperationInfo {
+        name: "vrndscaless",
+        value: InstructionOperation::VRNDSCALESS,
+    },
+    InstructionOperationInfo {
+        name: "vrndscalesd",
+        value: InstructionOperation::VRNDSCALESD,
+    },
+    InstructionOperationInfo {
+        name: "vdbpsadbw",
+        value: InstructionOperation::VDBPSADBW,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqu8",
+        value: InstructionOperation::VMOVDQU8,
+    },
+    InstructionOperationInfo {
+        name: "vmovdqu16",
+        value: InstructionOperation::VMOVDQU16,
+    },
+    InstructionOperationInfo {
+        name: "vcvttps2udq",
+        value: InstructionOperation::VCVTTPS2UDQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvttpd2udq",
+        value: InstructionOperation::VCVTTPD2UDQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvttps2uqq",
+        value: InstructionOperation::VCVTTPS2UQQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvttpd2uqq",
+        value: InstructionOperation::VCVTTPD2UQQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvttsd2usi",
+        value: InstructionOperation::VCVTTSD2USI,
+    },
+    InstructionOperationInfo {
+        name: "vcvttss2usi",
+        value: InstructionOperation::VCVTTSS2USI,
+    },
+    InstructionOperationInfo {
+        name: "vcvtps2udq",
+        value: InstructionOperation::VCVTPS2UDQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtpd2udq",
+        value: InstructionOperation::VCVTPD2UDQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtps2uqq",
+        value: InstructionOperation::VCVTPS2UQQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtpd2uqq",
+        value: InstructionOperation::VCVTPD2UQQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtsd2usi",
+        value: InstructionOperation::VCVTSD2USI,
+    },
+    InstructionOperationInfo {
+        name: "vcvtss2usi",
+        value: InstructionOperation::VCVTSS2USI,
+    },
+    InstructionOperationInfo {
+        name: "vcvttps2qq",
+        value: InstructionOperation::VCVTTPS2QQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvttpd2qq",
+        value: InstructionOperation::VCVTTPD2QQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtudq2ps",
+        value: InstructionOperation::VCVTUDQ2PS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtuqq2ps",
+        value: InstructionOperation::VCVTUQQ2PS,
+    },
+    InstructionOperationInfo {
+        name: "vcvtudq2pd",
+        value: InstructionOperation::VCVTUDQ2PD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtuqq2pd",
+        value: InstructionOperation::VCVTUQQ2PD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtps2qq",
+        value: InstructionOperation::VCVTPS2QQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtpd2qq",
+        value: InstructionOperation::VCVTPD2QQ,
+    },
+    InstructionOperationInfo {
+        name: "vcvtusi2sd",
+        value: InstructionOperation::VCVTUSI2SD,
+    },
+    InstructionOperationInfo {
+        name: "vcvtusi2ss",
+        value: InstructionOperation::VCVTUSI2SS,
+    },
+    InstructionOperationInfo {
+        name: "vpsrlvw",
+        value: InstructionOperation::VPSRLVW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovuswb",
+        value: InstructionOperation::VPMOVUSWB,
+    },
+    InstructionOperationInfo {
+        name: "vpsravw",
+        value: InstructionOperation::VPSRAVW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovusdb",
+        value: InstructionOperation::VPMOVUSDB,
+    },
+    InstructionOperationInfo {
+        name: "vpsllvw",
+        value: InstructionOperation::VPSLLVW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovusqb",
+        value: InstructionOperation::VPMOVUSQB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovusdw",
+        value: InstructionOperation::VPMOVUSDW,
+    },
+    InstructionOperationInfo {
+        name: "vprorvd",
+        value: InstructionOperation::VPRORVD,
+    },
+    InstructionOperationInfo {
+        name: "vprorvq",
+        value: InstructionOperation::VPRORVQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovusqw",
+        value: InstructionOperation::VPMOVUSQW,
+    },
+    InstructionOperationInfo {
+        name: "vprolvd",
+        value: InstructionOperation::VPROLVD,
+    },
+    InstructionOperationInfo {
+        name: "vprolvq",
+        value: InstructionOperation::VPROLVQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovusqd",
+        value: InstructionOperation::VPMOVUSQD,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastf32x2",
+        value: InstructionOperation::VBROADCASTF32X2,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastf32x4",
+        value: InstructionOperation::VBROADCASTF32X4,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastf64x2",
+        value: InstructionOperation::VBROADCASTF64X2,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastf32x8",
+        value: InstructionOperation::VBROADCASTF32X8,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcastf64x4",
+        value: InstructionOperation::VBROADCASTF64X4,
+    },
+    InstructionOperationInfo {
+        name: "vpabsq",
+        value: InstructionOperation::VPABSQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovswb",
+        value: InstructionOperation::VPMOVSWB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsdb",
+        value: InstructionOperation::VPMOVSDB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsqb",
+        value: InstructionOperation::VPMOVSQB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsdw",
+        value: InstructionOperation::VPMOVSDW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsqw",
+        value: InstructionOperation::VPMOVSQW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovsqd",
+        value: InstructionOperation::VPMOVSQD,
+    },
+    InstructionOperationInfo {
+        name: "vptestmb",
+        value: InstructionOperation::VPTESTMB,
+    },
+    InstructionOperationInfo {
+        name: "vptestmw",
+        value: InstructionOperation::VPTESTMW,
+    },
+    InstructionOperationInfo {
+        name: "vptestnmb",
+        value: InstructionOperation::VPTESTNMB,
+    },
+    InstructionOperationInfo {
+        name: "vptestnmw",
+        value: InstructionOperation::VPTESTNMW,
+    },
+    InstructionOperationInfo {
+        name: "vptestmd",
+        value: InstructionOperation::VPTESTMD,
+    },
+    InstructionOperationInfo {
+        name: "vptestmq",
+        value: InstructionOperation::VPTESTMQ,
+    },
+    InstructionOperationInfo {
+        name: "vptestnmd",
+        value: InstructionOperation::VPTESTNMD,
+    },
+    InstructionOperationInfo {
+        name: "vptestnmq",
+        value: InstructionOperation::VPTESTNMQ,
+    },
+    InstructionOperationInfo {
+        name: "vpmovm2b",
+        value: InstructionOperation::VPMOVM2B,
+    },
+    InstructionOperationInfo {
+        name: "vpmovm2w",
+        value: InstructionOperation::VPMOVM2W,
+    },
+    InstructionOperationInfo {
+        name: "vpmovb2m",
+        value: InstructionOperation::VPMOVB2M,
+    },
+    InstructionOperationInfo {
+        name: "vpmovw2m",
+        value: InstructionOperation::VPMOVW2M,
+    },
+    InstructionOperationInfo {
+        name: "vpbroadcastmb2q",
+        value: InstructionOperation::VPBROADCASTMB2Q,
+    },
+    InstructionOperationInfo {
+        name: "vscalefps",
+        value: InstructionOperation::VSCALEFPS,
+    },
+    InstructionOperationInfo {
+        name: "vscalefpd",
+        value: InstructionOperation::VSCALEFPD,
+    },
+    InstructionOperationInfo {
+        name: "vscalefss",
+        value: InstructionOperation::VSCALEFSS,
+    },
+    InstructionOperationInfo {
+        name: "vscalefsd",
+        value: InstructionOperation::VSCALEFSD,
+    },
+    InstructionOperationInfo {
+        name: "vpmovwb",
+        value: InstructionOperation::VPMOVWB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovdb",
+        value: InstructionOperation::VPMOVDB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovqb",
+        value: InstructionOperation::VPMOVQB,
+    },
+    InstructionOperationInfo {
+        name: "vpmovdw",
+        value: InstructionOperation::VPMOVDW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovqw",
+        value: InstructionOperation::VPMOVQW,
+    },
+    InstructionOperationInfo {
+        name: "vpmovqd",
+        value: InstructionOperation::VPMOVQD,
+    },
+    InstructionOperationInfo {
+        name: "vpmovm2d",
+        value: InstructionOperation::VPMOVM2D,
+    },
+    InstructionOperationInfo {
+        name: "vpmovm2q",
+        value: InstructionOperation::VPMOVM2Q,
+    },
+    InstructionOperationInfo {
+        name: "vpmovd2m",
+        value: InstructionOperation::VPMOVD2M,
+    },
+    InstructionOperationInfo {
+        name: "vpmovq2m",
+        value: InstructionOperation::VPMOVQ2M,
+    },
+    InstructionOperationInfo {
+        name: "vpbroadcastmw2d",
+        value: InstructionOperation::VPBROADCASTMW2D,
+    },
+    InstructionOperationInfo {
+        name: "vgetexpps",
+        value: InstructionOperation::VGETEXPPS,
+    },
+    InstructionOperationInfo {
+        name: "vgetexppd",
+        value: InstructionOperation::VGETEXPPD,
+    },
+    InstructionOperationInfo {
+        name: "vgetexpss",
+        value: InstructionOperation::VGETEXPSS,
+    },
+    InstructionOperationInfo {
+        name: "vgetexpsd",
+        value: InstructionOperation::VGETEXPSD,
+    },
+    InstructionOperationInfo {
+        name: "vplzcntd",
+        value: InstructionOperation::VPLZCNTD,
+    },
+    InstructionOperationInfo {
+        name: "vplzcntq",
+        value: InstructionOperation::VPLZCNTQ,
+    },
+    InstructionOperationInfo {
+        name: "vpsravq",
+        value: InstructionOperation::VPSRAVQ,
+    },
+    InstructionOperationInfo {
+        name: "vrcp14ps",
+        value: InstructionOperation::VRCP14PS,
+    },
+    InstructionOperationInfo {
+        name: "vrcp14pd",
+        value: InstructionOperation::VRCP14PD,
+    },
+    InstructionOperationInfo {
+        name: "vrcp14ss",
+        value: InstructionOperation::VRCP14SS,
+    },
+    InstructionOperationInfo {
+        name: "vrcp14sd",
+        value: InstructionOperation::VRCP14SD,
+    },
+    InstructionOperationInfo {
+        name: "vrsqrt14ps",
+        value: InstructionOperation::VRSQRT14PS,
+    },
+    InstructionOperationInfo {
+        name: "vrsqrt14pd",
+        value: InstructionOperation::VRSQRT14PD,
+    },
+    InstructionOperationInfo {
+        name: "vrsqrt14ss",
+        value: InstructionOperation::VRSQRT14SS,
+    },
+    InstructionOperationInfo {
+        name: "vrsqrt14sd",
+        value: InstructionOperation::VRSQRT14SD,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcasti32x2",
+        value: InstructionOperation::VBROADCASTI32X2,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcasti32x4",
+        value: InstructionOperation::VBROADCASTI32X4,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcasti64x2",
+        value: InstructionOperation::VBROADCASTI64X2,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcasti32x8",
+        value: InstructionOperation::VBROADCASTI32X8,
+    },
+    InstructionOperationInfo {
+        name: "vbroadcasti64x4",
+        value: InstructionOperation::VBROADCASTI64X4,
+    },
+    InstructionOperationInfo {
+        name: "vpblendmd",
+        value: InstructionOperation::VPBLENDMD,
+    },
+    InstructionOperationInfo {
+        name: "vpblendmq",
+        value: InstructionOperation::VPBLENDMQ,
+    },
+    InstructionOperationInfo {
+        name: "vblendmps",
+        value: InstructionOperation::VBLENDMPS,
+    },
+    InstructionOperationInfo {
+        name: "vblendmpd",
+        value: InstructionOperation::VBLENDMPD,
+    },
+    InstructionOperationInfo {
+        name: "vpblendmb",
+        value: InstructionOperation::VPBLENDMB,
+    },
+    InstructionOperationInfo {
+        name: "vpblendmw",
+        value: InstructionOperation::VPBLENDMW,
+    },
+    InstructionOperationInfo {
+        name: "vpermi2w",
+        value: InstructionOperation::VPERMI2W,
+    },
+    InstructionOperationInfo {
+        name: "vpermi2d",
+        value: InstructionOperation::VPERMI2D,
+    },
+    InstructionOperationInfo {
+        name: "vpermi2q",
+        value: InstructionOperation::VPERMI2Q,
+    },
+    InstructionOperationInfo {
+        name: "vpermi2ps",
+        value: InstructionOperation::VPERMI2PS,
+    },
+    InstructionOperationInfo {
+        name: "vpermi2pd",
+        value: InstructionOperation::VPERMI2PD,
+    },
+    InstructionOperationInfo {
+        name: "vpermt2w",
+        value: InstructionOperation::VPERMT2W,
+    },
+    InstructionOperationInfo {
+        name: "vpermt2d",
+        value: InstructionOperation::VPERMT2D,
+    },
+    InstructionOperationInfo {
+        name: "vpermt2q",
+        value: InstructionOperation::VPERMT2Q,
+    },
+    InstructionOperationInfo {
+        name: "vpermt2ps",
+        value: InstructionOperation::VPERMT2PS,
+    },
+    InstructionOperationInfo {
+        name: "vpermt2pd",
+        value: InstructionOperation::VPERMT2PD,
+    },
+    InstructionOperationInfo {
+        name: "vexpandps",
+        value: InstructionOperation::VEXPANDPS,
+    },
+    InstructionOperationInfo {
+        name: "vexpandpd",
+        value: InstructionOperation::VEXPANDPD,
+    },
+    InstructionOperationInfo {
+        name: "vpexpandd",
+        value: InstructionOperation::VPEXPANDD,
+    },
+    InstructionOperationInfo {
+        name: "vpexpandq",
+        value: InstructionOperation::VPEXPANDQ,
+    },
+    InstructionOperationInfo {
+        name: "vcompressps",
+        value: InstructionOperation::VCOMPRESSPS,
+    },
+    InstructionOperationInfo {
+        name: "vcompresspd",
+        value: InstructionOperation::VCOMPRESSPD,
+    },
+    InstructionOperationInfo {
+        name: "vpcompressd",
+        value: InstructionOperation::VPCOMPRESSD,
+    },
+    InstructionOperationInfo {
+        name: "vpcompressq",
+        value: InstructionOperation::VPCOMPRESSQ,
+    },
+    InstructionOperationInfo {
+        name: "vpermw",
+        value: InstructionOperation::VPERMW,
+    },
+    InstructionOperationInfo {
+        name: "vpscatterdd",
+        value: InstructionOperation::VPSCATTERDD,
+    },
+    InstructionOperationInfo {
+        name: "vpscatterdq",
+        value: InstructionOperation::VPSCATTERDQ,
+    },
+    InstructionOperationInfo {
+        name: "vpscatterqd",
+        value: InstructionOperation::VPSCATTERQD,
+    },
+    InstructionOperationInfo {
+        name: "vpscatterqq",
+        value: InstructionOperation::VPSCATTERQQ,
+    },
+    InstructionOperationInfo {
+        name: "vscatterdps",
+        value: InstructionOperation::VSCATTERDPS,
+    },
+    InstructionOperationInfo {
+        name: "vscatterdpd",
+        value: InstructionOperation::VSCATTERDPD,
+    },
+    InstructionOperationInfo {
+        name: "vscatterqps",
+        value: InstructionOperation::VSCATTERQPS,
+    },
+    InstructionOperationInfo {
+        name: "vscatterqpd",
+        value: InstructionOperation::VSCATTERQPD,
+    },
+    InstructionOperationInfo {
+        name: "vpconflictd",
+        value: InstructionOperation::VPCONFLICTD,
+    },
+    InstructionOperationInfo {
+        name: "vpconflictq",
+        value: InstructionOperation::VPCONFLICTQ,
+    },
+    InstructionOperationInfo {
+        name: "valignd",
+        value: InstructionOperation::VALIGND,
+    },
+    InstructionOperationInfo {
+        name: "valignq",
+        value: InstructionOperation::VALIGNQ,
+    },
+    InstructionOperationInfo {
+        name: "vinsertf32x4",
+        value: InstructionOperation::VINSERTF32X4,
+    },
+    InstructionOperationInfo {
+        name: "vinsertf64x2",
+        value: InstructionOperation::VINSERTF64X2,
+    },
+    InstructionOperationInfo {
+        name: "vextractf32x4",
+        value: InstructionOperation::VEXTRACTF32X4,
+    },
+    InstructionOperationInfo {
+        name: "vextractf64x2",
+        value: InstructionOperation::VEXTRACTF64X2,
+    },
+    InstructionOperationInfo {
+        name: "vinsertf32x8",
+        value: InstructionOperation::VINSERTF32X8,
+    },
+    InstructionOperationInfo {
+        name: "vinsertf64x4",
+        value: InstructionOperation::VINSERTF64X4,
+    },
+    InstructionOperationInfo {
+        name: "vextractf32x8",
+        value: InstructionOperation::VEXTRACTF32X8,
+    },
+    InstructionOperationInfo {
+        name: "vextractf64x4",
+        value: InstructionOperation::VEXTRACTF64X4,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpud",
+        value: InstructionOperation::VPCMPUD,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpuq",
+        value: InstructionOperation::VPCMPUQ,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpd",
+        value: InstructionOperation::VPCMPD,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpq",
+        value: InstructionOperation::VPCMPQ,
+    },
+    InstructionOperationInfo {
+        name: "vshuff32x4",
+        value: InstructionOperation::VSHUFF32X4,
+    },
+    InstructionOperationInfo {
+        name: "vshuff64x2",
+        value: InstructionOperation::VSHUFF64X2,
+    },
+    InstructionOperationInfo {
+        name: "vpternlogd",
+        value: InstructionOperation::VPTERNLOGD,
+    },
+    InstructionOperationInfo {
+        name: "vpternlogq",
+        value: InstructionOperation::VPTERNLOGQ,
+    },
+    InstructionOperationInfo {
+        name: "vgetmantps",
+        value: InstructionOperation::VGETMANTPS,
+    },
+    InstructionOperationInfo {
+        name: "vgetmantpd",
+        value: InstructionOperation::VGETMANTPD,
+    },
+    InstructionOperationInfo {
+        name: "vgetmantss",
+        value: InstructionOperation::VGETMANTSS,
+    },
+    InstructionOperationInfo {
+        name: "vgetmantsd",
+        value: InstructionOperation::VGETMANTSD,
+    },
+    InstructionOperationInfo {
+        name: "vinserti32x4",
+        value: InstructionOperation::VINSERTI32X4,
+    },
+    InstructionOperationInfo {
+        name: "vinserti64x2",
+        value: InstructionOperation::VINSERTI64X2,
+    },
+    InstructionOperationInfo {
+        name: "vextracti32x4",
+        value: InstructionOperation::VEXTRACTI32X4,
+    },
+    InstructionOperationInfo {
+        name: "vextracti64x2",
+        value: InstructionOperation::VEXTRACTI64X2,
+    },
+    InstructionOperationInfo {
+        name: "vinserti32x8",
+        value: InstructionOperation::VINSERTI32X8,
+    },
+    InstructionOperationInfo {
+        name: "vinserti64x4",
+        value: InstructionOperation::VINSERTI64X4,
+    },
+    InstructionOperationInfo {
+        name: "vextracti32x8",
+        value: InstructionOperation::VEXTRACTI32X8,
+    },
+    InstructionOperationInfo {
+        name: "vextracti64x4",
+        value: InstructionOperation::VEXTRACTI64X4,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpub",
+        value: InstructionOperation::VPCMPUB,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpuw",
+        value: InstructionOperation::VPCMPUW,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpb",
+        value: InstructionOperation::VPCMPB,
+    },
+    InstructionOperationInfo {
+        name: "vpcmpw",
+        value: InstructionOperation::VPCMPW,
+    },
+    InstructionOperationInfo {
+        name: "vshufi32x4",
+        value: InstructionOperation::VSHUFI32X4,
+    },
+    InstructionOperationInfo {
+        name: "vshufi64x2",
+        value: InstructionOperation::VSHUFI64X2,
+    },
+    InstructionOperationInfo {
+        name: "vrangeps",
+        value: InstructionOperation::VRANGEPS,
+    },
+    InstructionOperationInfo {
+        name: "vrangepd",
+        value: InstructionOperation::VRANGEPD,
+    },
+    InstructionOperationInfo {
+        name: "vrangess",
+        value: InstructionOperation::VRANGESS,
+    },
+    InstructionOperationInfo {
+        name: "vrangesd",
+        value: InstructionOperation::VRANGESD,
+    },
+    InstructionOperationInfo {
+        name: "vfixupimmps",
+        value: InstructionOperation::VFIXUPIMMPS,
+    },
+    InstructionOperationInfo {
+        name: "vfixupimmpd",
+        value: InstructionOperation::VFIXUPIMMPD,
+    },
+    InstructionOperationInfo {
+        name: "vfixupimmss",
+        value: InstructionOperation::VFIXUPIMMSS,
+    },
+    InstructionOperationInfo {
+        name: "vfixupimmsd",
+        value: InstructionOperation::VFIXUPIMMSD,
+    },
+    InstructionOperationInfo {
+        name: "vreduceps",
+        value: InstructionOperation::VREDUCEPS,
+    },
+    InstructionOperationInfo {
+        name: "vreducepd",
+        value: InstructionOperation::VREDUCEPD,
+    },
+    InstructionOperationInfo {
+        name: "vreducess",
+        value: InstructionOperation::VREDUCESS,
+    },
+    InstructionOperationInfo {
+        name: "vreducesd",
+        value: InstructionOperation::VREDUCESD,
+    },
+    InstructionOperationInfo {
+        name: "vfpclassps",
+        value: InstructionOperation::VFPCLASSPS,
+    },
+    InstructionOperationInfo {
+        name: "vfpclasspd",
+        value: InstructionOperation::VFPCLASSPD,
+    },
+    InstructionOperationInfo {
+        name: "vfpclassss",
+        value: InstructionOperation::VFPCLASSSS,
+    },
+    InstructionOperationInfo {
+        name: "vfpclasssd",
+        value: InstructionOperation::VFPCLASSSD,
+    },
+    InstructionOperationInfo {
+        name: "vprord",
+        value: InstructionOperation::VPRORD,
+    },
+    InstructionOperationInfo {
+        name: "vprorq",
+        value: InstructionOperation::VPRORQ,
+    },
+    InstructionOperationInfo {
+        name: "vprold",
+        value: InstructionOperation::VPROLD,
+    },
+    InstructionOperationInfo {
+        name: "vprolq",
+        value: InstructionOperation::VPROLQ,
+    },
+    InstructionOperationInfo {
+        name: "kandw",
+        value: InstructionOperation::KANDW,
+    },
+    InstructionOperationInfo {
+        name: "kandq",
+        value: InstructionOperation::KANDQ,
+    },
+    InstructionOperationInfo {
+        name: "kandb",
+        value: InstructionOperation::KANDB,
+    },
+    InstructionOperationInfo {
+        name: "kandd",
+        value: InstructionOperation::KANDD,
+    },
+    InstructionOperationInfo {
+        name: "kandnw",
+        value: InstructionOperation::KANDNW,
+    },
+    InstructionOperationInfo {
+        name: "kandnq",
+        value: InstructionOperation::KANDNQ,
+    },
+    InstructionOperationInfo {
+        name: "kandnb",
+        value: InstructionOperation::KANDNB,
+    },
+    InstructionOperationInfo {
+        name: "kandnd",
+        value: InstructionOperation::KANDND,
+    },
+    InstructionOperationInfo {
+        name: "korw",
+        value: InstructionOperation::KORW,
+    },
+    InstructionOperationInfo {
+        name: "korq",
+        value: InstructionOperation::KORQ,
+    },
+    InstructionOperationInfo {
+        name: "korb",
+        value: InstructionOperation::KORB,
+    },
+    InstructionOperationInfo {
+        name: "kord",
+        value: InstructionOperation::KORD,
+    },
+    InstructionOperationInfo {
+        name: "kxnorw",
+        value: InstructionOperation::KXNORW,
+    },
+    InstructionOperationInfo {
+        name: "kxnorq",
+        value: InstructionOperation::KXNORQ,
+    },
+    InstructionOperationInfo {
+        name: "kxnorb",
+        value: InstructionOperation::KXNORB,
+    },
+    InstructionOperationInfo {
+        name: "kxnord",
+        value: InstructionOperation::KXNORD,
+    },
+    InstructionOperationInfo {
+        name: "kxorw",
+        value: InstructionOperation::KXORW,
+    },
+    InstructionOperationInfo {
+        name: "kxorq",
+        value: InstructionOperation::KXORQ,
+    },
+    InstructionOperationInfo {
+        name: "kxorb",
+        value: InstructionOperation::KXORB,
+    },
+    InstructionOperationInfo {
+        name: "kxord",
+        value: InstructionOperation::KXORD,
+    },
+    InstructionOperationInfo {
+        name: "kaddw",
+        value: InstructionOperation::KADDW,
+    },
+    InstructionOperationInfo {
+        name: "kaddq",
+        value: InstructionOperation::KADDQ,
+    },
+    InstructionOperationInfo {
+        name: "kaddb",
+        value: InstructionOperation::KADDB,
+    },
+    InstructionOperationInfo {
+        name: "kaddd",
+        value: InstructionOperation::KADDD,
+    },
+    InstructionOperationInfo {
+        name: "knotw",
+        value: InstructionOperation::KNOTW,
+    },
+    InstructionOperationInfo {
+        name: "knotq",
+        value: InstructionOperation::KNOTQ,
+    },
+    InstructionOperationInfo {
+        name: "knotb",
+        value: InstructionOperation::KNOTB,
+    },
+    InstructionOperationInfo {
+        name: "knotd",
+        value: InstructionOperation::KNOTD,
+    },
+    InstructionOperationInfo {
+        name: "kunpckwd",
+        value: InstructionOperation::KUNPCKWD,
+    },
+    InstructionOperationInfo {
+        name: "kunpckdq",
+        value: InstructionOperation::KUNPCKDQ,
+    },
+    InstructionOperationInfo {
+        name: "kunpckbw",
+        value: InstructionOperation::KUNPCKBW,
+    },
+    InstructionOperationInfo {
+        name: "kmovw",
+        value: InstructionOperation::KMOVW,
+    },
+    InstructionOperationInfo {
+        name: "kmovq",
+        value: InstructionOperation::KMOVQ,
+    },
+    InstructionOperationInfo {
+        name: "kmovb",
+        value: InstructionOperation::KMOVB,
+    },
+    InstructionOperationInfo {
+        name: "kmovd",
+        value: InstructionOperation::KMOVD,
+    },
+    InstructionOperationInfo {
+        name: "kortestw",
+        value: InstructionOperation::KORTESTW,
+    },
+    InstructionOperationInfo {
+        name: "kortestq",
+        value: InstructionOperation::KORTESTQ,
+    },
+    InstructionOperationInfo {
+        name: "kortestb",
+        value: InstructionOperation::KORTESTB,
+    },
+    InstructionOperationInfo {
+        name: "kortestd",
+        value: InstructionOperation::KORTESTD,
+    },
+    InstructionOperationInfo {
+        name: "ktestw",
+        value: InstructionOperation::KTESTW,
+    },
+    InstructionOperationInfo {
+        name: "ktestq",
+        value: InstructionOperation::KTESTQ,
+    },
+    InstructionOperationInfo {
+        name: "ktestb",
+        value: InstructionOperation::KTESTB,
+    },
+    InstructionOperationInfo {
+        name: "ktestd",
+        value: InstructionOperation::KTESTD,
+    },
+    InstructionOperationInfo {
+        name: "kshiftrb",
+        value: InstructionOperation::KSHIFTRB,
+    },
+    InstructionOperationInfo {
+        name: "kshiftrw",
+        value: InstructionOperation::KSHIFTRW,
+    },
+    InstructionOperationInfo {
+        name: "kshiftrd",
+        value: InstructionOperation::KSHIFTRD,
+    },
+    InstructionOperationInfo {
+        name: "kshiftrq",
+        value: InstructionOperation::KSHIFTRQ,
+    },
+    InstructionOperationInfo {
+        name: "kshiftlb",
+        value: InstructionOperation::KSHIFTLB,
+    },
+    InstructionOperationInfo {
+        name: "kshiftlw",
+        value: InstructionOperation::KSHIFTLW,
+    },
+    InstructionOperationInfo {
+        name: "kshiftld",
+        value: InstructionOperation::KSHIFTLD,
+    },
+    InstructionOperationInfo {
+        name: "kshiftlq",
+        value: InstructionOperation::KSHIFTLQ,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsub132ps",
+        value: InstructionOperation::VFMADDSUB132PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsub132pd",
+        value: InstructionOperation::VFMADDSUB132PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubadd132ps",
+        value: InstructionOperation::VFMSUBADD132PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubadd132pd",
+        value: InstructionOperation::VFMSUBADD132PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd132ps",
+        value: InstructionOperation::VFMADD132PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd132pd",
+        value: InstructionOperation::VFMADD132PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd132ss",
+        value: InstructionOperation::VFMADD132SS,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd132sd",
+        value: InstructionOperation::VFMADD132SD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub132ps",
+        value: InstructionOperation::VFMSUB132PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub132pd",
+        value: InstructionOperation::VFMSUB132PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub132ss",
+        value: InstructionOperation::VFMSUB132SS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub132sd",
+        value: InstructionOperation::VFMSUB132SD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd132ps",
+        value: InstructionOperation::VFNMADD132PS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd132pd",
+        value: InstructionOperation::VFNMADD132PD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd132ss",
+        value: InstructionOperation::VFNMADD132SS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd132sd",
+        value: InstructionOperation::VFNMADD132SD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub132ps",
+        value: InstructionOperation::VFNMSUB132PS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub132pd",
+        value: InstructionOperation::VFNMSUB132PD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub132ss",
+        value: InstructionOperation::VFNMSUB132SS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub132sd",
+        value: InstructionOperation::VFNMSUB132SD,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsub213ps",
+        value: InstructionOperation::VFMADDSUB213PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsub213pd",
+        value: InstructionOperation::VFMADDSUB213PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubadd213ps",
+        value: InstructionOperation::VFMSUBADD213PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubadd213pd",
+        value: InstructionOperation::VFMSUBADD213PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd213ps",
+        value: InstructionOperation::VFMADD213PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd213pd",
+        value: InstructionOperation::VFMADD213PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd213ss",
+        value: InstructionOperation::VFMADD213SS,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd213sd",
+        value: InstructionOperation::VFMADD213SD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub213ps",
+        value: InstructionOperation::VFMSUB213PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub213pd",
+        value: InstructionOperation::VFMSUB213PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub213ss",
+        value: InstructionOperation::VFMSUB213SS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub213sd",
+        value: InstructionOperation::VFMSUB213SD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd213ps",
+        value: InstructionOperation::VFNMADD213PS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd213pd",
+        value: InstructionOperation::VFNMADD213PD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd213ss",
+        value: InstructionOperation::VFNMADD213SS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd213sd",
+        value: InstructionOperation::VFNMADD213SD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub213ps",
+        value: InstructionOperation::VFNMSUB213PS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub213pd",
+        value: InstructionOperation::VFNMSUB213PD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub213ss",
+        value: InstructionOperation::VFNMSUB213SS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub213sd",
+        value: InstructionOperation::VFNMSUB213SD,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsub231ps",
+        value: InstructionOperation::VFMADDSUB231PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsub231pd",
+        value: InstructionOperation::VFMADDSUB231PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubadd231ps",
+        value: InstructionOperation::VFMSUBADD231PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubadd231pd",
+        value: InstructionOperation::VFMSUBADD231PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd231ps",
+        value: InstructionOperation::VFMADD231PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd231pd",
+        value: InstructionOperation::VFMADD231PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd231ss",
+        value: InstructionOperation::VFMADD231SS,
+    },
+    InstructionOperationInfo {
+        name: "vfmadd231sd",
+        value: InstructionOperation::VFMADD231SD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub231ps",
+        value: InstructionOperation::VFMSUB231PS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub231pd",
+        value: InstructionOperation::VFMSUB231PD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub231ss",
+        value: InstructionOperation::VFMSUB231SS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsub231sd",
+        value: InstructionOperation::VFMSUB231SD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd231ps",
+        value: InstructionOperation::VFNMADD231PS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd231pd",
+        value: InstructionOperation::VFNMADD231PD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd231ss",
+        value: InstructionOperation::VFNMADD231SS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmadd231sd",
+        value: InstructionOperation::VFNMADD231SD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub231ps",
+        value: InstructionOperation::VFNMSUB231PS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub231pd",
+        value: InstructionOperation::VFNMSUB231PD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub231ss",
+        value: InstructionOperation::VFNMSUB231SS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsub231sd",
+        value: InstructionOperation::VFNMSUB231SD,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsubps",
+        value: InstructionOperation::VFMADDSUBPS,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsubpd",
+        value: InstructionOperation::VFMADDSUBPD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubaddps",
+        value: InstructionOperation::VFMSUBADDPS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubaddpd",
+        value: InstructionOperation::VFMSUBADDPD,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddps",
+        value: InstructionOperation::VFMADDPS,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddpd",
+        value: InstructionOperation::VFMADDPD,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddss",
+        value: InstructionOperation::VFMADDSS,
+    },
+    InstructionOperationInfo {
+        name: "vfmaddsd",
+        value: InstructionOperation::VFMADDSD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubps",
+        value: InstructionOperation::VFMSUBPS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubpd",
+        value: InstructionOperation::VFMSUBPD,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubss",
+        value: InstructionOperation::VFMSUBSS,
+    },
+    InstructionOperationInfo {
+        name: "vfmsubsd",
+        value: InstructionOperation::VFMSUBSD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmaddps",
+        value: InstructionOperation::VFNMADDPS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmaddpd",
+        value: InstructionOperation::VFNMADDPD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmaddss",
+        value: InstructionOperation::VFNMADDSS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmaddsd",
+        value: InstructionOperation::VFNMADDSD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsubps",
+        value: InstructionOperation::VFNMSUBPS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsubpd",
+        value: InstructionOperation::VFNMSUBPD,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsubss",
+        value: InstructionOperation::VFNMSUBSS,
+    },
+    InstructionOperationInfo {
+        name: "vfnmsubsd",
+        value: InstructionOperation::VFNMSUBSD,
+    },
+    InstructionOperationInfo {
+        name: "vpmacssww",
+        value: InstructionOperation::VPMACSSWW,
+    },
+    InstructionOperationInfo {
+        name: "vpmacsswd",
+        value: InstructionOperation::VPMACSSWD,
+    },
+    InstructionOperationInfo {
+        name: "vpmacssdql",
+        value: InstructionOperation::VPMACSSDQL,
+    },
+    InstructionOperationInfo {
+        name: "vpmacssdd",
+        value: InstructionOperation::VPMACSSDD,
+    },
+    InstructionOperationInfo {
+        name: "vpmacssdqh",
+        value: InstructionOperation::VPMACSSDQH,
+    },
+    InstructionOperationInfo {
+        name: "vpmacsww",
+        value: InstructionOperation::VPMACSWW,
+    },
+    InstructionOperationInfo {
+        name: "vpmacswd",
+        value: InstructionOperation::VPMACSWD,
+    },
+    InstructionOperationInfo {
+        name: "vpmacsdql",
+        value: InstructionOperation::VPMACSDQL,
+    },
+    InstructionOperationInfo {
+        name: "vpmacsdd",
+        value: InstructionOperation::VPMACSDD,
+    },
+    InstructionOperationInfo {
+        name: "vpmacsdqh",
+        value: InstructionOperation::VPMACSDQH,
+    },
+    InstructionOperationInfo {
+        name: "vpmadcsswd",
+        value: InstructionOperation::VPMADCSSWD,
+    },
+    InstructionOperationInfo {
+        name: "vpmadcswd",
+        value: InstructionOperation::VPMADCSWD,
+    },
+    InstructionOperationInfo {
+        name: "vpcmov",
+        value: InstructionOperation::VPCMOV,
+    },
+    InstructionOperationInfo {
+        name: "vpperm",
+        value: InstructionOperation::VPPERM,
+    },
+    InstructionOperationInfo {
+        name: "vprotb",
+        value: InstructionOperation::VPROTB,
+    },
+    InstructionOperationInfo {
+        name: "vprotw",
+        value: InstructionOperation::VPROTW,
+    },
+    InstructionOperationInfo {
+        name: "vprotd",
+        value: InstructionOperation::VPROTD,
+    },
+    InstructionOperationInfo {
+        name: "vprotq",
+        value: InstructionOperation::VPROTQ,
+    },
+    InstructionOperationInfo {
+        name: "vpcomb",
+        value: InstructionOperation::VPCOMB,
+    },
+    InstructionOperationInfo {
+        name: "vpcomw",
+        value: InstructionOperation::VPCOMW,
+    },
+    InstructionOperationInfo {
+        name: "vpcomd",
+        value: InstructionOperation::VPCOMD,
+    },
+    InstructionOperationInfo {
+        name: "vpcomq",
+        value: InstructionOperation::VPCOMQ,
+    },
+    InstructionOperationInfo {
+        name: "vpcomub",
+        value: InstructionOperation::VPCOMUB,
+    },
+    InstructionOperationInfo {
+        name: "vpcomuw",
+        value: InstructionOperation::VPCOMUW,
+    },
+    InstructionOperationInfo {
+        name: "vpcomud",
+        value: InstructionOperation::VPCOMUD,
+    },
+    InstructionOperationInfo {
+        name: "vpcomuq",
+        value: InstructionOperation::VPCOMUQ,
+    },
+    InstructionOperationInfo {
+        name: "vfrczps",
+        value: InstructionOperation::VFRCZPS,
+    },
+    InstructionOperationInfo {
+        name: "vfrczpd",
+        value: InstructionOperation::VFRCZPD,
+    },
+    InstructionOperationInfo {
+        name: "vfrczss",
+        value: InstructionOperation::VFRCZSS,
+    },
+    InstructionOperationInfo {
+        name: "vfrczsd",
+        value: InstructionOperation::VFRCZSD,
+    },
+    InstructionOperationInfo {
+        name: "vpshlb",
+        value: InstructionOperation::VPSHLB,
+    },
+    InstructionOperationInfo {
+        name: "vpshlw",
+        value: InstructionOperation::VPSHLW,
+    },
+    InstructionOperationInfo {
+        name: "vpshld",
+        value: InstructionOperation::VPSHLD,
+    },
+    InstructionOperationInfo {
+        name: "vpshlq",
+        value: InstructionOperation::VPSHLQ,
+    },
+    InstructionOperationInfo {
+        name: "vpshab",
+        value: InstructionOperation::VPSHAB,
+    },
+    InstructionOperationInfo {
+        name: "vpshaw",
+        value: InstructionOperation::VPSHAW,
+    },
+    InstructionOperationInfo {
+        name: "vpshad",
+        value: InstructionOperation::VPSHAD,
+    },
+    InstructionOperationInfo {
+        name: "vpshaq",
+        value: InstructionOperation::VPSHAQ,
+    },
+    InstructionOperationInfo {
+        name: "vphaddbw",
+        value: InstructionOperation::VPHADDBW,
+    },
+    InstructionOperationInfo {
+        name: "vphaddbd",
+        value: InstructionOperation::VPHADDBD,
+    },
+    InstructionOperationInfo {
+        name: "vphaddbq",
+        value: InstructionOperation::VPHADDBQ,
+    },
+    InstructionOperationInfo {
+        name: "vphaddwd",
+        value: InstructionOperation::VPHADDWD,
+    },
+    InstructionOperationInfo {
+        name: "vphaddwq",
+        value: InstructionOperation::VPHADDWQ,
+    },
+    InstructionOperationInfo {
+        name: "vphadddq",
+        value: InstructionOperation::VPHADDDQ,
+    },
+    InstructionOperationInfo {
+        name: "vphaddubw",
+        value: InstructionOperation::VPHADDUBW,
+    },
+    InstructionOperationInfo {
+        name: "vphaddubd",
+        value: InstructionOperation::VPHADDUBD,
+    },
+    InstructionOperationInfo {
+        name: "vphaddubq",
+        value: InstructionOperation::VPHADDUBQ,
+    },
+    InstructionOperationInfo {
+        name: "vphadduwd",
+        value: InstructionOperation::VPHADDUWD,
+    },
+    InstructionOperationInfo {
+        name: "vphadduwq",
+        value: InstructionOperation::VPHADDUWQ,
+    },
+    InstructionOperationInfo {
+        name: "vphaddudq",
+        value: InstructionOperation::VPHADDUDQ,
+    },
+    InstructionOperationInfo {
+        name: "vphsubbw",
+        value: InstructionOperation::VPHSUBBW,
+    },
+    InstructionOperationInfo {
+        name: "vphsubwd",
+        value: InstructionOperation::VPHSUBWD,
+    },
+    InstructionOperationInfo {
+        name: "vphsubdq",
+        value: InstructionOperation::VPHSUBDQ,
+    },
+    InstructionOperationInfo {
+        name: "tzcnt",
+        value: InstructionOperation::TZCNT,
+    },
+    InstructionOperationInfo {
+        name: "lzcnt",
+        value: InstructionOperation::LZCNT,
+    },
+    InstructionOperationInfo {
+        name: "movbe",
+        value: InstructionOperation::MOVBE,
+    },
+    InstructionOperationInfo {
+        name: "adcx",
+        value: InstructionOperation::ADCX,
+    },
+    InstructionOperationInfo {
+        name: "adox",
+        value: InstructionOperation::ADOX,
+    },
+    InstructionOperationInfo {
+        name: "andn",
+        value: InstructionOperation::ANDN,
+    },
+    InstructionOperationInfo {
+        name: "bextr",
+        value: InstructionOperation::BEXTR,
+    },
+    InstructionOperationInfo {
+        name: "blsr",
+        value: InstructionOperation::BLSR,
+    },
+    InstructionOperationInfo {
+        name: "blsmsk",
+        value: InstructionOperation::BLSMSK,
+    },
+    InstructionOperationInfo {
+        name: "blsi",
+        value: InstructionOperation::BLSI,
+    },
+    InstructionOperationInfo {
+        name: "bzhi",
+        value: InstructionOperation::BZHI,
+    },
+    InstructionOperationInfo {
+        name: "mulx",
+        value: InstructionOperation::MULX,
+    },
+    InstructionOperationInfo {
+        name: "pdep",
+        value: InstructionOperation::PDEP,
+    },
+    InstructionOperationInfo {
+        name: "pext",
+        value: InstructionOperation::PEXT,
+    },
+    InstructionOperationInfo {
+        name: "rorx",
+        value: InstructionOperation::RORX,
+    },
+    InstructionOperationInfo {
+        name: "sarx",
+        value: InstructionOperation::SARX,
+    },
+    InstructionOperationInfo {
+        name: "shlx",
+        value: InstructionOperation::SHLX,
+    },
+    InstructionOperationInfo {
+        name: "shrx",
+        value: InstructionOperation::SHRX,
+    },
+    InstructionOperationInfo {
+        name: "aesimc",
+        value: InstructionOperation::AESIMC,
+    },
+    InstructionOperationInfo {
+        name: "aesenc",
+        value: InstructionOperation::AESENC,
+    },
+    InstructionOperationInfo {
+        name: "aesenclast",
+        value: InstructionOperation::AESENCLAST,
+    },
+    InstructionOperationInfo {
+        name: "aesdec",
+        value: InstructionOperation::AESDEC,
+    },
+    InstructionOperationInfo {
+        name: "aesdeclast",
+        value: InstructionOperation::AESDECLAST,
+    },
+    InstructionOperationInfo {
+        name: "aeskeygenassist",
+        value: InstructionOperation::AESKEYGENASSIST,
+    },
+    InstructionOperationInfo {
+        name: "pclmulqdq",
+        value: InstructionOperation::PCLMULQDQ,
+    },
+    InstructionOperationInfo {
+        name: "sha1rnds4",
+        value: InstructionOperation::SHA1RNDS4,
+    },
+    InstructionOperationInfo {
+        name: "sha1nexte",
+        value: InstructionOperation::SHA1NEXTE,
+    },
+    InstructionOperationInfo {
+        name: "sha1msg1",
+        value: InstructionOperation::SHA1MSG1,
+    },
+    InstructionOperationInfo {
+        name: "sha1msg2",
+        value: InstructionOperation::SHA1MSG2,
+    },
+    InstructionOperationInfo {
+        name: "sha256rnds2",
+        value: InstructionOperation::SHA256RNDS2,
+    },
+    InstructionOperationInfo {
+        name: "sha256msg1",
+        value: InstructionOperation::SHA256MSG1,
+    },
+    InstructionOperationInfo {
+        name: "sha256msg2",
+        value: InstructionOperation::SHA256MSG2,
+    },
+    InstructionOperationInfo {
+        name: "gf2p8mulb",
+        value: InstructionOperation::GF2P8MULB,
+    },
+    InstructionOperationInfo {
+        name: "gf2p8affineqb",
+        value: InstructionOperation::GF2P8AFFINEQB,
+    },
+    InstructionOperationInfo {
+        name: "gf2p8affineinvqb",
+        value: InstructionOperation::GF2P8AFFINEINVQB,
+    },
+    InstructionOperationInfo {
+        name: "vaesimc",
+        value: InstructionOperation::VAESIMC,
+    },
+    InstructionOperationInfo {
+        name: "vaesenc",
+        value: InstructionOperation::VAESENC,
+    },
+    InstructionOperationInfo {
+        name: "vaesenclast",
+        value: InstructionOperation::VAESENCLAST,
+    },
+    InstructionOperationInfo {
+        name: "vaesdec",
+        value: InstructionOperation::VAESDEC,
+    },
+    InstructionOperationInfo {
+        name: "vaesdeclast",
+        value: InstructionOperation::VAESDECLAST,
+    },
+    InstructionOperationInfo {
+        name: "vaeskeygenassist",
+        value: InstructionOperation::VAESKEYGENASSIST,
+    },
+    InstructionOperationInfo {
+        name: "vpclmulqdq",
+        value: InstructionOperation::VPCLMULQDQ,
+    },
+    InstructionOperationInfo {
+        name: "vgf2p8mulb",
+        value: InstructionOperation::VGF2P8MULB,
+    },
+    InstructionOperationInfo {
+        name: "vgf2p8affineqb",
+        value: InstructionOperation::VGF2P8AFFINEQB,
+    },
+    InstructionOperationInfo {
+        name: "vgf2p8affineinvqb",
+        value: InstructionOperation::VGF2P8AFFINEINVQB,
+    },
+    InstructionOperationInfo {
+        name: "endbr32",
+        value: InstructionOperation::ENDBR32,
+    },
+    InstructionOperationInfo {
+        name: "endbr64",
+        value: InstructionOperation::ENDBR64,
+    },
+    InstructionOperationInfo {
+        name: "incsspd",
+        value: InstructionOperation::INCSSPD,
+    },
+    InstructionOperationInfo {
+        name: "incsspq",
+        value: InstructionOperation::INCSSPQ,
+    },
+    InstructionOperationInfo {
+        name: "rdsspd",
+        value: InstructionOperation::RDSSPD,
+    },
+    InstructionOperationInfo {
+        name: "rdsspq",
+        value: InstructionOperation::RDSSPQ,
+    },
+    InstructionOperationInfo {
+        name: "saveprevssp",
+        value: InstructionOperation::SAVEPREVSSP,
+    },
+    InstructionOperationInfo {
+        name: "rstorssp",
+        value: InstructionOperation::RSTORSSP,
+    },
+    InstructionOperationInfo {
+        name: "wrssd",
+        value: InstructionOperation::WRSSD,
+    },
+    InstructionOperationInfo {
+        name: "wrssq",
+        value: InstructionOperation::WRSSQ,
+    },
+    InstructionOperationInfo {
+        name: "wrussd",
+        value: InstructionOperation::WRUSSD,
+    },
+    InstructionOperationInfo {
+        name: "wrussq",
+        value: InstructionOperation::WRUSSQ,
+    },
+    InstructionOperationInfo {
+        name: "setssbsy",
+        value: InstructionOperation::SETSSBSY,
+    },
+    InstructionOperationInfo {
+        name: "clrssbsy",
+        value: InstructionOperation::CLRSSBSY,
+    },
+    InstructionOperationInfo {
+        name: "xbegin",
+        value: InstructionOperation::XBEGIN,
+    },
+    InstructionOperationInfo {
+        name: "xend",
+        value: InstructionOperation::XEND,
+    },
+    InstructionOperationInfo {
+        name: "xabort",
+        value: InstructionOperation::XABORT,
+    },
+    InstructionOperationInfo {
+        name: "xtest",
+        value: InstructionOperation::XTEST,
+    },
+    InstructionOperationInfo {
+        name: "rdrand",
+        value: InstructionOperation::RDRAND,
+    },
+    InstructionOperationInfo {
+        name: "rdseed",
+        value: InstructionOperation::RDSEED,
+    },
+    InstructionOperationInfo {
+        name: "rdpid",
+        value: InstructionOperation::RDPID,
+    },
+    InstructionOperationInfo {
+        name: "xsaveopt",
+        value: InstructionOperation::XSAVEOPT,
+    },
+    InstructionOperationInfo {
+        name: "xsavec",
+        value: InstructionOperation::XSAVEC,
+    },
+    InstructionOperationInfo {
+        name: "xsaves",
+        value: InstructionOperation::XSAVES,
+    },
+    InstructionOperationInfo {
+        name: "xrstors",
+        value: InstructionOperation::XRSTORS,
+    },
+    InstructionOperationInfo {
+        name: "clflushopt",
+        value: InstructionOperation::CLFLUSHOPT,
+    },
+    InstructionOperationInfo {
+        name: "clwb",
+        value: InstructionOperation::CLWB,
+    },
+    InstructionOperationInfo {
+        name: "prefetchwt1",
+        value: InstructionOperation::PREFETCHWT1,
+    },
+    InstructionOperationInfo {
+        name: "invept",
+        value: InstructionOperation::INVEPT,
+    },
+    InstructionOperationInfo {
+        name: "invvpid",
+        value: InstructionOperation::INVVPID,
+    },
+    InstructionOperationInfo {
+        name: "vmfunc",
+        value: InstructionOperation::VMFUNC,
+    },
+    InstructionOperationInfo {
+        name: "vmrun",
+        value: InstructionOperation::VMRUN,
+    },
+    InstructionOperationInfo {
+        name: "vmmcall",
+        value: InstructionOperation::VMMCALL,
+    },
+    InstructionOperationInfo {
+        name: "vmload",
+        value: InstructionOperation::VMLOAD,
+    },
+    InstructionOperationInfo {
+        name: "vmsave",
+        value: InstructionOperation::VMSAVE,
+    },
+    InstructionOperationInfo {
+        name: "stgi",
+        value: InstructionOperation::STGI,
+    },
+    InstructionOperationInfo {
+        name: "clgi",
+        value: InstructionOperation::CLGI,
+    },
+    InstructionOperationInfo {
+        name: "skinit",
+        value: InstructionOperation::SKINIT,
+    },
+    InstructionOperationInfo {
+        name: "invlpga",
+        value: InstructionOperation::INVLPGA,
+    },
+    InstructionOperationInfo {
+        name: "jmpabs",
+        value: InstructionOperation::JMPABS,
+    },
+    InstructionOperationInfo {
+        name: "pushp",
+        value: InstructionOperation::PUSHP,
+    },
+    InstructionOperationInfo {
+        name: "popp",
+        value: InstructionOperation::POPP,
+    },
+    InstructionOperationInfo {
+        name: "push2",
+        value: InstructionOperation::PUSH2,
+    },
+    InstructionOperationInfo {
+        name: "push2p",
+        value: InstructionOperation::PUSH2P,
+    },
+    InstructionOperationInfo {
+        name: "pop2",
+        value: InstructionOperation::POP2,
+    },
+    InstructionOperationInfo {
+        name: "pop2p",
+        value: InstructionOperation::POP2P,
+    },
+    InstructionOperationInfo {
+        name: "ldtilecfg",
+        value: InstructionOperation::LDTILECFG,
+    },
+    InstructionOperationInfo {
+        name: "sttilecfg",
+        value: InstructionOperation::STTILECFG,
+    },
+    InstructionOperationInfo {
+        name: "tilerelease",
+        value: InstructionOperation::TILERELEASE,
+    },
+    InstructionOperationInfo {
+        name: "tilezero",
+        value: InstructionOperation::TILEZERO,
+    },
+    InstructionOperationInfo {
+        name: "tileloadd",
+        value: InstructionOperation::TILELOADD,
+    },
+    InstructionOperationInfo {
+        name: "tileloaddt1",
+        value: InstructionOperation::TILELOADDT1,
+    },
+    InstructionOperationInfo {
+        name: "tilestored",
+        value: InstructionOperation::TILESTORED,
+    },
+    InstructionOperationInfo {
+        name: "tdpbssd",
+        value: InstructionOperation::TDPBSSD,
+    },
+    InstructionOperationInfo {
+        name: "tdpbsud",
+        value: InstructionOperation::TDPBSUD,
+    },
+    InstructionOperationInfo {
+        name: "tdpbusd",
+        value: InstructionOperation::TDPBUSD,
+    },
+    InstructionOperationInfo {
+        name: "tdpbuud",
+        value: InstructionOperation::TDPBUUD,
+    },
+    InstructionOperationInfo {
+        name: "tdpbf16ps",
+        value: InstructionOperation::TDPBF16PS,
+    },
+    InstructionOperationInfo {
+        name: "tdpfp16ps",
+        value: InstructionOperation::TDPFP16PS,
+    },
+    InstructionOperationInfo {
+        name: "tcmmimfp16ps",
+        value: InstructionOperation::TCMMIMFP16PS,
+    },
+    InstructionOperationInfo {
+        name: "tcmmrlfp16ps",
+        value: InstructionOperation::TCMMRLFP16PS,
+    },
+    InstructionOperationInfo {
+        name: "ud0",
+        value: InstructionOperation::UD0,
+    },
+    InstructionOperationInfo {
+        name: "ud1",
+        value: InstructionOperation::UD1,
+    },
 ];
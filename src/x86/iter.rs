@@ -0,0 +1,147 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{disassemble, DecodeError, Imm, InstructionOperation};
+use super::{X86Instruction, X86Mode, X86Operand};
+
+/// What an [`InstructionIter`] does when it reaches bytes that do not
+/// decode to a valid instruction.
+///
+/// Running out of bytes in the middle of an instruction always stops
+/// the iteration, regardless of the policy, so that the caller can
+/// supply more bytes and resume from [`InstructionIter::offset`].
+///
+/// [`InstructionIter`]: struct.InstructionIter.html
+/// [`InstructionIter::offset`]: struct.InstructionIter.html#method.offset
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum InvalidBytePolicy {
+    /// Stop iterating. The error is available from
+    /// [`InstructionIter::error`].
+    ///
+    /// [`InstructionIter::error`]: struct.InstructionIter.html#method.error
+    #[default]
+    Stop,
+    /// Skip a single byte and continue decoding after it.
+    Skip,
+    /// Yield a one byte instruction with the operation
+    /// `InstructionOperation::INVALID` and the offending byte as
+    /// an immediate operand, then continue decoding after it.
+    Emit,
+}
+
+/// An iterator over the instructions in a buffer of opcodes.
+///
+/// Each item is the address of an instruction along with the
+/// instruction itself.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // nop; push rbp; (bad); ret; and a truncated mov eax, imm32
+/// let data = [0x90u8, 0x55, 0x60, 0xc3, 0xb8, 0x01];
+/// let mut iter = InstructionIter::new(X86Mode::Bits64, &data, 0x1000)
+///     .with_policy(InvalidBytePolicy::Emit);
+/// let ops = iter.by_ref().map(|(addr, instr)| (addr, instr.operation)).collect::<Vec<_>>();
+/// assert_eq!(ops, [
+///     (0x1000, InstructionOperation::NOP),
+///     (0x1001, InstructionOperation::PUSH),
+///     (0x1002, InstructionOperation::INVALID),
+///     (0x1003, InstructionOperation::RETN),
+/// ]);
+/// assert!(iter.error().unwrap().is_insufficient_length());
+/// assert_eq!(iter.offset(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct InstructionIter<'a> {
+    mode: X86Mode,
+    bytes: &'a [u8],
+    addr: u64,
+    offset: usize,
+    policy: InvalidBytePolicy,
+    error: Option<DecodeError>,
+}
+
+impl<'a> InstructionIter<'a> {
+    /// Create an iterator over the instructions in `bytes`, which are
+    /// located at `addr`, decoding them in the given `mode`.
+    pub fn new(mode: X86Mode, bytes: &'a [u8], addr: u64) -> Self {
+        InstructionIter {
+            mode,
+            bytes,
+            addr,
+            offset: 0,
+            policy: InvalidBytePolicy::default(),
+            error: None,
+        }
+    }
+
+    /// Set the policy used for bytes that do not decode.
+    pub fn with_policy(mut self, policy: InvalidBytePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The offset within the buffer of the next instruction to decode.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The error that stopped the iteration, if any.
+    pub fn error(&self) -> Option<DecodeError> {
+        self.error
+    }
+}
+
+impl<'a> Iterator for InstructionIter<'a> {
+    type Item = (u64, X86Instruction);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.error.is_some() || self.offset >= self.bytes.len() {
+                return None;
+            }
+            let bytes = &self.bytes[self.offset..];
+            let addr = self.addr.wrapping_add(self.offset as u64);
+            match disassemble(self.mode, bytes, addr) {
+                Ok(instr) => {
+                    self.offset += instr.length;
+                    return Some((addr, instr));
+                }
+                Err(err) => {
+                    if err.is_insufficient_length() {
+                        self.error = Some(err);
+                        return None;
+                    }
+                    match self.policy {
+                        InvalidBytePolicy::Stop => {
+                            self.error = Some(err);
+                            return None;
+                        }
+                        InvalidBytePolicy::Skip => {
+                            self.offset += 1;
+                        }
+                        InvalidBytePolicy::Emit => {
+                            let mut instr = X86Instruction {
+                                operation: InstructionOperation::INVALID,
+                                length: 1,
+                                mode: self.mode,
+                                ..Default::default()
+                            };
+                            instr.operands[0] = X86Operand::Immediate(Imm {
+                                value: i64::from(bytes[0]),
+                                size: 1,
+                                encoded_size: 1,
+                                signed: false,
+                            });
+                            instr.operand_count = 1;
+                            self.offset += 1;
+                            return Some((addr, instr));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
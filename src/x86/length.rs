@@ -0,0 +1,429 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp;
+
+use super::{disassemble, DecodeError, DecodeFlags, InstructionOperation, X86Mode};
+use super::{GROUP_OPERATIONS, MAIN_OPCODE_MAP, SSE_TABLE, TWO_BYTE_OPCODE_MAP};
+
+/// What follows an opcode byte, as far as the length of the
+/// instruction is concerned.
+///
+/// Each class mirrors one or more of the decode functions in the
+/// opcode maps, including the checks that they make which can cause
+/// an instruction to be invalid.
+#[derive(Clone, Copy)]
+enum LengthClass {
+    /// Left to the full decoder.
+    Decode,
+    /// Nothing beyond the opcode.
+    Opcode,
+    /// A ModRM byte and any SIB byte and displacement.
+    ModRm,
+    /// A ModRM followed by an operand sized immediate.
+    ModRmImm,
+    /// A ModRM followed by an 8 bit immediate.
+    ModRmImm8,
+    /// An operand sized immediate.
+    Imm,
+    /// An 8 bit immediate.
+    Imm8,
+    /// A 16 bit immediate followed by an 8 bit immediate.
+    Imm16Imm8,
+    /// An operand sized immediate that may be 64 bits.
+    OpRegImm,
+    /// An address sized memory offset.
+    Addr,
+    /// An operand sized relative offset.
+    Rel,
+    /// A ModRM whose reg field selects the operation.
+    Group,
+    /// A `Group` followed by an operand sized immediate.
+    GroupImm,
+    /// A `Group` followed by an 8 bit immediate.
+    GroupImm8,
+    /// A `Group` followed by an immediate for `TEST` only.
+    GroupF6F7,
+    /// A `Group` where far calls and jumps need a memory operand.
+    GroupFF,
+    /// A ModRM whose operation comes from the SSE table.
+    SseTable,
+    /// A `SseTable` followed by an 8 bit immediate.
+    SseTableImm8,
+    /// A ModRM that is invalid with a `REPNE` or `REPE` prefix.
+    SsePacked,
+    /// A ModRM that is invalid with an operand size or `REPNE` prefix.
+    SseSingle,
+    /// A ModRM that requires an operand size prefix.
+    SseOnly,
+    /// An escape to the two byte opcode map.
+    TwoByte,
+}
+
+use self::LengthClass::*;
+
+static MAIN_OPCODE_LENGTHS: [LengthClass; 256] = [
+    // 0x00
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Opcode, Opcode,
+    // 0x08
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Opcode, TwoByte,
+    // 0x10
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Opcode, Opcode,
+    // 0x18
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Opcode, Opcode,
+    // 0x20
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Decode, Opcode,
+    // 0x28
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Decode, Opcode,
+    // 0x30
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Decode, Opcode,
+    // 0x38
+    ModRm, ModRm, ModRm, ModRm, Imm, Imm, Decode, Opcode,
+    // 0x40
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0x48
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0x50
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0x58
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0x60
+    Opcode, Opcode, Decode, ModRm, Decode, Decode, Decode, Decode,
+    // 0x68
+    Imm, ModRmImm, Imm, ModRmImm, Opcode, Opcode, Opcode, Opcode,
+    // 0x70
+    Rel, Rel, Rel, Rel, Rel, Rel, Rel, Rel,
+    // 0x78
+    Rel, Rel, Rel, Rel, Rel, Rel, Rel, Rel,
+    // 0x80
+    GroupImm, GroupImm, GroupImm, GroupImm, ModRm, ModRm, ModRm, ModRm,
+    // 0x88
+    ModRm, ModRm, ModRm, ModRm, Decode, ModRm, Decode, Decode,
+    // 0x90
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0x98
+    Opcode, Opcode, Decode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0xa0
+    Addr, Addr, Addr, Addr, Opcode, Opcode, Opcode, Opcode,
+    // 0xa8
+    Imm, Imm, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0xb0
+    OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm,
+    // 0xb8
+    OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm, OpRegImm,
+    // 0xc0
+    GroupImm, GroupImm8, Imm, Opcode, Decode, Decode, GroupImm, GroupImm,
+    // 0xc8
+    Imm16Imm8, Opcode, Imm, Opcode, Opcode, Imm, Opcode, Opcode,
+    // 0xd0
+    Group, Group, Group, Group, Imm, Imm, Decode, Opcode,
+    // 0xd8
+    Decode, Decode, Decode, Decode, Decode, Decode, Decode, Decode,
+    // 0xe0
+    Rel, Rel, Rel, Rel, Imm8, Imm8, Imm8, Imm8,
+    // 0xe8
+    Rel, Rel, Decode, Rel, Opcode, Opcode, Opcode, Opcode,
+    // 0xf0
+    Decode, Opcode, Decode, Decode, Opcode, Opcode, GroupF6F7, GroupF6F7,
+    // 0xf8
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Group, GroupFF,
+];
+
+static TWO_BYTE_OPCODE_LENGTHS: [LengthClass; 256] = [
+    // 0x00
+    Decode, Decode, ModRm, ModRm, Decode, Opcode, Opcode, Opcode,
+    // 0x08
+    Opcode, Opcode, Decode, Opcode, Decode, Group, Opcode, Decode,
+    // 0x10
+    SseTable, SseTable, SseTable, SseTable, SseTable, SseTable, SseTable, SseTable,
+    // 0x18
+    Group, Group, Group, Group, Group, Group, Group, Group,
+    // 0x20
+    Decode, Decode, Decode, Decode, Decode, Decode, Decode, Decode,
+    // 0x28
+    SseTable, SseTable, SseTable, SseTable, SseTable, SseTable, SseTable, SseTable,
+    // 0x30
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Decode, Opcode,
+    // 0x38
+    Decode, Decode, Decode, Decode, Decode, Decode, Decode, Decode,
+    // 0x40
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0x48
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0x50
+    SseTable, ModRm, SseSingle, SseSingle, SsePacked, SsePacked, SsePacked, SsePacked,
+    // 0x58
+    ModRm, ModRm, SseTable, SseTable, ModRm, ModRm, ModRm, ModRm,
+    // 0x60
+    SseTable, SseTable, SseTable, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0x68
+    ModRm, ModRm, ModRm, ModRm, SseOnly, SseOnly, SseTable, SseTable,
+    // 0x70
+    SseTableImm8, Decode, Decode, Decode, ModRm, ModRm, ModRm, Opcode,
+    // 0x78
+    Decode, Decode, Decode, Decode, SseTable, SseTable, SseTable, SseTable,
+    // 0x80
+    Rel, Rel, Rel, Rel, Rel, Rel, Rel, Rel,
+    // 0x88
+    Rel, Rel, Rel, Rel, Rel, Rel, Rel, Rel,
+    // 0x90
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0x98
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0xa0
+    Opcode, Opcode, Opcode, ModRm, ModRmImm8, ModRm, Decode, Decode,
+    // 0xa8
+    Opcode, Opcode, Opcode, ModRm, ModRmImm8, ModRm, Decode, ModRm,
+    // 0xb0
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0xb8
+    Decode, Decode, GroupImm8, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0xc0
+    ModRm, ModRm, SseTableImm8, Decode, Decode, SseTableImm8, SseTableImm8, Decode,
+    // 0xc8
+    Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode, Opcode,
+    // 0xd0
+    SseTable, ModRm, ModRm, ModRm, ModRm, ModRm, SseTable, SseTable,
+    // 0xd8
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0xe0
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, SseTable, SseTable,
+    // 0xe8
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm,
+    // 0xf0
+    SseTable, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, SseTable,
+    // 0xf8
+    ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, ModRm, Decode,
+];
+
+/// Determine the length of a single x86 instruction in the given mode
+/// without decoding its operands.
+///
+/// This walks the prefixes, the opcode maps, the ModRM and SIB bytes,
+/// the displacement and the immediates of the instruction, which is
+/// considerably faster than a full [`disassemble`] when only the
+/// instruction boundaries are needed.
+///
+/// At most 15 bytes of `bytes` are examined. When `bytes` do not
+/// start with a valid instruction, the error is the same as the one
+/// returned by [`disassemble`].
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // mov rax, [rip+0x10]; lock add [rax], eax
+/// let data = [0x48u8, 0x8b, 0x05, 0x10, 0x00, 0x00, 0x00, 0xf0, 0x01, 0x00];
+/// assert_eq!(instruction_length(X86Mode::Bits64, &data), Ok(7));
+/// assert_eq!(instruction_length(X86Mode::Bits64, &data[7..]), Ok(3));
+/// assert!(instruction_length(X86Mode::Bits64, &data[..5]).unwrap_err().is_insufficient_length());
+///
+/// // punpcklqdq needs the operand size prefix without REPNE or REPE.
+/// let data = [0x66u8, 0xf2, 0x0f, 0x6c, 0x4e, 0x78];
+/// assert!(instruction_length(X86Mode::Bits64, &data).is_err());
+/// assert_eq!(
+///     instruction_length(X86Mode::Bits64, &data),
+///     disassemble_64(&data, 0, data.len()).map(|instr| instr.length),
+/// );
+/// ```
+///
+/// [`disassemble`]: fn.disassemble.html
+pub fn instruction_length(mode: X86Mode, bytes: &[u8]) -> Result<usize, DecodeError> {
+    match fast_length(mode, bytes) {
+        Some(length) => Ok(length),
+        None => decode_length(mode, bytes),
+    }
+}
+
+// Kept out of line so that the fast path does not pay for the
+// decoder state.
+#[cold]
+#[inline(never)]
+fn decode_length(mode: X86Mode, bytes: &[u8]) -> Result<usize, DecodeError> {
+    disassemble(mode, bytes, 0).map(|instr| instr.length)
+}
+
+/// The length of a ModRM byte and any SIB byte and displacement that
+/// follow it, along with the ModRM byte itself.
+fn mod_rm_length(bytes: &[u8], pos: usize, addr_size: u16) -> Option<(u8, usize)> {
+    let mod_rm = *bytes.get(pos)?;
+    let mod_ = mod_rm >> 6;
+    let rm = mod_rm & 7;
+    let length = if mod_ == 3 {
+        1
+    } else if addr_size == 2 {
+        match mod_ {
+            2 => 3,
+            1 => 2,
+            _ if rm == 6 => 3,
+            _ => 1,
+        }
+    } else {
+        let (sib, base) = if rm == 4 {
+            (1, *bytes.get(pos + 1)? & 7)
+        } else {
+            (0, rm)
+        };
+        match mod_ {
+            2 => 5 + sib,
+            1 => 2 + sib,
+            _ if base == 5 => 5 + sib,
+            _ => 1 + sib,
+        }
+    };
+    Some((mod_rm, length))
+}
+
+/// Determine the length of an instruction that is known to be valid,
+/// returning `None` for anything that needs the full decoder, which
+/// includes every invalid or truncated instruction.
+fn fast_length(mode: X86Mode, bytes: &[u8]) -> Option<usize> {
+    let bytes = &bytes[..cmp::min(bytes.len(), 15)];
+    let using64 = mode == X86Mode::Bits64;
+    let (mut addr_size, mut op_size) = match mode {
+        X86Mode::Bits16 => (2, 2),
+        X86Mode::Bits32 => (4, 4),
+        X86Mode::Bits64 => (8, 4),
+    };
+    let mut op_prefix = false;
+    let mut addr_prefix = false;
+    let mut rep: u8 = 0;
+    let mut rex: u8 = 0;
+    let mut pos = 0;
+    loop {
+        let prefix = *bytes.get(pos)?;
+        match prefix {
+            0x26 | 0x2e | 0x36 | 0x3e | 0x64 | 0x65 => {}
+            0x66 => op_prefix = true,
+            0x67 => addr_prefix = true,
+            // Lock semantics depend on the decoded operands.
+            0xf0 => return None,
+            0xf2 | 0xf3 => rep = prefix,
+            // REX2 selects an opcode map of its own.
+            0xd5 if using64 => return None,
+            0x40..=0x4f if using64 => {
+                rex = prefix;
+                pos += 1;
+                continue;
+            }
+            _ => break,
+        }
+        // Only a REX prefix immediately before the opcode counts.
+        rex = 0;
+        pos += 1;
+    }
+    if op_prefix {
+        op_size = if op_size == 2 { 4 } else { 2 };
+    }
+    if addr_prefix {
+        addr_size = if addr_size == 4 { 2 } else { 4 };
+    }
+    if rex & 8 != 0 {
+        op_size = 8;
+    }
+
+    let opcode = usize::from(bytes[pos]);
+    pos += 1;
+    let (encoding, class) = match MAIN_OPCODE_LENGTHS[opcode] {
+        TwoByte => {
+            let opcode = usize::from(*bytes.get(pos)?);
+            pos += 1;
+            (&TWO_BYTE_OPCODE_MAP[opcode], TWO_BYTE_OPCODE_LENGTHS[opcode])
+        }
+        class => (&MAIN_OPCODE_MAP[opcode], class),
+    };
+    let flags = u32::from(encoding.flags);
+    if using64 && flags & DecodeFlags::INVALID_IN_64BIT != 0 {
+        return None;
+    }
+    if using64 && flags & DecodeFlags::DEFAULT_TO_64BIT != 0 {
+        op_size = if op_prefix { 4 } else { 8 };
+    }
+    let final_op_size = if flags & DecodeFlags::FORCE_16BIT != 0 {
+        2
+    } else if flags & DecodeFlags::BYTE != 0 {
+        1
+    } else {
+        op_size
+    };
+    let imm_size = if flags & DecodeFlags::IMM_SX != 0 {
+        1
+    } else {
+        cmp::min(final_op_size, 4)
+    };
+    let sse_type = if op_prefix {
+        1
+    } else if rep == 0xf2 {
+        2
+    } else if rep == 0xf3 {
+        3
+    } else {
+        0
+    };
+
+    let length = match class {
+        Decode | TwoByte => return None,
+        Opcode => pos,
+        Imm => pos + imm_size,
+        Imm8 => pos + 1,
+        Imm16Imm8 => pos + 3,
+        OpRegImm => pos + if final_op_size == 8 { 8 } else { imm_size },
+        Addr => pos + usize::from(addr_size),
+        Rel => pos + cmp::min(final_op_size, 4),
+        _ => {
+            let (mod_rm, length) = mod_rm_length(bytes, pos, addr_size)?;
+            let is_reg = mod_rm >= 0xc0;
+            let reg = usize::from(mod_rm >> 3 & 7);
+            let group_operation = || GROUP_OPERATIONS[usize::from(encoding.operation)][reg];
+            let imm_size = match class {
+                ModRm => {
+                    // Only memory operands may differ from the operand size.
+                    if is_reg && flags & DecodeFlags::REG_RM_SIZE_MASK != 0 {
+                        return None;
+                    }
+                    0
+                }
+                ModRmImm => imm_size,
+                ModRmImm8 => 1,
+                Group | GroupImm | GroupImm8 | GroupF6F7 | GroupFF => {
+                    let operation = group_operation();
+                    match class {
+                        _ if operation == InstructionOperation::INVALID => return None,
+                        GroupImm => imm_size,
+                        GroupImm8 => 1,
+                        GroupF6F7 if operation == InstructionOperation::TEST => imm_size,
+                        GroupFF if is_reg &&
+                            (operation == InstructionOperation::CALLF ||
+                                 operation == InstructionOperation::JMPF) => return None,
+                        _ => 0,
+                    }
+                }
+                SseTable | SseTableImm8 => {
+                    let entry = &SSE_TABLE[usize::from(encoding.operation)];
+                    let op_entry = if is_reg {
+                        &entry.reg_ops[sse_type]
+                    } else {
+                        &entry.mem_ops[sse_type]
+                    };
+                    if op_entry.operation == InstructionOperation::INVALID {
+                        return None;
+                    }
+                    if let SseTableImm8 = class { 1 } else { 0 }
+                }
+                SsePacked if sse_type == 2 || sse_type == 3 => return None,
+                SseSingle if sse_type == 1 || sse_type == 2 => return None,
+                // A REPNE or REPE prefix takes priority over the operand
+                // size prefix, as in `decode_mmx_sse_only`.
+                SseOnly if !op_prefix || rep != 0 => return None,
+                _ => 0,
+            };
+            pos + length + imm_size
+        }
+    };
+    if length <= bytes.len() {
+        Some(length)
+    } else {
+        None
+    }
+}
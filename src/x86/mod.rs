@@ -6,22 +6,219 @@
 //! Disassemble x86 and x86_64 code.
 //!
 //! This is based on a C library, asmx86.
+//!
+//! ## System instructions
+//!
+//! The register forms of `0f 01` pick an instruction from both the reg
+//! and the rm fields of the ModRM byte:
+//!
+//! ```
+//! use burst::x86::*;
+//!
+//! fn text(data: &[u8]) -> String {
+//!     let instr = disassemble(X86Mode::Bits64, data, 0).unwrap();
+//!     let mut out = String::new();
+//!     format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//!     out.trim_end().to_string()
+//! }
+//!
+//! assert_eq!(text(&[0x0f, 0x01, 0xc2]), "vmlaunch");
+//! assert_eq!(text(&[0x0f, 0x01, 0xd0]), "xgetbv");
+//! assert_eq!(text(&[0x0f, 0x01, 0xf8]), "swapgs");
+//! assert!(disassemble(X86Mode::Bits64, &[0x0f, 0x01, 0xc7], 0).is_err());
+//!
+//! // VMFUNC, and the AMD SVM instructions.
+//! assert_eq!(text(&[0x0f, 0x01, 0xd4]), "vmfunc");
+//! assert_eq!(text(&[0x0f, 0x01, 0xd8]), "vmrun");
+//! assert_eq!(text(&[0x0f, 0x01, 0xd9]), "vmmcall");
+//! assert_eq!(text(&[0x0f, 0x01, 0xdc]), "stgi");
+//! assert_eq!(text(&[0x0f, 0x01, 0xdf]), "invlpga");
+//! ```
+//!
+//! INVEPT and INVVPID need the `66` prefix and a memory descriptor:
+//!
+//! ```
+//! # use burst::x86::*;
+//! # fn text(data: &[u8]) -> String {
+//! #     let instr = disassemble(X86Mode::Bits64, data, 0).unwrap();
+//! #     let mut out = String::new();
+//! #     format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//! #     out.trim_end().to_string()
+//! # }
+//! assert_eq!(text(&[0x66, 0x0f, 0x38, 0x80, 0x01]), "invept rax, oword [rcx]");
+//! assert_eq!(text(&[0x66, 0x0f, 0x38, 0x81, 0x01]), "invvpid rax, oword [rcx]");
+//! assert!(disassemble(X86Mode::Bits64, &[0x0f, 0x38, 0x80, 0x01], 0).is_err());
+//! assert!(disassemble(X86Mode::Bits64, &[0x66, 0x0f, 0x38, 0x80, 0xc1], 0).is_err());
+//! ```
+//!
+//! ## Bit manipulation
+//!
+//! An `f3` prefix turns `bsf` and `bsr` into `tzcnt` and `lzcnt`, as it
+//! does for `popcnt`. The operand size still follows `66` and `REX.W`:
+//!
+//! ```
+//! # use burst::x86::*;
+//! # fn text(data: &[u8]) -> String {
+//! #     let instr = disassemble(X86Mode::Bits64, data, 0).unwrap();
+//! #     let mut out = String::new();
+//! #     format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//! #     out.trim_end().to_string()
+//! # }
+//! assert_eq!(text(&[0xf3, 0x0f, 0xbc, 0xc1]), "tzcnt eax, ecx");
+//! assert_eq!(text(&[0xf3, 0x0f, 0xbd, 0xc1]), "lzcnt eax, ecx");
+//! assert_eq!(text(&[0xf3, 0x0f, 0xb8, 0xc1]), "popcnt eax, ecx");
+//! assert_eq!(text(&[0x0f, 0xbc, 0xc1]), "bsf eax, ecx");
+//! assert_eq!(text(&[0x0f, 0xbd, 0xc1]), "bsr eax, ecx");
+//!
+//! assert_eq!(text(&[0x66, 0xf3, 0x0f, 0xbc, 0xc1]), "tzcnt ax, cx");
+//! assert_eq!(text(&[0xf3, 0x48, 0x0f, 0xbd, 0xc1]), "lzcnt rax, rcx");
+//! assert_eq!(text(&[0x66, 0xf3, 0x0f, 0xb8, 0xc1]), "popcnt ax, cx");
+//! assert_eq!(text(&[0xf3, 0x48, 0x0f, 0xb8, 0xc1]), "popcnt rax, rcx");
+//! ```
+//!
+//! ## VMREAD and VMWRITE
+//!
+//! `vmread` writes its rm operand and `vmwrite` reads it, and both always
+//! use operands of the native size. With a `66` or `f2` prefix, `0f 78`
+//! and `0f 79` are the SSE4a `extrq` and `insertq` instead, which are
+//! rejected rather than shown as `vmread` or `vmwrite`:
+//!
+//! ```
+//! # use burst::x86::*;
+//! # fn text(data: &[u8]) -> String {
+//! #     let instr = disassemble(X86Mode::Bits64, data, 0).unwrap();
+//! #     let mut out = String::new();
+//! #     format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//! #     out.trim_end().to_string()
+//! # }
+//! assert_eq!(text(&[0x0f, 0x78, 0xc1]), "vmread rcx, rax");
+//! assert_eq!(text(&[0x0f, 0x78, 0x01]), "vmread qword [rcx], rax");
+//! assert_eq!(text(&[0x0f, 0x79, 0xc1]), "vmwrite rax, rcx");
+//! assert_eq!(text(&[0x0f, 0x79, 0x01]), "vmwrite rax, qword [rcx]");
+//! assert_eq!(text(&[0x48, 0x0f, 0x78, 0xc1]), "vmread rcx, rax");
+//!
+//! let instr = disassemble(X86Mode::Bits32, &[0x0f, 0x79, 0xc1], 0).unwrap();
+//! let mut out = String::new();
+//! format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//! assert_eq!(out.trim_end(), "vmwrite eax, ecx");
+//!
+//! for data in &[
+//!     &[0x66, 0x0f, 0x78, 0xc0, 0x01, 0x02][..],
+//!     &[0xf2, 0x0f, 0x78, 0xc1, 0x01, 0x02],
+//!     &[0x66, 0x0f, 0x79, 0xc1],
+//!     &[0xf2, 0x0f, 0x79, 0xc1],
+//! ] {
+//!     assert!(disassemble(X86Mode::Bits64, data, 0).is_err());
+//! }
+//! ```
+//!
+//! ## Control-flow enforcement
+//!
+//! The CET instructions are encoded in the hint NOP space at `0f 1e`
+//! behind an `f3` prefix. Without it the same bytes are still a hint NOP:
+//!
+//! ```
+//! # use burst::x86::*;
+//! # fn text(data: &[u8]) -> String {
+//! #     let instr = disassemble(X86Mode::Bits64, data, 0).unwrap();
+//! #     let mut out = String::new();
+//! #     format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//! #     out.trim_end().to_string()
+//! # }
+//! assert_eq!(text(&[0xf3, 0x0f, 0x1e, 0xfa]), "endbr64");
+//! assert_eq!(text(&[0xf3, 0x0f, 0x1e, 0xfb]), "endbr32");
+//! assert_eq!(text(&[0xf3, 0x0f, 0x1e, 0xc8]), "rdsspd eax");
+//! assert_eq!(text(&[0xf3, 0x48, 0x0f, 0x1e, 0xc8]), "rdsspq rax");
+//!
+//! assert_eq!(text(&[0x0f, 0x1e, 0xfa]), "mmxnop edx");
+//! assert_eq!(text(&[0x0f, 0x1e, 0xfb]), "mmxnop ebx");
+//! ```
+//!
+//! ## Crypto extensions
+//!
+//! The AES-NI, SHA and GFNI instructions take the destination from the
+//! reg field, as the other SSE instructions do:
+//!
+//! ```
+//! # use burst::x86::*;
+//! # fn text(data: &[u8]) -> String {
+//! #     let instr = disassemble(X86Mode::Bits64, data, 0).unwrap();
+//! #     let mut out = String::new();
+//! #     format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//! #     out.trim_end().to_string()
+//! # }
+//! assert_eq!(text(&[0x66, 0x0f, 0x38, 0xdc, 0xc1]), "aesenc xmm0, xmm1");
+//! assert_eq!(text(&[0x66, 0x0f, 0x38, 0xdc, 0x01]), "aesenc xmm0, oword [rcx]");
+//! assert_eq!(text(&[0xc4, 0xe2, 0x71, 0xdc, 0xc2]), "vaesenc xmm0, xmm1, xmm2");
+//! assert_eq!(text(&[0x0f, 0x38, 0xcb, 0xc1]), "sha256rnds2 xmm0, xmm1");
+//! assert_eq!(text(&[0x0f, 0x38, 0xcb, 0x01]), "sha256rnds2 xmm0, oword [rcx]");
+//! assert_eq!(text(&[0x66, 0x0f, 0x3a, 0xce, 0xc1, 0x05]), "gf2p8affineqb xmm0, xmm1, 0x5");
+//! assert_eq!(
+//!     text(&[0xc4, 0xe3, 0xf1, 0xce, 0xc2, 0x05]),
+//!     "vgf2p8affineqb xmm0, xmm1, xmm2, 0x5"
+//! );
+//! ```
+//!
+//! ## AMX
+//!
+//! The tile loads and stores address memory through a SIB byte, and are
+//! rejected without one. The tile arithmetic only takes tile registers,
+//! and all of them are only available in 64 bit mode:
+//!
+//! ```
+//! # use burst::x86::*;
+//! # fn text(data: &[u8]) -> String {
+//! #     let instr = disassemble(X86Mode::Bits64, data, 0).unwrap();
+//! #     let mut out = String::new();
+//! #     format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+//! #     out.trim_end().to_string()
+//! # }
+//! assert_eq!(text(&[0xc4, 0xe2, 0x7b, 0x4b, 0x0c, 0x08]), "tileloadd tmm1, [rax+rcx]");
+//! assert_eq!(text(&[0xc4, 0xe2, 0x7a, 0x4b, 0x0c, 0x08]), "tilestored [rax+rcx], tmm1");
+//! assert!(disassemble(X86Mode::Bits64, &[0xc4, 0xe2, 0x7b, 0x4b, 0x08], 0).is_err());
+//! assert!(disassemble(X86Mode::Bits64, &[0xc4, 0xe2, 0x7b, 0x4b, 0xca], 0).is_err());
+//!
+//! assert_eq!(text(&[0xc4, 0xe2, 0x63, 0x5e, 0xca]), "tdpbssd tmm1, tmm2, tmm3");
+//! assert!(disassemble(X86Mode::Bits64, &[0xc4, 0xe2, 0x63, 0x5e, 0x08], 0).is_err());
+//!
+//! assert!(disassemble(X86Mode::Bits32, &[0xc4, 0xe2, 0x7b, 0x4b, 0x0c, 0x08], 0).is_err());
+//! ```
 
+mod decoder;
+mod evex;
+mod features;
+mod flow;
 mod instruction_operations;
+mod iter;
+mod length;
+mod operand;
 mod operand_types;
+mod prefixes;
+mod registers;
+mod rflags;
 
+pub use self::decoder::*;
+pub use self::evex::*;
+pub use self::features::*;
+pub use self::flow::*;
 pub use self::instruction_operations::*;
+pub use self::iter::*;
+pub use self::length::*;
+pub use self::operand::*;
 pub use self::operand_types::*;
+pub use self::prefixes::*;
+pub use self::registers::*;
+pub use self::rflags::*;
 
-use std::cmp;
-use std::fmt;
-use std::ptr;
+use core::cmp;
+use core::fmt;
+use core::ops::Range;
 
-use {Instruction, Operand, Operation};
+use {Instruction, Operation};
 
 /// A segment register
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd)]
 #[repr(i32)]
 pub enum SegmentRegister {
     /// Extra data segment register.
@@ -37,6 +234,7 @@ pub enum SegmentRegister {
     /// Extra data segment register. Used as a thread register on some platforms.
     GS = 5,
     /// Default segment register.
+    #[default]
     DEFAULT = 7,
 }
 
@@ -56,13 +254,15 @@ impl SegmentRegister {
     }
 }
 
-impl Default for SegmentRegister {
-    fn default() -> Self {
-        SegmentRegister::DEFAULT
-    }
-}
 
-/// An operand for an `Instruction`.
+/// The flat form of an `X86Operand`, with every field present for
+/// every kind of operand.
+///
+/// This is available as a compatibility view through
+/// `X86Instruction::flat_operands`, which builds it from the operands
+/// of the instruction. A relative
+/// branch target and both parts of a far pointer are `IMM` operands
+/// here.
 ///
 /// The type of operand is given by the `operand` member. If the type is
 /// `OperandType::NONE`, none of the other members are defined.
@@ -85,11 +285,29 @@ impl Default for SegmentRegister {
 /// address = components[0] + components[1] * scale + immediate
 /// ```
 ///
-/// TODO: Perhaps this should be an enumeration with separate values
-/// for invalid, immediate value, a memory reference or a register.
-#[derive(Debug)]
+/// Immediates are 64 bits wide on every host:
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // mov rax, 0xf122334455667788
+/// let data = [0x48u8, 0xb8, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0xf1];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// let operands = instr.flat_operands();
+/// assert_eq!(operands[1].immediate as u64, 0xf122_3344_5566_7788);
+/// assert_eq!(operands[1].immediate_size, 8);
+///
+/// // add rsp, -8
+/// let data = [0x48u8, 0x83, 0xc4, 0xf8];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// let operands = instr.flat_operands();
+/// assert_eq!(operands[1].immediate, -8);
+/// assert_eq!(operands[1].immediate_size, 1);
+/// assert!(operands[1].immediate_signed);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(C)]
-pub struct X86Operand {
+pub struct X86FlatOperand {
     /// The type of the operand, a register or one of a set of special values.
     pub operand: OperandType,
     /// The address components of a memory operand.
@@ -104,23 +322,33 @@ pub struct X86Operand {
     /// The value of a constant integer when the operand is the `IMM`
     /// type. When the operand is a memory reference, this contains
     /// a constant offset for the memory reference.
-    pub immediate: isize,
+    ///
+    /// This is always 64 bits wide, regardless of the host, so that
+    /// a 64 bit immediate or address is never truncated. Use
+    /// `immediate as u64` for the unsigned value.
+    pub immediate: i64,
+    /// The number of bytes that `immediate` was encoded in, or `0` if
+    /// it is implied by the opcode (as with a shift by one).
+    pub immediate_size: u8,
+    /// Whether the encoded value was sign extended to form
+    /// `immediate`, rather than zero extended.
+    pub immediate_signed: bool,
     /// The segment register that will be used for a memory access. This
     /// will always contain a segment register, as `SegmentRegister::DEFAULT`
     /// is resolved to the default register.
     pub segment: SegmentRegister,
 }
 
-impl Operand for X86Operand {}
-
-impl Default for X86Operand {
+impl Default for X86FlatOperand {
     fn default() -> Self {
-        X86Operand {
+        X86FlatOperand {
             operand: OperandType::NONE,
             components: [OperandType::NONE, OperandType::NONE],
             scale: 1,
             size: 0,
             immediate: 0,
+            immediate_size: 0,
+            immediate_signed: false,
             segment: SegmentRegister::DEFAULT,
         }
     }
@@ -131,13 +359,13 @@ impl Default for X86Operand {
 /// An instruction represents the full amount of information that
 /// we have about the instruction that has been disassembled from
 /// the binary opcode data.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 #[repr(C)]
 pub struct X86Instruction {
     /// Which `InstructionOperation` this instruction is.
     pub operation: InstructionOperation,
-    /// The operands for this instruction.
-    pub operands: [X86Operand; 3],
+    operands: [X86Operand; 4],
+    operand_count: u8,
     /// A bit field that may contain the flags described by [`X86Flag`].
     ///
     /// [`X86Flag`]: struct.X86Flag.html
@@ -145,6 +373,8 @@ pub struct X86Instruction {
     /// The segment prefix. This will be either `SegmentPrefix::DEFAULT`
     /// or a segment register (like `SegmentPrefix::ES`).
     pub segment: SegmentRegister,
+    prefix_bytes: [u8; MAX_PREFIXES],
+    prefix_usage: u8,
     /// How many bytes in the binary opcode data are used by this
     /// instruction.
     ///
@@ -152,6 +382,228 @@ pub struct X86Instruction {
     /// instruction. An invalid instruction may have a value of
     /// `0` here.
     pub length: usize,
+    layout: EncodingLayout,
+    /// The masking, broadcast and rounding given by an EVEX prefix.
+    pub evex: Option<X86Evex>,
+    mode: X86Mode,
+}
+
+impl X86Instruction {
+    /// The operands for this instruction.
+    pub fn operands(&self) -> &[X86Operand] {
+        &self.operands[..usize::from(self.operand_count)]
+    }
+
+    /// The operands for this instruction in their flat form. Unused
+    /// operands have the type `OperandType::NONE`.
+    ///
+    /// These are built from `operands` on each call.
+    pub fn flat_operands(&self) -> [X86FlatOperand; 4] {
+        let mut flat = [X86FlatOperand::default(); 4];
+        let mut count = 0;
+        for operand in self.operands() {
+            // Only a far pointer is two flat operands.
+            let mut offset = None;
+            flat[count] = match *operand {
+                X86Operand::Register(reg) => X86FlatOperand {
+                    operand: reg.register,
+                    size: reg.size,
+                    ..Default::default()
+                },
+                X86Operand::Immediate(imm) => X86FlatOperand {
+                    operand: OperandType::IMM,
+                    size: imm.size,
+                    immediate: imm.value,
+                    immediate_size: imm.encoded_size,
+                    immediate_signed: imm.signed,
+                    ..Default::default()
+                },
+                X86Operand::Memory(mem) => X86FlatOperand {
+                    operand: OperandType::MEM,
+                    components: [
+                        mem.base.unwrap_or(OperandType::NONE),
+                        mem.index.unwrap_or(OperandType::NONE),
+                    ],
+                    scale: mem.scale,
+                    size: mem.size,
+                    immediate: mem.displacement,
+                    immediate_size: mem.displacement_size,
+                    immediate_signed: self.displacement_signed(&mem),
+                    segment: mem.segment,
+                },
+                X86Operand::FarPointer { seg, off } => {
+                    // The offset is encoded first, and is the size of
+                    // the operands.
+                    let size = self.layout.immediate_size;
+                    offset = Some(X86FlatOperand {
+                        operand: OperandType::IMM,
+                        size: u16::from(size),
+                        immediate: off as i64,
+                        immediate_size: size,
+                        ..Default::default()
+                    });
+                    X86FlatOperand {
+                        operand: OperandType::IMM,
+                        size: 2,
+                        immediate: i64::from(seg),
+                        immediate_size: 2,
+                        ..Default::default()
+                    }
+                }
+                X86Operand::RelativeTarget(target) => {
+                    let absolute = self.operation == InstructionOperation::JMPABS;
+                    X86FlatOperand {
+                        operand: OperandType::IMM,
+                        size: if absolute { 8 } else { self.branch_op_size() },
+                        immediate: target as i64,
+                        immediate_size: self.layout.immediate_size,
+                        immediate_signed: !absolute,
+                        ..Default::default()
+                    }
+                }
+            };
+            count += 1;
+            if let Some(offset) = offset {
+                flat[count] = offset;
+                count += 1;
+            }
+        }
+        flat
+    }
+
+    /// Whether the displacement of a memory operand was sign extended.
+    /// Only a memory offset without a ModRM byte and a 16 bit address
+    /// without a base or index are not.
+    fn displacement_signed(&self, mem: &MemRef) -> bool {
+        if mem.displacement_size == 0 || !self.layout.mod_rm {
+            false
+        } else {
+            mem.base.is_some() || mem.index.is_some() || mem.displacement_size != 2
+        }
+    }
+
+    /// The operand size of a relative branch, which is the size of the
+    /// instruction pointer that it updates.
+    fn branch_op_size(&self) -> u16 {
+        let op_prefix = self.flags & X86Flag::OPSIZE != 0;
+        match self.mode {
+            X86Mode::Bits16 => if op_prefix { 4 } else { 2 },
+            X86Mode::Bits32 => if op_prefix { 2 } else { 4 },
+            // XBEGIN always updates all of RIP.
+            X86Mode::Bits64 if op_prefix && self.operation != InstructionOperation::XBEGIN => 4,
+            X86Mode::Bits64 => 8,
+        }
+    }
+
+    /// The processor mode that this instruction was decoded for.
+    pub fn mode(&self) -> X86Mode {
+        self.mode
+    }
+
+    /// Every prefix of this instruction, including those that were
+    /// redundant or had no effect.
+    ///
+    /// These are worked out from the prefix bytes on each call.
+    pub fn prefixes(&self) -> X86Prefixes {
+        let mut prefixes = X86Prefixes::from_bytes(self.prefix_bytes());
+        let segment = self.segment;
+        let segment_used = self.operands().iter().any(|operand| match *operand {
+            X86Operand::Memory(mem) => mem.segment == segment,
+            _ => false,
+        });
+        let using64 = self.mode == X86Mode::Bits64;
+        let rep_used = self.prefix_usage & PrefixUsage::REP != 0;
+        prefixes.mark(X86PrefixStatus::Ignored, |kind| match kind {
+            X86PrefixKind::Segment(seg) => !segment_used || using64 && seg < SegmentRegister::FS,
+            X86PrefixKind::Repne | X86PrefixKind::Repe => !rep_used,
+            _ => false,
+        });
+        if self.prefix_usage & PrefixUsage::OP_SIZE_OVERRIDDEN != 0 {
+            prefixes.mark(X86PrefixStatus::Overridden, |kind| {
+                kind == X86PrefixKind::OperandSize
+            });
+        }
+        prefixes
+    }
+
+    /// Where each part of this instruction is found in the binary
+    /// opcode data.
+    ///
+    /// This is worked out from the sizes of the parts on each call.
+    pub fn encoding(&self) -> X86Encoding {
+        let layout = self.layout;
+        let prefixes = self.prefix_bytes();
+        let length = prefixes.len();
+        // Only a REX or REX2 prefix right before the opcode applies.
+        let rex_offset = if self.mode != X86Mode::Bits64 {
+            None
+        } else if length >= 2 && prefixes[length - 2] == 0xd5 {
+            Some(length as u8 - 2)
+        } else if length >= 1 && prefixes[length - 1] & 0xf0 == 0x40 {
+            Some(length as u8 - 1)
+        } else {
+            None
+        };
+        let vex_offset = if self.flags & (X86Flag::VEX | X86Flag::EVEX | X86Flag::XOP) != 0 {
+            Some(layout.prefix_length)
+        } else {
+            None
+        };
+        // The rest of the parts follow the opcode in order.
+        let mut end = layout.opcode_offset + layout.opcode_length;
+        let mod_rm_offset = if layout.mod_rm { Some(next_part(&mut end, 1)) } else { None };
+        let sib_offset = if layout.sib { Some(next_part(&mut end, 1)) } else { None };
+        X86Encoding {
+            prefix_length: layout.prefix_length,
+            rex_offset,
+            vex_offset,
+            opcode_offset: layout.opcode_offset,
+            opcode_length: layout.opcode_length,
+            mod_rm_offset,
+            sib_offset,
+            displacement_offset: next_part(&mut end, layout.displacement_size),
+            displacement_size: layout.displacement_size,
+            immediate_offset: next_part(&mut end, layout.immediate_size),
+            immediate_size: layout.immediate_size,
+            immediate2_offset: next_part(&mut end, layout.immediate2_size),
+            immediate2_size: layout.immediate2_size,
+        }
+    }
+
+    /// The prefix bytes, with the payload of a REX2 prefix.
+    fn prefix_bytes(&self) -> &[u8] {
+        let length = cmp::min(usize::from(self.layout.prefix_length), MAX_PREFIXES);
+        &self.prefix_bytes[..length]
+    }
+}
+
+/// How the decoder used the prefixes of an instruction, so that their
+/// status can be worked out later.
+struct PrefixUsage;
+
+impl PrefixUsage {
+    /// The instruction uses a `REPNE` or `REPE` prefix.
+    const REP: u8 = 1;
+    /// REX.W sets the operand size over an operand size prefix.
+    const OP_SIZE_OVERRIDDEN: u8 = 2;
+}
+
+impl Default for X86Instruction {
+    fn default() -> Self {
+        X86Instruction {
+            operation: InstructionOperation::default(),
+            operands: NO_OPERANDS,
+            operand_count: 0,
+            flags: 0,
+            segment: SegmentRegister::default(),
+            prefix_bytes: [0; MAX_PREFIXES],
+            prefix_usage: 0,
+            length: 0,
+            layout: EncodingLayout::default(),
+            evex: None,
+            mode: X86Mode::Bits64,
+        }
+    }
 }
 
 impl Instruction for X86Instruction {
@@ -167,7 +619,7 @@ impl Instruction for X86Instruction {
     }
 
     fn operands(&self) -> &[X86Operand] {
-        &self.operands
+        X86Instruction::operands(self)
     }
 
     fn length(&self) -> usize {
@@ -175,6 +627,124 @@ impl Instruction for X86Instruction {
     }
 }
 
+/// The layout of an instruction within the binary opcode data.
+///
+/// All offsets are relative to the first byte of the instruction. The
+/// parts of an instruction appear in the order of the fields here.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // mov dword [rbp-0x10], 0x12345678
+/// let data = [0x3eu8, 0x48, 0xc7, 0x45, 0xf0, 0x78, 0x56, 0x34, 0x12];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// let encoding = instr.encoding();
+/// assert_eq!(encoding.prefix_length, 2);
+/// assert_eq!(encoding.rex_offset, Some(1));
+/// assert_eq!(encoding.opcode_offset, 2);
+/// assert_eq!(encoding.opcode_length, 1);
+/// assert_eq!(encoding.mod_rm_offset, Some(3));
+/// assert_eq!(encoding.sib_offset, None);
+/// assert_eq!(encoding.displacement(), Some(4..5));
+/// assert_eq!(encoding.immediate(), Some(5..9));
+///
+/// // Patch the displacement in place.
+/// let mut patched = data;
+/// patched[encoding.displacement().unwrap()].copy_from_slice(&[0xe8]);
+/// let instr = disassemble_64(&patched, 0, patched.len()).unwrap();
+/// assert_eq!(instr.flat_operands()[0].immediate, -0x18);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct X86Encoding {
+    /// The number of prefix bytes, including any REX or REX2 prefix.
+    /// The prefixes are the bytes before `opcode_offset` (or
+    /// `vex_offset`), in the order that they were given.
+    pub prefix_length: u8,
+    /// The offset of the REX or REX2 prefix, if one applies to this
+    /// instruction. A REX prefix that is followed by another prefix
+    /// is ignored and not reported here.
+    pub rex_offset: Option<u8>,
+    /// The offset of the VEX, XOP or EVEX prefix, if there is one. It
+    /// follows the other prefixes and is not counted in `prefix_length`.
+    pub vex_offset: Option<u8>,
+    /// The offset of the first opcode byte.
+    pub opcode_offset: u8,
+    /// The number of opcode bytes, including any `0x0f`, `0x0f 0x38`
+    /// or `0x0f 0x3a` escape. The opcode suffix byte of a 3DNow!
+    /// instruction is not included, and the escape of a VEX encoded
+    /// instruction is part of the VEX prefix.
+    pub opcode_length: u8,
+    /// The offset of the ModRM byte, if there is one.
+    pub mod_rm_offset: Option<u8>,
+    /// The offset of the SIB byte, if there is one.
+    pub sib_offset: Option<u8>,
+    /// The offset of the displacement of a memory operand.
+    pub displacement_offset: u8,
+    /// The size in bytes of the displacement, or `0` if there is none.
+    pub displacement_size: u8,
+    /// The offset of the immediate.
+    pub immediate_offset: u8,
+    /// The size in bytes of the immediate, or `0` if there is none.
+    pub immediate_size: u8,
+    /// The offset of a second immediate, as used by `ENTER` and by
+    /// the segment selector of a far pointer.
+    pub immediate2_offset: u8,
+    /// The size in bytes of the second immediate, or `0` if there is
+    /// none.
+    pub immediate2_size: u8,
+}
+
+impl X86Encoding {
+    /// The range of bytes holding the displacement, if any.
+    pub fn displacement(&self) -> Option<Range<usize>> {
+        encoding_range(self.displacement_offset, self.displacement_size)
+    }
+
+    /// The range of bytes holding the immediate, if any.
+    pub fn immediate(&self) -> Option<Range<usize>> {
+        encoding_range(self.immediate_offset, self.immediate_size)
+    }
+
+    /// The range of bytes holding the second immediate, if any.
+    pub fn immediate2(&self) -> Option<Range<usize>> {
+        encoding_range(self.immediate2_offset, self.immediate2_size)
+    }
+}
+
+fn encoding_range(offset: u8, size: u8) -> Option<Range<usize>> {
+    if size == 0 {
+        None
+    } else {
+        Some(usize::from(offset)..usize::from(offset) + usize::from(size))
+    }
+}
+
+/// The offset of a part of the given size that starts at `end`, or `0`
+/// if there is no such part.
+fn next_part(end: &mut u8, size: u8) -> u8 {
+    if size == 0 {
+        0
+    } else {
+        let offset = *end;
+        *end += size;
+        offset
+    }
+}
+
+/// The sizes of the parts of an instruction that `X86Encoding` is
+/// worked out from.
+#[derive(Clone, Copy, Debug, Default)]
+struct EncodingLayout {
+    prefix_length: u8,
+    opcode_offset: u8,
+    opcode_length: u8,
+    mod_rm: bool,
+    sib: bool,
+    displacement_size: u8,
+    immediate_size: u8,
+    immediate2_size: u8,
+}
+
 /// Flags used by `Instruction`.
 pub struct X86Flag;
 
@@ -193,6 +763,38 @@ impl X86Flag {
     pub const OPSIZE: u32 = 16;
     /// The address size prefix was used.
     pub const ADDRSIZE: u32 = 32;
+    /// The instruction has a VEX prefix.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// let data = [0xc5u8, 0xf4, 0x58, 0xc2];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert!(instr.flags & X86Flag::VEX != 0);
+    /// assert_eq!(instr.encoding().vex_offset, Some(0));
+    /// let mut out = String::new();
+    /// format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+    /// assert_eq!(out, "vaddps ymm0, ymm1, ymm2");
+    /// ```
+    pub const VEX: u32 = 64;
+    /// The instruction has an EVEX prefix, and `evex` describes how it
+    /// was used.
+    pub const EVEX: u32 = 128;
+    /// The instruction has an AMD XOP prefix, which is laid out like
+    /// a three byte VEX prefix.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// let data = [0x8fu8, 0xe8, 0x70, 0xa2, 0xc2, 0x30];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert!(instr.flags & X86Flag::XOP != 0);
+    /// assert_eq!(instr.operands().len(), 4);
+    /// let mut out = String::new();
+    /// format_instruction_string(&mut out, "%i %o", None, 0, &instr).unwrap();
+    /// assert_eq!(out, "vpcmov xmm0, xmm1, xmm2, xmm3");
+    /// ```
+    pub const XOP: u32 = 256;
 
     /// The instruction may be valid, but an insufficient number of bytes
     /// were provided. When this flag is set, the disassembly should not
@@ -203,7 +805,155 @@ impl X86Flag {
     pub const ANY_REP: u32 = X86Flag::REP | X86Flag::REPE | X86Flag::REPNE;
 }
 
-#[allow(non_camel_case_types)]
+/// The reason that an instruction could not be decoded.
+///
+/// Every variant carries the number of bytes that were consumed before
+/// decoding stopped and the operation that had been identified so far
+/// (which may be `InstructionOperation::INVALID` if decoding failed
+/// before the opcode was known).
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // A `mov eax, imm32` that is missing the last byte of its immediate.
+/// let data = [0xb8u8, 0x01, 0x02, 0x03];
+/// match disassemble_32(&data, 0, data.len()) {
+///     Err(DecodeError::InsufficientLength { operation, .. }) => {
+///         assert_eq!(operation, InstructionOperation::MOV);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// // Prefixes with no opcode after them.
+/// for data in [[0x66u8], [0xd5u8]].iter() {
+///     let err = disassemble_64(data, 0, data.len()).unwrap_err();
+///     assert_eq!(err, DecodeError::InsufficientLength {
+///         length: 1,
+///         operation: InstructionOperation::INVALID,
+///     });
+/// }
+///
+/// // `pusha` does not exist in 64 bit mode.
+/// let data = [0x60u8];
+/// let err = disassemble_64(&data, 0, data.len()).unwrap_err();
+/// assert_eq!(err, DecodeError::InvalidIn64BitMode {
+///     length: 1,
+///     operation: InstructionOperation::PUSHA,
+/// });
+///
+/// // A sign extended immediate is fine in 64 bit mode.
+/// let data = [0x48u8, 0x83, 0xc4, 0xf8];
+/// assert!(disassemble_64(&data, 0, data.len()).is_ok());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecodeError {
+    /// The opcode data ended before the instruction did. Decoding may
+    /// succeed if more bytes are provided.
+    InsufficientLength {
+        /// The number of bytes consumed.
+        length: usize,
+        /// The partially decoded operation.
+        operation: InstructionOperation,
+    },
+    /// The instruction would be longer than the architectural limit of
+    /// 15 bytes.
+    TooLong {
+        /// The number of bytes consumed.
+        length: usize,
+        /// The partially decoded operation.
+        operation: InstructionOperation,
+    },
+    /// The opcode (including any mandatory prefixes) does not correspond
+    /// to a known instruction.
+    InvalidOpcode {
+        /// The number of bytes consumed.
+        length: usize,
+        /// The partially decoded operation.
+        operation: InstructionOperation,
+    },
+    /// The instruction is not valid in 64 bit mode.
+    InvalidIn64BitMode {
+        /// The number of bytes consumed.
+        length: usize,
+        /// The partially decoded operation.
+        operation: InstructionOperation,
+    },
+    /// A `LOCK` prefix was used with an instruction or operand that
+    /// does not allow it.
+    InvalidLock {
+        /// The number of bytes consumed.
+        length: usize,
+        /// The partially decoded operation.
+        operation: InstructionOperation,
+    },
+    /// The opcode is known, but the operands are not valid for it, such
+    /// as a register where only a memory reference is allowed.
+    InvalidOperands {
+        /// The number of bytes consumed.
+        length: usize,
+        /// The partially decoded operation.
+        operation: InstructionOperation,
+    },
+}
+
+impl DecodeError {
+    /// How many bytes were consumed before decoding stopped.
+    pub fn length(&self) -> usize {
+        match *self {
+            DecodeError::InsufficientLength { length, .. } |
+            DecodeError::TooLong { length, .. } |
+            DecodeError::InvalidOpcode { length, .. } |
+            DecodeError::InvalidIn64BitMode { length, .. } |
+            DecodeError::InvalidLock { length, .. } |
+            DecodeError::InvalidOperands { length, .. } => length,
+        }
+    }
+
+    /// The operation that had been decoded when decoding stopped.
+    pub fn operation(&self) -> InstructionOperation {
+        match *self {
+            DecodeError::InsufficientLength { operation, .. } |
+            DecodeError::TooLong { operation, .. } |
+            DecodeError::InvalidOpcode { operation, .. } |
+            DecodeError::InvalidIn64BitMode { operation, .. } |
+            DecodeError::InvalidLock { operation, .. } |
+            DecodeError::InvalidOperands { operation, .. } => operation,
+        }
+    }
+
+    /// Whether providing more bytes could allow the instruction to
+    /// be decoded.
+    pub fn is_insufficient_length(&self) -> bool {
+        matches!(*self, DecodeError::InsufficientLength { .. })
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match *self {
+            DecodeError::InsufficientLength { .. } => "insufficient bytes for instruction",
+            DecodeError::TooLong { .. } => "instruction exceeds 15 bytes",
+            DecodeError::InvalidOpcode { .. } => "invalid opcode",
+            DecodeError::InvalidIn64BitMode { .. } => "instruction is invalid in 64 bit mode",
+            DecodeError::InvalidLock { .. } => "invalid use of lock prefix",
+            DecodeError::InvalidOperands { .. } => "invalid operands",
+        };
+        write!(f, "{} after {} bytes", reason, self.length())
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for DecodeError {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum InvalidReason {
+    Opcode,
+    In64BitMode,
+    Lock,
+    Operands,
+}
+
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 #[repr(i32)]
 enum RepPrefix {
@@ -213,57 +963,95 @@ enum RepPrefix {
 }
 
 #[derive(Debug)]
-#[repr(C)]
-struct DecodeState {
-    result: X86Instruction,
-    operand0: *mut X86Operand,
-    operand1: *mut X86Operand,
-    opcode_start: *const u8,
-    opcode: *const u8,
-    addr: usize,
+struct DecodeState<'a> {
+    /// The instruction being decoded. It is kept outside of the state
+    /// so that the state is smaller and cheaper to set up.
+    result: &'a mut X86Instruction,
+    operand0: usize,
+    operand1: usize,
+    opcode: &'a [u8],
+    pos: usize,
+    addr: u64,
     len: usize,
-    original_length: usize,
     op_size: u16,
     final_op_size: u16,
     addr_size: u16,
     flags: u32,
     invalid: bool,
+    invalid_reason: InvalidReason,
     insufficient_length: bool,
     op_prefix: bool,
+    op_prefix_used: bool,
     rep: RepPrefix,
+    rep_used: bool,
     using64: bool,
     rex: bool,
+    rex_w: bool,
     rex_rm_1: bool,
     rex_rm_2: bool,
     rex_reg: bool,
-    rip_rel_fixup: *mut isize,
+    rex2: Option<u8>,
+    rex2_rm_1: bool,
+    rex2_rm_2: bool,
+    rex2_reg: bool,
+    rip_rel_fixup: Option<usize>,
+    rm_operand: Option<usize>,
+    vex_l: bool,
+    vex_w: bool,
+    vex_v: u8,
+    vex_v_used: bool,
+    vex_form: u32,
+    vsib: Option<&'static [OperandType]>,
+    evex_form: u16,
+    evex_p2: u8,
+    evex_r: bool,
+    evex_x: bool,
 }
 
-impl Default for DecodeState {
-    fn default() -> Self {
+impl<'a> DecodeState<'a> {
+    /// The state before decoding into `result`.
+    fn new(result: &'a mut X86Instruction) -> Self {
         DecodeState {
-            result: X86Instruction::default(),
-            operand0: ptr::null_mut(),
-            operand1: ptr::null_mut(),
-            opcode_start: ptr::null(),
-            opcode: ptr::null(),
-            addr: 0usize,
+            result,
+            operand0: 0,
+            operand1: 1,
+            opcode: &[],
+            pos: 0usize,
+            addr: 0u64,
             len: 0usize,
-            original_length: 0usize,
             op_size: 0u16,
             final_op_size: 0u16,
             addr_size: 0u16,
             flags: 0u32,
             invalid: false,
+            invalid_reason: InvalidReason::Opcode,
             insufficient_length: false,
             op_prefix: false,
+            op_prefix_used: false,
             rep: RepPrefix::NONE,
+            rep_used: false,
             using64: false,
             rex: false,
+            rex_w: false,
             rex_rm_1: false,
             rex_rm_2: false,
             rex_reg: false,
-            rip_rel_fixup: ptr::null_mut(),
+            rex2: None,
+            rex2_rm_1: false,
+            rex2_rm_2: false,
+            rex2_reg: false,
+            rip_rel_fixup: None,
+            rm_operand: None,
+            vex_l: false,
+            vex_w: false,
+            vex_v: 0,
+            vex_v_used: false,
+            vex_form: 0,
+            vsib: None,
+            evex_form: 0,
+            evex_p2: 0,
+            evex_r: false,
+            evex_x: false,
         }
     }
 }
@@ -280,7 +1068,9 @@ impl DecodeFlags {
     const INC_OPERATION_FOR_64: u32 = 0x0800;
     const OPERATION_OP_SIZE: u32 = 0x1000;
     const FORCE_16BIT: u32 = 0x2000;
-    const INVALID_IN_64BIT: u32 = 0x400;
+    // This must not share a bit with IMM_SX, or every instruction
+    // with a sign extended immediate would be invalid in 64 bit mode.
+    const INVALID_IN_64BIT: u32 = 0x4000;
     const DEFAULT_TO_64BIT: u32 = 0x8000;
 
     const REG_RM_SIZE_MASK: u32 = 0x03;
@@ -791,7 +1581,7 @@ static MAIN_OPCODE_MAP: [InstructionEncoding; 256] = [
     InstructionEncoding {
         operation: InstructionOperation::BOUND as u16,
         flags: DecodeFlags::REG_RM_2X_SIZE as u16,
-        func: decode_reg_rm,
+        func: decode_bound,
     },
     InstructionEncoding {
         operation: InstructionOperation::ARPL as u16,
@@ -1016,7 +1806,7 @@ static MAIN_OPCODE_MAP: [InstructionEncoding; 256] = [
     InstructionEncoding {
         operation: InstructionOperation::POP as u16,
         flags: DecodeFlags::DEFAULT_TO_64BIT as u16,
-        func: decode_rmv,
+        func: decode_pop_rmv,
     },
     InstructionEncoding {
         operation: InstructionOperation::NOP as u16,
@@ -1285,22 +2075,22 @@ static MAIN_OPCODE_MAP: [InstructionEncoding; 256] = [
     InstructionEncoding {
         operation: InstructionOperation::LES as u16,
         flags: DecodeFlags::REG_RM_FAR_SIZE as u16,
-        func: decode_reg_rm,
+        func: decode_les_lds,
     },
     InstructionEncoding {
         operation: InstructionOperation::LDS as u16,
         flags: DecodeFlags::REG_RM_FAR_SIZE as u16,
-        func: decode_reg_rm,
+        func: decode_les_lds,
     },
     InstructionEncoding {
         operation: 2u16,
         flags: DecodeFlags::BYTE as u16,
-        func: decode_group_rm_imm,
+        func: decode_group_c6_c7,
     },
     InstructionEncoding {
         operation: 2u16,
         flags: 0,
-        func: decode_group_rm_imm,
+        func: decode_group_c6_c7,
     },
     InstructionEncoding {
         operation: InstructionOperation::ENTER as u16,
@@ -1738,7 +2528,7 @@ static TWO_BYTE_OPCODE_MAP: [InstructionEncoding; 256] = [
     InstructionEncoding {
         operation: 10u16,
         flags: DecodeFlags::REG_RM_NO_SIZE as u16,
-        func: decode_group_rm,
+        func: decode_group_0f1e,
     },
     InstructionEncoding {
         operation: 10u16,
@@ -2190,13 +2980,13 @@ static TWO_BYTE_OPCODE_MAP: [InstructionEncoding; 256] = [
     },
     InstructionEncoding {
         operation: InstructionOperation::VMREAD as u16,
-        flags: (DecodeFlags::FLIP_OPERANDS | DecodeFlags::DEFAULT_TO_64BIT) as u16,
-        func: decode_reg_rm,
+        flags: DecodeFlags::FLIP_OPERANDS as u16,
+        func: decode_vmread_vmwrite,
     },
     InstructionEncoding {
         operation: InstructionOperation::VMWRITE as u16,
-        flags: (DecodeFlags::FLIP_OPERANDS | DecodeFlags::DEFAULT_TO_64BIT) as u16,
-        func: decode_reg_rm,
+        flags: 0,
+        func: decode_vmread_vmwrite,
     },
     InstructionEncoding {
         operation: InstructionOperation::INVALID as u16,
@@ -2514,9 +3304,9 @@ static TWO_BYTE_OPCODE_MAP: [InstructionEncoding; 256] = [
         func: decode_0fb8,
     },
     InstructionEncoding {
-        operation: InstructionOperation::INVALID as u16,
+        operation: InstructionOperation::UD1 as u16,
         flags: 0,
-        func: invalid_decode,
+        func: decode_reg_rm,
     },
     InstructionEncoding {
         operation: 11u16,
@@ -2531,12 +3321,12 @@ static TWO_BYTE_OPCODE_MAP: [InstructionEncoding; 256] = [
     InstructionEncoding {
         operation: InstructionOperation::BSF as u16,
         flags: 0,
-        func: decode_reg_rm,
+        func: decode_bsf_bsr,
     },
     InstructionEncoding {
         operation: InstructionOperation::BSR as u16,
         flags: 0,
-        func: decode_reg_rm,
+        func: decode_bsf_bsr,
     },
     InstructionEncoding {
         operation: InstructionOperation::MOVSX as u16,
@@ -2575,7 +3365,7 @@ static TWO_BYTE_OPCODE_MAP: [InstructionEncoding; 256] = [
     },
     InstructionEncoding {
         operation: 28u16,
-        flags: DecodeFlags::FLIP_OPERANDS as u16,
+        flags: 0,
         func: decode_see_table_imm_8,
     },
     InstructionEncoding {
@@ -2864,9 +3654,9 @@ static TWO_BYTE_OPCODE_MAP: [InstructionEncoding; 256] = [
         func: decode_mmx,
     },
     InstructionEncoding {
-        operation: InstructionOperation::INVALID as u16,
+        operation: InstructionOperation::UD0 as u16,
         flags: 0,
-        func: invalid_decode,
+        func: decode_reg_rm,
     },
 ];
 
@@ -2876,7 +3666,7 @@ struct SparseInstructionEncoding {
     pub encoding: InstructionEncoding,
 }
 
-static THREE_BYTE_0F38_MAP: [SparseInstructionEncoding; 48] = [
+static THREE_BYTE_0F38_MAP: [SparseInstructionEncoding; 64] = [
     SparseInstructionEncoding {
         opcode: 0x0u8,
         encoding: InstructionEncoding {
@@ -3246,804 +4036,1202 @@ static THREE_BYTE_0F38_MAP: [SparseInstructionEncoding; 48] = [
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xf0u8,
+        opcode: 0x80u8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::CRC32 as u16,
-            flags: DecodeFlags::BYTE as u16,
-            func: decode_crc_32,
+            operation: InstructionOperation::INVEPT as u16,
+            flags: 0,
+            func: decode_invept_invvpid,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xf1u8,
+        opcode: 0x81u8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::CRC32 as u16,
+            operation: InstructionOperation::INVVPID as u16,
             flags: 0,
-            func: decode_crc_32,
+            func: decode_invept_invvpid,
         },
     },
-];
-
-static THREE_BYTE_0F3A_MAP: [SparseInstructionEncoding; 22] = [
     SparseInstructionEncoding {
-        opcode: 0x8u8,
+        opcode: 0xc8u8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::ROUNDPS as u16,
+            operation: InstructionOperation::SHA1NEXTE as u16,
             flags: 0,
-            func: decode_mmx_sse_only,
+            func: decode_sse_no_prefix,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x9u8,
+        opcode: 0xc9u8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::ROUNDPD as u16,
+            operation: InstructionOperation::SHA1MSG1 as u16,
             flags: 0,
-            func: decode_mmx_sse_only,
+            func: decode_sse_no_prefix,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xau8,
+        opcode: 0xcau8,
         encoding: InstructionEncoding {
-            operation: 50u16,
+            operation: InstructionOperation::SHA1MSG2 as u16,
             flags: 0,
-            func: decode_sse_table,
+            func: decode_sse_no_prefix,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xbu8,
+        opcode: 0xcbu8,
         encoding: InstructionEncoding {
-            operation: 51u16,
+            operation: InstructionOperation::SHA256RNDS2 as u16,
             flags: 0,
-            func: decode_sse_table,
+            func: decode_sse_no_prefix,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xcu8,
+        opcode: 0xccu8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::BLENDPS as u16,
+            operation: InstructionOperation::SHA256MSG1 as u16,
             flags: 0,
-            func: decode_mmx_sse_only,
+            func: decode_sse_no_prefix,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xdu8,
+        opcode: 0xcdu8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::BLENDPD as u16,
+            operation: InstructionOperation::SHA256MSG2 as u16,
             flags: 0,
-            func: decode_mmx_sse_only,
+            func: decode_sse_no_prefix,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xeu8,
+        opcode: 0xcfu8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::PBLENDW as u16,
+            operation: InstructionOperation::GF2P8MULB as u16,
             flags: 0,
             func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0xfu8,
+        opcode: 0xdbu8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::PALIGNR as u16,
+            operation: InstructionOperation::AESIMC as u16,
             flags: 0,
-            func: decode_mmx,
+            func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x14u8,
+        opcode: 0xdcu8,
         encoding: InstructionEncoding {
-            operation: 52u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_sse_table_mem_8,
+            operation: InstructionOperation::AESENC as u16,
+            flags: 0,
+            func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x15u8,
+        opcode: 0xddu8,
         encoding: InstructionEncoding {
-            operation: 53u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_sse_table,
+            operation: InstructionOperation::AESENCLAST as u16,
+            flags: 0,
+            func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x16u8,
+        opcode: 0xdeu8,
         encoding: InstructionEncoding {
-            operation: 54u16,
-            flags: (DecodeFlags::INC_OPERATION_FOR_64 | DecodeFlags::FLIP_OPERANDS) as u16,
-            func: decode_sse_table,
+            operation: InstructionOperation::AESDEC as u16,
+            flags: 0,
+            func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x17u8,
+        opcode: 0xdfu8,
         encoding: InstructionEncoding {
-            operation: 55u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_sse_table,
+            operation: InstructionOperation::AESDECLAST as u16,
+            flags: 0,
+            func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x20u8,
+        opcode: 0xf0u8,
         encoding: InstructionEncoding {
-            operation: 56u16,
-            flags: 0,
-            func: decode_sse_table_mem_8,
+            operation: InstructionOperation::CRC32 as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_crc_32,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x21u8,
+        opcode: 0xf1u8,
         encoding: InstructionEncoding {
-            operation: 57u16,
+            operation: InstructionOperation::CRC32 as u16,
             flags: 0,
-            func: decode_sse_table,
+            func: decode_crc_32,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x22u8,
+        opcode: 0xf5u8,
         encoding: InstructionEncoding {
-            operation: 58u16,
-            flags: DecodeFlags::INC_OPERATION_FOR_64 as u16,
-            func: decode_sse_table,
+            operation: InstructionOperation::WRUSSD as u16,
+            flags: 0,
+            func: decode_wrss,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x40u8,
+        opcode: 0xf6u8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::DPPS as u16,
+            operation: InstructionOperation::ADCX as u16,
             flags: 0,
-            func: decode_mmx_sse_only,
+            func: decode_adcx_adox,
         },
     },
-    SparseInstructionEncoding {
-        opcode: 0x41u8,
+];
+
+static THREE_BYTE_0F3A_MAP: [SparseInstructionEncoding; 27] = [
+    SparseInstructionEncoding {
+        opcode: 0x8u8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::DPPD as u16,
+            operation: InstructionOperation::ROUNDPS as u16,
             flags: 0,
             func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x42u8,
+        opcode: 0x9u8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::MPSADBW as u16,
+            operation: InstructionOperation::ROUNDPD as u16,
             flags: 0,
             func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x60u8,
+        opcode: 0xau8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::PCMPESTRM as u16,
+            operation: 50u16,
             flags: 0,
-            func: decode_mmx_sse_only,
+            func: decode_sse_table,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x61u8,
+        opcode: 0xbu8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::PCMPESTRI as u16,
+            operation: 51u16,
             flags: 0,
-            func: decode_mmx_sse_only,
+            func: decode_sse_table,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x62u8,
+        opcode: 0xcu8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::PCMPISTRM as u16,
+            operation: InstructionOperation::BLENDPS as u16,
             flags: 0,
             func: decode_mmx_sse_only,
         },
     },
     SparseInstructionEncoding {
-        opcode: 0x63u8,
+        opcode: 0xdu8,
         encoding: InstructionEncoding {
-            operation: InstructionOperation::PCMPISTRI as u16,
+            operation: InstructionOperation::BLENDPD as u16,
             flags: 0,
             func: decode_mmx_sse_only,
         },
     },
-];
-
-static FPU_MEM_OPCODE_MAP: [[InstructionEncoding; 8]; 8] = [
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FADD as u16,
+    SparseInstructionEncoding {
+        opcode: 0xeu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::PBLENDW as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FMUL as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xfu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::PALIGNR as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FCOM as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x14u8,
+        encoding: InstructionEncoding {
+            operation: 52u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_sse_table_mem_8,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FCOMP as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x15u8,
+        encoding: InstructionEncoding {
+            operation: 53u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_sse_table,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSUB as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x16u8,
+        encoding: InstructionEncoding {
+            operation: 54u16,
+            flags: (DecodeFlags::INC_OPERATION_FOR_64 | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_sse_table,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSUBR as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x17u8,
+        encoding: InstructionEncoding {
+            operation: 55u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_sse_table,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FDIV as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x20u8,
+        encoding: InstructionEncoding {
+            operation: 56u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_sse_table_mem_8,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FDIVR as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x21u8,
+        encoding: InstructionEncoding {
+            operation: 57u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_sse_table,
         },
-    ],
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FLD as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x22u8,
+        encoding: InstructionEncoding {
+            operation: 58u16,
+            flags: DecodeFlags::INC_OPERATION_FOR_64 as u16,
+            func: decode_sse_table,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x40u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::DPPS as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FST as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x41u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::DPPD as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSTP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x42u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::MPSADBW as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FLDENV as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x44u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::PCLMULQDQ as u16,
             flags: 0,
-            func: decode_mem_float_env,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FLDCW as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x60u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::PCMPESTRM as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSTENV as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x61u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::PCMPESTRI as u16,
             flags: 0,
-            func: decode_mem_float_env,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSTCW as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x62u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::PCMPISTRM as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_mmx_sse_only,
         },
-    ],
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FIADD as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x63u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::PCMPISTRI as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIMUL as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xccu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SHA1RNDS4 as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_sse_no_prefix,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FICOM as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xceu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::GF2P8AFFINEQB as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FICOMP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xcfu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::GF2P8AFFINEINVQB as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISUB as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xdfu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::AESKEYGENASSIST as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_mmx_sse_only,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISUBR as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+];
+
+static EVEX_MAP4: [SparseInstructionEncoding; 54] = [
+    SparseInstructionEncoding {
+        opcode: 0x0u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADD as u16,
+            flags: (DecodeFlags::BYTE | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIDIV as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x1u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADD as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIDIVR as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x2u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADD as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_reg_rm,
         },
-    ],
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FILD as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x3u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADD as u16,
             flags: 0,
-            func: decode_mem_32,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISTTP as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x8u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::OR as u16,
+            flags: (DecodeFlags::BYTE | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIST as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x9u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::OR as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISTP as u16,
-            flags: 0,
-            func: decode_mem_32,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xau8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::OR as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xbu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::OR as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FLD as u16,
-            flags: 0,
-            func: decode_mem_80,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x10u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADC as u16,
+            flags: (DecodeFlags::BYTE | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
-            flags: 0,
-            func: invalid_decode,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x11u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADC as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSTP as u16,
-            flags: 0,
-            func: decode_mem_80,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x12u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADC as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_reg_rm,
         },
-    ],
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FADD as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x13u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADC as u16,
             flags: 0,
-            func: decode_mem_64,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FMUL as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x18u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SBB as u16,
+            flags: (DecodeFlags::BYTE | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FCOM as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x19u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SBB as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FCOMP as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x1au8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SBB as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSUB as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x1bu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SBB as u16,
             flags: 0,
-            func: decode_mem_64,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSUBR as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x20u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::AND as u16,
+            flags: (DecodeFlags::BYTE | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FDIV as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x21u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::AND as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FDIVR as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x22u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::AND as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_reg_rm,
         },
-    ],
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FLD as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x23u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::AND as u16,
             flags: 0,
-            func: decode_mem_64,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISTTP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x24u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SHLD as u16,
             flags: 0,
-            func: decode_mem_64,
+            func: decode_rm_reg_imm_8,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FST as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x28u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SUB as u16,
+            flags: (DecodeFlags::BYTE | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSTP as u16,
-            flags: 0,
-            func: decode_mem_64,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x29u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SUB as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FRSTOR as u16,
-            flags: 0,
-            func: decode_mem_float_save,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x2au8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SUB as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x2bu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SUB as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSAVE as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x2cu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SHRD as u16,
             flags: 0,
-            func: decode_mem_float_save,
+            func: decode_rm_reg_imm_8,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FSTSW as u16,
-            flags: 0,
-            func: decode_mem_16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x30u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::XOR as u16,
+            flags: (DecodeFlags::BYTE | DecodeFlags::FLIP_OPERANDS) as u16,
+            func: decode_reg_rm,
         },
-    ],
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FIADD as u16,
-            flags: 0,
-            func: decode_mem_16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x31u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::XOR as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIMUL as u16,
-            flags: 0,
-            func: decode_mem_16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x32u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::XOR as u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FICOM as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x33u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::XOR as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FICOMP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x65u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::WRUSSD as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_wrss,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISUB as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x66u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::ADCX as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_adcx_adox,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISUBR as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x69u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::IMUL as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_reg_rm_imm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIDIV as u16,
-            flags: 0,
-            func: decode_mem_16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x6bu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::IMUL as u16,
+            flags: DecodeFlags::IMM_SX as u16,
+            func: decode_reg_rm_imm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIDIVR as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x80u8,
+        encoding: InstructionEncoding {
+            operation: 0u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_group_rm_imm,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0x81u8,
+        encoding: InstructionEncoding {
+            operation: 0u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_group_rm_imm,
         },
-    ],
-    [
-        InstructionEncoding {
-            operation: InstructionOperation::FILD as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x83u8,
+        encoding: InstructionEncoding {
+            operation: 0u16,
+            flags: DecodeFlags::IMM_SX as u16,
+            func: decode_group_rm_imm,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0x88u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::POPCNT as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISTTP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0x8fu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::POP2 as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_push2_pop2,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FIST as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xa5u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SHLD as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_rm_reg_cl,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISTP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xadu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::SHRD as u16,
             flags: 0,
-            func: decode_mem_16,
+            func: decode_rm_reg_cl,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FBLD as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xafu8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::IMUL as u16,
             flags: 0,
-            func: decode_mem_80,
+            func: decode_reg_rm,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FILD as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xc0u8,
+        encoding: InstructionEncoding {
+            operation: 1u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_group_rm_imm,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xc1u8,
+        encoding: InstructionEncoding {
+            operation: 1u16,
             flags: 0,
-            func: decode_mem_64,
+            func: decode_group_rm_imm_8v,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FBSTP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xd0u8,
+        encoding: InstructionEncoding {
+            operation: 1u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_group_rm_one,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xd1u8,
+        encoding: InstructionEncoding {
+            operation: 1u16,
             flags: 0,
-            func: decode_mem_80,
+            func: decode_group_rm_one,
         },
-        InstructionEncoding {
-            operation: InstructionOperation::FISTP as u16,
+    },
+    SparseInstructionEncoding {
+        opcode: 0xd2u8,
+        encoding: InstructionEncoding {
+            operation: 1u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_group_rm_cl,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xd3u8,
+        encoding: InstructionEncoding {
+            operation: 1u16,
             flags: 0,
-            func: decode_mem_64,
+            func: decode_group_rm_cl,
         },
-    ],
+    },
+    SparseInstructionEncoding {
+        opcode: 0xf4u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::TZCNT as u16,
+            flags: 0,
+            func: decode_reg_rm,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xf5u8,
+        encoding: InstructionEncoding {
+            operation: InstructionOperation::LZCNT as u16,
+            flags: 0,
+            func: decode_reg_rm,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xf6u8,
+        encoding: InstructionEncoding {
+            operation: 3u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_group_f6f7,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xf7u8,
+        encoding: InstructionEncoding {
+            operation: 3u16,
+            flags: 0,
+            func: decode_group_f6f7,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xfeu8,
+        encoding: InstructionEncoding {
+            operation: 4u16,
+            flags: DecodeFlags::BYTE as u16,
+            func: decode_group_rm,
+        },
+    },
+    SparseInstructionEncoding {
+        opcode: 0xffu8,
+        encoding: InstructionEncoding {
+            operation: 5u16,
+            flags: 0,
+            func: decode_push2_pop2,
+        },
+    },
 ];
 
-static FPU_REG_OPCODE_MAP: [[InstructionEncoding; 8]; 8] = [
+static FPU_MEM_OPCODE_MAP: [[InstructionEncoding; 8]; 8] = [
     [
         InstructionEncoding {
             operation: InstructionOperation::FADD as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
             operation: InstructionOperation::FMUL as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
             operation: InstructionOperation::FCOM as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
             operation: InstructionOperation::FCOMP as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
             operation: InstructionOperation::FSUB as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
             operation: InstructionOperation::FSUBR as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
             operation: InstructionOperation::FDIV as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
             operation: InstructionOperation::FDIVR as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            flags: 0,
+            func: decode_mem_32,
         },
     ],
     [
         InstructionEncoding {
             operation: InstructionOperation::FLD as u16,
             flags: 0,
-            func: decode_fpu_reg,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FXCH as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
         },
         InstructionEncoding {
-            operation: 12u16,
+            operation: InstructionOperation::FST as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+            operation: InstructionOperation::FSTP as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: 13u16,
+            operation: InstructionOperation::FLDENV as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: decode_mem_float_env,
         },
         InstructionEncoding {
-            operation: 14u16,
+            operation: InstructionOperation::FLDCW as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: decode_mem_16,
         },
         InstructionEncoding {
-            operation: 15u16,
+            operation: InstructionOperation::FSTENV as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: decode_mem_float_env,
         },
         InstructionEncoding {
-            operation: 16u16,
+            operation: InstructionOperation::FSTCW as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: decode_mem_16,
         },
     ],
     [
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVB as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FIADD as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVE as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FIMUL as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVBE as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FICOM as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVU as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FICOMP as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+            operation: InstructionOperation::FISUB as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: 17u16,
+            operation: InstructionOperation::FISUBR as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+            operation: InstructionOperation::FIDIV as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+            operation: InstructionOperation::FIDIVR as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mem_32,
         },
     ],
     [
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVNB as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FILD as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVNE as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FISTTP as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVNBE as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FIST as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCMOVNU as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FISTP as u16,
+            flags: 0,
+            func: decode_mem_32,
         },
         InstructionEncoding {
-            operation: 18u16,
+            operation: InstructionOperation::INVALID as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: invalid_decode,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FUCOMI as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::FLD as u16,
+            flags: 0,
+            func: decode_mem_80,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCOMI as u16,
-            flags: DecodeFlags::FLIP_OPERANDS as u16,
-            func: decode_fpu_reg_st0,
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
         },
         InstructionEncoding {
-            operation: 21u16,
+            operation: InstructionOperation::FSTP as u16,
             flags: 0,
-            func: decode_reg_group_no_operands,
+            func: decode_mem_80,
         },
     ],
     [
         InstructionEncoding {
             operation: InstructionOperation::FADD as u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_mem_64,
         },
         InstructionEncoding {
             operation: InstructionOperation::FMUL as u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+            operation: InstructionOperation::FCOM as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+            operation: InstructionOperation::FCOMP as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FSUBR as u16,
+            operation: InstructionOperation::FSUB as u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FSUB as u16,
+            operation: InstructionOperation::FSUBR as u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FDIVR as u16,
+            operation: InstructionOperation::FDIV as u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FDIV as u16,
+            operation: InstructionOperation::FDIVR as u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_mem_64,
         },
     ],
     [
         InstructionEncoding {
-            operation: InstructionOperation::FFREE as u16,
+            operation: InstructionOperation::FLD as u16,
             flags: 0,
-            func: decode_fpu_reg,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
+            operation: InstructionOperation::FISTTP as u16,
             flags: 0,
-            func: invalid_decode,
+            func: decode_mem_64,
         },
         InstructionEncoding {
             operation: InstructionOperation::FST as u16,
             flags: 0,
-            func: decode_fpu_reg,
+            func: decode_mem_64,
         },
         InstructionEncoding {
             operation: InstructionOperation::FSTP as u16,
             flags: 0,
-            func: decode_fpu_reg,
+            func: decode_mem_64,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FUCOM as u16,
+            operation: InstructionOperation::FRSTOR as u16,
+            flags: 0,
+            func: decode_mem_float_save,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSAVE as u16,
+            flags: 0,
+            func: decode_mem_float_save,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSTSW as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+    ],
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FIADD as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FIMUL as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FICOM as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FICOMP as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FISUB as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FISUBR as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FIDIV as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FIDIVR as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+    ],
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FILD as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FISTTP as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FIST as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FISTP as u16,
+            flags: 0,
+            func: decode_mem_16,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FBLD as u16,
+            flags: 0,
+            func: decode_mem_80,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FILD as u16,
+            flags: 0,
+            func: decode_mem_64,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FBSTP as u16,
+            flags: 0,
+            func: decode_mem_80,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FISTP as u16,
+            flags: 0,
+            func: decode_mem_64,
+        },
+    ],
+];
+
+static FPU_REG_OPCODE_MAP: [[InstructionEncoding; 8]; 8] = [
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FADD as u16,
             flags: DecodeFlags::FLIP_OPERANDS as u16,
             func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FUCOMP as u16,
+            operation: InstructionOperation::FMUL as u16,
             flags: DecodeFlags::FLIP_OPERANDS as u16,
             func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: InstructionOperation::INVALID as u16,
-            flags: 0,
-            func: invalid_decode,
+            operation: InstructionOperation::FCOM as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: 22u16,
-            flags: 0,
-            func: decode_reg_group_no_operands,
+            operation: InstructionOperation::FCOMP as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSUB as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSUBR as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FDIV as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FDIVR as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
         },
     ],
     [
         InstructionEncoding {
-            operation: InstructionOperation::FADDP as u16,
+            operation: InstructionOperation::FLD as u16,
             flags: 0,
+            func: decode_fpu_reg,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FXCH as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
             func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FMULP as u16,
+            operation: 12u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_reg_group_no_operands,
         },
         InstructionEncoding {
             operation: InstructionOperation::INVALID as u16,
@@ -4051,42 +5239,57 @@ static FPU_REG_OPCODE_MAP: [[InstructionEncoding; 8]; 8] = [
             func: invalid_decode,
         },
         InstructionEncoding {
-            operation: 19u16,
+            operation: 13u16,
             flags: 0,
             func: decode_reg_group_no_operands,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FSUBRP as u16,
+            operation: 14u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_reg_group_no_operands,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FSUBP as u16,
+            operation: 15u16,
             flags: 0,
-            func: decode_fpu_reg_st0,
+            func: decode_reg_group_no_operands,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FDIVRP as u16,
+            operation: 16u16,
             flags: 0,
+            func: decode_reg_group_no_operands,
+        },
+    ],
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FCMOVB as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
             func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FDIVP as u16,
-            flags: 0,
+            operation: InstructionOperation::FCMOVE as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
             func: decode_fpu_reg_st0,
         },
-    ],
-    [
         InstructionEncoding {
-            operation: InstructionOperation::FFREEP as u16,
-            flags: 0,
-            func: decode_fpu_reg,
+            operation: InstructionOperation::FCMOVBE as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FCMOVU as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
             operation: InstructionOperation::INVALID as u16,
             flags: 0,
             func: invalid_decode,
         },
+        InstructionEncoding {
+            operation: 17u16,
+            flags: 0,
+            func: decode_reg_group_no_operands,
+        },
         InstructionEncoding {
             operation: InstructionOperation::INVALID as u16,
             flags: 0,
@@ -4097,34 +5300,224 @@ static FPU_REG_OPCODE_MAP: [[InstructionEncoding; 8]; 8] = [
             flags: 0,
             func: invalid_decode,
         },
+    ],
+    [
         InstructionEncoding {
-            operation: 20u16,
-            flags: 0,
-            func: decode_reg_group_ax,
+            operation: InstructionOperation::FCMOVNB as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FUCOMIP as u16,
+            operation: InstructionOperation::FCMOVNE as u16,
             flags: DecodeFlags::FLIP_OPERANDS as u16,
             func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: InstructionOperation::FCOMIP as u16,
+            operation: InstructionOperation::FCMOVNBE as u16,
             flags: DecodeFlags::FLIP_OPERANDS as u16,
             func: decode_fpu_reg_st0,
         },
         InstructionEncoding {
-            operation: 23u16,
+            operation: InstructionOperation::FCMOVNU as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: 18u16,
             flags: 0,
             func: decode_reg_group_no_operands,
         },
-    ],
-];
-
-static GROUP_OPERATIONS: [[InstructionOperation; 8]; 26] = [
-    [
-        InstructionOperation::ADD,
-        InstructionOperation::OR,
-        InstructionOperation::ADC,
+        InstructionEncoding {
+            operation: InstructionOperation::FUCOMI as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FCOMI as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: 21u16,
+            flags: 0,
+            func: decode_reg_group_no_operands,
+        },
+    ],
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FADD as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FMUL as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSUBR as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSUB as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FDIVR as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FDIV as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+    ],
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FFREE as u16,
+            flags: 0,
+            func: decode_fpu_reg,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FST as u16,
+            flags: 0,
+            func: decode_fpu_reg,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSTP as u16,
+            flags: 0,
+            func: decode_fpu_reg,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FUCOM as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FUCOMP as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: 22u16,
+            flags: 0,
+            func: decode_reg_group_no_operands,
+        },
+    ],
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FADDP as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FMULP as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: 19u16,
+            flags: 0,
+            func: decode_reg_group_no_operands,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSUBRP as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FSUBP as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FDIVRP as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FDIVP as u16,
+            flags: 0,
+            func: decode_fpu_reg_st0,
+        },
+    ],
+    [
+        InstructionEncoding {
+            operation: InstructionOperation::FFREEP as u16,
+            flags: 0,
+            func: decode_fpu_reg,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::INVALID as u16,
+            flags: 0,
+            func: invalid_decode,
+        },
+        InstructionEncoding {
+            operation: 20u16,
+            flags: 0,
+            func: decode_reg_group_ax,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FUCOMIP as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: InstructionOperation::FCOMIP as u16,
+            flags: DecodeFlags::FLIP_OPERANDS as u16,
+            func: decode_fpu_reg_st0,
+        },
+        InstructionEncoding {
+            operation: 23u16,
+            flags: 0,
+            func: decode_reg_group_no_operands,
+        },
+    ],
+];
+
+static GROUP_OPERATIONS: [[InstructionOperation; 8]; 26] = [
+    [
+        InstructionOperation::ADD,
+        InstructionOperation::OR,
+        InstructionOperation::ADC,
         InstructionOperation::SBB,
         InstructionOperation::AND,
         InstructionOperation::SUB,
@@ -4204,7 +5597,7 @@ static GROUP_OPERATIONS: [[InstructionOperation; 8]; 26] = [
     [
         InstructionOperation::PREFETCH,
         InstructionOperation::PREFETCHW,
-        InstructionOperation::PREFETCH,
+        InstructionOperation::PREFETCHWT1,
         InstructionOperation::PREFETCH,
         InstructionOperation::PREFETCH,
         InstructionOperation::PREFETCH,
@@ -4368,7 +5761,7 @@ static GROUP_OPERATIONS: [[InstructionOperation; 8]; 26] = [
         InstructionOperation::STMXCSR,
         InstructionOperation::XSAVE,
         InstructionOperation::XRSTOR,
-        InstructionOperation::INVALID,
+        InstructionOperation::XSAVEOPT,
         InstructionOperation::CLFLUSH,
     ],
     [
@@ -4409,20 +5802,20 @@ static GROUP_0F01_REG_OPERATIONS: [[InstructionOperation; 8]; 8] = [
         InstructionOperation::XSETBV,
         InstructionOperation::INVALID,
         InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
+        InstructionOperation::VMFUNC,
+        InstructionOperation::XEND,
+        InstructionOperation::XTEST,
         InstructionOperation::INVALID,
     ],
     [
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
+        InstructionOperation::VMRUN,
+        InstructionOperation::VMMCALL,
+        InstructionOperation::VMLOAD,
+        InstructionOperation::VMSAVE,
+        InstructionOperation::STGI,
+        InstructionOperation::CLGI,
+        InstructionOperation::SKINIT,
+        InstructionOperation::INVLPGA,
     ],
     [
         InstructionOperation::INVALID,
@@ -4435,9 +5828,9 @@ static GROUP_0F01_REG_OPERATIONS: [[InstructionOperation; 8]; 8] = [
         InstructionOperation::INVALID,
     ],
     [
+        InstructionOperation::SETSSBSY,
         InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
-        InstructionOperation::INVALID,
+        InstructionOperation::SAVEPREVSSP,
         InstructionOperation::INVALID,
         InstructionOperation::INVALID,
         InstructionOperation::INVALID,
@@ -4515,7 +5908,7 @@ struct SSETableEntry {
     pub mem_ops: [SSETableOperationEntry; 4],
 }
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 #[repr(i32)]
 enum SSETableOperandType {
@@ -4525,12 +5918,14 @@ enum SSETableOperandType {
     SSE_64,
     SSE_128,
     SSE_128_FLIP,
+    SSE_64_FLIP,
+    GPR_32,
     GPR_32_OR_64,
     MMX_32,
     MMX_64,
 }
 
-static SSE_TABLE: [SSETableEntry; 58] = [
+static SSE_TABLE: [SSETableEntry; 59] = [
     SSETableEntry {
         reg_ops: [
             SSETableOperationEntry {
@@ -4579,16 +5974,16 @@ static SSE_TABLE: [SSETableEntry; 58] = [
     },
     SSETableEntry {
         reg_ops: [
-            SSETableOperationEntry {
-                operation: InstructionOperation::INVALID,
-                reg_type: SSETableOperandType::INVALID,
-                rm_type: SSETableOperandType::INVALID,
-            },
             SSETableOperationEntry {
                 operation: InstructionOperation::MOVHLPS,
                 reg_type: SSETableOperandType::SSE_128,
                 rm_type: SSETableOperandType::SSE_128,
             },
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
             SSETableOperationEntry {
                 operation: InstructionOperation::MOVDDUP,
                 reg_type: SSETableOperandType::SSE_128,
@@ -4763,11 +6158,6 @@ static SSE_TABLE: [SSETableEntry; 58] = [
     },
     SSETableEntry {
         reg_ops: [
-            SSETableOperationEntry {
-                operation: InstructionOperation::INVALID,
-                reg_type: SSETableOperandType::INVALID,
-                rm_type: SSETableOperandType::INVALID,
-            },
             SSETableOperationEntry {
                 operation: InstructionOperation::MOVLHPS,
                 reg_type: SSETableOperandType::SSE_128,
@@ -4778,6 +6168,11 @@ static SSE_TABLE: [SSETableEntry; 58] = [
                 reg_type: SSETableOperandType::INVALID,
                 rm_type: SSETableOperandType::INVALID,
             },
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
             SSETableOperationEntry {
                 operation: InstructionOperation::MOVSHDUP,
                 reg_type: SSETableOperandType::SSE_128,
@@ -5723,7 +7118,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::MOVQ,
                 reg_type: SSETableOperandType::SSE_128_FLIP,
-                rm_type: SSETableOperandType::SSE_128_FLIP,
+                rm_type: SSETableOperandType::SSE_64_FLIP,
             },
         ],
     },
@@ -5778,12 +7173,12 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::PINSRW,
                 reg_type: SSETableOperandType::MMX_64,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::PINSRW,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -5800,12 +7195,12 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::PINSRW,
                 reg_type: SSETableOperandType::MMX_64,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::PINSRW,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -5823,13 +7218,13 @@ static SSE_TABLE: [SSETableEntry; 58] = [
         reg_ops: [
             SSETableOperationEntry {
                 operation: InstructionOperation::PEXTRW,
-                reg_type: SSETableOperandType::MMX_64,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                reg_type: SSETableOperandType::GPR_32,
+                rm_type: SSETableOperandType::MMX_64,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::PEXTRW,
-                reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                reg_type: SSETableOperandType::GPR_32,
+                rm_type: SSETableOperandType::SSE_128,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -5844,14 +7239,14 @@ static SSE_TABLE: [SSETableEntry; 58] = [
         ],
         mem_ops: [
             SSETableOperationEntry {
-                operation: InstructionOperation::PEXTRW,
-                reg_type: SSETableOperandType::MMX_64,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
             },
             SSETableOperationEntry {
-                operation: InstructionOperation::PEXTRW,
-                reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -5989,7 +7384,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::MOVQ,
                 reg_type: SSETableOperandType::SSE_128_FLIP,
-                rm_type: SSETableOperandType::SSE_128_FLIP,
+                rm_type: SSETableOperandType::SSE_64_FLIP,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -6091,7 +7486,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::CVTDQ2PD,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::SSE_128,
+                rm_type: SSETableOperandType::SSE_64,
             },
         ],
     },
@@ -6933,7 +8328,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::PEXTRB,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -6955,7 +8350,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::PEXTRB,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -6979,7 +8374,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::PEXTRW,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -7071,7 +8466,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::EXTRACTPS,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -7117,7 +8512,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::PINSRB,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -7139,7 +8534,7 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             SSETableOperationEntry {
                 operation: InstructionOperation::PINSRB,
                 reg_type: SSETableOperandType::SSE_128,
-                rm_type: SSETableOperandType::GPR_32_OR_64,
+                rm_type: SSETableOperandType::GPR_32,
             },
             SSETableOperationEntry {
                 operation: InstructionOperation::INVALID,
@@ -7199,6 +8594,52 @@ static SSE_TABLE: [SSETableEntry; 58] = [
             },
         ],
     },
+    SSETableEntry {
+        reg_ops: [
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
+            SSETableOperationEntry {
+                operation: InstructionOperation::PINSRD,
+                reg_type: SSETableOperandType::SSE_128,
+                rm_type: SSETableOperandType::GPR_32_OR_64,
+            },
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
+        ],
+        mem_ops: [
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
+            SSETableOperationEntry {
+                operation: InstructionOperation::PINSRD,
+                reg_type: SSETableOperandType::SSE_128,
+                rm_type: SSETableOperandType::GPR_32_OR_64,
+            },
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
+            SSETableOperationEntry {
+                operation: InstructionOperation::INVALID,
+                reg_type: SSETableOperandType::INVALID,
+                rm_type: SSETableOperandType::INVALID,
+            },
+        ],
+    },
 ];
 
 #[derive(Debug)]
@@ -7315,1279 +8756,9249 @@ static SPARSE_3DNOW_OPCODES: [SparseOpEntry; 26] = [
     },
 ];
 
-static INVALID_REG_LIST: [OperandType; 0] = [];
-
-static REG8_LIST: [OperandType; 8] = [
-    OperandType::REG_AL,
-    OperandType::REG_CL,
-    OperandType::REG_DL,
-    OperandType::REG_BL,
-    OperandType::REG_AH,
-    OperandType::REG_CH,
-    OperandType::REG_DH,
-    OperandType::REG_BH,
-];
-
-static REG8_LIST64: [OperandType; 16] = [
-    OperandType::REG_AL,
-    OperandType::REG_CL,
-    OperandType::REG_DL,
-    OperandType::REG_BL,
-    OperandType::REG_SPL,
-    OperandType::REG_BPL,
-    OperandType::REG_SIL,
-    OperandType::REG_DIL,
-    OperandType::REG_R8B,
-    OperandType::REG_R9B,
-    OperandType::REG_R10B,
-    OperandType::REG_R11B,
-    OperandType::REG_R12B,
-    OperandType::REG_R13B,
-    OperandType::REG_R14B,
-    OperandType::REG_R15B,
-];
-
-static REG16_LIST: [OperandType; 16] = [
-    OperandType::REG_AX,
-    OperandType::REG_CX,
-    OperandType::REG_DX,
-    OperandType::REG_BX,
-    OperandType::REG_SP,
-    OperandType::REG_BP,
-    OperandType::REG_SI,
-    OperandType::REG_DI,
-    OperandType::REG_R8W,
-    OperandType::REG_R9W,
-    OperandType::REG_R10W,
-    OperandType::REG_R11W,
-    OperandType::REG_R12W,
-    OperandType::REG_R13W,
-    OperandType::REG_R14W,
-    OperandType::REG_R15W,
-];
-
-static REG32_LIST: [OperandType; 16] = [
-    OperandType::REG_EAX,
-    OperandType::REG_ECX,
-    OperandType::REG_EDX,
-    OperandType::REG_EBX,
-    OperandType::REG_ESP,
-    OperandType::REG_EBP,
-    OperandType::REG_ESI,
-    OperandType::REG_EDI,
-    OperandType::REG_R8D,
-    OperandType::REG_R9D,
-    OperandType::REG_R10D,
-    OperandType::REG_R11D,
-    OperandType::REG_R12D,
-    OperandType::REG_R13D,
-    OperandType::REG_R14D,
-    OperandType::REG_R15D,
-];
+/// How the operands of a VEX encoded instruction differ from those of
+/// the legacy SSE form that it is decoded with.
+struct VexForm;
 
-static REG64_LIST: [OperandType; 16] = [
-    OperandType::REG_RAX,
-    OperandType::REG_RCX,
-    OperandType::REG_RDX,
-    OperandType::REG_RBX,
-    OperandType::REG_RSP,
-    OperandType::REG_RBP,
-    OperandType::REG_RSI,
-    OperandType::REG_RDI,
-    OperandType::REG_R8,
-    OperandType::REG_R9,
-    OperandType::REG_R10,
-    OperandType::REG_R11,
-    OperandType::REG_R12,
-    OperandType::REG_R13,
-    OperandType::REG_R14,
-    OperandType::REG_R15,
-];
+impl VexForm {
+    /// `VEX.vvvv` is a source register, inserted after the destination.
+    const NDS: u32 = 0x0001;
+    /// `VEX.vvvv` is a source register, but only when the `r/m` operand
+    /// is a register.
+    const NDS_REG: u32 = 0x0002;
+    /// `VEX.vvvv` is a source register, but only when the destination
+    /// is a register.
+    const NDS_LOAD: u32 = 0x0004;
+    /// `VEX.vvvv` is the destination register.
+    const NDD: u32 = 0x0008;
+    /// `NDD` for a shift by an immediate, otherwise `NDS` with a shift
+    /// count that stays 128 bits.
+    const SHIFT: u32 = 0x0010;
+    /// `VEX.L` must be 0.
+    const L0: u32 = 0x0020;
+    /// `VEX.L` must be 1.
+    const L1: u32 = 0x0040;
+    /// `VEX.L` is ignored and the operands are not widened.
+    const LIG: u32 = 0x0080;
+    /// The `r/m` operand is half of the vector length.
+    const RM_HALF: u32 = 0x0100;
+    /// The `r/m` operand is not widened.
+    const RM_XMM: u32 = 0x0200;
+    /// The `reg` operand is not widened.
+    const REG_XMM: u32 = 0x0400;
+    /// `VEX.W` must be 0.
+    const W0: u32 = 0x0800;
+    /// `VEX.W` must be 1.
+    const W1: u32 = 0x1000;
+    /// `VEX.W` selects the operation after the one given.
+    const W_OPERATION: u32 = 0x2000;
+    /// The upper 4 bits of the 8 bit immediate select a register.
+    const IS4: u32 = 0x4000;
+    /// The `reg` operand is the second operand rather than the first.
+    const FLIP_OPERANDS: u32 = 0x8000;
+    /// `VEX.vvvv` is a source register, added after the other operands.
+    const NDS_LAST: u32 = 0x10000;
+    /// `VEX.W` swaps the last two operands, so that the `r/m` operand
+    /// can be any of the sources.
+    const W_SWAP: u32 = 0x20000;
+    /// `VEX.vvvv` is a general purpose register of the operand size.
+    const GPR: u32 = 0x40000;
+}
 
-static MMX_REG_LIST: [OperandType; 16] = [
-    OperandType::REG_MM0,
-    OperandType::REG_MM1,
-    OperandType::REG_MM2,
-    OperandType::REG_MM3,
-    OperandType::REG_MM4,
-    OperandType::REG_MM5,
-    OperandType::REG_MM6,
-    OperandType::REG_MM7,
-    OperandType::REG_MM0,
-    OperandType::REG_MM1,
-    OperandType::REG_MM2,
-    OperandType::REG_MM3,
-    OperandType::REG_MM4,
-    OperandType::REG_MM5,
-    OperandType::REG_MM6,
-    OperandType::REG_MM7,
-];
+/// What an EVEX encoded instruction allows beyond its `VexForm`.
+struct EvexForm;
 
-static XMM_REG_LIST: [OperandType; 16] = [
-    OperandType::REG_XMM0,
-    OperandType::REG_XMM1,
-    OperandType::REG_XMM2,
-    OperandType::REG_XMM3,
-    OperandType::REG_XMM4,
-    OperandType::REG_XMM5,
-    OperandType::REG_XMM6,
-    OperandType::REG_XMM7,
-    OperandType::REG_XMM8,
-    OperandType::REG_XMM9,
-    OperandType::REG_XMM10,
-    OperandType::REG_XMM11,
-    OperandType::REG_XMM12,
-    OperandType::REG_XMM13,
-    OperandType::REG_XMM14,
-    OperandType::REG_XMM15,
-];
+impl EvexForm {
+    /// `EVEX.b` with a memory operand broadcasts a single element.
+    const BCST: u16 = 0x0001;
+    /// `EVEX.b` with register operands gives a static rounding mode.
+    const ER: u16 = 0x0002;
+    /// `EVEX.b` with register operands suppresses all exceptions.
+    const SAE: u16 = 0x0004;
+    /// The `reg` operand is an opmask register.
+    const K_DEST: u16 = 0x0008;
+    /// `EVEX.aaa` and `EVEX.z` must be 0.
+    const NO_MASK: u16 = 0x0010;
+    /// `EVEX.aaa` must not be 0 and `EVEX.z` must be 0.
+    const MASK_REQUIRED: u16 = 0x0020;
+    /// `EVEX.L'L` must select 512 bits.
+    const L512: u16 = 0x0040;
+    /// With `RM_HALF`, the `r/m` operand is a quarter of the vector
+    /// length, and a register is always 128 bits.
+    const RM_QUARTER: u16 = 0x0080;
+    /// An 8 bit displacement is scaled by the size of one element
+    /// rather than by the size of the memory operand.
+    const T1S: u16 = 0x0100;
+}
 
-static FPU_REG_LIST: [OperandType; 16] = [
-    OperandType::REG_ST0,
-    OperandType::REG_ST1,
-    OperandType::REG_ST2,
-    OperandType::REG_ST3,
-    OperandType::REG_ST4,
-    OperandType::REG_ST5,
-    OperandType::REG_ST6,
-    OperandType::REG_ST7,
-    OperandType::REG_ST0,
-    OperandType::REG_ST1,
-    OperandType::REG_ST2,
-    OperandType::REG_ST3,
-    OperandType::REG_ST4,
-    OperandType::REG_ST5,
-    OperandType::REG_ST6,
-    OperandType::REG_ST7,
-];
+/// An instruction that only exists with an EVEX prefix, like
+/// `VexInstructionEncoding`.
+#[repr(C)]
+struct EvexInstructionEncoding {
+    pub opcode: u8,
+    pub prefix: u8,
+    pub operation: InstructionOperation,
+    pub form: u32,
+    pub evex: u16,
+    pub func: fn(&mut DecodeState),
+}
 
-fn invalid_decode(state: &mut DecodeState) {
-    state.invalid = true;
+/// The EVEX form of a legacy SSE operation. This is sorted by `legacy`,
+/// and an operation may have an entry for each value of `EVEX.W`.
+#[derive(Debug)]
+#[repr(C)]
+struct EvexOperationEntry {
+    pub legacy: InstructionOperation,
+    pub operation: InstructionOperation,
+    pub form: u32,
+    pub evex: u16,
 }
 
-fn read_8(state: &mut DecodeState) -> u8 {
-    if state.len < 1 {
-        state.invalid = true;
-        state.insufficient_length = true;
-        state.len = 0;
-        0xcc
-    } else {
-        let val = unsafe {
-            *{
-                let _old = state.opcode;
-                state.opcode = state.opcode.offset(1);
-                _old
-            }
-        };
-        state.len = state.len.wrapping_sub(1);
-        val
-    }
+/// An instruction that only exists with a VEX prefix. The `prefix` is
+/// the implied SSE prefix from `VEX.pp`, using the same numbering as
+/// the columns of `SSE_TABLE`.
+#[repr(C)]
+struct VexInstructionEncoding {
+    pub opcode: u8,
+    pub prefix: u8,
+    pub operation: InstructionOperation,
+    pub form: u32,
+    pub func: fn(&mut DecodeState),
 }
 
-fn get_final_op_size(state: &DecodeState) -> u16 {
-    if state.flags & DecodeFlags::BYTE != 0 {
-        1u16
-    } else {
-        state.op_size
-    }
+/// The VEX form of a legacy SSE operation. This is sorted by `legacy`.
+#[derive(Debug)]
+#[repr(C)]
+struct VexOperationEntry {
+    pub legacy: InstructionOperation,
+    pub operation: InstructionOperation,
+    pub form: u32,
 }
 
-fn process_encoding(state: &mut DecodeState, encoding: &InstructionEncoding) {
-    state.result.operation = InstructionOperation::from_i32(i32::from(encoding.operation));
-    state.flags = u32::from(encoding.flags);
-    if state.using64 && (state.flags & DecodeFlags::INVALID_IN_64BIT != 0) {
-        state.invalid = true;
-    } else {
-        if state.using64 && (state.flags & DecodeFlags::DEFAULT_TO_64BIT != 0) {
-            state.op_size = if state.op_prefix { 4 } else { 8 };
-        }
-        state.final_op_size = get_final_op_size(state);
-        if state.flags & DecodeFlags::FLIP_OPERANDS != 0 {
-            state.operand0 = &mut state.result.operands[1] as *mut X86Operand;
-            state.operand1 = &mut state.result.operands[0] as *mut X86Operand;
-        } else {
-            state.operand0 = &mut state.result.operands[0] as *mut X86Operand;
-            state.operand1 = &mut state.result.operands[1] as *mut X86Operand;
-        }
-        if state.flags & DecodeFlags::FORCE_16BIT != 0 {
-            state.final_op_size = 2;
-        }
-        if state.flags & DecodeFlags::OPERATION_OP_SIZE != 0 {
-            if state.final_op_size == 4 {
-                state.result.operation =
-                    InstructionOperation::from_i32(state.result.operation as i32 + 1);
-            } else if state.final_op_size == 8 {
-                state.result.operation =
-                    InstructionOperation::from_i32(state.result.operation as i32 + 2);
-            }
-        }
-        if state.flags & DecodeFlags::REP != 0 {
-            if state.rep != RepPrefix::NONE {
-                state.result.flags |= X86Flag::REP;
-            }
-        } else if state.flags & DecodeFlags::REP_COND != 0 {
-            if state.rep == RepPrefix::REPNE {
-                state.result.flags |= X86Flag::REPNE;
-            } else if state.rep == RepPrefix::REPE {
-                state.result.flags |= X86Flag::REPE;
-            }
-        }
-        (encoding.func)(state);
+static VEX_0F_MAP: [VexInstructionEncoding; 56] = [
+    VexInstructionEncoding {
+        opcode: 0x41u8,
+        prefix: 0,
+        operation: InstructionOperation::KANDW,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x41u8,
+        prefix: 0,
+        operation: InstructionOperation::KANDQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x41u8,
+        prefix: 1,
+        operation: InstructionOperation::KANDB,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x41u8,
+        prefix: 1,
+        operation: InstructionOperation::KANDD,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x42u8,
+        prefix: 0,
+        operation: InstructionOperation::KANDNW,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x42u8,
+        prefix: 0,
+        operation: InstructionOperation::KANDNQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x42u8,
+        prefix: 1,
+        operation: InstructionOperation::KANDNB,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x42u8,
+        prefix: 1,
+        operation: InstructionOperation::KANDND,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x44u8,
+        prefix: 0,
+        operation: InstructionOperation::KNOTW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x44u8,
+        prefix: 0,
+        operation: InstructionOperation::KNOTQ,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x44u8,
+        prefix: 1,
+        operation: InstructionOperation::KNOTB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x44u8,
+        prefix: 1,
+        operation: InstructionOperation::KNOTD,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x45u8,
+        prefix: 0,
+        operation: InstructionOperation::KORW,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x45u8,
+        prefix: 0,
+        operation: InstructionOperation::KORQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x45u8,
+        prefix: 1,
+        operation: InstructionOperation::KORB,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x45u8,
+        prefix: 1,
+        operation: InstructionOperation::KORD,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 0,
+        operation: InstructionOperation::KXNORW,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 0,
+        operation: InstructionOperation::KXNORQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 1,
+        operation: InstructionOperation::KXNORB,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 1,
+        operation: InstructionOperation::KXNORD,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x47u8,
+        prefix: 0,
+        operation: InstructionOperation::KXORW,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x47u8,
+        prefix: 0,
+        operation: InstructionOperation::KXORQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x47u8,
+        prefix: 1,
+        operation: InstructionOperation::KXORB,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x47u8,
+        prefix: 1,
+        operation: InstructionOperation::KXORD,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4au8,
+        prefix: 0,
+        operation: InstructionOperation::KADDW,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4au8,
+        prefix: 0,
+        operation: InstructionOperation::KADDQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4au8,
+        prefix: 1,
+        operation: InstructionOperation::KADDB,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4au8,
+        prefix: 1,
+        operation: InstructionOperation::KADDD,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4bu8,
+        prefix: 0,
+        operation: InstructionOperation::KUNPCKWD,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4bu8,
+        prefix: 0,
+        operation: InstructionOperation::KUNPCKDQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4bu8,
+        prefix: 1,
+        operation: InstructionOperation::KUNPCKBW,
+        form: VexForm::L1 | VexForm::W0,
+        func: decode_vex_mask_nds,
+    },
+    VexInstructionEncoding {
+        opcode: 0x77u8,
+        prefix: 0,
+        operation: InstructionOperation::VZEROUPPER,
+        form: VexForm::LIG,
+        func: decode_vzero,
+    },
+    VexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 0,
+        operation: InstructionOperation::KMOVW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 0,
+        operation: InstructionOperation::KMOVQ,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 1,
+        operation: InstructionOperation::KMOVB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 1,
+        operation: InstructionOperation::KMOVD,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 0,
+        operation: InstructionOperation::KMOVW,
+        form: VexForm::L0 | VexForm::FLIP_OPERANDS | VexForm::W0,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 0,
+        operation: InstructionOperation::KMOVQ,
+        form: VexForm::L0 | VexForm::FLIP_OPERANDS | VexForm::W1,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 1,
+        operation: InstructionOperation::KMOVB,
+        form: VexForm::L0 | VexForm::FLIP_OPERANDS | VexForm::W0,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 1,
+        operation: InstructionOperation::KMOVD,
+        form: VexForm::L0 | VexForm::FLIP_OPERANDS | VexForm::W1,
+        func: decode_vex_mask_mov,
+    },
+    VexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 0,
+        operation: InstructionOperation::KMOVW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 1,
+        operation: InstructionOperation::KMOVB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 2,
+        operation: InstructionOperation::KMOVD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 2,
+        operation: InstructionOperation::KMOVQ,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 0,
+        operation: InstructionOperation::KMOVW,
+        form: VexForm::L0 | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 1,
+        operation: InstructionOperation::KMOVB,
+        form: VexForm::L0 | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 2,
+        operation: InstructionOperation::KMOVD,
+        form: VexForm::L0 | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 2,
+        operation: InstructionOperation::KMOVQ,
+        form: VexForm::L0 | VexForm::W1 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_mask_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 0,
+        operation: InstructionOperation::KORTESTW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 0,
+        operation: InstructionOperation::KORTESTQ,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 1,
+        operation: InstructionOperation::KORTESTB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 1,
+        operation: InstructionOperation::KORTESTD,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 0,
+        operation: InstructionOperation::KTESTW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 0,
+        operation: InstructionOperation::KTESTQ,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 1,
+        operation: InstructionOperation::KTESTB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 1,
+        operation: InstructionOperation::KTESTD,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+];
+
+static VEX_0F38_MAP: [VexInstructionEncoding; 82] = [
+    VexInstructionEncoding {
+        opcode: 0xcu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPS,
+        form: VexForm::NDS | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xdu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPD,
+        form: VexForm::NDS | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xeu8,
+        prefix: 1,
+        operation: InstructionOperation::VTESTPS,
+        form: VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xfu8,
+        prefix: 1,
+        operation: InstructionOperation::VTESTPD,
+        form: VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x13u8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPH2PS,
+        form: VexForm::RM_HALF | VexForm::W0,
+        func: decode_vex_sse_64,
+    },
+    VexInstructionEncoding {
+        opcode: 0x16u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMPS,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x18u8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTSS,
+        form: VexForm::RM_XMM | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x19u8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTSD,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x1au8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTF128,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x2cu8,
+        prefix: 1,
+        operation: InstructionOperation::VMASKMOVPS,
+        form: VexForm::NDS | VexForm::W0,
+        func: decode_vex_sse_mem,
+    },
+    VexInstructionEncoding {
+        opcode: 0x2du8,
+        prefix: 1,
+        operation: InstructionOperation::VMASKMOVPD,
+        form: VexForm::NDS | VexForm::W0,
+        func: decode_vex_sse_mem,
+    },
+    VexInstructionEncoding {
+        opcode: 0x2eu8,
+        prefix: 1,
+        operation: InstructionOperation::VMASKMOVPS,
+        form: VexForm::NDS | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_sse_mem,
+    },
+    VexInstructionEncoding {
+        opcode: 0x2fu8,
+        prefix: 1,
+        operation: InstructionOperation::VMASKMOVPD,
+        form: VexForm::NDS | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_sse_mem,
+    },
+    VexInstructionEncoding {
+        opcode: 0x36u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMD,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x45u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRLVD,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRAVD,
+        form: VexForm::NDS | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x47u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSLLVD,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x49u8,
+        prefix: 0,
+        operation: InstructionOperation::LDTILECFG,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_config,
+    },
+    VexInstructionEncoding {
+        opcode: 0x49u8,
+        prefix: 1,
+        operation: InstructionOperation::STTILECFG,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_config,
+    },
+    VexInstructionEncoding {
+        opcode: 0x49u8,
+        prefix: 2,
+        operation: InstructionOperation::TILEZERO,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_config,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4bu8,
+        prefix: 1,
+        operation: InstructionOperation::TILELOADDT1,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_load_store,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4bu8,
+        prefix: 2,
+        operation: InstructionOperation::TILELOADD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_load_store,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4bu8,
+        prefix: 3,
+        operation: InstructionOperation::TILESTORED,
+        form: VexForm::L0 | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_amx_tile_load_store,
+    },
+    VexInstructionEncoding {
+        opcode: 0x58u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTD,
+        form: VexForm::RM_XMM | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x59u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTQ,
+        form: VexForm::RM_XMM | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5au8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTI128,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5cu8,
+        prefix: 2,
+        operation: InstructionOperation::TDPFP16PS,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5cu8,
+        prefix: 3,
+        operation: InstructionOperation::TDPBF16PS,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5eu8,
+        prefix: 0,
+        operation: InstructionOperation::TDPBUUD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5eu8,
+        prefix: 1,
+        operation: InstructionOperation::TDPBUSD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5eu8,
+        prefix: 2,
+        operation: InstructionOperation::TDPBSSD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5eu8,
+        prefix: 3,
+        operation: InstructionOperation::TDPBSUD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6cu8,
+        prefix: 0,
+        operation: InstructionOperation::TCMMRLFP16PS,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6cu8,
+        prefix: 1,
+        operation: InstructionOperation::TCMMIMFP16PS,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_amx_tile_dot,
+    },
+    VexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTB,
+        form: VexForm::RM_XMM | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTW,
+        form: VexForm::RM_XMM | VexForm::W0,
+        func: decode_vex_broadcast,
+    },
+    VexInstructionEncoding {
+        opcode: 0x8cu8,
+        prefix: 1,
+        operation: InstructionOperation::VPMASKMOVD,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse_mem,
+    },
+    VexInstructionEncoding {
+        opcode: 0x8eu8,
+        prefix: 1,
+        operation: InstructionOperation::VPMASKMOVD,
+        form: VexForm::NDS | VexForm::W_OPERATION | VexForm::FLIP_OPERANDS,
+        func: decode_vex_sse_mem,
+    },
+    VexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 1,
+        operation: InstructionOperation::VPGATHERDD,
+        form: VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_gather,
+    },
+    VexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 1,
+        operation: InstructionOperation::VPGATHERQD,
+        form: VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_gather,
+    },
+    VexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 1,
+        operation: InstructionOperation::VGATHERDPS,
+        form: VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_gather,
+    },
+    VexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 1,
+        operation: InstructionOperation::VGATHERQPS,
+        form: VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_gather,
+    },
+    VexInstructionEncoding {
+        opcode: 0x96u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB132PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x97u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD132PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD132PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9au8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB132PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9bu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9cu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD132PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9du8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9eu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB132PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9fu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xa6u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB213PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xa7u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD213PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xa8u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD213PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xa9u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xaau8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB213PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xabu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xacu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD213PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xadu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xaeu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB213PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xafu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xb6u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB231PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xb7u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD231PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xb8u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD231PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xb9u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xbau8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB231PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xbbu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xbcu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD231PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xbdu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xbeu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB231PS,
+        form: VexForm::NDS | VexForm::W_OPERATION,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xbfu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W_OPERATION,
+        func: decode_vex_scalar,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf2u8,
+        prefix: 0,
+        operation: InstructionOperation::ANDN,
+        form: VexForm::NDS | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf3u8,
+        prefix: 0,
+        operation: InstructionOperation::BLSR,
+        form: VexForm::NDD | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr_group,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf5u8,
+        prefix: 0,
+        operation: InstructionOperation::BZHI,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf5u8,
+        prefix: 2,
+        operation: InstructionOperation::PDEP,
+        form: VexForm::NDS | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf5u8,
+        prefix: 3,
+        operation: InstructionOperation::PEXT,
+        form: VexForm::NDS | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf6u8,
+        prefix: 2,
+        operation: InstructionOperation::MULX,
+        form: VexForm::NDS | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf7u8,
+        prefix: 0,
+        operation: InstructionOperation::BEXTR,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf7u8,
+        prefix: 1,
+        operation: InstructionOperation::SHLX,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf7u8,
+        prefix: 2,
+        operation: InstructionOperation::SHRX,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf7u8,
+        prefix: 3,
+        operation: InstructionOperation::SARX,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+];
+
+static VEX_0F3A_MAP: [VexInstructionEncoding; 44] = [
+    VexInstructionEncoding {
+        opcode: 0x0u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMQ,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x1u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMPD,
+        form: VexForm::L1 | VexForm::W1,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x2u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBLENDD,
+        form: VexForm::NDS | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPS,
+        form: VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPD,
+        form: VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERM2F128,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x18u8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTF128,
+        form: VexForm::NDS | VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x19u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTF128,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x1du8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPS2PH,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_sse_64,
+    },
+    VexInstructionEncoding {
+        opcode: 0x30u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTRB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x30u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTRW,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x31u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTRD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x31u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTRQ,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x32u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTLB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x32u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTLW,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x33u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTLD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x33u8,
+        prefix: 1,
+        operation: InstructionOperation::KSHIFTLQ,
+        form: VexForm::L0 | VexForm::W1,
+        func: decode_vex_mask,
+    },
+    VexInstructionEncoding {
+        opcode: 0x38u8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTI128,
+        form: VexForm::NDS | VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x39u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTI128,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERM2I128,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4au8,
+        prefix: 1,
+        operation: InstructionOperation::VBLENDVPS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4bu8,
+        prefix: 1,
+        operation: InstructionOperation::VBLENDVPD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x4cu8,
+        prefix: 1,
+        operation: InstructionOperation::VPBLENDVB,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5cu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUBPS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5du8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUBPD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5eu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADDPS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x5fu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADDPD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x68u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDPS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x69u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDPD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6au8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_32,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6bu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_64,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6cu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBPS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6du8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBPD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6eu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBSS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_32,
+    },
+    VexInstructionEncoding {
+        opcode: 0x6fu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBSD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_64,
+    },
+    VexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADDPS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADDPD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADDSS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_32,
+    },
+    VexInstructionEncoding {
+        opcode: 0x7bu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADDSD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_64,
+    },
+    VexInstructionEncoding {
+        opcode: 0x7cu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUBPS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x7du8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUBPD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x7eu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUBSS,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_32,
+    },
+    VexInstructionEncoding {
+        opcode: 0x7fu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUBSD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP | VexForm::LIG,
+        func: decode_vex_sse_64,
+    },
+    VexInstructionEncoding {
+        opcode: 0xf0u8,
+        prefix: 2,
+        operation: InstructionOperation::RORX,
+        form: VexForm::L0 | VexForm::GPR,
+        func: decode_vex_gpr,
+    },
+];
+
+static XOP_08_MAP: [VexInstructionEncoding; 26] = [
+    VexInstructionEncoding {
+        opcode: 0x85u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSSWW,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x86u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSSWD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x87u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSSDQL,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x8eu8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSSDD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x8fu8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSSDQH,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x95u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSWW,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x96u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSWD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x97u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSDQL,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9eu8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSDD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9fu8,
+        prefix: 0,
+        operation: InstructionOperation::VPMACSDQH,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xa2u8,
+        prefix: 0,
+        operation: InstructionOperation::VPCMOV,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xa3u8,
+        prefix: 0,
+        operation: InstructionOperation::VPPERM,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xa6u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMADCSSWD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xb6u8,
+        prefix: 0,
+        operation: InstructionOperation::VPMADCSWD,
+        form: VexForm::NDS | VexForm::IS4 | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc0u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTB,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc1u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc2u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc3u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xccu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMB,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xcdu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMW,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xceu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMD,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xcfu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMQ,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xecu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMUB,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xedu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMUW,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xeeu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMUD,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xefu8,
+        prefix: 0,
+        operation: InstructionOperation::VPCOMUQ,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+];
+
+static XOP_09_MAP: [VexInstructionEncoding; 31] = [
+    VexInstructionEncoding {
+        opcode: 0x80u8,
+        prefix: 0,
+        operation: InstructionOperation::VFRCZPS,
+        form: VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x81u8,
+        prefix: 0,
+        operation: InstructionOperation::VFRCZPD,
+        form: VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x82u8,
+        prefix: 0,
+        operation: InstructionOperation::VFRCZSS,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse_32,
+    },
+    VexInstructionEncoding {
+        opcode: 0x83u8,
+        prefix: 0,
+        operation: InstructionOperation::VFRCZSD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse_64,
+    },
+    VexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTB,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTW,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTD,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 0,
+        operation: InstructionOperation::VPROTQ,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x94u8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHLB,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x95u8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHLW,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x96u8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHLD,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x97u8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHLQ,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHAB,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHAW,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9au8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHAD,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0x9bu8,
+        prefix: 0,
+        operation: InstructionOperation::VPSHAQ,
+        form: VexForm::NDS_LAST | VexForm::L0 | VexForm::W_SWAP,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc1u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDBW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc2u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDBD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc3u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDBQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc6u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDWD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xc7u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDWQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xcbu8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDDQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xd1u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDUBW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xd2u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDUBD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xd3u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDUBQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xd6u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDUWD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xd7u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDUWQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xdbu8,
+        prefix: 0,
+        operation: InstructionOperation::VPHADDUDQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xe1u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHSUBBW,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xe2u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHSUBWD,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+    VexInstructionEncoding {
+        opcode: 0xe3u8,
+        prefix: 0,
+        operation: InstructionOperation::VPHSUBDQ,
+        form: VexForm::L0 | VexForm::W0,
+        func: decode_vex_sse,
+    },
+];
+
+static VEX_OPERATIONS: [VexOperationEntry; 245] = [
+    VexOperationEntry {
+        legacy: InstructionOperation::BLENDPD,
+        operation: InstructionOperation::VBLENDPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::BLENDPS,
+        operation: InstructionOperation::VBLENDPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::DPPD,
+        operation: InstructionOperation::VDPPD,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::DPPS,
+        operation: InstructionOperation::VDPPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::LDMXCSR,
+        operation: InstructionOperation::VLDMXCSR,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVSS,
+        operation: InstructionOperation::VMOVSS,
+        form: VexForm::NDS_REG | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVUPD,
+        operation: InstructionOperation::VMOVUPD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVUPS,
+        operation: InstructionOperation::VMOVUPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MPSADBW,
+        operation: InstructionOperation::VMPSADBW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PACKSSDW,
+        operation: InstructionOperation::VPACKSSDW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PACKSSWB,
+        operation: InstructionOperation::VPACKSSWB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PACKUSDW,
+        operation: InstructionOperation::VPACKUSDW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PACKUSWB,
+        operation: InstructionOperation::VPACKUSWB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PABSB,
+        operation: InstructionOperation::VPABSB,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PABSD,
+        operation: InstructionOperation::VPABSD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PABSW,
+        operation: InstructionOperation::VPABSW,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDB,
+        operation: InstructionOperation::VPADDB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDD,
+        operation: InstructionOperation::VPADDD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDQ,
+        operation: InstructionOperation::VPADDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDW,
+        operation: InstructionOperation::VPADDW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDSB,
+        operation: InstructionOperation::VPADDSB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDSW,
+        operation: InstructionOperation::VPADDSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDUSB,
+        operation: InstructionOperation::VPADDUSB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PADDUSW,
+        operation: InstructionOperation::VPADDUSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PALIGNR,
+        operation: InstructionOperation::VPALIGNR,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PAND,
+        operation: InstructionOperation::VPAND,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PANDN,
+        operation: InstructionOperation::VPANDN,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PAVGB,
+        operation: InstructionOperation::VPAVGB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PAVGW,
+        operation: InstructionOperation::VPAVGW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PBLENDW,
+        operation: InstructionOperation::VPBLENDW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPEQB,
+        operation: InstructionOperation::VPCMPEQB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPEQD,
+        operation: InstructionOperation::VPCMPEQD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPEQQ,
+        operation: InstructionOperation::VPCMPEQQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPEQW,
+        operation: InstructionOperation::VPCMPEQW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPESTRI,
+        operation: InstructionOperation::VPCMPESTRI,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPESTRM,
+        operation: InstructionOperation::VPCMPESTRM,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPGTB,
+        operation: InstructionOperation::VPCMPGTB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPGTD,
+        operation: InstructionOperation::VPCMPGTD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPGTQ,
+        operation: InstructionOperation::VPCMPGTQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPGTW,
+        operation: InstructionOperation::VPCMPGTW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPISTRI,
+        operation: InstructionOperation::VPCMPISTRI,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCMPISTRM,
+        operation: InstructionOperation::VPCMPISTRM,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PHADDD,
+        operation: InstructionOperation::VPHADDD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PHADDSW,
+        operation: InstructionOperation::VPHADDSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PHADDW,
+        operation: InstructionOperation::VPHADDW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PHMINPOSUW,
+        operation: InstructionOperation::VPHMINPOSUW,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PHSUBD,
+        operation: InstructionOperation::VPHSUBD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PHSUBSW,
+        operation: InstructionOperation::VPHSUBSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PHSUBW,
+        operation: InstructionOperation::VPHSUBW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMADDWD,
+        operation: InstructionOperation::VPMADDWD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMADDUBSW,
+        operation: InstructionOperation::VPMADDUBSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMAXSB,
+        operation: InstructionOperation::VPMAXSB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMAXSD,
+        operation: InstructionOperation::VPMAXSD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMAXSW,
+        operation: InstructionOperation::VPMAXSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMAXUB,
+        operation: InstructionOperation::VPMAXUB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMAXUD,
+        operation: InstructionOperation::VPMAXUD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMAXUW,
+        operation: InstructionOperation::VPMAXUW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMINSB,
+        operation: InstructionOperation::VPMINSB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMINSD,
+        operation: InstructionOperation::VPMINSD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMINSW,
+        operation: InstructionOperation::VPMINSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMINUB,
+        operation: InstructionOperation::VPMINUB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMINUD,
+        operation: InstructionOperation::VPMINUD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMINUW,
+        operation: InstructionOperation::VPMINUW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMULDQ,
+        operation: InstructionOperation::VPMULDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMULHRSW,
+        operation: InstructionOperation::VPMULHRSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMULHUW,
+        operation: InstructionOperation::VPMULHUW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMULHW,
+        operation: InstructionOperation::VPMULHW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMULLD,
+        operation: InstructionOperation::VPMULLD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMULLW,
+        operation: InstructionOperation::VPMULLW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMULUDQ,
+        operation: InstructionOperation::VPMULUDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::POR,
+        operation: InstructionOperation::VPOR,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSADBW,
+        operation: InstructionOperation::VPSADBW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSHUFB,
+        operation: InstructionOperation::VPSHUFB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSIGNB,
+        operation: InstructionOperation::VPSIGNB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSIGND,
+        operation: InstructionOperation::VPSIGND,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSIGNW,
+        operation: InstructionOperation::VPSIGNW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSLLD,
+        operation: InstructionOperation::VPSLLD,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSLLDQ,
+        operation: InstructionOperation::VPSLLDQ,
+        form: VexForm::NDD,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSLLQ,
+        operation: InstructionOperation::VPSLLQ,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSLLW,
+        operation: InstructionOperation::VPSLLW,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSRAD,
+        operation: InstructionOperation::VPSRAD,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSRAW,
+        operation: InstructionOperation::VPSRAW,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSRLD,
+        operation: InstructionOperation::VPSRLD,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSRLDQ,
+        operation: InstructionOperation::VPSRLDQ,
+        form: VexForm::NDD,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSRLQ,
+        operation: InstructionOperation::VPSRLQ,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSRLW,
+        operation: InstructionOperation::VPSRLW,
+        form: VexForm::SHIFT,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBB,
+        operation: InstructionOperation::VPSUBB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBD,
+        operation: InstructionOperation::VPSUBD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBQ,
+        operation: InstructionOperation::VPSUBQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBW,
+        operation: InstructionOperation::VPSUBW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBSB,
+        operation: InstructionOperation::VPSUBSB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBSW,
+        operation: InstructionOperation::VPSUBSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBUSB,
+        operation: InstructionOperation::VPSUBUSB,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSUBUSW,
+        operation: InstructionOperation::VPSUBUSW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PTEST,
+        operation: InstructionOperation::VPTEST,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHBW,
+        operation: InstructionOperation::VPUNPCKHBW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHDQ,
+        operation: InstructionOperation::VPUNPCKHDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHQDQ,
+        operation: InstructionOperation::VPUNPCKHQDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHWD,
+        operation: InstructionOperation::VPUNPCKHWD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLQDQ,
+        operation: InstructionOperation::VPUNPCKLQDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PXOR,
+        operation: InstructionOperation::VPXOR,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ROUNDPS,
+        operation: InstructionOperation::VROUNDPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ROUNDPD,
+        operation: InstructionOperation::VROUNDPD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::STMXCSR,
+        operation: InstructionOperation::VSTMXCSR,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ADDPS,
+        operation: InstructionOperation::VADDPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ADDPD,
+        operation: InstructionOperation::VADDPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ADDSD,
+        operation: InstructionOperation::VADDSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ADDSS,
+        operation: InstructionOperation::VADDSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ADDSUBPD,
+        operation: InstructionOperation::VADDSUBPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ADDSUBPS,
+        operation: InstructionOperation::VADDSUBPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ANDNPS,
+        operation: InstructionOperation::VANDNPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ANDNPD,
+        operation: InstructionOperation::VANDNPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ANDPS,
+        operation: InstructionOperation::VANDPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ANDPD,
+        operation: InstructionOperation::VANDPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CMPSD,
+        operation: InstructionOperation::VCMPSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::DIVPS,
+        operation: InstructionOperation::VDIVPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::DIVPD,
+        operation: InstructionOperation::VDIVPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::DIVSD,
+        operation: InstructionOperation::VDIVSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::DIVSS,
+        operation: InstructionOperation::VDIVSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MAXPS,
+        operation: InstructionOperation::VMAXPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MAXPD,
+        operation: InstructionOperation::VMAXPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MAXSD,
+        operation: InstructionOperation::VMAXSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MAXSS,
+        operation: InstructionOperation::VMAXSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MINPS,
+        operation: InstructionOperation::VMINPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MINPD,
+        operation: InstructionOperation::VMINPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MINSD,
+        operation: InstructionOperation::VMINSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MINSS,
+        operation: InstructionOperation::VMINSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVD,
+        operation: InstructionOperation::VMOVD,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVQ,
+        operation: InstructionOperation::VMOVQ,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVSD,
+        operation: InstructionOperation::VMOVSD,
+        form: VexForm::NDS_REG | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MULPS,
+        operation: InstructionOperation::VMULPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MULPD,
+        operation: InstructionOperation::VMULPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MULSD,
+        operation: InstructionOperation::VMULSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MULSS,
+        operation: InstructionOperation::VMULSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ORPS,
+        operation: InstructionOperation::VORPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ORPD,
+        operation: InstructionOperation::VORPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PEXTRD,
+        operation: InstructionOperation::VPEXTRD,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PEXTRQ,
+        operation: InstructionOperation::VPEXTRQ,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PINSRD,
+        operation: InstructionOperation::VPINSRD,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PINSRQ,
+        operation: InstructionOperation::VPINSRQ,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::RCPPS,
+        operation: InstructionOperation::VRCPPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::RCPSS,
+        operation: InstructionOperation::VRCPSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::RSQRTPS,
+        operation: InstructionOperation::VRSQRTPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::RSQRTSS,
+        operation: InstructionOperation::VRSQRTSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SQRTPS,
+        operation: InstructionOperation::VSQRTPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SQRTPD,
+        operation: InstructionOperation::VSQRTPD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SQRTSD,
+        operation: InstructionOperation::VSQRTSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SQRTSS,
+        operation: InstructionOperation::VSQRTSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SUBPS,
+        operation: InstructionOperation::VSUBPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SUBPD,
+        operation: InstructionOperation::VSUBPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SUBSD,
+        operation: InstructionOperation::VSUBSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SUBSS,
+        operation: InstructionOperation::VSUBSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::XORPS,
+        operation: InstructionOperation::VXORPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::XORPD,
+        operation: InstructionOperation::VXORPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CMPPD,
+        operation: InstructionOperation::VCMPPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CMPPS,
+        operation: InstructionOperation::VCMPPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CMPSS,
+        operation: InstructionOperation::VCMPSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::COMISD,
+        operation: InstructionOperation::VCOMISD,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::COMISS,
+        operation: InstructionOperation::VCOMISS,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTDQ2PD,
+        operation: InstructionOperation::VCVTDQ2PD,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTDQ2PS,
+        operation: InstructionOperation::VCVTDQ2PS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTPD2DQ,
+        operation: InstructionOperation::VCVTPD2DQ,
+        form: VexForm::REG_XMM,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTPD2PS,
+        operation: InstructionOperation::VCVTPD2PS,
+        form: VexForm::REG_XMM,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTPS2DQ,
+        operation: InstructionOperation::VCVTPS2DQ,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTPS2PD,
+        operation: InstructionOperation::VCVTPS2PD,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTSD2SI,
+        operation: InstructionOperation::VCVTSD2SI,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTSD2SS,
+        operation: InstructionOperation::VCVTSD2SS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTSI2SD,
+        operation: InstructionOperation::VCVTSI2SD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTSI2SS,
+        operation: InstructionOperation::VCVTSI2SS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTSS2SD,
+        operation: InstructionOperation::VCVTSS2SD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTSS2SI,
+        operation: InstructionOperation::VCVTSS2SI,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTTPD2DQ,
+        operation: InstructionOperation::VCVTTPD2DQ,
+        form: VexForm::REG_XMM,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTTPS2DQ,
+        operation: InstructionOperation::VCVTTPS2DQ,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTTSD2SI,
+        operation: InstructionOperation::VCVTTSD2SI,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::CVTTSS2SI,
+        operation: InstructionOperation::VCVTTSS2SI,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::EXTRACTPS,
+        operation: InstructionOperation::VEXTRACTPS,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::HADDPD,
+        operation: InstructionOperation::VHADDPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::HADDPS,
+        operation: InstructionOperation::VHADDPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::HSUBPD,
+        operation: InstructionOperation::VHSUBPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::HSUBPS,
+        operation: InstructionOperation::VHSUBPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::INSERTPS,
+        operation: InstructionOperation::VINSERTPS,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::LDDQU,
+        operation: InstructionOperation::VLDDQU,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MASKMOVDQU,
+        operation: InstructionOperation::VMASKMOVDQU,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVAPD,
+        operation: InstructionOperation::VMOVAPD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVAPS,
+        operation: InstructionOperation::VMOVAPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVDDUP,
+        operation: InstructionOperation::VMOVDDUP,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVDQA,
+        operation: InstructionOperation::VMOVDQA,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVDQU,
+        operation: InstructionOperation::VMOVDQU,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVHLPS,
+        operation: InstructionOperation::VMOVHLPS,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVHPD,
+        operation: InstructionOperation::VMOVHPD,
+        form: VexForm::NDS_LOAD | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVHPS,
+        operation: InstructionOperation::VMOVHPS,
+        form: VexForm::NDS_LOAD | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVSHDUP,
+        operation: InstructionOperation::VMOVSHDUP,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVSLDUP,
+        operation: InstructionOperation::VMOVSLDUP,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVLHPS,
+        operation: InstructionOperation::VMOVLHPS,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVLPD,
+        operation: InstructionOperation::VMOVLPD,
+        form: VexForm::NDS_LOAD | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVLPS,
+        operation: InstructionOperation::VMOVLPS,
+        form: VexForm::NDS_LOAD | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVMSKPD,
+        operation: InstructionOperation::VMOVMSKPD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVMSKPS,
+        operation: InstructionOperation::VMOVMSKPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVNTDQ,
+        operation: InstructionOperation::VMOVNTDQ,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVNTDQA,
+        operation: InstructionOperation::VMOVNTDQA,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVNTPD,
+        operation: InstructionOperation::VMOVNTPD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::MOVNTPS,
+        operation: InstructionOperation::VMOVNTPS,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PINSRB,
+        operation: InstructionOperation::VPINSRB,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PINSRW,
+        operation: InstructionOperation::VPINSRW,
+        form: VexForm::NDS | VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PEXTRB,
+        operation: InstructionOperation::VPEXTRB,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PEXTRW,
+        operation: InstructionOperation::VPEXTRW,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVMSKB,
+        operation: InstructionOperation::VPMOVMSKB,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVSXBD,
+        operation: InstructionOperation::VPMOVSXBD,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVSXBQ,
+        operation: InstructionOperation::VPMOVSXBQ,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVSXDQ,
+        operation: InstructionOperation::VPMOVSXDQ,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVSXBW,
+        operation: InstructionOperation::VPMOVSXBW,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVSXWD,
+        operation: InstructionOperation::VPMOVSXWD,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVSXWQ,
+        operation: InstructionOperation::VPMOVSXWQ,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVZXBD,
+        operation: InstructionOperation::VPMOVZXBD,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVZXBQ,
+        operation: InstructionOperation::VPMOVZXBQ,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVZXDQ,
+        operation: InstructionOperation::VPMOVZXDQ,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVZXBW,
+        operation: InstructionOperation::VPMOVZXBW,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVZXWD,
+        operation: InstructionOperation::VPMOVZXWD,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PMOVZXWQ,
+        operation: InstructionOperation::VPMOVZXWQ,
+        form: VexForm::RM_HALF,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSHUFD,
+        operation: InstructionOperation::VPSHUFD,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSHUFHW,
+        operation: InstructionOperation::VPSHUFHW,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PSHUFLW,
+        operation: InstructionOperation::VPSHUFLW,
+        form: 0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLBW,
+        operation: InstructionOperation::VPUNPCKLBW,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLDQ,
+        operation: InstructionOperation::VPUNPCKLDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLWD,
+        operation: InstructionOperation::VPUNPCKLWD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ROUNDSD,
+        operation: InstructionOperation::VROUNDSD,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::ROUNDSS,
+        operation: InstructionOperation::VROUNDSS,
+        form: VexForm::NDS | VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SHUFPD,
+        operation: InstructionOperation::VSHUFPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::SHUFPS,
+        operation: InstructionOperation::VSHUFPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::UCOMISD,
+        operation: InstructionOperation::VUCOMISD,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::UCOMISS,
+        operation: InstructionOperation::VUCOMISS,
+        form: VexForm::LIG,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::UNPCKHPD,
+        operation: InstructionOperation::VUNPCKHPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::UNPCKHPS,
+        operation: InstructionOperation::VUNPCKHPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::UNPCKLPD,
+        operation: InstructionOperation::VUNPCKLPD,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::UNPCKLPS,
+        operation: InstructionOperation::VUNPCKLPS,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::AESIMC,
+        operation: InstructionOperation::VAESIMC,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::AESENC,
+        operation: InstructionOperation::VAESENC,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::AESENCLAST,
+        operation: InstructionOperation::VAESENCLAST,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::AESDEC,
+        operation: InstructionOperation::VAESDEC,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::AESDECLAST,
+        operation: InstructionOperation::VAESDECLAST,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::AESKEYGENASSIST,
+        operation: InstructionOperation::VAESKEYGENASSIST,
+        form: VexForm::L0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::PCLMULQDQ,
+        operation: InstructionOperation::VPCLMULQDQ,
+        form: VexForm::NDS,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::GF2P8MULB,
+        operation: InstructionOperation::VGF2P8MULB,
+        form: VexForm::NDS | VexForm::W0,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::GF2P8AFFINEQB,
+        operation: InstructionOperation::VGF2P8AFFINEQB,
+        form: VexForm::NDS | VexForm::W1,
+    },
+    VexOperationEntry {
+        legacy: InstructionOperation::GF2P8AFFINEINVQB,
+        operation: InstructionOperation::VGF2P8AFFINEINVQB,
+        form: VexForm::NDS | VexForm::W1,
+    },
+];
+
+static EVEX_0F_MAP: [EvexInstructionEncoding; 30] = [
+    EvexInstructionEncoding {
+        opcode: 0x6fu8,
+        prefix: 2,
+        operation: InstructionOperation::VMOVDQU8,
+        form: VexForm::W0,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x6fu8,
+        prefix: 2,
+        operation: InstructionOperation::VMOVDQU16,
+        form: VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x71u8,
+        prefix: 1,
+        operation: InstructionOperation::INVALID,
+        form: VexForm::NDD,
+        evex: 0,
+        func: decode_evex_shift_imm,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x72u8,
+        prefix: 1,
+        operation: InstructionOperation::INVALID,
+        form: VexForm::NDD,
+        evex: 0,
+        func: decode_evex_shift_imm,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x73u8,
+        prefix: 1,
+        operation: InstructionOperation::INVALID,
+        form: VexForm::NDD,
+        evex: 0,
+        func: decode_evex_shift_imm,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 0,
+        operation: InstructionOperation::VCVTTPS2UDQ,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 0,
+        operation: InstructionOperation::VCVTTPD2UDQ,
+        form: VexForm::REG_XMM | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTTPS2UQQ,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTTPD2UQQ,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 2,
+        operation: InstructionOperation::VCVTTSD2USI,
+        form: VexForm::LIG,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+        func: decode_evex_to_gpr,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 3,
+        operation: InstructionOperation::VCVTTSS2USI,
+        form: VexForm::LIG,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+        func: decode_evex_to_gpr,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 0,
+        operation: InstructionOperation::VCVTPS2UDQ,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 0,
+        operation: InstructionOperation::VCVTPD2UDQ,
+        form: VexForm::REG_XMM | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPS2UQQ,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPD2UQQ,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 2,
+        operation: InstructionOperation::VCVTSD2USI,
+        form: VexForm::LIG,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+        func: decode_evex_to_gpr,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 3,
+        operation: InstructionOperation::VCVTSS2USI,
+        form: VexForm::LIG,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+        func: decode_evex_to_gpr,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTTPS2QQ,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTTPD2QQ,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 2,
+        operation: InstructionOperation::VCVTUDQ2PS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 2,
+        operation: InstructionOperation::VCVTUQQ2PS,
+        form: VexForm::REG_XMM | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 3,
+        operation: InstructionOperation::VCVTUDQ2PD,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 3,
+        operation: InstructionOperation::VCVTUQQ2PD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7bu8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPS2QQ,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7bu8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPD2QQ,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7bu8,
+        prefix: 2,
+        operation: InstructionOperation::VCVTUSI2SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+        func: decode_evex_from_gpr,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7bu8,
+        prefix: 2,
+        operation: InstructionOperation::VCVTUSI2SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+        func: decode_evex_from_gpr,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7bu8,
+        prefix: 3,
+        operation: InstructionOperation::VCVTUSI2SS,
+        form: VexForm::NDS | VexForm::LIG,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+        func: decode_evex_from_gpr,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7fu8,
+        prefix: 2,
+        operation: InstructionOperation::VMOVDQU8,
+        form: VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7fu8,
+        prefix: 2,
+        operation: InstructionOperation::VMOVDQU16,
+        form: VexForm::W1 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+];
+
+static EVEX_0F38_MAP: [EvexInstructionEncoding; 198] = [
+    EvexInstructionEncoding {
+        opcode: 0xcu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xdu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x10u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRLVW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x10u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVUSWB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x11u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRAVW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x11u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVUSDB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_vex_sse_32,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x12u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSLLVW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x12u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVUSQB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_evex_sse_16,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x13u8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPH2PS,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::SAE,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x13u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVUSDW,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x14u8,
+        prefix: 1,
+        operation: InstructionOperation::VPRORVD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x14u8,
+        prefix: 1,
+        operation: InstructionOperation::VPRORVQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x14u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVUSQW,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_vex_sse_32,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x15u8,
+        prefix: 1,
+        operation: InstructionOperation::VPROLVD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x15u8,
+        prefix: 1,
+        operation: InstructionOperation::VPROLVQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x15u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVUSQD,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x16u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMPS,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x16u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMPD,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x18u8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTSS,
+        form: VexForm::RM_XMM | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x19u8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTF32X2,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x19u8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTSD,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W1,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1au8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTF32X4,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1au8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTF64X2,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W1,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1bu8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTF32X8,
+        form: VexForm::RM_XMM | VexForm::W0,
+        evex: EvexForm::L512,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1bu8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTF64X4,
+        form: VexForm::RM_XMM | VexForm::W1,
+        evex: EvexForm::L512,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1fu8,
+        prefix: 1,
+        operation: InstructionOperation::VPABSQ,
+        form: VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x20u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVSWB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x21u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVSDB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_vex_sse_32,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x22u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVSQB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_evex_sse_16,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x23u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVSDW,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x24u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVSQW,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_vex_sse_32,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x25u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVSQD,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x26u8,
+        prefix: 1,
+        operation: InstructionOperation::VPTESTMB,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x26u8,
+        prefix: 1,
+        operation: InstructionOperation::VPTESTMW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x26u8,
+        prefix: 3,
+        operation: InstructionOperation::VPTESTNMB,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x26u8,
+        prefix: 3,
+        operation: InstructionOperation::VPTESTNMW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x27u8,
+        prefix: 1,
+        operation: InstructionOperation::VPTESTMD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x27u8,
+        prefix: 1,
+        operation: InstructionOperation::VPTESTMQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x27u8,
+        prefix: 3,
+        operation: InstructionOperation::VPTESTNMD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x27u8,
+        prefix: 3,
+        operation: InstructionOperation::VPTESTNMQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x28u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVM2B,
+        form: VexForm::W0,
+        evex: EvexForm::NO_MASK,
+        func: decode_evex_from_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x28u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVM2W,
+        form: VexForm::W1,
+        evex: EvexForm::NO_MASK,
+        func: decode_evex_from_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x29u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVB2M,
+        form: VexForm::W0,
+        evex: EvexForm::K_DEST | EvexForm::NO_MASK,
+        func: decode_evex_to_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x29u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVW2M,
+        form: VexForm::W1,
+        evex: EvexForm::K_DEST | EvexForm::NO_MASK,
+        func: decode_evex_to_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x2au8,
+        prefix: 3,
+        operation: InstructionOperation::VPBROADCASTMB2Q,
+        form: VexForm::W1,
+        evex: EvexForm::NO_MASK,
+        func: decode_evex_from_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x2cu8,
+        prefix: 1,
+        operation: InstructionOperation::VSCALEFPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x2cu8,
+        prefix: 1,
+        operation: InstructionOperation::VSCALEFPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x2du8,
+        prefix: 1,
+        operation: InstructionOperation::VSCALEFSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x2du8,
+        prefix: 1,
+        operation: InstructionOperation::VSCALEFSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x30u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVWB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x31u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVDB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_vex_sse_32,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x32u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVQB,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_evex_sse_16,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x33u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVDW,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x34u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVQW,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::RM_QUARTER,
+        func: decode_vex_sse_32,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x35u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVQD,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: 0,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x36u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMD,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x36u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMQ,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x38u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVM2D,
+        form: VexForm::W0,
+        evex: EvexForm::NO_MASK,
+        func: decode_evex_from_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x38u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVM2Q,
+        form: VexForm::W1,
+        evex: EvexForm::NO_MASK,
+        func: decode_evex_from_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x39u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVD2M,
+        form: VexForm::W0,
+        evex: EvexForm::K_DEST | EvexForm::NO_MASK,
+        func: decode_evex_to_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x39u8,
+        prefix: 3,
+        operation: InstructionOperation::VPMOVQ2M,
+        form: VexForm::W1,
+        evex: EvexForm::K_DEST | EvexForm::NO_MASK,
+        func: decode_evex_to_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3au8,
+        prefix: 3,
+        operation: InstructionOperation::VPBROADCASTMW2D,
+        form: VexForm::W0,
+        evex: EvexForm::NO_MASK,
+        func: decode_evex_from_mask,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x42u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETEXPPS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x42u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETEXPPD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x43u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETEXPSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x43u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETEXPSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x44u8,
+        prefix: 1,
+        operation: InstructionOperation::VPLZCNTD,
+        form: VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x44u8,
+        prefix: 1,
+        operation: InstructionOperation::VPLZCNTQ,
+        form: VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x45u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRLVD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x45u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRLVQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRAVD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x46u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSRAVQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x47u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSLLVD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x47u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSLLVQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4cu8,
+        prefix: 1,
+        operation: InstructionOperation::VRCP14PS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4cu8,
+        prefix: 1,
+        operation: InstructionOperation::VRCP14PD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4du8,
+        prefix: 1,
+        operation: InstructionOperation::VRCP14SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: 0,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4du8,
+        prefix: 1,
+        operation: InstructionOperation::VRCP14SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: 0,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4eu8,
+        prefix: 1,
+        operation: InstructionOperation::VRSQRT14PS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4eu8,
+        prefix: 1,
+        operation: InstructionOperation::VRSQRT14PD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4fu8,
+        prefix: 1,
+        operation: InstructionOperation::VRSQRT14SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: 0,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4fu8,
+        prefix: 1,
+        operation: InstructionOperation::VRSQRT14SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: 0,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x58u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTD,
+        form: VexForm::RM_XMM | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x59u8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTI32X2,
+        form: VexForm::RM_XMM | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x59u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTQ,
+        form: VexForm::RM_XMM | VexForm::W1,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x5au8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTI32X4,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x5au8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTI64X2,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::W1,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x5bu8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTI32X8,
+        form: VexForm::RM_XMM | VexForm::W0,
+        evex: EvexForm::L512,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x5bu8,
+        prefix: 1,
+        operation: InstructionOperation::VBROADCASTI64X4,
+        form: VexForm::RM_XMM | VexForm::W1,
+        evex: EvexForm::L512,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x64u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBLENDMD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x64u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBLENDMQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x65u8,
+        prefix: 1,
+        operation: InstructionOperation::VBLENDMPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x65u8,
+        prefix: 1,
+        operation: InstructionOperation::VBLENDMPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x66u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBLENDMB,
+        form: VexForm::NDS | VexForm::W0,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x66u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBLENDMW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x75u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMI2W,
+        form: VexForm::NDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x76u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMI2D,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x76u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMI2Q,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x77u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMI2PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x77u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMI2PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x78u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTB,
+        form: VexForm::RM_XMM | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x79u8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTW,
+        form: VexForm::RM_XMM | VexForm::W0,
+        evex: 0,
+        func: decode_vex_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7au8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTB,
+        form: VexForm::W0,
+        evex: 0,
+        func: decode_evex_gpr_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7bu8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTW,
+        form: VexForm::W0,
+        evex: 0,
+        func: decode_evex_gpr_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7cu8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTD,
+        form: VexForm::W0,
+        evex: 0,
+        func: decode_evex_gpr_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7cu8,
+        prefix: 1,
+        operation: InstructionOperation::VPBROADCASTQ,
+        form: VexForm::W1,
+        evex: 0,
+        func: decode_evex_gpr_broadcast,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7du8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMT2W,
+        form: VexForm::NDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7eu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMT2D,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7eu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMT2Q,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7fu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMT2PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x7fu8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMT2PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x88u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXPANDPS,
+        form: VexForm::W0,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x88u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXPANDPD,
+        form: VexForm::W1,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x89u8,
+        prefix: 1,
+        operation: InstructionOperation::VPEXPANDD,
+        form: VexForm::W0,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x89u8,
+        prefix: 1,
+        operation: InstructionOperation::VPEXPANDQ,
+        form: VexForm::W1,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x8au8,
+        prefix: 1,
+        operation: InstructionOperation::VCOMPRESSPS,
+        form: VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x8au8,
+        prefix: 1,
+        operation: InstructionOperation::VCOMPRESSPD,
+        form: VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x8bu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCOMPRESSD,
+        form: VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x8bu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCOMPRESSQ,
+        form: VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::T1S,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x8du8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 1,
+        operation: InstructionOperation::VPGATHERDD,
+        form: VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x90u8,
+        prefix: 1,
+        operation: InstructionOperation::VPGATHERDQ,
+        form: VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 1,
+        operation: InstructionOperation::VPGATHERQD,
+        form: VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x91u8,
+        prefix: 1,
+        operation: InstructionOperation::VPGATHERQQ,
+        form: VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 1,
+        operation: InstructionOperation::VGATHERDPS,
+        form: VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x92u8,
+        prefix: 1,
+        operation: InstructionOperation::VGATHERDPD,
+        form: VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 1,
+        operation: InstructionOperation::VGATHERQPS,
+        form: VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x93u8,
+        prefix: 1,
+        operation: InstructionOperation::VGATHERQPD,
+        form: VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x96u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB132PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x96u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB132PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x97u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD132PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x97u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD132PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD132PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x98u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD132PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x99u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD132SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9au8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB132PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9au8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB132PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9bu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9bu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB132SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9cu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD132PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9cu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD132PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9du8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9du8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD132SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9eu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB132PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9eu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB132PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9fu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB132SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x9fu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB132SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa0u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSCATTERDD,
+        form: VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa0u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSCATTERDQ,
+        form: VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa1u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSCATTERQD,
+        form: VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa1u8,
+        prefix: 1,
+        operation: InstructionOperation::VPSCATTERQQ,
+        form: VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa2u8,
+        prefix: 1,
+        operation: InstructionOperation::VSCATTERDPS,
+        form: VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa2u8,
+        prefix: 1,
+        operation: InstructionOperation::VSCATTERDPD,
+        form: VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa3u8,
+        prefix: 1,
+        operation: InstructionOperation::VSCATTERQPS,
+        form: VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa3u8,
+        prefix: 1,
+        operation: InstructionOperation::VSCATTERQPD,
+        form: VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::MASK_REQUIRED,
+        func: decode_evex_gather,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa6u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB213PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa6u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB213PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa7u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD213PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa7u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD213PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa8u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD213PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa8u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD213PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa9u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xa9u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD213SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xaau8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB213PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xaau8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB213PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xabu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xabu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB213SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xacu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD213PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xacu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD213PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xadu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xadu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD213SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xaeu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB213PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xaeu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB213PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xafu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB213SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xafu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB213SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb6u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB231PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb6u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADDSUB231PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb7u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD231PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb7u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUBADD231PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb8u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD231PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb8u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD231PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb9u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xb9u8,
+        prefix: 1,
+        operation: InstructionOperation::VFMADD231SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbau8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB231PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbau8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB231PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbbu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbbu8,
+        prefix: 1,
+        operation: InstructionOperation::VFMSUB231SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbcu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD231PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbcu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD231PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbdu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbdu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMADD231SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbeu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB231PS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbeu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB231PD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbfu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB231SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xbfu8,
+        prefix: 1,
+        operation: InstructionOperation::VFNMSUB231SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xc4u8,
+        prefix: 1,
+        operation: InstructionOperation::VPCONFLICTD,
+        form: VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0xc4u8,
+        prefix: 1,
+        operation: InstructionOperation::VPCONFLICTQ,
+        form: VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+];
+
+static EVEX_0F3A_MAP: [EvexInstructionEncoding; 57] = [
+    EvexInstructionEncoding {
+        opcode: 0x0u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMQ,
+        form: VexForm::L1 | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMPD,
+        form: VexForm::L1 | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3u8,
+        prefix: 1,
+        operation: InstructionOperation::VALIGND,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3u8,
+        prefix: 1,
+        operation: InstructionOperation::VALIGNQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x4u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x5u8,
+        prefix: 1,
+        operation: InstructionOperation::VPERMILPD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x18u8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTF32X4,
+        form: VexForm::NDS | VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x18u8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTF64X2,
+        form: VexForm::NDS | VexForm::RM_XMM | VexForm::L1 | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x19u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTF32X4,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x19u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTF64X2,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1au8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTF32X8,
+        form: VexForm::NDS | VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1au8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTF64X4,
+        form: VexForm::NDS | VexForm::RM_HALF | VexForm::W1,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1bu8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTF32X8,
+        form: VexForm::RM_HALF | VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1bu8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTF64X4,
+        form: VexForm::RM_HALF | VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1du8,
+        prefix: 1,
+        operation: InstructionOperation::VCVTPS2PH,
+        form: VexForm::RM_HALF | VexForm::W0 | VexForm::FLIP_OPERANDS,
+        evex: EvexForm::SAE,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1eu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPUD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1eu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPUQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1fu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x1fu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x23u8,
+        prefix: 1,
+        operation: InstructionOperation::VSHUFF32X4,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x23u8,
+        prefix: 1,
+        operation: InstructionOperation::VSHUFF64X2,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x25u8,
+        prefix: 1,
+        operation: InstructionOperation::VPTERNLOGD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x25u8,
+        prefix: 1,
+        operation: InstructionOperation::VPTERNLOGQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x26u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETMANTPS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x26u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETMANTPD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x27u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETMANTSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x27u8,
+        prefix: 1,
+        operation: InstructionOperation::VGETMANTSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x38u8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTI32X4,
+        form: VexForm::NDS | VexForm::RM_XMM | VexForm::L1 | VexForm::W0,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x38u8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTI64X2,
+        form: VexForm::NDS | VexForm::RM_XMM | VexForm::L1 | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x39u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTI32X4,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x39u8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTI64X2,
+        form: VexForm::RM_XMM | VexForm::L1 | VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: 0,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3au8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTI32X8,
+        form: VexForm::NDS | VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3au8,
+        prefix: 1,
+        operation: InstructionOperation::VINSERTI64X4,
+        form: VexForm::NDS | VexForm::RM_HALF | VexForm::W1,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3bu8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTI32X8,
+        form: VexForm::RM_HALF | VexForm::FLIP_OPERANDS | VexForm::W0,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3bu8,
+        prefix: 1,
+        operation: InstructionOperation::VEXTRACTI64X4,
+        form: VexForm::RM_HALF | VexForm::FLIP_OPERANDS | VexForm::W1,
+        evex: EvexForm::L512,
+        func: decode_vex_sse_64,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3eu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPUB,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3eu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPUW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3fu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPB,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x3fu8,
+        prefix: 1,
+        operation: InstructionOperation::VPCMPW,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x43u8,
+        prefix: 1,
+        operation: InstructionOperation::VSHUFI32X4,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W0,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x43u8,
+        prefix: 1,
+        operation: InstructionOperation::VSHUFI64X2,
+        form: VexForm::NDS | VexForm::L1 | VexForm::W1,
+        evex: EvexForm::BCST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x50u8,
+        prefix: 1,
+        operation: InstructionOperation::VRANGEPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x50u8,
+        prefix: 1,
+        operation: InstructionOperation::VRANGEPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x51u8,
+        prefix: 1,
+        operation: InstructionOperation::VRANGESS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x51u8,
+        prefix: 1,
+        operation: InstructionOperation::VRANGESD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x54u8,
+        prefix: 1,
+        operation: InstructionOperation::VFIXUPIMMPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x54u8,
+        prefix: 1,
+        operation: InstructionOperation::VFIXUPIMMPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x55u8,
+        prefix: 1,
+        operation: InstructionOperation::VFIXUPIMMSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x55u8,
+        prefix: 1,
+        operation: InstructionOperation::VFIXUPIMMSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x56u8,
+        prefix: 1,
+        operation: InstructionOperation::VREDUCEPS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x56u8,
+        prefix: 1,
+        operation: InstructionOperation::VREDUCEPD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x57u8,
+        prefix: 1,
+        operation: InstructionOperation::VREDUCESS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x57u8,
+        prefix: 1,
+        operation: InstructionOperation::VREDUCESD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x66u8,
+        prefix: 1,
+        operation: InstructionOperation::VFPCLASSPS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x66u8,
+        prefix: 1,
+        operation: InstructionOperation::VFPCLASSPD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+        func: decode_vex_sse,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x67u8,
+        prefix: 1,
+        operation: InstructionOperation::VFPCLASSSS,
+        form: VexForm::LIG | VexForm::W0,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_scalar,
+    },
+    EvexInstructionEncoding {
+        opcode: 0x67u8,
+        prefix: 1,
+        operation: InstructionOperation::VFPCLASSSD,
+        form: VexForm::LIG | VexForm::W1,
+        evex: EvexForm::K_DEST,
+        func: decode_vex_scalar,
+    },
+];
+
+static EVEX_OPERATIONS: [EvexOperationEntry; 219] = [
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVSS,
+        operation: InstructionOperation::VMOVSS,
+        form: VexForm::NDS_REG | VexForm::LIG | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVUPD,
+        operation: InstructionOperation::VMOVUPD,
+        form: VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVUPS,
+        operation: InstructionOperation::VMOVUPS,
+        form: VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MPSADBW,
+        operation: InstructionOperation::VDBPSADBW,
+        form: VexForm::NDS | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PACKSSDW,
+        operation: InstructionOperation::VPACKSSDW,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PACKSSWB,
+        operation: InstructionOperation::VPACKSSWB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PACKUSDW,
+        operation: InstructionOperation::VPACKUSDW,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PACKUSWB,
+        operation: InstructionOperation::VPACKUSWB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PABSB,
+        operation: InstructionOperation::VPABSB,
+        form: 0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PABSD,
+        operation: InstructionOperation::VPABSD,
+        form: VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PABSW,
+        operation: InstructionOperation::VPABSW,
+        form: 0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDB,
+        operation: InstructionOperation::VPADDB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDD,
+        operation: InstructionOperation::VPADDD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDQ,
+        operation: InstructionOperation::VPADDQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDW,
+        operation: InstructionOperation::VPADDW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDSB,
+        operation: InstructionOperation::VPADDSB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDSW,
+        operation: InstructionOperation::VPADDSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDUSB,
+        operation: InstructionOperation::VPADDUSB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PADDUSW,
+        operation: InstructionOperation::VPADDUSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PALIGNR,
+        operation: InstructionOperation::VPALIGNR,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PAND,
+        operation: InstructionOperation::VPANDD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PAND,
+        operation: InstructionOperation::VPANDQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PANDN,
+        operation: InstructionOperation::VPANDND,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PANDN,
+        operation: InstructionOperation::VPANDNQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PAVGB,
+        operation: InstructionOperation::VPAVGB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PAVGW,
+        operation: InstructionOperation::VPAVGW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPEQB,
+        operation: InstructionOperation::VPCMPEQB,
+        form: VexForm::NDS,
+        evex: EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPEQD,
+        operation: InstructionOperation::VPCMPEQD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPEQQ,
+        operation: InstructionOperation::VPCMPEQQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPEQW,
+        operation: InstructionOperation::VPCMPEQW,
+        form: VexForm::NDS,
+        evex: EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPGTB,
+        operation: InstructionOperation::VPCMPGTB,
+        form: VexForm::NDS,
+        evex: EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPGTD,
+        operation: InstructionOperation::VPCMPGTD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPGTQ,
+        operation: InstructionOperation::VPCMPGTQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCMPGTW,
+        operation: InstructionOperation::VPCMPGTW,
+        form: VexForm::NDS,
+        evex: EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMADDWD,
+        operation: InstructionOperation::VPMADDWD,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMADDUBSW,
+        operation: InstructionOperation::VPMADDUBSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXSB,
+        operation: InstructionOperation::VPMAXSB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXSD,
+        operation: InstructionOperation::VPMAXSD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXSD,
+        operation: InstructionOperation::VPMAXSQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXSW,
+        operation: InstructionOperation::VPMAXSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXUB,
+        operation: InstructionOperation::VPMAXUB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXUD,
+        operation: InstructionOperation::VPMAXUD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXUD,
+        operation: InstructionOperation::VPMAXUQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMAXUW,
+        operation: InstructionOperation::VPMAXUW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINSB,
+        operation: InstructionOperation::VPMINSB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINSD,
+        operation: InstructionOperation::VPMINSD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINSD,
+        operation: InstructionOperation::VPMINSQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINSW,
+        operation: InstructionOperation::VPMINSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINUB,
+        operation: InstructionOperation::VPMINUB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINUD,
+        operation: InstructionOperation::VPMINUD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINUD,
+        operation: InstructionOperation::VPMINUQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMINUW,
+        operation: InstructionOperation::VPMINUW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULDQ,
+        operation: InstructionOperation::VPMULDQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULHRSW,
+        operation: InstructionOperation::VPMULHRSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULHUW,
+        operation: InstructionOperation::VPMULHUW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULHW,
+        operation: InstructionOperation::VPMULHW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULLD,
+        operation: InstructionOperation::VPMULLD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULLD,
+        operation: InstructionOperation::VPMULLQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULLW,
+        operation: InstructionOperation::VPMULLW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMULUDQ,
+        operation: InstructionOperation::VPMULUDQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::POR,
+        operation: InstructionOperation::VPORD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::POR,
+        operation: InstructionOperation::VPORQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSADBW,
+        operation: InstructionOperation::VPSADBW,
+        form: VexForm::NDS,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSHUFB,
+        operation: InstructionOperation::VPSHUFB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSLLD,
+        operation: InstructionOperation::VPSLLD,
+        form: VexForm::SHIFT | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSLLQ,
+        operation: InstructionOperation::VPSLLQ,
+        form: VexForm::SHIFT | VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSLLW,
+        operation: InstructionOperation::VPSLLW,
+        form: VexForm::SHIFT,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSRAD,
+        operation: InstructionOperation::VPSRAD,
+        form: VexForm::SHIFT | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSRAD,
+        operation: InstructionOperation::VPSRAQ,
+        form: VexForm::SHIFT | VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSRAW,
+        operation: InstructionOperation::VPSRAW,
+        form: VexForm::SHIFT,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSRLD,
+        operation: InstructionOperation::VPSRLD,
+        form: VexForm::SHIFT | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSRLQ,
+        operation: InstructionOperation::VPSRLQ,
+        form: VexForm::SHIFT | VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSRLW,
+        operation: InstructionOperation::VPSRLW,
+        form: VexForm::SHIFT,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBB,
+        operation: InstructionOperation::VPSUBB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBD,
+        operation: InstructionOperation::VPSUBD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBQ,
+        operation: InstructionOperation::VPSUBQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBW,
+        operation: InstructionOperation::VPSUBW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBSB,
+        operation: InstructionOperation::VPSUBSB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBSW,
+        operation: InstructionOperation::VPSUBSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBUSB,
+        operation: InstructionOperation::VPSUBUSB,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSUBUSW,
+        operation: InstructionOperation::VPSUBUSW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHBW,
+        operation: InstructionOperation::VPUNPCKHBW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHDQ,
+        operation: InstructionOperation::VPUNPCKHDQ,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHQDQ,
+        operation: InstructionOperation::VPUNPCKHQDQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKHWD,
+        operation: InstructionOperation::VPUNPCKHWD,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLQDQ,
+        operation: InstructionOperation::VPUNPCKLQDQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PXOR,
+        operation: InstructionOperation::VPXORD,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PXOR,
+        operation: InstructionOperation::VPXORQ,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ROUNDPS,
+        operation: InstructionOperation::VRNDSCALEPS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ROUNDPD,
+        operation: InstructionOperation::VRNDSCALEPD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ADDPS,
+        operation: InstructionOperation::VADDPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ADDPD,
+        operation: InstructionOperation::VADDPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ADDSD,
+        operation: InstructionOperation::VADDSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ADDSS,
+        operation: InstructionOperation::VADDSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ANDNPS,
+        operation: InstructionOperation::VANDNPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ANDNPD,
+        operation: InstructionOperation::VANDNPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ANDPS,
+        operation: InstructionOperation::VANDPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ANDPD,
+        operation: InstructionOperation::VANDPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CMPSD,
+        operation: InstructionOperation::VCMPSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::DIVPS,
+        operation: InstructionOperation::VDIVPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::DIVPD,
+        operation: InstructionOperation::VDIVPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::DIVSD,
+        operation: InstructionOperation::VDIVSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::DIVSS,
+        operation: InstructionOperation::VDIVSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MAXPS,
+        operation: InstructionOperation::VMAXPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MAXPD,
+        operation: InstructionOperation::VMAXPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MAXSD,
+        operation: InstructionOperation::VMAXSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MAXSS,
+        operation: InstructionOperation::VMAXSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MINPS,
+        operation: InstructionOperation::VMINPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MINPD,
+        operation: InstructionOperation::VMINPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MINSD,
+        operation: InstructionOperation::VMINSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MINSS,
+        operation: InstructionOperation::VMINSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVD,
+        operation: InstructionOperation::VMOVD,
+        form: VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVQ,
+        operation: InstructionOperation::VMOVQ,
+        form: VexForm::L0 | VexForm::W1,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVSD,
+        operation: InstructionOperation::VMOVSD,
+        form: VexForm::NDS_REG | VexForm::LIG | VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MULPS,
+        operation: InstructionOperation::VMULPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MULPD,
+        operation: InstructionOperation::VMULPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MULSD,
+        operation: InstructionOperation::VMULSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MULSS,
+        operation: InstructionOperation::VMULSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ORPS,
+        operation: InstructionOperation::VORPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ORPD,
+        operation: InstructionOperation::VORPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PEXTRD,
+        operation: InstructionOperation::VPEXTRD,
+        form: VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PEXTRQ,
+        operation: InstructionOperation::VPEXTRQ,
+        form: VexForm::L0 | VexForm::W1,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PINSRD,
+        operation: InstructionOperation::VPINSRD,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PINSRQ,
+        operation: InstructionOperation::VPINSRQ,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W1,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SQRTPS,
+        operation: InstructionOperation::VSQRTPS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SQRTPD,
+        operation: InstructionOperation::VSQRTPD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SQRTSD,
+        operation: InstructionOperation::VSQRTSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SQRTSS,
+        operation: InstructionOperation::VSQRTSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SUBPS,
+        operation: InstructionOperation::VSUBPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SUBPD,
+        operation: InstructionOperation::VSUBPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SUBSD,
+        operation: InstructionOperation::VSUBSD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SUBSS,
+        operation: InstructionOperation::VSUBSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::XORPS,
+        operation: InstructionOperation::VXORPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::XORPD,
+        operation: InstructionOperation::VXORPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CMPPD,
+        operation: InstructionOperation::VCMPPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CMPPS,
+        operation: InstructionOperation::VCMPPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CMPSS,
+        operation: InstructionOperation::VCMPSS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE | EvexForm::K_DEST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::COMISD,
+        operation: InstructionOperation::VCOMISD,
+        form: VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::COMISS,
+        operation: InstructionOperation::VCOMISS,
+        form: VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTDQ2PD,
+        operation: InstructionOperation::VCVTDQ2PD,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTDQ2PD,
+        operation: InstructionOperation::VCVTQQ2PD,
+        form: VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTDQ2PS,
+        operation: InstructionOperation::VCVTDQ2PS,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTDQ2PS,
+        operation: InstructionOperation::VCVTQQ2PS,
+        form: VexForm::REG_XMM | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTPD2DQ,
+        operation: InstructionOperation::VCVTPD2DQ,
+        form: VexForm::REG_XMM | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTPD2PS,
+        operation: InstructionOperation::VCVTPD2PS,
+        form: VexForm::REG_XMM | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTPS2DQ,
+        operation: InstructionOperation::VCVTPS2DQ,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTPS2PD,
+        operation: InstructionOperation::VCVTPS2PD,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTSD2SI,
+        operation: InstructionOperation::VCVTSD2SI,
+        form: VexForm::LIG,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTSD2SS,
+        operation: InstructionOperation::VCVTSD2SS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTSI2SD,
+        operation: InstructionOperation::VCVTSI2SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTSI2SD,
+        operation: InstructionOperation::VCVTSI2SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTSI2SS,
+        operation: InstructionOperation::VCVTSI2SS,
+        form: VexForm::NDS | VexForm::LIG,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTSS2SD,
+        operation: InstructionOperation::VCVTSS2SD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTSS2SI,
+        operation: InstructionOperation::VCVTSS2SI,
+        form: VexForm::LIG,
+        evex: EvexForm::ER | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTTPD2DQ,
+        operation: InstructionOperation::VCVTTPD2DQ,
+        form: VexForm::REG_XMM | VexForm::W1,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTTPS2DQ,
+        operation: InstructionOperation::VCVTTPS2DQ,
+        form: VexForm::W0,
+        evex: EvexForm::BCST | EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTTSD2SI,
+        operation: InstructionOperation::VCVTTSD2SI,
+        form: VexForm::LIG,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::CVTTSS2SI,
+        operation: InstructionOperation::VCVTTSS2SI,
+        form: VexForm::LIG,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::EXTRACTPS,
+        operation: InstructionOperation::VEXTRACTPS,
+        form: VexForm::L0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::INSERTPS,
+        operation: InstructionOperation::VINSERTPS,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVAPD,
+        operation: InstructionOperation::VMOVAPD,
+        form: VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVAPS,
+        operation: InstructionOperation::VMOVAPS,
+        form: VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVDDUP,
+        operation: InstructionOperation::VMOVDDUP,
+        form: VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVDQA,
+        operation: InstructionOperation::VMOVDQA32,
+        form: VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVDQA,
+        operation: InstructionOperation::VMOVDQA64,
+        form: VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVDQU,
+        operation: InstructionOperation::VMOVDQU32,
+        form: VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVDQU,
+        operation: InstructionOperation::VMOVDQU64,
+        form: VexForm::W1,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVHLPS,
+        operation: InstructionOperation::VMOVHLPS,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVHPD,
+        operation: InstructionOperation::VMOVHPD,
+        form: VexForm::NDS_LOAD | VexForm::L0 | VexForm::W1,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVHPS,
+        operation: InstructionOperation::VMOVHPS,
+        form: VexForm::NDS_LOAD | VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVSHDUP,
+        operation: InstructionOperation::VMOVSHDUP,
+        form: VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVSLDUP,
+        operation: InstructionOperation::VMOVSLDUP,
+        form: VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVLHPS,
+        operation: InstructionOperation::VMOVLHPS,
+        form: VexForm::NDS | VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVLPD,
+        operation: InstructionOperation::VMOVLPD,
+        form: VexForm::NDS_LOAD | VexForm::L0 | VexForm::W1,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVLPS,
+        operation: InstructionOperation::VMOVLPS,
+        form: VexForm::NDS_LOAD | VexForm::L0 | VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVNTDQ,
+        operation: InstructionOperation::VMOVNTDQ,
+        form: VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVNTDQA,
+        operation: InstructionOperation::VMOVNTDQA,
+        form: VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVNTPD,
+        operation: InstructionOperation::VMOVNTPD,
+        form: VexForm::W1,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::MOVNTPS,
+        operation: InstructionOperation::VMOVNTPS,
+        form: VexForm::W0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PINSRB,
+        operation: InstructionOperation::VPINSRB,
+        form: VexForm::NDS | VexForm::L0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PINSRW,
+        operation: InstructionOperation::VPINSRW,
+        form: VexForm::NDS | VexForm::L0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PEXTRB,
+        operation: InstructionOperation::VPEXTRB,
+        form: VexForm::L0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PEXTRW,
+        operation: InstructionOperation::VPEXTRW,
+        form: VexForm::L0,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVSXBD,
+        operation: InstructionOperation::VPMOVSXBD,
+        form: VexForm::RM_HALF,
+        evex: EvexForm::RM_QUARTER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVSXBQ,
+        operation: InstructionOperation::VPMOVSXBQ,
+        form: VexForm::RM_HALF,
+        evex: EvexForm::RM_QUARTER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVSXDQ,
+        operation: InstructionOperation::VPMOVSXDQ,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVSXBW,
+        operation: InstructionOperation::VPMOVSXBW,
+        form: VexForm::RM_HALF,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVSXWD,
+        operation: InstructionOperation::VPMOVSXWD,
+        form: VexForm::RM_HALF,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVSXWQ,
+        operation: InstructionOperation::VPMOVSXWQ,
+        form: VexForm::RM_HALF,
+        evex: EvexForm::RM_QUARTER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVZXBD,
+        operation: InstructionOperation::VPMOVZXBD,
+        form: VexForm::RM_HALF,
+        evex: EvexForm::RM_QUARTER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVZXBQ,
+        operation: InstructionOperation::VPMOVZXBQ,
+        form: VexForm::RM_HALF,
+        evex: EvexForm::RM_QUARTER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVZXDQ,
+        operation: InstructionOperation::VPMOVZXDQ,
+        form: VexForm::RM_HALF | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVZXBW,
+        operation: InstructionOperation::VPMOVZXBW,
+        form: VexForm::RM_HALF,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVZXWD,
+        operation: InstructionOperation::VPMOVZXWD,
+        form: VexForm::RM_HALF,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PMOVZXWQ,
+        operation: InstructionOperation::VPMOVZXWQ,
+        form: VexForm::RM_HALF,
+        evex: EvexForm::RM_QUARTER,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSHUFD,
+        operation: InstructionOperation::VPSHUFD,
+        form: VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSHUFHW,
+        operation: InstructionOperation::VPSHUFHW,
+        form: 0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PSHUFLW,
+        operation: InstructionOperation::VPSHUFLW,
+        form: 0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLBW,
+        operation: InstructionOperation::VPUNPCKLBW,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLDQ,
+        operation: InstructionOperation::VPUNPCKLDQ,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PUNPCKLWD,
+        operation: InstructionOperation::VPUNPCKLWD,
+        form: VexForm::NDS,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ROUNDSD,
+        operation: InstructionOperation::VRNDSCALESD,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::ROUNDSS,
+        operation: InstructionOperation::VRNDSCALESS,
+        form: VexForm::NDS | VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SHUFPD,
+        operation: InstructionOperation::VSHUFPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::SHUFPS,
+        operation: InstructionOperation::VSHUFPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::UCOMISD,
+        operation: InstructionOperation::VUCOMISD,
+        form: VexForm::LIG | VexForm::W1,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::UCOMISS,
+        operation: InstructionOperation::VUCOMISS,
+        form: VexForm::LIG | VexForm::W0,
+        evex: EvexForm::SAE | EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::UNPCKHPD,
+        operation: InstructionOperation::VUNPCKHPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::UNPCKHPS,
+        operation: InstructionOperation::VUNPCKHPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::UNPCKLPD,
+        operation: InstructionOperation::VUNPCKLPD,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::UNPCKLPS,
+        operation: InstructionOperation::VUNPCKLPS,
+        form: VexForm::NDS | VexForm::W0,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::AESENC,
+        operation: InstructionOperation::VAESENC,
+        form: VexForm::NDS,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::AESENCLAST,
+        operation: InstructionOperation::VAESENCLAST,
+        form: VexForm::NDS,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::AESDEC,
+        operation: InstructionOperation::VAESDEC,
+        form: VexForm::NDS,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::AESDECLAST,
+        operation: InstructionOperation::VAESDECLAST,
+        form: VexForm::NDS,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::PCLMULQDQ,
+        operation: InstructionOperation::VPCLMULQDQ,
+        form: VexForm::NDS,
+        evex: EvexForm::NO_MASK,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::GF2P8MULB,
+        operation: InstructionOperation::VGF2P8MULB,
+        form: VexForm::NDS | VexForm::W0,
+        evex: 0,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::GF2P8AFFINEQB,
+        operation: InstructionOperation::VGF2P8AFFINEQB,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+    EvexOperationEntry {
+        legacy: InstructionOperation::GF2P8AFFINEINVQB,
+        operation: InstructionOperation::VGF2P8AFFINEINVQB,
+        form: VexForm::NDS | VexForm::W1,
+        evex: EvexForm::BCST,
+    },
+];
+
+static EVEX_SHIFT_OPERATIONS: [[[InstructionOperation; 2]; 8]; 3] = [
+    [
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::VPSRLW, InstructionOperation::VPSRLW],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::VPSRAW, InstructionOperation::VPSRAW],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::VPSLLW, InstructionOperation::VPSLLW],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+    ],
+    [
+        [InstructionOperation::VPRORD, InstructionOperation::VPRORQ],
+        [InstructionOperation::VPROLD, InstructionOperation::VPROLQ],
+        [InstructionOperation::VPSRLD, InstructionOperation::INVALID],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::VPSRAD, InstructionOperation::VPSRAQ],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::VPSLLD, InstructionOperation::INVALID],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+    ],
+    [
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::INVALID, InstructionOperation::VPSRLQ],
+        [InstructionOperation::VPSRLDQ, InstructionOperation::VPSRLDQ],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::INVALID, InstructionOperation::INVALID],
+        [InstructionOperation::INVALID, InstructionOperation::VPSLLQ],
+        [InstructionOperation::VPSLLDQ, InstructionOperation::VPSLLDQ],
+    ],
+];
+static INVALID_REG_LIST: [OperandType; 0] = [];
+
+static REG8_LIST: [OperandType; 8] = [
+    OperandType::REG_AL,
+    OperandType::REG_CL,
+    OperandType::REG_DL,
+    OperandType::REG_BL,
+    OperandType::REG_AH,
+    OperandType::REG_CH,
+    OperandType::REG_DH,
+    OperandType::REG_BH,
+];
+
+static REG8_LIST64: [OperandType; 32] = [
+    OperandType::REG_AL,
+    OperandType::REG_CL,
+    OperandType::REG_DL,
+    OperandType::REG_BL,
+    OperandType::REG_SPL,
+    OperandType::REG_BPL,
+    OperandType::REG_SIL,
+    OperandType::REG_DIL,
+    OperandType::REG_R8B,
+    OperandType::REG_R9B,
+    OperandType::REG_R10B,
+    OperandType::REG_R11B,
+    OperandType::REG_R12B,
+    OperandType::REG_R13B,
+    OperandType::REG_R14B,
+    OperandType::REG_R15B,
+    OperandType::REG_R16B,
+    OperandType::REG_R17B,
+    OperandType::REG_R18B,
+    OperandType::REG_R19B,
+    OperandType::REG_R20B,
+    OperandType::REG_R21B,
+    OperandType::REG_R22B,
+    OperandType::REG_R23B,
+    OperandType::REG_R24B,
+    OperandType::REG_R25B,
+    OperandType::REG_R26B,
+    OperandType::REG_R27B,
+    OperandType::REG_R28B,
+    OperandType::REG_R29B,
+    OperandType::REG_R30B,
+    OperandType::REG_R31B,
+];
+
+static REG16_LIST: [OperandType; 32] = [
+    OperandType::REG_AX,
+    OperandType::REG_CX,
+    OperandType::REG_DX,
+    OperandType::REG_BX,
+    OperandType::REG_SP,
+    OperandType::REG_BP,
+    OperandType::REG_SI,
+    OperandType::REG_DI,
+    OperandType::REG_R8W,
+    OperandType::REG_R9W,
+    OperandType::REG_R10W,
+    OperandType::REG_R11W,
+    OperandType::REG_R12W,
+    OperandType::REG_R13W,
+    OperandType::REG_R14W,
+    OperandType::REG_R15W,
+    OperandType::REG_R16W,
+    OperandType::REG_R17W,
+    OperandType::REG_R18W,
+    OperandType::REG_R19W,
+    OperandType::REG_R20W,
+    OperandType::REG_R21W,
+    OperandType::REG_R22W,
+    OperandType::REG_R23W,
+    OperandType::REG_R24W,
+    OperandType::REG_R25W,
+    OperandType::REG_R26W,
+    OperandType::REG_R27W,
+    OperandType::REG_R28W,
+    OperandType::REG_R29W,
+    OperandType::REG_R30W,
+    OperandType::REG_R31W,
+];
+
+static REG32_LIST: [OperandType; 32] = [
+    OperandType::REG_EAX,
+    OperandType::REG_ECX,
+    OperandType::REG_EDX,
+    OperandType::REG_EBX,
+    OperandType::REG_ESP,
+    OperandType::REG_EBP,
+    OperandType::REG_ESI,
+    OperandType::REG_EDI,
+    OperandType::REG_R8D,
+    OperandType::REG_R9D,
+    OperandType::REG_R10D,
+    OperandType::REG_R11D,
+    OperandType::REG_R12D,
+    OperandType::REG_R13D,
+    OperandType::REG_R14D,
+    OperandType::REG_R15D,
+    OperandType::REG_R16D,
+    OperandType::REG_R17D,
+    OperandType::REG_R18D,
+    OperandType::REG_R19D,
+    OperandType::REG_R20D,
+    OperandType::REG_R21D,
+    OperandType::REG_R22D,
+    OperandType::REG_R23D,
+    OperandType::REG_R24D,
+    OperandType::REG_R25D,
+    OperandType::REG_R26D,
+    OperandType::REG_R27D,
+    OperandType::REG_R28D,
+    OperandType::REG_R29D,
+    OperandType::REG_R30D,
+    OperandType::REG_R31D,
+];
+
+static REG64_LIST: [OperandType; 32] = [
+    OperandType::REG_RAX,
+    OperandType::REG_RCX,
+    OperandType::REG_RDX,
+    OperandType::REG_RBX,
+    OperandType::REG_RSP,
+    OperandType::REG_RBP,
+    OperandType::REG_RSI,
+    OperandType::REG_RDI,
+    OperandType::REG_R8,
+    OperandType::REG_R9,
+    OperandType::REG_R10,
+    OperandType::REG_R11,
+    OperandType::REG_R12,
+    OperandType::REG_R13,
+    OperandType::REG_R14,
+    OperandType::REG_R15,
+    OperandType::REG_R16,
+    OperandType::REG_R17,
+    OperandType::REG_R18,
+    OperandType::REG_R19,
+    OperandType::REG_R20,
+    OperandType::REG_R21,
+    OperandType::REG_R22,
+    OperandType::REG_R23,
+    OperandType::REG_R24,
+    OperandType::REG_R25,
+    OperandType::REG_R26,
+    OperandType::REG_R27,
+    OperandType::REG_R28,
+    OperandType::REG_R29,
+    OperandType::REG_R30,
+    OperandType::REG_R31,
+];
+
+static MMX_REG_LIST: [OperandType; 16] = [
+    OperandType::REG_MM0,
+    OperandType::REG_MM1,
+    OperandType::REG_MM2,
+    OperandType::REG_MM3,
+    OperandType::REG_MM4,
+    OperandType::REG_MM5,
+    OperandType::REG_MM6,
+    OperandType::REG_MM7,
+    OperandType::REG_MM0,
+    OperandType::REG_MM1,
+    OperandType::REG_MM2,
+    OperandType::REG_MM3,
+    OperandType::REG_MM4,
+    OperandType::REG_MM5,
+    OperandType::REG_MM6,
+    OperandType::REG_MM7,
+];
+
+static XMM_REG_LIST: [OperandType; 32] = [
+    OperandType::REG_XMM0,
+    OperandType::REG_XMM1,
+    OperandType::REG_XMM2,
+    OperandType::REG_XMM3,
+    OperandType::REG_XMM4,
+    OperandType::REG_XMM5,
+    OperandType::REG_XMM6,
+    OperandType::REG_XMM7,
+    OperandType::REG_XMM8,
+    OperandType::REG_XMM9,
+    OperandType::REG_XMM10,
+    OperandType::REG_XMM11,
+    OperandType::REG_XMM12,
+    OperandType::REG_XMM13,
+    OperandType::REG_XMM14,
+    OperandType::REG_XMM15,
+    OperandType::REG_XMM16,
+    OperandType::REG_XMM17,
+    OperandType::REG_XMM18,
+    OperandType::REG_XMM19,
+    OperandType::REG_XMM20,
+    OperandType::REG_XMM21,
+    OperandType::REG_XMM22,
+    OperandType::REG_XMM23,
+    OperandType::REG_XMM24,
+    OperandType::REG_XMM25,
+    OperandType::REG_XMM26,
+    OperandType::REG_XMM27,
+    OperandType::REG_XMM28,
+    OperandType::REG_XMM29,
+    OperandType::REG_XMM30,
+    OperandType::REG_XMM31,
+];
+
+static YMM_REG_LIST: [OperandType; 32] = [
+    OperandType::REG_YMM0,
+    OperandType::REG_YMM1,
+    OperandType::REG_YMM2,
+    OperandType::REG_YMM3,
+    OperandType::REG_YMM4,
+    OperandType::REG_YMM5,
+    OperandType::REG_YMM6,
+    OperandType::REG_YMM7,
+    OperandType::REG_YMM8,
+    OperandType::REG_YMM9,
+    OperandType::REG_YMM10,
+    OperandType::REG_YMM11,
+    OperandType::REG_YMM12,
+    OperandType::REG_YMM13,
+    OperandType::REG_YMM14,
+    OperandType::REG_YMM15,
+    OperandType::REG_YMM16,
+    OperandType::REG_YMM17,
+    OperandType::REG_YMM18,
+    OperandType::REG_YMM19,
+    OperandType::REG_YMM20,
+    OperandType::REG_YMM21,
+    OperandType::REG_YMM22,
+    OperandType::REG_YMM23,
+    OperandType::REG_YMM24,
+    OperandType::REG_YMM25,
+    OperandType::REG_YMM26,
+    OperandType::REG_YMM27,
+    OperandType::REG_YMM28,
+    OperandType::REG_YMM29,
+    OperandType::REG_YMM30,
+    OperandType::REG_YMM31,
+];
+
+static ZMM_REG_LIST: [OperandType; 32] = [
+    OperandType::REG_ZMM0,
+    OperandType::REG_ZMM1,
+    OperandType::REG_ZMM2,
+    OperandType::REG_ZMM3,
+    OperandType::REG_ZMM4,
+    OperandType::REG_ZMM5,
+    OperandType::REG_ZMM6,
+    OperandType::REG_ZMM7,
+    OperandType::REG_ZMM8,
+    OperandType::REG_ZMM9,
+    OperandType::REG_ZMM10,
+    OperandType::REG_ZMM11,
+    OperandType::REG_ZMM12,
+    OperandType::REG_ZMM13,
+    OperandType::REG_ZMM14,
+    OperandType::REG_ZMM15,
+    OperandType::REG_ZMM16,
+    OperandType::REG_ZMM17,
+    OperandType::REG_ZMM18,
+    OperandType::REG_ZMM19,
+    OperandType::REG_ZMM20,
+    OperandType::REG_ZMM21,
+    OperandType::REG_ZMM22,
+    OperandType::REG_ZMM23,
+    OperandType::REG_ZMM24,
+    OperandType::REG_ZMM25,
+    OperandType::REG_ZMM26,
+    OperandType::REG_ZMM27,
+    OperandType::REG_ZMM28,
+    OperandType::REG_ZMM29,
+    OperandType::REG_ZMM30,
+    OperandType::REG_ZMM31,
+];
+
+static MASK_REG_LIST: [OperandType; 8] = [
+    OperandType::REG_K0,
+    OperandType::REG_K1,
+    OperandType::REG_K2,
+    OperandType::REG_K3,
+    OperandType::REG_K4,
+    OperandType::REG_K5,
+    OperandType::REG_K6,
+    OperandType::REG_K7,
+];
+
+static TMM_REG_LIST: [OperandType; 8] = [
+    OperandType::REG_TMM0,
+    OperandType::REG_TMM1,
+    OperandType::REG_TMM2,
+    OperandType::REG_TMM3,
+    OperandType::REG_TMM4,
+    OperandType::REG_TMM5,
+    OperandType::REG_TMM6,
+    OperandType::REG_TMM7,
+];
+
+static FPU_REG_LIST: [OperandType; 16] = [
+    OperandType::REG_ST0,
+    OperandType::REG_ST1,
+    OperandType::REG_ST2,
+    OperandType::REG_ST3,
+    OperandType::REG_ST4,
+    OperandType::REG_ST5,
+    OperandType::REG_ST6,
+    OperandType::REG_ST7,
+    OperandType::REG_ST0,
+    OperandType::REG_ST1,
+    OperandType::REG_ST2,
+    OperandType::REG_ST3,
+    OperandType::REG_ST4,
+    OperandType::REG_ST5,
+    OperandType::REG_ST6,
+    OperandType::REG_ST7,
+];
+
+fn set_invalid(state: &mut DecodeState, reason: InvalidReason) {
+    // Keep the first reason found, as later failures are often a
+    // consequence of it.
+    if !state.invalid {
+        state.invalid_reason = reason;
+    }
+    state.invalid = true;
+}
+
+fn invalid_decode(state: &mut DecodeState) {
+    set_invalid(state, InvalidReason::Opcode);
+}
+
+fn read_8(state: &mut DecodeState) -> u8 {
+    if state.len < 1 {
+        state.invalid = true;
+        state.insufficient_length = true;
+        state.len = 0;
+        0xcc
+    } else {
+        let val = state.opcode[state.pos];
+        state.pos += 1;
+        state.len -= 1;
+        val
+    }
+}
+
+fn get_final_op_size(state: &DecodeState) -> u16 {
+    if state.flags & DecodeFlags::BYTE != 0 {
+        1u16
+    } else {
+        state.op_size
+    }
+}
+
+fn process_encoding(state: &mut DecodeState, encoding: &InstructionEncoding) {
+    if state.insufficient_length {
+        // The opcode was cut short, so there is nothing to look up.
+        state.result.operation = InstructionOperation::INVALID;
+        return;
+    }
+    state.result.operation = InstructionOperation::from_i32(i32::from(encoding.operation));
+    state.flags = u32::from(encoding.flags);
+    if state.using64 && (state.flags & DecodeFlags::INVALID_IN_64BIT != 0) {
+        set_invalid(state, InvalidReason::In64BitMode);
+    } else {
+        if state.using64 && (state.flags & DecodeFlags::DEFAULT_TO_64BIT != 0) {
+            state.op_size = if state.op_prefix { 4 } else { 8 };
+        }
+        state.final_op_size = get_final_op_size(state);
+        if state.flags & DecodeFlags::FLIP_OPERANDS != 0 {
+            state.operand0 = 1;
+            state.operand1 = 0;
+        } else {
+            state.operand0 = 0;
+            state.operand1 = 1;
+        }
+        if state.flags & DecodeFlags::FORCE_16BIT != 0 {
+            state.final_op_size = 2;
+        }
+        if state.flags & DecodeFlags::OPERATION_OP_SIZE != 0 {
+            if state.final_op_size == 4 {
+                state.result.operation =
+                    InstructionOperation::from_i32(state.result.operation as i32 + 1);
+            } else if state.final_op_size == 8 {
+                state.result.operation =
+                    InstructionOperation::from_i32(state.result.operation as i32 + 2);
+            }
+        }
+        if state.flags & (DecodeFlags::REP | DecodeFlags::REP_COND) != 0 {
+            state.rep_used = true;
+        }
+        if state.flags & DecodeFlags::REP != 0 {
+            if state.rep != RepPrefix::NONE {
+                state.result.flags |= X86Flag::REP;
+            }
+        } else if state.flags & DecodeFlags::REP_COND != 0 {
+            if state.rep == RepPrefix::REPNE {
+                state.result.flags |= X86Flag::REPNE;
+            } else if state.rep == RepPrefix::REPE {
+                state.result.flags |= X86Flag::REPE;
+            }
+        }
+        (encoding.func)(state);
         if state.result.operation == InstructionOperation::INVALID {
-            state.invalid = true;
+            set_invalid(state, InvalidReason::Opcode);
+        }
+        // Ensure instruction allows lock and it has proper semantics
+        if state.result.flags & X86Flag::LOCK != 0 &&
+            (state.flags & DecodeFlags::LOCK == 0 ||
+                 state.result.operation == InstructionOperation::CMP ||
+                 operand_type(state, 0) != OperandType::MEM &&
+                     operand_type(state, 1) != OperandType::MEM)
+        {
+            set_invalid(state, InvalidReason::Lock);
+        }
+    }
+}
+
+fn process_opcode(state: &mut DecodeState, map: &[InstructionEncoding], opcode: u8) {
+    process_encoding(state, &map[opcode as usize]);
+}
+
+fn process_rex2_opcode(state: &mut DecodeState, payload: u8, opcode: u8) {
+    let map1 = payload & 0x80 != 0;
+    let rex_w = payload & 8 != 0;
+    // REX2 replaces the escape bytes, and isn't allowed with the
+    // opcodes that have no register to extend, such as the short
+    // jumps.
+    let reserved = if map1 {
+        matches!(opcode >> 4, 0x3 | 0x8)
+    } else {
+        matches!(opcode >> 4, 0x4 | 0x7 | 0xa | 0xe) && (opcode != 0xa1 || rex_w) ||
+            matches!(opcode, 0x0f | 0x62 | 0xc4 | 0xc5 | 0xd5)
+    };
+    if reserved {
+        state.result.operation = InstructionOperation::INVALID;
+        set_invalid(state, InvalidReason::Opcode);
+    } else if map1 {
+        process_opcode(state, &TWO_BYTE_OPCODE_MAP, opcode);
+    } else if opcode == 0xa1 {
+        decode_jmpabs(state);
+    } else {
+        process_opcode(state, &MAIN_OPCODE_MAP, opcode);
+        if rex_w && (0x50..=0x5f).contains(&opcode) {
+            // REX2.W is a hint that the push and pop are paired.
+            state.result.operation = if opcode < 0x58 {
+                InstructionOperation::PUSHP
+            } else {
+                InstructionOperation::POPP
+            };
+        }
+    }
+}
+
+fn decode_jmpabs(state: &mut DecodeState) {
+    state.result.operation = InstructionOperation::JMPABS;
+    if state.op_prefix || state.rep != RepPrefix::NONE || state.result.flags & X86Flag::LOCK != 0 {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    let operand0 = state.operand0;
+    let start = state.pos;
+    let target = read_64(state);
+    record_immediate(state, operand0, start, false);
+    state.result.operands[operand0] = X86Operand::RelativeTarget(target);
+}
+
+fn process_sparse_opcode(state: &mut DecodeState, map: &[SparseInstructionEncoding], opcode: u8) {
+    state.result.operation = InstructionOperation::INVALID;
+    if let Ok(idx) = map.binary_search_by_key(&opcode, |entry| entry.opcode) {
+        process_encoding(state, &map[idx].encoding);
+    } else {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+}
+
+fn set_operand_to_imm_8(state: &mut DecodeState, oper: usize) {
+    let start = state.pos;
+    let value = i64::from(read_8(state));
+    set_imm_operand(state, oper, value, 1);
+    record_immediate(state, oper, start, false);
+}
+
+fn decode_two_byte(state: &mut DecodeState) {
+    let opcode: u8 = read_8(state);
+    state.result.layout.opcode_length = 2;
+    if opcode == 0x38 || opcode == 0x3a {
+        state.result.layout.opcode_length = 3;
+    }
+    if opcode == 0x38 {
+        let next_opcode = read_8(state);
+        process_sparse_opcode(state, &THREE_BYTE_0F38_MAP, next_opcode);
+    } else if opcode == 0x3a {
+        let next_opcode = read_8(state);
+        process_sparse_opcode(state, &THREE_BYTE_0F3A_MAP, next_opcode);
+        let operand = 2;
+        set_operand_to_imm_8(state, operand);
+    } else {
+        process_opcode(state, &TWO_BYTE_OPCODE_MAP, opcode);
+    }
+}
+
+fn peek_8(state: &mut DecodeState) -> u8 {
+    if state.len < 1 {
+        state.invalid = true;
+        state.insufficient_length = true;
+        state.len = 0;
+        0xcc
+    } else {
+        state.opcode[state.pos]
+    }
+}
+
+fn decode_fpu(state: &mut DecodeState) {
+    let mod_rm = peek_8(state);
+    let reg = mod_rm >> 3 & 7;
+    let op = state.result.operation as u8;
+    let map = if mod_rm & 0xc0 == 0xc0 {
+        &FPU_REG_OPCODE_MAP[op as usize]
+    } else {
+        &FPU_MEM_OPCODE_MAP[op as usize]
+    };
+    process_encoding(state, &map[reg as usize]);
+}
+
+fn decode_no_operands(_state: &mut DecodeState) {}
+
+fn get_byte_reg_list(state: &DecodeState) -> &'static [OperandType] {
+    // VEX and EVEX prefixes extend the registers in the same way as REX.
+    if state.rex || state.result.flags & (X86Flag::VEX | X86Flag::EVEX | X86Flag::XOP) != 0 {
+        &REG8_LIST64
+    } else {
+        &REG8_LIST
+    }
+}
+
+fn get_reg_list_for_final_op_size(state: &DecodeState) -> &'static [OperandType] {
+    match state.final_op_size {
+        8 => &REG64_LIST,
+        4 => &REG32_LIST,
+        2 => &REG16_LIST,
+        1 => get_byte_reg_list(state),
+        _ => &INVALID_REG_LIST,
+    }
+}
+
+/// The offset into `reg_list` of the register that a REX or REX2
+/// prefix extends. The fourth bit from REX2 only extends general
+/// purpose registers.
+fn reg_offset(reg_list: &[OperandType], rex: bool, rex2: bool) -> u8 {
+    let mut offset = if rex { 8 } else { 0 };
+    if rex2 && reg_list.get(16).is_some_and(|reg| reg.family() == OperandType::REG_R16) {
+        offset += 16;
+    }
+    offset
+}
+
+fn get_reg_list_for_addr_size(state: &DecodeState) -> &'static [OperandType] {
+    match state.addr_size {
+        8 => &REG64_LIST,
+        4 => &REG32_LIST,
+        2 => &REG16_LIST,
+        _ => &INVALID_REG_LIST,
+    }
+}
+
+fn read_le(state: &mut DecodeState, size: usize) -> u64 {
+    if state.len < size {
+        state.invalid = true;
+        state.insufficient_length = true;
+        state.len = 0;
+        0
+    } else {
+        let bytes = &state.opcode[state.pos..state.pos + size];
+        state.pos += size;
+        state.len -= size;
+        bytes.iter().rev().fold(0, |val, &byte| val << 8 | u64::from(byte))
+    }
+}
+
+fn read_mod_rm(state: &mut DecodeState) -> u8 {
+    state.result.layout.mod_rm = true;
+    read_8(state)
+}
+
+fn record_displacement(state: &mut DecodeState, oper: usize, start: usize) {
+    if state.pos > start {
+        let size = (state.pos - start) as u8;
+        state.result.layout.displacement_size = size;
+        if let X86Operand::Memory(mem) = &mut state.result.operands[oper] {
+            mem.displacement_size = size;
+        }
+    }
+}
+
+fn record_immediate(state: &mut DecodeState, oper: usize, start: usize, signed: bool) {
+    if state.pos > start {
+        let size = (state.pos - start) as u8;
+        if let X86Operand::Immediate(imm) = &mut state.result.operands[oper] {
+            imm.encoded_size = size;
+            imm.signed = signed;
+        }
+        let layout = &mut state.result.layout;
+        if layout.immediate_size == 0 {
+            layout.immediate_size = size;
+        } else {
+            layout.immediate2_size = size;
+        }
+    }
+}
+
+fn read_32(state: &mut DecodeState) -> u32 {
+    read_le(state, 4) as u32
+}
+
+fn read_signed_32(state: &mut DecodeState) -> i64 {
+    i64::from(read_32(state) as i32)
+}
+
+fn read_signed_8(state: &mut DecodeState) -> i64 {
+    i64::from(read_8(state) as i8)
+}
+
+fn get_final_segment(state: &DecodeState, seg: SegmentRegister) -> SegmentRegister {
+    if state.result.segment == SegmentRegister::DEFAULT {
+        seg
+    } else {
+        state.result.segment
+    }
+}
+
+#[derive(Debug)]
+#[repr(C)]
+struct RMDef {
+    pub first: OperandType,
+    pub second: OperandType,
+    pub segment: SegmentRegister,
+}
+
+/// The type of an operand as it would be in its flat form: `IMM` or
+/// `MEM`, or the register, which is `NONE` for an unused operand.
+fn operand_type(state: &DecodeState, oper: usize) -> OperandType {
+    match state.result.operands[oper] {
+        X86Operand::Register(reg) => reg.register,
+        X86Operand::Memory(_) => OperandType::MEM,
+        _ => OperandType::IMM,
+    }
+}
+
+fn set_reg_operand(state: &mut DecodeState, oper: usize, register: OperandType, size: u16) {
+    state.result.operands[oper] = X86Operand::Register(Reg { register, size });
+}
+
+fn set_imm_operand(state: &mut DecodeState, oper: usize, value: i64, size: u16) {
+    state.result.operands[oper] = X86Operand::Immediate(Imm {
+        value,
+        size,
+        encoded_size: 0,
+        signed: false,
+    });
+}
+
+/// Change the size of operand `oper` if it is a memory operand.
+fn set_mem_size(state: &mut DecodeState, oper: usize, size: u16) {
+    if let X86Operand::Memory(mem) = &mut state.result.operands[oper] {
+        mem.size = size;
+    }
+}
+
+fn address_component(register: OperandType) -> Option<OperandType> {
+    if register == OperandType::NONE {
+        None
+    } else {
+        Some(register)
+    }
+}
+
+fn set_mem_operand(state: &mut DecodeState, oper: usize, def: &RMDef, immed: i64, size: u16) {
+    state.result.operands[oper] = X86Operand::Memory(MemRef {
+        base: address_component(def.first),
+        index: address_component(def.second),
+        scale: 1,
+        displacement: immed,
+        displacement_size: 0,
+        segment: get_final_segment(state, def.segment),
+        size,
+    });
+}
+
+fn read_16(state: &mut DecodeState) -> u16 {
+    read_le(state, 2) as u16
+}
+
+fn read_signed_16(state: &mut DecodeState) -> i64 {
+    i64::from(read_16(state) as i16)
+}
+
+fn decode_rm(
+    state: &mut DecodeState,
+    rm_oper: usize,
+    reg_list: &[OperandType],
+    rm_size: u16,
+    reg_oper: &mut u8,
+) {
+    let rm_byte: u8 = read_mod_rm(state);
+    let mod_: u8 = rm_byte >> 6;
+    let mut rm: u8 = rm_byte & 7;
+    *reg_oper = rm_byte >> 3 & 7;
+    state.rm_operand = Some(rm_oper);
+    if state.vsib.is_some() && (mod_ == 3 || rm != 4 || state.addr_size == 2) {
+        // A vector index needs a SIB byte.
+        set_invalid(state, InvalidReason::Operands);
+    }
+    if state.addr_size == 2 {
+        static RM16_COMPONENTS: [RMDef; 9] = [
+            RMDef {
+                first: OperandType::REG_BX,
+                second: OperandType::REG_SI,
+                segment: SegmentRegister::DS,
+            },
+            RMDef {
+                first: OperandType::REG_BX,
+                second: OperandType::REG_DI,
+                segment: SegmentRegister::DS,
+            },
+            RMDef {
+                first: OperandType::REG_BP,
+                second: OperandType::REG_SI,
+                segment: SegmentRegister::SS,
+            },
+            RMDef {
+                first: OperandType::REG_BP,
+                second: OperandType::REG_DI,
+                segment: SegmentRegister::SS,
+            },
+            RMDef {
+                first: OperandType::REG_SI,
+                second: OperandType::NONE,
+                segment: SegmentRegister::DS,
+            },
+            RMDef {
+                first: OperandType::REG_DI,
+                second: OperandType::NONE,
+                segment: SegmentRegister::DS,
+            },
+            RMDef {
+                first: OperandType::REG_BP,
+                second: OperandType::NONE,
+                segment: SegmentRegister::SS,
+            },
+            RMDef {
+                first: OperandType::REG_BX,
+                second: OperandType::NONE,
+                segment: SegmentRegister::DS,
+            },
+            RMDef {
+                first: OperandType::NONE,
+                second: OperandType::NONE,
+                segment: SegmentRegister::DS,
+            },
+        ];
+        let disp_start = state.pos;
+        if mod_ == 3 {
+            set_reg_operand(state, rm_oper, reg_list[rm as usize], rm_size);
+            return;
+        } else if mod_ == 2 {
+            let immediate = read_signed_16(state);
+            set_mem_operand(state, rm_oper, &RM16_COMPONENTS[rm as usize], immediate, rm_size);
+        } else if mod_ == 1 {
+            let immediate = read_signed_8(state);
+            set_mem_operand(state, rm_oper, &RM16_COMPONENTS[rm as usize], immediate, rm_size);
+        } else if mod_ == 0 {
+            if rm == 6 {
+                rm = 8;
+                let immediate = read_16(state);
+                set_mem_operand(
+                    state,
+                    rm_oper,
+                    &RM16_COMPONENTS[rm as usize],
+                    i64::from(immediate),
+                    rm_size,
+                );
+            } else {
+                set_mem_operand(state, rm_oper, &RM16_COMPONENTS[rm as usize], 0, rm_size);
+            }
         }
-        if state.result.flags & X86Flag::LOCK != 0 {
-            // Ensure instruction allows lock and it has proper semantics
-            if state.flags & DecodeFlags::LOCK == 0 {
-                state.invalid = true;
-            } else if state.result.operation == InstructionOperation::CMP {
-                state.invalid = true;
-            } else if state.result.operands[0].operand != OperandType::MEM &&
-                       (state.result.operands[1].operand != OperandType::MEM)
-            {
-                state.invalid = true;
+        record_displacement(state, rm_oper, disp_start);
+        if let X86Operand::Memory(mem) = &mut state.result.operands[rm_oper] {
+            if mem.base.is_none() {
+                mem.displacement &= 0xffff;
+            }
+        }
+    } else {
+        let addr_reg_list = get_reg_list_for_addr_size(state);
+        let rm_reg_1_offset = reg_offset(addr_reg_list, state.rex_rm_1, state.rex2_rm_1);
+        let rm_reg_2_offset = reg_offset(addr_reg_list, state.rex_rm_2, state.rex2_rm_2);
+        if mod_ == 3 {
+            let offset = reg_offset(reg_list, state.rex_rm_1, state.rex2_rm_1);
+            set_reg_operand(state, rm_oper, reg_list[(rm + offset) as usize], rm_size);
+            return;
+        }
+        let mut components = [None, None];
+        let mut scale: u8 = 1;
+        let mut immediate: i64 = 0;
+        let seg: SegmentRegister;
+        let mut disp_start = state.pos;
+        if rm == 4 {
+            state.result.layout.sib = true;
+            let sib_byte: u8 = read_8(state);
+            disp_start = state.pos;
+            let base: u8 = sib_byte & 7;
+            let index: u8 = sib_byte >> 3 & 7;
+            scale = 1 << (sib_byte >> 6);
+            if mod_ != 0 || base != 5 {
+                components[0] = Some(addr_reg_list[(base + rm_reg_1_offset) as usize]);
+            }
+            if let Some(vsib) = state.vsib {
+                let vsib_offset = reg_offset(vsib, state.rex_rm_2, state.rex2_rm_2);
+                components[1] = Some(vsib[(index + vsib_offset) as usize]);
+            } else if index + rm_reg_2_offset != 4 {
+                components[1] = Some(addr_reg_list[(index + rm_reg_2_offset) as usize]);
             }
+            if mod_ == 2 {
+                immediate = read_signed_32(state);
+            } else if mod_ == 1 {
+                immediate = read_signed_8(state);
+            } else if mod_ == 0 && base == 5 {
+                immediate = read_signed_32(state);
+            }
+            if base + rm_reg_1_offset == 4 || base + rm_reg_1_offset == 5 {
+                seg = SegmentRegister::SS;
+            } else {
+                seg = SegmentRegister::DS;
+            }
+        } else if mod_ == 2 {
+            components[0] = Some(addr_reg_list[(rm + rm_reg_1_offset) as usize]);
+            immediate = read_signed_32(state);
+            seg = if rm == 5 {
+                SegmentRegister::SS
+            } else {
+                SegmentRegister::DS
+            };
+        } else if mod_ == 1 {
+            components[0] = Some(addr_reg_list[(rm + rm_reg_1_offset) as usize]);
+            immediate = read_signed_8(state);
+            seg = if rm == 5 {
+                SegmentRegister::SS
+            } else {
+                SegmentRegister::DS
+            };
+        } else {
+            if rm == 5 {
+                immediate = read_signed_32(state);
+                if state.addr_size == 8 {
+                    state.rip_rel_fixup = Some(rm_oper);
+                }
+            } else {
+                components[0] = Some(addr_reg_list[(rm + rm_reg_1_offset) as usize]);
+            }
+            seg = SegmentRegister::DS;
+        }
+        state.result.operands[rm_oper] = X86Operand::Memory(MemRef {
+            base: components[0],
+            index: components[1],
+            scale,
+            displacement: immediate,
+            displacement_size: 0,
+            segment: get_final_segment(state, seg),
+            size: rm_size,
+        });
+        record_displacement(state, rm_oper, disp_start);
+    }
+}
+
+fn decode_rm_reg(
+    state: &mut DecodeState,
+    rm_oper: usize,
+    rm_reg_list: &[OperandType],
+    rm_size: u16,
+    reg_oper: usize,
+    reg_list: &[OperandType],
+    reg_size: u16,
+) {
+    let mut reg: u8 = 0;
+    decode_rm(state, rm_oper, rm_reg_list, rm_size, &mut reg);
+    let offset = reg_offset(reg_list, state.rex_reg, state.rex2_reg);
+    set_reg_operand(state, reg_oper, reg_list[(reg + offset) as usize], reg_size);
+}
+
+fn decode_reg_rm(state: &mut DecodeState) {
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let size = match state.flags & DecodeFlags::REG_RM_SIZE_MASK {
+        0 => state.final_op_size,
+        DecodeFlags::REG_RM_2X_SIZE => state.final_op_size * 2,
+        DecodeFlags::REG_RM_FAR_SIZE => state.final_op_size + 2,
+        DecodeFlags::REG_RM_NO_SIZE => 0,
+        _ => panic!("This isn't possible. This shouldn't be needed to suppress a warning."),
+    };
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let final_op_size = state.final_op_size;
+    decode_rm_reg(
+        state,
+        operand1,
+        reg_list,
+        size,
+        operand0,
+        reg_list,
+        final_op_size,
+    );
+    if size != state.final_op_size && operand_type(state, state.operand1) != OperandType::MEM
+    {
+        set_invalid(state, InvalidReason::Operands);
+    }
+}
+
+fn read_final_op_size(state: &mut DecodeState) -> i64 {
+    if state.flags & DecodeFlags::IMM_SX != 0 {
+        read_signed_8(state)
+    } else {
+        match state.final_op_size {
+            8 => read_signed_32(state),
+            4 => i64::from(read_32(state)),
+            2 => i64::from(read_16(state)),
+            1 => i64::from(read_8(state)),
+            _ => 0,
+        }
+    }
+}
+
+fn final_op_size_is_signed(state: &DecodeState) -> bool {
+    state.flags & DecodeFlags::IMM_SX != 0 || state.final_op_size == 8
+}
+
+fn set_operand_to_imm(state: &mut DecodeState, oper: usize) {
+    let start = state.pos;
+    let value = read_final_op_size(state);
+    let size = state.final_op_size;
+    set_imm_operand(state, oper, value, size);
+    let signed = final_op_size_is_signed(state);
+    record_immediate(state, oper, start, signed);
+}
+
+fn decode_reg_rm_imm(state: &mut DecodeState) {
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let final_op_size = state.final_op_size;
+    decode_rm_reg(
+        state,
+        operand1,
+        reg_list,
+        final_op_size,
+        operand0,
+        reg_list,
+        final_op_size,
+    );
+    let imm_operand = 2;
+    set_operand_to_imm(state, imm_operand);
+}
+
+fn decode_rm_reg_imm_8(state: &mut DecodeState) {
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let final_op_size = state.final_op_size;
+    decode_rm_reg(
+        state,
+        operand0,
+        reg_list,
+        final_op_size,
+        operand1,
+        reg_list,
+        final_op_size,
+    );
+    let imm_operand = 2;
+    set_operand_to_imm_8(state, imm_operand);
+}
+
+fn decode_rm_reg_cl(state: &mut DecodeState) {
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let final_op_size = state.final_op_size;
+    decode_rm_reg(
+        state,
+        operand0,
+        reg_list,
+        final_op_size,
+        operand1,
+        reg_list,
+        final_op_size,
+    );
+    set_reg_operand(state, 2, OperandType::REG_CL, 1);
+}
+
+fn set_operand_to_eax_final_op_size(state: &mut DecodeState, oper: usize) {
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let size = state.final_op_size;
+    set_reg_operand(state, oper, reg_list[0], size);
+}
+
+fn decode_eax_imm(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    set_operand_to_eax_final_op_size(state, operand0);
+    let operand1 = state.operand1;
+    set_operand_to_imm(state, operand1);
+}
+
+fn last_opcode_byte(state: &DecodeState) -> u8 {
+    state.opcode[state.pos - 1]
+}
+
+fn decode_push_pop_seg(state: &mut DecodeState) {
+    let opcode = last_opcode_byte(state);
+    let offset: i32 = if opcode >= 0xa0 {
+        // FS/GS
+        -16
+    } else {
+        0
+    };
+    let reg = OperandType::from_i32(OperandType::REG_ES as i32 + i32::from(opcode >> 3) + offset);
+    set_reg_operand(state, state.operand0, reg, state.op_size);
+}
+
+fn set_operand_to_op_reg(state: &mut DecodeState, oper: usize) {
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let offset = reg_offset(reg_list, state.rex_rm_1, state.rex2_rm_1);
+    let reg = reg_list[usize::from((last_opcode_byte(state) & 7) + offset)];
+    set_reg_operand(state, oper, reg, state.final_op_size);
+}
+
+fn decode_op_reg(state: &mut DecodeState) {
+    set_operand_to_op_reg(state, state.operand0);
+}
+
+fn decode_eax_op_reg(state: &mut DecodeState) {
+    set_operand_to_eax_final_op_size(state, state.operand0);
+    set_operand_to_op_reg(state, state.operand1);
+}
+
+fn read_64(state: &mut DecodeState) -> u64 {
+    read_le(state, 8)
+}
+
+fn decode_op_reg_imm(state: &mut DecodeState) {
+    set_operand_to_op_reg(state, state.operand0);
+    let start = state.pos;
+    let operand1 = state.operand1;
+    let value = if state.final_op_size == 8 {
+        read_64(state) as i64
+    } else {
+        read_final_op_size(state)
+    };
+    set_imm_operand(state, operand1, value, state.final_op_size);
+    // A full 64 bit immediate needs no extension.
+    let signed = state.final_op_size != 8 && final_op_size_is_signed(state);
+    record_immediate(state, operand1, start, signed);
+}
+
+fn decode_nop(state: &mut DecodeState) {
+    if state.rex_rm_1 || state.rex2_rm_1 {
+        state.result.operation = InstructionOperation::XCHG;
+        decode_eax_op_reg(state);
+    }
+}
+
+fn decode_imm(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    set_operand_to_imm(state, operand0);
+}
+
+fn set_operand_to_imm_16(state: &mut DecodeState, oper: usize) {
+    let start = state.pos;
+    let value = i64::from(read_16(state));
+    set_imm_operand(state, oper, value, 2);
+    record_immediate(state, oper, start, false);
+}
+
+fn decode_imm_16_imm_8(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    set_operand_to_imm_16(state, operand0);
+    let operand1 = state.operand1;
+    set_operand_to_imm_8(state, operand1);
+}
+
+fn set_operand_to_es_edi(state: &mut DecodeState, oper: usize, size: u16) {
+    let addr_reg_list = get_reg_list_for_addr_size(state);
+    state.result.operands[oper] = X86Operand::Memory(MemRef {
+        base: Some(addr_reg_list[7]),
+        index: None,
+        scale: 1,
+        displacement: 0,
+        displacement_size: 0,
+        segment: SegmentRegister::ES,
+        size,
+    });
+}
+
+fn decode_edi_dx(state: &mut DecodeState) {
+    set_operand_to_es_edi(state, state.operand0, state.final_op_size);
+    set_reg_operand(state, state.operand1, OperandType::REG_DX, 2);
+}
+
+fn set_operand_to_ds_esi(state: &mut DecodeState, oper: usize, size: u16) {
+    let addr_reg_list = get_reg_list_for_addr_size(state);
+    state.result.operands[oper] = X86Operand::Memory(MemRef {
+        base: Some(addr_reg_list[6]),
+        index: None,
+        scale: 1,
+        displacement: 0,
+        displacement_size: 0,
+        segment: get_final_segment(state, SegmentRegister::DS),
+        size,
+    });
+}
+
+fn decode_dx_esi(state: &mut DecodeState) {
+    set_reg_operand(state, state.operand0, OperandType::REG_DX, 2);
+    set_operand_to_ds_esi(state, state.operand1, state.final_op_size);
+}
+
+fn read_signed_final_op_size(state: &mut DecodeState) -> i64 {
+    match state.final_op_size {
+        4 | 8 => read_signed_32(state),
+        2 => read_signed_16(state),
+        1 => read_signed_8(state),
+        _ => 0,
+    }
+}
+
+fn decode_rel_imm(state: &mut DecodeState) {
+    let start = state.pos;
+    let offset = read_signed_final_op_size(state);
+    let operand0 = state.operand0;
+    record_immediate(state, operand0, start, true);
+    let target = (offset as u64).wrapping_add(state.addr.wrapping_add(state.pos as u64));
+    state.result.operands[operand0] = X86Operand::RelativeTarget(target & address_mask(state));
+}
+
+/// The mask for relative targets, so that they wrap around at the
+/// same place as the instruction pointer regardless of the host. A 16
+/// bit operand size truncates the instruction pointer to 16 bits.
+fn address_mask(state: &DecodeState) -> u64 {
+    if state.op_size == 2 {
+        0xffff
+    } else if state.using64 {
+        u64::MAX
+    } else {
+        0xffff_ffff
+    }
+}
+
+fn update_operation_for_addr_size(state: &mut DecodeState) {
+    if state.addr_size == 4 {
+        state.result.operation = InstructionOperation::from_i32(state.result.operation as i32 + 1);
+    } else if state.addr_size == 8 {
+        state.result.operation = InstructionOperation::from_i32(state.result.operation as i32 + 2);
+    }
+}
+
+fn decode_rel_imm_addr_size(state: &mut DecodeState) {
+    decode_rel_imm(state);
+    update_operation_for_addr_size(state);
+}
+
+fn decode_group_rm(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let reg_size = state.final_op_size;
+    let mut reg_field: u8 = 0;
+    decode_rm(state, operand0, reg_list, reg_size, &mut reg_field);
+    state.result.operation = GROUP_OPERATIONS[state.result.operation as usize][reg_field as usize];
+}
+
+fn decode_group_rm_imm(state: &mut DecodeState) {
+    decode_group_rm(state);
+    let operand1 = state.operand1;
+    set_operand_to_imm(state, operand1);
+}
+
+fn decode_group_rm_imm_8v(state: &mut DecodeState) {
+    decode_group_rm(state);
+    let operand1 = state.operand1;
+    set_operand_to_imm_8(state, operand1);
+}
+
+fn decode_group_c6_c7(state: &mut DecodeState) {
+    if peek_8(state) != 0xf8 {
+        decode_group_rm_imm(state);
+        return;
+    }
+    // The register form of /7 with a zero r/m field is a transaction.
+    read_mod_rm(state);
+    if state.final_op_size == 1 {
+        state.result.operation = InstructionOperation::XABORT;
+        let operand0 = state.operand0;
+        set_operand_to_imm_8(state, operand0);
+    } else {
+        state.result.operation = InstructionOperation::XBEGIN;
+        if state.using64 {
+            state.op_size = 8;
         }
+        decode_rel_imm(state);
     }
 }
 
-fn process_opcode(state: &mut DecodeState, map: &[InstructionEncoding], opcode: u8) {
-    process_encoding(state, &map[opcode as usize]);
+fn decode_group_rm_one(state: &mut DecodeState) {
+    decode_group_rm(state);
+    set_imm_operand(state, state.operand1, 1, 1);
 }
 
-fn process_sparse_opcode(state: &mut DecodeState, map: &[SparseInstructionEncoding], opcode: u8) {
-    state.result.operation = InstructionOperation::INVALID;
-    if let Ok(idx) = map.binary_search_by_key(&opcode, |entry| entry.opcode) {
-        process_encoding(state, &map[idx].encoding);
+fn decode_group_rm_cl(state: &mut DecodeState) {
+    decode_group_rm(state);
+    set_reg_operand(state, state.operand1, OperandType::REG_CL, 1);
+}
+
+fn decode_group_f6f7(state: &mut DecodeState) {
+    decode_group_rm(state);
+    if state.result.operation == InstructionOperation::TEST {
+        let operand1 = state.operand1;
+        set_operand_to_imm(state, operand1);
+    }
+    // Check for valid locking semantics
+    if state.result.flags & X86Flag::LOCK != 0 &&
+        (state.result.operation != InstructionOperation::NOT) &&
+        (state.result.operation != InstructionOperation::NEG)
+    {
+        set_invalid(state, InvalidReason::Lock);
     }
 }
 
-fn set_operand_to_imm_8(state: &mut DecodeState, oper: *mut X86Operand) {
-    unsafe {
-        (*oper).operand = OperandType::IMM;
-        (*oper).size = 1u16;
-        (*oper).immediate = read_8(state) as isize;
+fn decode_group_ff(state: &mut DecodeState) {
+    if state.using64 {
+        // Default to 64-bit for jumps and calls
+        let rm: u8 = peek_8(state);
+        let reg_field: u8 = rm >> 3 & 7;
+        if (2..=5).contains(&reg_field) {
+            state.final_op_size = {
+                state.op_size = if state.op_prefix { 4 } else { 8 };
+                state.op_size
+            };
+        }
+    }
+    decode_group_rm(state);
+    // Check for valid jump/call semantics
+    if state.result.operation == InstructionOperation::CALLF ||
+        state.result.operation == InstructionOperation::JMPF
+    {
+        match &mut state.result.operands[state.operand0] {
+            X86Operand::Memory(mem) => mem.size += 2,
+            _ => set_invalid(state, InvalidReason::Operands),
+        }
+    }
+    // Check for valid locking semantics
+    if state.result.flags & X86Flag::LOCK != 0 &&
+        (state.result.operation != InstructionOperation::INC) &&
+        (state.result.operation != InstructionOperation::DEC)
+    {
+        set_invalid(state, InvalidReason::Lock);
     }
 }
 
-fn decode_two_byte(state: &mut DecodeState) {
-    let opcode: u8 = read_8(state);
-    if opcode == 0x38 {
-        let next_opcode = read_8(state);
-        process_sparse_opcode(state, &THREE_BYTE_0F38_MAP, next_opcode);
-    } else if opcode == 0x3a {
-        let next_opcode = read_8(state);
-        process_sparse_opcode(state, &THREE_BYTE_0F3A_MAP, next_opcode);
-        let operand = &mut state.result.operands[2] as *mut X86Operand;
-        set_operand_to_imm_8(state, operand);
+fn decode_group_0f00(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
+    let mod_field: u8 = rm >> 6 & 3;
+    let reg_field: u8 = rm >> 3 & 7;
+    if reg_field >= 2 || mod_field != 3 {
+        state.final_op_size = 2;
+    }
+    decode_group_rm(state);
+}
+
+fn decode_group_0f01(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
+    let mod_field: u8 = rm >> 6 & 3;
+    let reg_field: u8 = rm >> 3 & 7;
+    let rm_field: u8 = rm & 7;
+    if mod_field == 3 && reg_field != 4 && reg_field != 6 {
+        read_mod_rm(state);
+        state.result.operation = GROUP_0F01_REG_OPERATIONS[reg_field as usize][rm_field as usize];
+        if reg_field == 5 {
+            // The shadow stack instructions in this row need REPE.
+            if state.rep == RepPrefix::REPE {
+                state.rep_used = true;
+            } else {
+                state.result.operation = InstructionOperation::INVALID;
+            }
+        }
+    } else if reg_field == 5 && state.rep == RepPrefix::REPE {
+        state.rep_used = true;
+        state.result.operation = InstructionOperation::RSTORSSP;
+        state.final_op_size = 8;
+        decode_rm_final_op_size(state);
     } else {
-        process_opcode(state, &TWO_BYTE_OPCODE_MAP, opcode);
+        if reg_field < 4 {
+            state.final_op_size = if state.using64 { 10 } else { 6 };
+        } else if reg_field == 7 {
+            state.final_op_size = 1;
+        } else if reg_field != 4 || mod_field != 3 {
+            // Only SMSW to a register uses the operand size.
+            state.final_op_size = 2;
+        }
+        decode_group_rm(state);
     }
 }
 
-fn peek_8(state: &mut DecodeState) -> u8 {
-    if state.len < 1 {
-        state.invalid = true;
-        state.insufficient_length = true;
-        state.len = 0;
-        0xcc
+fn decode_group_0f1e(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
+    if state.rep != RepPrefix::REPE || (rm != 0xfa && rm != 0xfb && rm & 0xf8 != 0xc8) {
+        decode_group_rm(state);
+        return;
+    }
+    // REPE turns some of these hint NOPs into CET instructions.
+    state.rep_used = true;
+    if rm == 0xfa {
+        read_mod_rm(state);
+        state.result.operation = InstructionOperation::ENDBR64;
+    } else if rm == 0xfb {
+        read_mod_rm(state);
+        state.result.operation = InstructionOperation::ENDBR32;
     } else {
-        unsafe { *state.opcode }
+        set_operation_for_rex_w(state, InstructionOperation::RDSSPD);
+        decode_rm_final_op_size(state);
     }
 }
 
-fn decode_fpu(state: &mut DecodeState) {
-    let mod_rm = peek_8(state);
-    let reg = mod_rm >> 3 & 7;
-    let op = state.result.operation as u8;
-    let map = if mod_rm & 0xc0 == 0xc0 {
-        &FPU_REG_OPCODE_MAP[op as usize]
+fn decode_group_0fae(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
+    let mod_field: u8 = rm >> 6 & 3;
+    let reg_field: u8 = rm >> 3 & 7;
+    if mod_field == 3 {
+        if reg_field == 5 && state.rep == RepPrefix::REPE {
+            state.rep_used = true;
+            set_operation_for_rex_w(state, InstructionOperation::INCSSPD);
+            decode_rm_final_op_size(state);
+        } else {
+            read_mod_rm(state);
+            state.result.operation = GROUP_OPERATIONS[state.result.operation as usize + 1]
+                [reg_field as usize];
+        }
+    } else if reg_field == 6 && state.rep == RepPrefix::REPE {
+        state.rep_used = true;
+        state.result.operation = InstructionOperation::CLRSSBSY;
+        state.final_op_size = 8;
+        decode_rm_final_op_size(state);
+    } else if reg_field >= 6 && state.op_prefix {
+        state.op_prefix_used = true;
+        state.result.operation = if reg_field == 6 {
+            InstructionOperation::CLWB
+        } else {
+            InstructionOperation::CLFLUSHOPT
+        };
+        state.final_op_size = 1;
+        decode_rm_final_op_size(state);
     } else {
-        &FPU_MEM_OPCODE_MAP[op as usize]
-    };
-    process_encoding(state, &map[reg as usize]);
+        state.final_op_size = match reg_field {
+            2 | 3 => 4,
+            7 => 1,
+            _ => 512,
+        };
+        decode_group_rm(state);
+    }
 }
 
-fn decode_no_operands(_state: &mut DecodeState) {}
+fn decode_0fb8(state: &mut DecodeState) {
+    if state.rep != RepPrefix::REPE {
+        if state.using64 {
+            state.op_size = if state.op_prefix { 4 } else { 8 };
+        }
+        state.final_op_size = get_final_op_size(state);
+        decode_rel_imm(state);
+    } else {
+        state.rep_used = true;
+        decode_reg_rm(state);
+    }
+}
 
-fn get_byte_reg_list(state: &DecodeState) -> &'static [OperandType] {
-    if state.rex { &REG8_LIST64 } else { &REG8_LIST }
+fn decode_bsf_bsr(state: &mut DecodeState) {
+    // Like POPCNT, TZCNT and LZCNT are given by a REPE prefix.
+    if state.rep == RepPrefix::REPE {
+        state.rep_used = true;
+        state.result.operation = if state.result.operation == InstructionOperation::BSF {
+            InstructionOperation::TZCNT
+        } else {
+            InstructionOperation::LZCNT
+        };
+    }
+    decode_reg_rm(state);
+}
+
+fn get_reg_list_for_op_size(state: &DecodeState) -> &'static [OperandType] {
+    match state.op_size {
+        8 => &REG64_LIST,
+        4 => &REG32_LIST,
+        2 => &REG16_LIST,
+        _ => &INVALID_REG_LIST,
+    }
+}
+
+fn decode_rms_reg_v(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let reg_list = get_reg_list_for_op_size(state);
+    let reg_size = state.op_size;
+    let mut reg_field: u8 = 0;
+    decode_rm(state, operand0, reg_list, reg_size, &mut reg_field);
+    if reg_field >= 6 {
+        // There are only six segment registers.
+        set_invalid(state, InvalidReason::Operands);
+        return;
+    }
+    let reg = OperandType::from_i32(OperandType::REG_ES as i32 + i32::from(reg_field));
+    set_reg_operand(state, state.operand1, reg, 2);
+    if operand_type(state, 0) == OperandType::REG_CS {
+        set_invalid(state, InvalidReason::Operands);
+    }
+}
+
+fn decode_rm8(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let reg_list = get_byte_reg_list(state);
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, reg_list, 1, &mut _ignore);
 }
 
-fn get_reg_list_for_final_op_size(state: &DecodeState) -> &'static [OperandType] {
-    match state.final_op_size {
-        8 => &REG64_LIST,
-        4 => &REG32_LIST,
-        2 => &REG16_LIST,
-        1 => get_byte_reg_list(state),
-        _ => &INVALID_REG_LIST,
-    }
+fn decode_rmv(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let reg_list = get_reg_list_for_op_size(state);
+    let reg_size = state.op_size;
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, reg_list, reg_size, &mut _ignore);
 }
 
-fn get_reg_list_for_addr_size(state: &DecodeState) -> &'static [OperandType] {
-    match state.addr_size {
-        8 => &REG64_LIST,
-        4 => &REG32_LIST,
-        2 => &REG16_LIST,
-        _ => &INVALID_REG_LIST,
-    }
+fn decode_rm_final_op_size(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let reg_size = state.final_op_size;
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, reg_list, reg_size, &mut _ignore);
 }
 
-fn read_32(state: &mut DecodeState) -> u32 {
-    let val: u32;
-    if state.len < 4 {
-        state.invalid = true;
-        state.insufficient_length = true;
-        state.len = 0;
-        0
+/// Use `operation` for 32 bit operands, or the operation after it
+/// for 64 bit operands when REX.W is set.
+fn set_operation_for_rex_w(state: &mut DecodeState, operation: InstructionOperation) {
+    if state.op_size == 8 {
+        state.final_op_size = 8;
+        state.result.operation = InstructionOperation::from_i32(operation as i32 + 1);
     } else {
-        unsafe {
-            val = *(state.opcode as *mut u32);
-            state.opcode = state.opcode.offset(4);
-            state.len = state.len.wrapping_sub(4);
-            val
-        }
+        state.final_op_size = 4;
+        state.result.operation = operation;
     }
 }
 
-fn read_signed_32(state: &mut DecodeState) -> isize {
-    read_32(state) as i32 as isize
+fn decode_far_imm(state: &mut DecodeState) {
+    // The offset comes before the segment selector.
+    let start = state.pos;
+    let off = read_final_op_size(state) as u64;
+    record_immediate(state, state.operand1, start, false);
+    let start = state.pos;
+    let seg = read_16(state);
+    record_immediate(state, state.operand0, start, false);
+    state.result.operands[state.operand0] = X86Operand::FarPointer { seg, off };
 }
 
-fn read_signed_8(state: &mut DecodeState) -> isize {
-    read_8(state) as i8 as isize
+fn read_addr_size(state: &mut DecodeState) -> i64 {
+    match state.addr_size {
+        8 => read_64(state) as i64,
+        4 => i64::from(read_32(state)),
+        2 => i64::from(read_16(state)),
+        _ => 0,
+    }
 }
 
-fn get_final_segment(state: &DecodeState, seg: SegmentRegister) -> SegmentRegister {
-    if state.result.segment == SegmentRegister::DEFAULT {
-        seg
-    } else {
-        state.result.segment
-    }
+fn set_operand_to_imm_addr(state: &mut DecodeState, oper: usize) {
+    let start = state.pos;
+    let displacement = read_addr_size(state);
+    state.result.operands[oper] = X86Operand::Memory(MemRef {
+        base: None,
+        index: None,
+        scale: 1,
+        displacement,
+        displacement_size: 0,
+        segment: get_final_segment(state, SegmentRegister::DS),
+        size: state.final_op_size,
+    });
+    record_displacement(state, oper, start);
 }
 
-#[derive(Debug)]
-#[repr(C)]
-struct RMDef {
-    pub first: OperandType,
-    pub second: OperandType,
-    pub segment: SegmentRegister,
+fn decode_eax_addr(state: &mut DecodeState) {
+    set_operand_to_eax_final_op_size(state, state.operand0);
+    let operand1 = state.operand1;
+    set_operand_to_imm_addr(state, operand1);
 }
 
-fn set_mem_operand(state: &DecodeState, oper: *mut X86Operand, def: &RMDef, immed: isize) {
-    unsafe {
-        (*oper).operand = OperandType::MEM;
-        (*oper).components[0] = def.first;
-        (*oper).components[1] = def.second;
-        (*oper).immediate = immed;
-        (*oper).segment = get_final_segment(state, def.segment);
-    }
+fn decode_edi_esi(state: &mut DecodeState) {
+    set_operand_to_es_edi(state, state.operand0, state.final_op_size);
+    set_operand_to_ds_esi(state, state.operand1, state.final_op_size);
 }
 
-fn read_16(state: &mut DecodeState) -> u16 {
-    let val: u16;
-    if state.len < 2 {
-        state.invalid = true;
-        state.insufficient_length = true;
-        state.len = 0;
-        0
-    } else {
-        unsafe {
-            val = *(state.opcode as *mut u16);
-            state.opcode = state.opcode.offset(2);
-            state.len = state.len.wrapping_sub(2);
-            val
-        }
-    }
+fn decode_edi_eax(state: &mut DecodeState) {
+    set_operand_to_es_edi(state, state.operand0, state.final_op_size);
+    set_operand_to_eax_final_op_size(state, state.operand1);
 }
 
-fn read_signed_16(state: &mut DecodeState) -> isize {
-    read_16(state) as i16 as isize
+fn decode_eax_esi(state: &mut DecodeState) {
+    set_operand_to_eax_final_op_size(state, state.operand0);
+    set_operand_to_ds_esi(state, state.operand1, state.final_op_size);
 }
 
-fn decode_rm(
-    state: &mut DecodeState,
-    mut rm_oper: *mut X86Operand,
-    reg_list: &[OperandType],
-    rm_size: u16,
-    reg_oper: &mut u8,
-) {
-    let rm_byte: u8 = read_8(state);
-    let mod_: u8 = rm_byte >> 6;
-    let mut rm: u8 = rm_byte & 7;
-    let mut temp = X86Operand::default();
-    *reg_oper = rm_byte >> 3 & 7;
-    unsafe {
-        if rm_oper.is_null() {
-            rm_oper = &mut temp as *mut X86Operand;
-        }
-        (*rm_oper).size = rm_size;
-    }
-    if state.addr_size == 2 {
-        static RM16_COMPONENTS: [RMDef; 9] = [
-            RMDef {
-                first: OperandType::REG_BX,
-                second: OperandType::REG_SI,
-                segment: SegmentRegister::DS,
-            },
-            RMDef {
-                first: OperandType::REG_BX,
-                second: OperandType::REG_DI,
-                segment: SegmentRegister::DS,
-            },
-            RMDef {
-                first: OperandType::REG_BP,
-                second: OperandType::REG_SI,
-                segment: SegmentRegister::SS,
-            },
-            RMDef {
-                first: OperandType::REG_BP,
-                second: OperandType::REG_DI,
-                segment: SegmentRegister::SS,
-            },
-            RMDef {
-                first: OperandType::REG_SI,
-                second: OperandType::NONE,
-                segment: SegmentRegister::DS,
-            },
-            RMDef {
-                first: OperandType::REG_DI,
-                second: OperandType::NONE,
-                segment: SegmentRegister::DS,
-            },
-            RMDef {
-                first: OperandType::REG_BP,
-                second: OperandType::NONE,
-                segment: SegmentRegister::SS,
-            },
-            RMDef {
-                first: OperandType::REG_BX,
-                second: OperandType::NONE,
-                segment: SegmentRegister::DS,
-            },
-            RMDef {
-                first: OperandType::NONE,
-                second: OperandType::NONE,
-                segment: SegmentRegister::DS,
-            },
-        ];
-        if mod_ == 3 {
-            unsafe {
-                (*rm_oper).operand = reg_list[rm as usize];
-            }
-        } else if mod_ == 2 {
-            let immediate = read_signed_16(state);
-            set_mem_operand(state, rm_oper, &RM16_COMPONENTS[rm as usize], immediate);
-        } else if mod_ == 1 {
-            let immediate = read_signed_8(state);
-            set_mem_operand(state, rm_oper, &RM16_COMPONENTS[rm as usize], immediate);
-        } else if mod_ == 0 {
-            if rm == 6 {
-                rm = 8;
-                let immediate = read_16(state);
-                set_mem_operand(
-                    state,
-                    rm_oper,
-                    &RM16_COMPONENTS[rm as usize],
-                    immediate as isize,
-                );
-            } else {
-                set_mem_operand(state, rm_oper, &RM16_COMPONENTS[rm as usize], 0);
-            }
-        }
-        unsafe {
-            if (*rm_oper).components[0] == OperandType::NONE {
-                (*rm_oper).immediate &= 0xffff;
-            }
-        }
-    } else {
-        let addr_reg_list = get_reg_list_for_addr_size(state);
-        let rm_reg_1_offset: u8 = if state.rex_rm_1 { 8 } else { 0 };
-        let rm_reg_2_offset: u8 = if state.rex_rm_2 { 8 } else { 0 };
-        let mut seg: SegmentRegister = SegmentRegister::DEFAULT;
-        unsafe {
-            (*rm_oper).operand = OperandType::MEM;
-            if mod_ != 3 && rm == 4 {
-                let sib_byte: u8 = read_8(state);
-                let base: u8 = sib_byte & 7;
-                let index: u8 = sib_byte >> 3 & 7;
-                (*rm_oper).scale = 1 << sib_byte >> 6;
-                if mod_ != 0 || base != 5 {
-                    (*rm_oper).components[0] = addr_reg_list[(base + rm_reg_1_offset) as usize];
-                }
-                if index + rm_reg_2_offset != 4 {
-                    (*rm_oper).components[1] = addr_reg_list[(index + rm_reg_2_offset) as usize];
-                }
-                if mod_ == 2 {
-                    (*rm_oper).immediate = read_signed_32(state);
-                } else if mod_ == 1 {
-                    (*rm_oper).immediate = read_signed_8(state);
-                } else if mod_ == 0 && base == 5 {
-                    (*rm_oper).immediate = read_signed_32(state);
-                }
-                if base + rm_reg_1_offset == 4 || base + rm_reg_1_offset == 5 {
-                    seg = SegmentRegister::SS;
-                } else {
-                    seg = SegmentRegister::DS;
-                }
-            } else if mod_ == 3 {
-                (*rm_oper).operand = reg_list[(rm + rm_reg_1_offset) as usize];
-            } else if mod_ == 2 {
-                (*rm_oper).components[0] = addr_reg_list[(rm + rm_reg_1_offset) as usize];
-                (*rm_oper).immediate = read_signed_32(state);
-                seg = if rm == 5 {
-                    SegmentRegister::SS
-                } else {
-                    SegmentRegister::DS
-                };
-            } else if mod_ == 1 {
-                (*rm_oper).components[0] = addr_reg_list[(rm + rm_reg_1_offset) as usize];
-                (*rm_oper).immediate = read_signed_8(state);
-                seg = if rm == 5 {
-                    SegmentRegister::SS
-                } else {
-                    SegmentRegister::DS
-                };
-            } else if mod_ == 0 {
-                if rm == 5 {
-                    (*rm_oper).immediate = read_signed_32(state);
-                    if state.addr_size == 8 {
-                        state.rip_rel_fixup = &mut (*rm_oper).immediate as *mut isize;
-                    }
-                } else {
-                    (*rm_oper).components[0] = addr_reg_list[(rm + rm_reg_1_offset) as usize];
-                }
-                seg = SegmentRegister::DS;
-            }
-            if seg != SegmentRegister::DEFAULT {
-                (*rm_oper).segment = get_final_segment(state, seg);
-            }
-        }
-    }
+fn decode_al_ebx_al(state: &mut DecodeState) {
+    let reg_list = get_reg_list_for_addr_size(state);
+    set_reg_operand(state, state.operand0, OperandType::REG_AL, 1);
+    state.result.operands[state.operand1] = X86Operand::Memory(MemRef {
+        base: Some(reg_list[3]),
+        index: Some(OperandType::REG_AL),
+        scale: 1,
+        displacement: 0,
+        displacement_size: 0,
+        segment: get_final_segment(state, SegmentRegister::DS),
+        size: 1,
+    });
 }
 
-fn decode_rm_reg(
-    state: &mut DecodeState,
-    rm_oper: *mut X86Operand,
-    rm_reg_list: &[OperandType],
-    rm_size: u16,
-    reg_oper: *mut X86Operand,
-    reg_list: &[OperandType],
-    reg_size: u16,
-) {
-    let mut reg: u8 = 0;
-    decode_rm(state, rm_oper, rm_reg_list, rm_size, &mut reg);
-    unsafe {
-        if !reg_oper.is_null() {
-            let reg_offset: u8 = if state.rex_reg { 8 } else { 0 };
-            (*reg_oper).size = reg_size;
-            (*reg_oper).operand = reg_list[(reg + reg_offset) as usize];
-        }
-    }
+fn decode_eax_imm_8(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    set_operand_to_eax_final_op_size(state, operand0);
+    let operand1 = state.operand1;
+    set_operand_to_imm_8(state, operand1);
 }
 
-fn decode_reg_rm(state: &mut DecodeState) {
-    let reg_list = get_reg_list_for_final_op_size(state);
-    let size = match state.flags & DecodeFlags::REG_RM_SIZE_MASK {
-        0 => state.final_op_size,
-        DecodeFlags::REG_RM_2X_SIZE => state.final_op_size * 2,
-        DecodeFlags::REG_RM_FAR_SIZE => state.final_op_size + 2,
-        DecodeFlags::REG_RM_NO_SIZE => 0,
-        _ => panic!("This isn't possible. This shouldn't be needed to suppress a warning."),
-    };
+fn decode_eax_dx(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    set_operand_to_eax_final_op_size(state, operand0);
+    set_reg_operand(state, state.operand1, OperandType::REG_DX, 2);
+}
+
+fn decode_3dnow(state: &mut DecodeState) {
     let operand0 = state.operand0;
     let operand1 = state.operand1;
-    let final_op_size = state.final_op_size;
     decode_rm_reg(
         state,
         operand1,
-        reg_list,
-        size,
+        &MMX_REG_LIST,
+        8,
         operand0,
-        reg_list,
-        final_op_size,
+        &MMX_REG_LIST,
+        8,
     );
-    unsafe {
-        if size != state.final_op_size && ((*state.operand1).operand != OperandType::MEM) {
-            state.invalid = true;
+    let op = read_8(state);
+    state.result.operation =
+        match SPARSE_3DNOW_OPCODES.binary_search_by_key(&op, |entry| entry.opcode) {
+            Ok(idx) => SPARSE_3DNOW_OPCODES[idx].operation,
+            Err(_) => InstructionOperation::INVALID,
         }
+}
+
+fn decode_sse_prefix(state: &mut DecodeState) -> u8 {
+    if state.op_prefix {
+        state.op_prefix = false;
+        state.op_prefix_used = true;
+        1
+    } else if state.rep == RepPrefix::REPNE {
+        state.rep = RepPrefix::NONE;
+        state.rep_used = true;
+        2
+    } else if state.rep == RepPrefix::REPE {
+        state.rep = RepPrefix::NONE;
+        state.rep_used = true;
+        3
+    } else {
+        0
+    }
+}
+
+fn get_operand_for_sse_entry_type(
+    state: &DecodeState,
+    entry_type: SSETableOperandType,
+    operand_index: u8,
+) -> usize {
+    let operand_index = if entry_type == SSETableOperandType::SSE_128_FLIP ||
+        entry_type == SSETableOperandType::SSE_64_FLIP
+    {
+        1 - operand_index
+    } else {
+        operand_index
+    };
+    if operand_index == 0 {
+        state.operand0
+    } else {
+        state.operand1
+    }
+}
+
+fn get_reg_list_for_sse_entry_type(
+    state: &DecodeState,
+    entry_type: SSETableOperandType,
+) -> &'static [OperandType] {
+    if entry_type == SSETableOperandType::GPR_32_OR_64 {
+        if state.op_size == 8 {
+             &REG64_LIST
+         } else {
+             &REG32_LIST
+         }
+    } else if entry_type == SSETableOperandType::GPR_32 {
+        &REG32_LIST
+    } else if entry_type == SSETableOperandType::MMX_64 ||
+               entry_type == SSETableOperandType::MMX_32
+    {
+        &MMX_REG_LIST
+    } else {
+        &XMM_REG_LIST
     }
 }
 
-fn read_final_op_size(state: &mut DecodeState) -> isize {
-    if state.flags & DecodeFlags::IMM_SX != 0 {
-        read_signed_8(state)
-    } else {
-        match state.final_op_size {
-            8 => read_signed_32(state),
-            4 => read_32(state) as isize,
-            2 => read_16(state) as isize,
-            1 => read_8(state) as isize,
-            _ => 0,
-        }
+fn get_size_for_sse_entry_type(state: &DecodeState, entry_type: SSETableOperandType) -> u16 {
+    match entry_type {
+        SSETableOperandType::GPR_32_OR_64 => if state.op_size == 8 { 8 } else { 4 },
+        SSETableOperandType::MMX_64 |
+        SSETableOperandType::SSE_64 |
+        SSETableOperandType::SSE_64_FLIP => 8,
+        SSETableOperandType::GPR_32 |
+        SSETableOperandType::MMX_32 |
+        SSETableOperandType::SSE_32 => 4,
+        SSETableOperandType::SSE_16 => 2,
+        _ => 16,
     }
 }
 
-fn set_operand_to_imm(state: &mut DecodeState, oper: *mut X86Operand) {
-    unsafe {
-        (*oper).operand = OperandType::IMM;
-        (*oper).size = state.final_op_size;
-        (*oper).immediate = read_final_op_size(state);
+fn update_operation_for_sse_entry_type(state: &mut DecodeState, entry_type: SSETableOperandType) {
+    if entry_type == SSETableOperandType::GPR_32_OR_64 && (state.op_size == 8) {
+        state.result.operation =
+            InstructionOperation::from_i32(state.result.operation as i32 + 1);
     }
 }
 
-fn decode_reg_rm_imm(state: &mut DecodeState) {
-    let reg_list = get_reg_list_for_final_op_size(state);
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    let final_op_size = state.final_op_size;
+fn decode_sse_table(state: &mut DecodeState) {
+    let entry_type = decode_sse_prefix(state) as usize;
+    let rm: u8 = peek_8(state);
+    let mod_field: u8 = rm >> 6 & 3;
+    let entry = &SSE_TABLE[state.result.operation as usize];
+    let op_entry = if mod_field == 3 {
+        &entry.reg_ops[entry_type]
+    } else {
+        &entry.mem_ops[entry_type]
+    };
+    state.result.operation = op_entry.operation;
+    let operand1 = get_operand_for_sse_entry_type(state, op_entry.rm_type, 1);
+    let rm_reg_list = get_reg_list_for_sse_entry_type(state, op_entry.rm_type);
+    let rm_reg_size = get_size_for_sse_entry_type(state, op_entry.rm_type);
+    let operand0 = get_operand_for_sse_entry_type(state, op_entry.reg_type, 0);
+    let reg_list = get_reg_list_for_sse_entry_type(state, op_entry.reg_type);
+    let reg_size = get_size_for_sse_entry_type(state, op_entry.reg_type);
     decode_rm_reg(
         state,
         operand1,
-        reg_list,
-        final_op_size,
+        rm_reg_list,
+        rm_reg_size,
         operand0,
         reg_list,
-        final_op_size,
+        reg_size,
     );
-    let imm_operand = &mut state.result.operands[2] as *mut X86Operand;
-    set_operand_to_imm(state, imm_operand);
+    if state.flags & DecodeFlags::INC_OPERATION_FOR_64 != 0 {
+        update_operation_for_sse_entry_type(state, op_entry.reg_type);
+        update_operation_for_sse_entry_type(state, op_entry.rm_type);
+    }
 }
 
-fn decode_rm_reg_imm_8(state: &mut DecodeState) {
-    let reg_list = get_reg_list_for_final_op_size(state);
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    let final_op_size = state.final_op_size;
-    decode_rm_reg(
-        state,
-        operand0,
-        reg_list,
-        final_op_size,
-        operand1,
-        reg_list,
-        final_op_size,
-    );
-    let imm_operand = &mut state.result.operands[2] as *mut X86Operand;
-    set_operand_to_imm_8(state, imm_operand);
+fn decode_see_table_imm_8(state: &mut DecodeState) {
+    decode_sse_table(state);
+    let operand = 2;
+    set_operand_to_imm_8(state, operand);
 }
 
-fn decode_rm_reg_cl(state: &mut DecodeState) {
-    let reg_list = get_reg_list_for_final_op_size(state);
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    let final_op_size = state.final_op_size;
-    decode_rm_reg(
-        state,
-        operand0,
-        reg_list,
-        final_op_size,
-        operand1,
-        reg_list,
-        final_op_size,
-    );
-    state.result.operands[2].operand = OperandType::REG_CL;
-    state.result.operands[2].size = 1;
+fn decode_sse_table_mem_8(state: &mut DecodeState) {
+    decode_sse_table(state);
+    set_mem_size(state, state.operand0, 1);
+    set_mem_size(state, state.operand1, 1);
 }
 
-fn set_operand_to_eax_final_op_size(state: &DecodeState, oper: *mut X86Operand) {
-    let reg_list = get_reg_list_for_final_op_size(state);
-    unsafe {
-        (*oper).operand = reg_list[0];
-        (*oper).size = state.final_op_size;
+fn get_size_for_sse_type(type_: u8) -> u16 {
+    match type_ {
+        2 => 8,
+        3 => 4,
+        _ => 16,
     }
 }
 
-fn decode_eax_imm(state: &mut DecodeState) {
+fn decode_sse(state: &mut DecodeState) {
+    let type_: u8 = decode_sse_prefix(state);
+    let rm: u8 = peek_8(state);
+    let mod_field: u8 = rm >> 6 & 3;
+    state.result.operation =
+        InstructionOperation::from_i32(state.result.operation as i32 + i32::from(type_));
+    let size: u16 = if mod_field == 3 {
+        16
+    } else {
+        get_size_for_sse_type(type_)
+    };
     let operand0 = state.operand0;
-    set_operand_to_eax_final_op_size(state, operand0);
     let operand1 = state.operand1;
-    set_operand_to_imm(state, operand1);
+    decode_rm_reg(
+        state,
+        operand1,
+        &XMM_REG_LIST,
+        size,
+        operand0,
+        &XMM_REG_LIST,
+        16,
+    );
 }
 
-fn decode_push_pop_seg(state: &mut DecodeState) {
-    unsafe {
-        let offset: i32 = if *state.opcode.offset(-1) >= 0xa0 {
-            // FS/GS
-            -16
+fn decode_sse_single(state: &mut DecodeState) {
+    let type_: u8 = decode_sse_prefix(state);
+    if type_ == 1 || type_ == 2 {
+        set_invalid(state, InvalidReason::Opcode);
+    } else {
+        state.result.operation =
+            InstructionOperation::from_i32(state.result.operation as i32 + i32::from((type_) & 1));
+        let rm: u8 = peek_8(state);
+        let size: u16 = if rm >> 6 == 3 {
+            16
         } else {
-            0
+            get_size_for_sse_type(type_)
         };
-        (*state.operand0).operand = OperandType::from_i32(
-            OperandType::REG_ES as i32 + i32::from((*state.opcode.offset(-1)) >> 3) +
-                offset,
+        let operand0 = state.operand0;
+        let operand1 = state.operand1;
+        decode_rm_reg(
+            state,
+            operand1,
+            &XMM_REG_LIST,
+            size,
+            operand0,
+            &XMM_REG_LIST,
+            16,
         );
-        (*state.operand0).size = state.op_size;
     }
 }
 
-fn set_operand_to_op_reg(state: &DecodeState, oper: *mut X86Operand) {
-    let reg_list = get_reg_list_for_final_op_size(state);
-    let reg_offset: usize = if state.rex_rm_1 { 8 } else { 0 };
-    unsafe {
-        (*oper).operand = reg_list[(*state.opcode.offset(-1) as usize & 7) + reg_offset];
-        (*oper).size = state.final_op_size;
+fn decode_sse_packed(state: &mut DecodeState) {
+    let type_: u8 = decode_sse_prefix(state);
+    if type_ == 2 || type_ == 3 {
+        set_invalid(state, InvalidReason::Opcode);
+    } else {
+        state.result.operation =
+            InstructionOperation::from_i32(state.result.operation as i32 + i32::from((type_) & 1));
+        let operand0 = state.operand0;
+        let operand1 = state.operand1;
+        decode_rm_reg(
+            state,
+            operand1,
+            &XMM_REG_LIST,
+            16,
+            operand0,
+            &XMM_REG_LIST,
+            16,
+        );
     }
 }
 
-fn decode_op_reg(state: &mut DecodeState) {
-    set_operand_to_op_reg(state, state.operand0);
-}
-
-fn decode_eax_op_reg(state: &mut DecodeState) {
-    set_operand_to_eax_final_op_size(state, state.operand0);
-    set_operand_to_op_reg(state, state.operand1);
+fn decode_mmx(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    if state.op_prefix {
+        state.op_prefix_used = true;
+        decode_rm_reg(
+            state,
+            operand1,
+            &XMM_REG_LIST,
+            16,
+            operand0,
+            &XMM_REG_LIST,
+            16,
+        );
+    } else {
+        decode_rm_reg(
+            state,
+            operand1,
+            &MMX_REG_LIST,
+            8,
+            operand0,
+            &MMX_REG_LIST,
+            8,
+        );
+    }
 }
 
-fn read_64(state: &mut DecodeState) -> usize {
-    if state.len < 8 {
-        state.invalid = true;
-        state.insufficient_length = true;
-        state.len = 0;
-        0
+fn decode_mmx_sse_only(state: &mut DecodeState) {
+    // A REPNE or REPE prefix takes priority over the operand size
+    // prefix, and selects a different instruction.
+    if state.op_prefix && state.rep == RepPrefix::NONE {
+        state.op_prefix_used = true;
+        let operand0 = state.operand0;
+        let operand1 = state.operand1;
+        decode_rm_reg(
+            state,
+            operand1,
+            &XMM_REG_LIST,
+            16,
+            operand0,
+            &XMM_REG_LIST,
+            16,
+        );
     } else {
-        unsafe {
-            let old_val = (*state.opcode) as usize;
-            state.opcode = state.opcode.offset(8);
-            state.len = state.len.wrapping_sub(8);
-            old_val
-        }
+        set_invalid(state, InvalidReason::Opcode);
     }
 }
 
-fn decode_op_reg_imm(state: &mut DecodeState) {
-    set_operand_to_op_reg(state, state.operand0);
-    unsafe {
-        (*state.operand1).operand = OperandType::IMM;
-        (*state.operand1).size = state.final_op_size;
-        (*state.operand1).immediate = if state.op_size == 8 {
-            read_64(state) as isize
-        } else {
-            read_final_op_size(state)
-        };
+fn decode_sse_no_prefix(state: &mut DecodeState) {
+    if state.op_prefix || state.rep != RepPrefix::NONE {
+        set_invalid(state, InvalidReason::Opcode);
+    } else {
+        let operand0 = state.operand0;
+        let operand1 = state.operand1;
+        decode_rm_reg(
+            state,
+            operand1,
+            &XMM_REG_LIST,
+            16,
+            operand0,
+            &XMM_REG_LIST,
+            16,
+        );
     }
 }
 
-fn decode_nop(state: &mut DecodeState) {
-    if state.rex_rm_1 {
-        state.result.operation = InstructionOperation::XCHG;
-        decode_eax_op_reg(state);
+fn decode_mmx_group(state: &mut DecodeState) {
+    let mut reg_field: u8 = 0;
+    if state.op_prefix {
+        state.op_prefix_used = true;
+        let operand0 = state.operand0;
+        decode_rm(state, operand0, &XMM_REG_LIST, 16, &mut reg_field);
+        state.result.operation =
+            MMX_GROUP_OPERATIONS[state.result.operation as usize][reg_field as usize][1];
+    } else {
+        let operand0 = state.operand0;
+        decode_rm(state, operand0, &MMX_REG_LIST, 8, &mut reg_field);
+        state.result.operation =
+            MMX_GROUP_OPERATIONS[state.result.operation as usize][reg_field as usize][0];
+    }
+    if operand_type(state, state.operand0) == OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
+    let operand1 = state.operand1;
+    set_operand_to_imm_8(state, operand1);
 }
 
-fn decode_imm(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    set_operand_to_imm(state, operand0);
+fn decode_pinsrw(state: &mut DecodeState) {
+    decode_see_table_imm_8(state);
+    set_mem_size(state, state.operand1, 2);
 }
 
-fn set_operand_to_imm_16(state: &mut DecodeState, oper: *mut X86Operand) {
-    unsafe {
-        (*oper).operand = OperandType::IMM;
-        (*oper).size = 2;
-        (*oper).immediate = read_16(state) as isize;
+fn decode_reg_cr(state: &mut DecodeState) {
+    if state.op_size == 2 {
+        state.op_size = 4;
+    }
+    let reg_list = get_reg_list_for_op_size(state);
+    let reg = read_mod_rm(state);
+    if state.result.flags & X86Flag::LOCK != 0 {
+        state.result.flags &= !X86Flag::LOCK;
+        state.rex_reg = true;
     }
+    let rm_offset = reg_offset(reg_list, state.rex_rm_1, state.rex2_rm_1);
+    set_reg_operand(state, state.operand0, reg_list[((reg & 7) + rm_offset) as usize], state.op_size);
+    let control = OperandType::from_i32(
+        state.result.operation as i32 + (i32::from(reg) >> 3 & 7) +
+            if state.rex_reg { 8 } else { 0 },
+    );
+    set_reg_operand(state, state.operand1, control, state.op_size);
+    state.result.operation = InstructionOperation::MOV;
 }
 
-fn decode_imm_16_imm_8(state: &mut DecodeState) {
+fn decode_mov_sxzx8(state: &mut DecodeState) {
     let operand0 = state.operand0;
-    set_operand_to_imm_16(state, operand0);
     let operand1 = state.operand1;
-    set_operand_to_imm_8(state, operand1);
+    let rm_reg_list = get_byte_reg_list(state);
+    let reg_list = get_reg_list_for_op_size(state);
+    let reg_size = state.op_size;
+    decode_rm_reg(
+        state,
+        operand1,
+        rm_reg_list,
+        1,
+        operand0,
+        reg_list,
+        reg_size,
+    );
 }
 
-fn set_operand_to_es_edi(state: &DecodeState, oper: *mut X86Operand, size: u16) {
-    let addr_reg_list = get_reg_list_for_addr_size(state);
-    unsafe {
-        (*oper).operand = OperandType::MEM;
-        (*oper).components[0] = addr_reg_list[7];
-        (*oper).size = size;
-        (*oper).segment = SegmentRegister::ES;
-    }
+fn decode_mov_sxzx16(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let reg_list = get_reg_list_for_op_size(state);
+    let reg_size = state.op_size;
+    decode_rm_reg(
+        state,
+        operand1,
+        &REG16_LIST,
+        2,
+        operand0,
+        reg_list,
+        reg_size,
+    );
 }
 
-fn decode_edi_dx(state: &mut DecodeState) {
-    set_operand_to_es_edi(state, state.operand0, state.final_op_size);
-    unsafe {
-        (*state.operand1).operand = OperandType::REG_DX;
-        (*state.operand1).size = 2u16;
+fn decode_mem_16(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, &REG32_LIST, 2, &mut _ignore);
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn set_operand_to_ds_esi(state: &DecodeState, oper: *mut X86Operand, size: u16) {
-    let addr_reg_list = get_reg_list_for_addr_size(state);
-    unsafe {
-        (*oper).operand = OperandType::MEM;
-        (*oper).components[0usize] = addr_reg_list[6];
-        (*oper).size = size;
-        (*oper).segment = get_final_segment(state, SegmentRegister::DS);
+fn decode_mem_32(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, &REG32_LIST, 4, &mut _ignore);
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_dx_esi(state: &mut DecodeState) {
-    unsafe {
-        (*state.operand0).operand = OperandType::REG_DX;
-        (*state.operand0).size = 2u16;
+fn decode_mem_64(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, &REG32_LIST, 8, &mut _ignore);
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
-    set_operand_to_ds_esi(state, state.operand1, state.final_op_size);
 }
 
-fn read_signed_final_op_size(state: &mut DecodeState) -> isize {
-    match state.final_op_size {
-        4 | 8 => read_signed_32(state),
-        2 => read_signed_16(state),
-        1 => read_signed_8(state),
-        _ => 0,
+fn decode_mem_80(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, &REG32_LIST, 10, &mut _ignore);
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_rel_imm(state: &mut DecodeState) {
-    unsafe {
-        (*state.operand0).operand = OperandType::IMM;
-        (*state.operand0).size = state.op_size;
-        (*state.operand0).immediate = read_signed_final_op_size(state);
-        (*state.operand0).immediate =
-            ((*state.operand0).immediate as usize).wrapping_add(state.addr.wrapping_add(
-                ((state.opcode as isize).wrapping_sub(state.opcode_start as isize) /
-                     ::std::mem::size_of::<u8>() as isize) as
-                    usize,
-            )) as isize;
+fn decode_mem_float_env(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let rm_size = if state.op_size == 2 { 14 } else { 28 };
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, &REG32_LIST, rm_size, &mut _ignore);
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn update_operation_for_addr_size(state: &mut DecodeState) {
-    if state.addr_size == 4 {
-        state.result.operation = InstructionOperation::from_i32(state.result.operation as i32 + 1);
-    } else if state.addr_size == 8 {
-        state.result.operation = InstructionOperation::from_i32(state.result.operation as i32 + 2);
+fn decode_mem_float_save(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let rm_size = if state.op_size == 2 { 94 } else { 108 };
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, &REG32_LIST, rm_size, &mut _ignore);
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_rel_imm_addr_size(state: &mut DecodeState) {
-    decode_rel_imm(state);
-    update_operation_for_addr_size(state);
-}
-
-fn decode_group_rm(state: &mut DecodeState) {
+fn decode_fpu_reg(state: &mut DecodeState) {
     let operand0 = state.operand0;
-    let reg_list = get_reg_list_for_final_op_size(state);
-    let reg_size = state.final_op_size;
-    let mut reg_field: u8 = 0;
-    decode_rm(state, operand0, reg_list, reg_size, &mut reg_field);
-    state.result.operation = GROUP_OPERATIONS[state.result.operation as usize][reg_field as usize];
-}
-
-fn decode_group_rm_imm(state: &mut DecodeState) {
-    decode_group_rm(state);
-    let operand1 = state.operand1;
-    set_operand_to_imm(state, operand1);
+    let mut _ignore: u8 = 0;
+    decode_rm(state, operand0, &FPU_REG_LIST, 10, &mut _ignore);
 }
 
-fn decode_group_rm_imm_8v(state: &mut DecodeState) {
-    decode_group_rm(state);
-    let operand1 = state.operand1;
-    set_operand_to_imm_8(state, operand1);
+fn decode_fpu_reg_st0(state: &mut DecodeState) {
+    decode_fpu_reg(state);
+    set_reg_operand(state, state.operand1, OperandType::REG_ST0, 10);
 }
 
-fn decode_group_rm_one(state: &mut DecodeState) {
-    decode_group_rm(state);
-    unsafe {
-        (*state.operand1).operand = OperandType::IMM;
-        (*state.operand1).size = 1;
-        (*state.operand1).immediate = 1;
-    }
+fn decode_reg_group_no_operands(state: &mut DecodeState) {
+    let rm_byte: u8 = read_mod_rm(state);
+    state.result.operation = GROUP_OPERATIONS[state.result.operation as usize][(rm_byte & 7) as
+                                                                                   usize];
 }
 
-fn decode_group_rm_cl(state: &mut DecodeState) {
-    decode_group_rm(state);
-    unsafe {
-        (*state.operand1).operand = OperandType::REG_CL;
-        (*state.operand1).size = 1;
-    }
+fn decode_reg_group_ax(state: &mut DecodeState) {
+    decode_reg_group_no_operands(state);
+    set_reg_operand(state, state.operand0, OperandType::REG_AX, 2);
 }
 
-fn decode_group_f6f7(state: &mut DecodeState) {
-    decode_group_rm(state);
-    if state.result.operation == InstructionOperation::TEST {
-        let operand1 = state.operand1;
-        set_operand_to_imm(state, operand1);
-    }
-    // Check for valid locking semantics
-    if state.result.flags & X86Flag::LOCK != 0 &&
-        (state.result.operation != InstructionOperation::NOT) &&
-        (state.result.operation != InstructionOperation::NEG)
-    {
-        state.invalid = true;
+fn decode_cmp_xch_8b(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
+    let mod_field: u8 = rm >> 6 & 3;
+    let reg_field: u8 = rm >> 3 & 7;
+    if mod_field == 3 && reg_field >= 6 {
+        decode_rdrand_rdseed(state);
+        return;
     }
-}
-
-fn decode_group_ff(state: &mut DecodeState) {
-    if state.using64 {
-        // Default to 64-bit for jumps and calls
-        let rm: u8 = peek_8(state);
-        let reg_field: u8 = rm >> 3 & 7;
-        if reg_field >= 2 && reg_field <= 5 {
-            state.final_op_size = {
-                state.op_size = if state.op_prefix { 4 } else { 8 };
-                state.op_size
-            };
+    if reg_field == 1 {
+        if state.op_size == 2 {
+            state.op_size = 4;
+        } else if state.op_size == 8 {
+            state.result.operation = InstructionOperation::CMPXCH16B;
         }
-    }
-    decode_group_rm(state);
-    // Check for valid jump/call semantics
-    if state.result.operation == InstructionOperation::CALLF ||
-        state.result.operation == InstructionOperation::JMPF
-    {
-        unsafe {
-            if (*state.operand0).operand != OperandType::MEM {
-                state.invalid = true;
-            }
-            (*state.operand0).size += 2;
+        let operand0 = state.operand0;
+        let rm_size = state.op_size * 2;
+        let reg_list = get_reg_list_for_op_size(state);
+        let mut _ignore: u8 = 0;
+        decode_rm(state, operand0, reg_list, rm_size, &mut _ignore);
+    } else if (3..=5).contains(&reg_field) {
+        state.result.operation = match reg_field {
+            3 => InstructionOperation::XRSTORS,
+            4 => InstructionOperation::XSAVEC,
+            _ => InstructionOperation::XSAVES,
+        };
+        let operand0 = state.operand0;
+        let mut _ignore: u8 = 0;
+        decode_rm(state, operand0, &REG64_LIST, 512, &mut _ignore);
+    } else if reg_field == 6 {
+        if state.op_prefix {
+            state.op_prefix_used = true;
+            state.result.operation = InstructionOperation::VMCLEAR;
+        } else if state.rep == RepPrefix::REPE {
+            state.rep_used = true;
+            state.result.operation = InstructionOperation::VMXON;
+        } else {
+            state.result.operation = InstructionOperation::VMPTRLD;
         }
+        let operand0 = state.operand0;
+        let mut _ignore: u8 = 0;
+        decode_rm(state, operand0, &REG64_LIST, 8, &mut _ignore);
+    } else if reg_field == 7 {
+        state.result.operation = InstructionOperation::VMPTRST;
+        let operand0 = state.operand0;
+        let mut _ignore: u8 = 0;
+        decode_rm(state, operand0, &REG64_LIST, 8, &mut _ignore);
+    } else {
+        set_invalid(state, InvalidReason::Opcode);
     }
-    // Check for valid locking semantics
-    if state.result.flags & X86Flag::LOCK != 0 &&
-        (state.result.operation != InstructionOperation::INC) &&
-        (state.result.operation != InstructionOperation::DEC)
-    {
-        state.invalid = true;
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_group_0f00(state: &mut DecodeState) {
-    let rm: u8 = peek_8(state);
-    let reg_field: u8 = rm >> 3 & 7;
-    if reg_field >= 2 {
-        state.op_size = 2;
+fn decode_vmread_vmwrite(state: &mut DecodeState) {
+    // The operands are always the native size, and there is no form
+    // with an operand size prefix.
+    state.final_op_size = if state.using64 { 8 } else { 4 };
+    decode_reg_rm(state);
+    if state.op_prefix || state.rep != RepPrefix::NONE {
+        set_invalid(state, InvalidReason::Opcode);
     }
-    decode_group_rm(state);
 }
 
-fn decode_group_0f01(state: &mut DecodeState) {
-    let rm: u8 = peek_8(state);
-    let mod_field: u8 = rm >> 6 & 3;
-    let reg_field: u8 = rm >> 3 & 7;
-    let rm_field: u8 = rm & 7;
-    if mod_field == 3 && reg_field != 4 && reg_field != 6 {
-        state.result.operation = GROUP_0F01_REG_OPERATIONS[rm_field as usize][reg_field as usize];
-    } else {
-        if reg_field < 4 {
-            state.op_size = if state.using64 { 10 } else { 6 };
-        } else if reg_field != 7 {
-            state.op_size = 2;
-        } else {
-            state.op_size = 1;
-        }
-        decode_group_rm(state);
+fn decode_invept_invvpid(state: &mut DecodeState) {
+    // The operand size prefix is part of the opcode, and the register
+    // is always the native size.
+    state.final_op_size = if state.using64 { 8 } else { 4 };
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let final_op_size = state.final_op_size;
+    decode_rm_reg(state, operand1, reg_list, 16, operand0, reg_list, final_op_size);
+    state.op_prefix_used = true;
+    if !state.op_prefix || state.rep != RepPrefix::NONE {
+        set_invalid(state, InvalidReason::Opcode);
+    } else if !rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_group_0fae(state: &mut DecodeState) {
+fn decode_rdrand_rdseed(state: &mut DecodeState) {
     let rm: u8 = peek_8(state);
-    let mod_field: u8 = rm >> 6 & 3;
-    let reg_field: u8 = rm >> 3 & 7;
-    if mod_field == 3 {
-        state.result.operation = GROUP_OPERATIONS[(state.result.operation as usize + 1)]
-            [reg_field as usize];
+    if rm & 0x38 == 0x30 {
+        state.result.operation = InstructionOperation::RDRAND;
+    } else if state.rep == RepPrefix::REPE {
+        // RDPID always reads a register of the native size.
+        state.rep_used = true;
+        state.result.operation = InstructionOperation::RDPID;
+        state.final_op_size = if state.using64 { 8 } else { 4 };
     } else {
-        if reg_field & 2 == 0 {
-            state.op_size = 512;
-        } else if reg_field & 6 == 2 {
-            state.op_size = 4;
-        } else {
-            state.op_size = 1;
-        }
-        decode_group_rm(state);
+        state.result.operation = InstructionOperation::RDSEED;
     }
+    decode_rm_final_op_size(state);
 }
 
-fn decode_0fb8(state: &mut DecodeState) {
-    if state.rep != RepPrefix::REPE {
-        if state.using64 {
-            state.op_size = if state.op_prefix { 4 } else { 8 };
-        }
-        state.final_op_size = get_final_op_size(state);
-        decode_rel_imm(state);
-    } else {
-        decode_reg_rm(state);
+fn decode_mov_nti(state: &mut DecodeState) {
+    if state.op_size == 2 {
+        state.op_size = 4;
+    }
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let op_size = state.op_size;
+    let reg_list = get_reg_list_for_op_size(state);
+    decode_rm_reg(
+        state,
+        operand0,
+        reg_list,
+        op_size,
+        operand1,
+        reg_list,
+        op_size,
+    );
+    if operand_type(state, state.operand0) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn get_reg_list_for_op_size(state: &DecodeState) -> &'static [OperandType] {
-    match state.op_size {
-        8 => &REG64_LIST,
-        4 => &REG32_LIST,
-        2 => &REG16_LIST,
-        _ => &INVALID_REG_LIST,
+fn decode_crc_32(state: &mut DecodeState) {
+    // CRC32 needs a REPNE prefix, and is MOVBE without it.
+    if state.rep != RepPrefix::REPNE {
+        decode_movbe(state);
+        return;
     }
+    state.rep_used = true;
+    let src_reg_list = get_reg_list_for_final_op_size(state);
+    let dest_reg_list = if state.op_size == 8 {
+        &REG64_LIST
+    } else {
+        &REG32_LIST
+    };
+    let dest_size: u16 = if state.op_size == 8 { 8 } else { 4 };
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    let final_op_size = state.final_op_size;
+    decode_rm_reg(
+        state,
+        operand1,
+        src_reg_list,
+        final_op_size,
+        operand0,
+        dest_reg_list,
+        dest_size,
+    );
 }
 
-fn decode_rms_reg_v(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let reg_list = get_reg_list_for_op_size(state);
-    let reg_size = state.op_size;
-    let mut reg_field: u8 = 0;
-    decode_rm(state, operand0, reg_list, reg_size, &mut reg_field);
-    if reg_field >= 6 {
-        state.invalid = true;
+fn decode_movbe(state: &mut DecodeState) {
+    state.result.operation = InstructionOperation::MOVBE;
+    state.final_op_size = state.op_size;
+    if last_opcode_byte(state) == 0xf1 {
+        state.operand0 = 1;
+        state.operand1 = 0;
     }
-    unsafe {
-        (*state.operand1).operand =
-            OperandType::from_i32(OperandType::REG_ES as i32 + i32::from(reg_field));
-        (*state.operand1).size = 2;
-        if state.result.operands[0].operand == OperandType::REG_CS {
-            state.invalid = true;
-        }
+    decode_reg_rm(state);
+    if state.rep == RepPrefix::REPE {
+        set_invalid(state, InvalidReason::Opcode);
+    } else if !rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_rm8(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let reg_list = get_byte_reg_list(state);
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, reg_list, 1, &mut _ignore);
+fn decode_adcx_adox(state: &mut DecodeState) {
+    if !state.op_prefix && state.rep == RepPrefix::NONE {
+        decode_wrss(state);
+        return;
+    }
+    // REPE selects ADOX, and takes priority over the operand size
+    // prefix, which otherwise selects ADCX rather than a 16 bit operand.
+    state.result.operation = if state.rep == RepPrefix::REPE {
+        state.rep_used = true;
+        InstructionOperation::ADOX
+    } else if state.op_prefix && state.rep == RepPrefix::NONE {
+        state.op_prefix_used = true;
+        InstructionOperation::ADCX
+    } else {
+        InstructionOperation::INVALID
+    };
+    state.final_op_size = if state.op_size == 8 { 8 } else { 4 };
+    decode_reg_rm(state);
 }
 
-fn decode_rmv(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let reg_list = get_reg_list_for_op_size(state);
-    let reg_size = state.op_size;
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, reg_list, reg_size, &mut _ignore);
+fn decode_wrss(state: &mut DecodeState) {
+    // WRUSS needs the operand size prefix, which doesn't change the
+    // size of its operands.
+    let user = matches!(last_opcode_byte(state), 0xf5 | 0x65);
+    if user {
+        state.op_prefix_used = true;
+        set_operation_for_rex_w(state, InstructionOperation::WRUSSD);
+    } else {
+        set_operation_for_rex_w(state, InstructionOperation::WRSSD);
+    }
+    state.operand0 = 1;
+    state.operand1 = 0;
+    decode_reg_rm(state);
+    if state.op_prefix != user || state.rep != RepPrefix::NONE {
+        set_invalid(state, InvalidReason::Opcode);
+    } else if !rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
+    }
 }
 
-fn decode_far_imm(state: &mut DecodeState) {
-    let operand1 = state.operand1;
-    set_operand_to_imm(state, operand1);
-    let operand0 = state.operand0;
-    set_operand_to_imm_16(state, operand0);
+fn decode_arpl(state: &mut DecodeState) {
+    if state.using64 {
+        // In 64-bit, ARPL is repurposed to MOVSXD
+        let reg_list = get_reg_list_for_final_op_size(state);
+        state.result.operation = InstructionOperation::MOVSXD;
+        let operand0 = state.operand0;
+        let operand1 = state.operand1;
+        let final_op_size = state.final_op_size;
+        decode_rm_reg(
+            state,
+            operand1,
+            &REG32_LIST,
+            4,
+            operand0,
+            reg_list,
+            final_op_size,
+        );
+    } else {
+        // ARPL instruction
+        state.operand0 = 1;
+        state.operand1 = 0;
+        state.final_op_size = 2;
+        decode_reg_rm(state);
+    }
 }
 
-fn read_addr_size(state: &mut DecodeState) -> isize {
-    match state.addr_size {
-        4 | 8 => read_32(state) as isize,
-        2 => read_16(state) as isize,
-        _ => 0,
+fn decode_les_lds(state: &mut DecodeState) {
+    // LES and LDS can't have a register operand, which leaves those
+    // encodings for the VEX prefix. In 64-bit, they are always VEX.
+    if state.using64 || peek_8(state) >= 0xc0 {
+        decode_vex(state);
+    } else {
+        decode_reg_rm(state);
     }
 }
 
-fn set_operand_to_imm_addr(state: &mut DecodeState, oper: *mut X86Operand) {
-    unsafe {
-        (*oper).operand = OperandType::MEM;
-        (*oper).immediate = read_addr_size(state);
-        (*oper).segment = get_final_segment(state, SegmentRegister::DS);
-        (*oper).size = state.final_op_size;
+fn decode_bound(state: &mut DecodeState) {
+    // Like LES and LDS, BOUND leaves its register forms for the EVEX
+    // prefix, and is always EVEX in 64-bit.
+    if state.using64 || peek_8(state) >= 0xc0 {
+        decode_evex(state);
+    } else {
+        decode_reg_rm(state);
     }
 }
 
-fn decode_eax_addr(state: &mut DecodeState) {
-    set_operand_to_eax_final_op_size(state, state.operand0);
-    let operand1 = state.operand1;
-    set_operand_to_imm_addr(state, operand1);
+fn decode_pop_rmv(state: &mut DecodeState) {
+    // POP only uses a reg field of 0, so a map select of 8 or more in
+    // the low bits of the ModRM is an XOP prefix instead. An XOP prefix
+    // can't follow REX2.
+    let rm: u8 = peek_8(state);
+    if state.rex2.is_none() && rm & 0x1f >= 8 {
+        decode_vex(state);
+    } else {
+        if rm & 0x38 != 0 {
+            set_invalid(state, InvalidReason::Opcode);
+        }
+        decode_rmv(state);
+    }
 }
 
-fn decode_edi_esi(state: &mut DecodeState) {
-    set_operand_to_es_edi(state, state.operand0, state.final_op_size);
-    set_operand_to_ds_esi(state, state.operand1, state.final_op_size);
+/// The implied SSE prefix of `VEX.pp` or `EVEX.pp`, numbered like the
+/// columns of `SSE_TABLE`.
+fn vex_prefix_column(pp: u8) -> u8 {
+    [0, 1, 3, 2][usize::from(pp & 3)]
 }
 
-fn decode_edi_eax(state: &mut DecodeState) {
-    set_operand_to_es_edi(state, state.operand0, state.final_op_size);
-    set_operand_to_eax_final_op_size(state, state.operand1);
+/// Whether `VEX.W` or `EVEX.W` meets the requirement of a `VexForm`.
+fn vex_w_allowed(form: u32, w: bool) -> bool {
+    !(w && form & VexForm::W0 != 0 || !w && form & VexForm::W1 != 0)
 }
 
-fn decode_eax_esi(state: &mut DecodeState) {
-    set_operand_to_eax_final_op_size(state, state.operand0);
-    set_operand_to_ds_esi(state, state.operand1, state.final_op_size);
+/// Find the entry for `key` in a table sorted by key, preferring the
+/// one that allows the value of `W`, as some operations differ only
+/// by `W`. `entry_key` gives the key and `VexForm` of an entry.
+fn find_vex_entry<T, K: Ord, F: Fn(&T) -> (K, u32)>(
+    table: &'static [T],
+    key: K,
+    w: bool,
+    entry_key: F,
+) -> Option<&'static T> {
+    let start = table.partition_point(|entry| entry_key(entry).0 < key);
+    let mut entries = table[start..].iter().take_while(|entry| entry_key(entry).0 == key);
+    let first = entries.clone().next();
+    entries.find(|entry| vex_w_allowed(entry_key(entry).1, w)).or(first)
 }
 
-fn decode_al_ebx_al(state: &mut DecodeState) {
-    let reg_list = get_reg_list_for_addr_size(state);
-    unsafe {
-        (*state.operand0).operand = OperandType::REG_AL;
-        (*state.operand0).size = 1;
-        (*state.operand1).operand = OperandType::MEM;
-        (*state.operand1).components[0] = reg_list[3];
-        (*state.operand1).components[1] = OperandType::REG_AL;
-        (*state.operand1).size = 1;
-        (*state.operand1).segment = get_final_segment(state, SegmentRegister::DS);
+fn decode_vex(state: &mut DecodeState) {
+    // The VEX prefix replaces these prefixes, so they can't be used
+    // with it.
+    if state.op_prefix || state.rep != RepPrefix::NONE || state.rex ||
+        state.result.flags & X86Flag::LOCK != 0
+    {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    let xop = state.result.operation == InstructionOperation::POP;
+    state.result.flags |= if xop { X86Flag::XOP } else { X86Flag::VEX };
+    let byte1 = read_8(state);
+    let (rxb_map, w_vvvv_l_pp) = if state.result.operation != InstructionOperation::LDS {
+        let byte2 = read_8(state);
+        (byte1, byte2)
+    } else {
+        // The two byte form implies the 0x0f map, and clear X, B and W.
+        (byte1 & 0x80 | 0x61, byte1 & 0x7f)
+    };
+    state.result.layout.opcode_offset = state.pos as u8;
+    state.result.layout.opcode_length = 1;
+    // R, X, B and vvvv are all stored inverted.
+    state.vex_w = w_vvvv_l_pp & 0x80 != 0;
+    state.vex_v = (!w_vvvv_l_pp >> 3) & 0x0f;
+    state.vex_l = w_vvvv_l_pp & 4 != 0;
+    if state.using64 {
+        state.rex_reg = rxb_map & 0x80 == 0;
+        state.rex_rm_2 = rxb_map & 0x40 == 0;
+        state.rex_rm_1 = rxb_map & 0x20 == 0;
+        if state.vex_w {
+            state.op_size = 8;
+        }
+    } else {
+        state.vex_v &= 7;
+    }
+    match w_vvvv_l_pp & 3 {
+        1 => state.op_prefix = true,
+        2 => state.rep = RepPrefix::REPE,
+        3 => state.rep = RepPrefix::REPNE,
+        _ => {}
+    }
+    let map = rxb_map & 0x1f;
+    let vex_map: &[VexInstructionEncoding] = match (xop, map) {
+        (false, 1) => &VEX_0F_MAP,
+        (false, 2) => &VEX_0F38_MAP,
+        (false, 3) => &VEX_0F3A_MAP,
+        (true, 8) => &XOP_08_MAP,
+        (true, 9) => &XOP_09_MAP,
+        _ => {
+            state.result.operation = InstructionOperation::INVALID;
+            set_invalid(state, InvalidReason::Opcode);
+            return;
+        }
+    };
+    let opcode = read_8(state);
+    let prefix = vex_prefix_column(w_vvvv_l_pp);
+    let native = find_vex_entry(vex_map, (opcode, prefix), state.vex_w, |entry| {
+        ((entry.opcode, entry.prefix), entry.form)
+    });
+    if let Some(encoding) = native {
+        process_vex_encoding(state, encoding);
+    } else if xop {
+        state.result.operation = InstructionOperation::INVALID;
+        set_invalid(state, InvalidReason::Opcode);
+        return;
+    } else {
+        // Everything else is a legacy SSE instruction with a new form.
+        match map {
+            1 => process_opcode(state, &TWO_BYTE_OPCODE_MAP, opcode),
+            2 => process_sparse_opcode(state, &THREE_BYTE_0F38_MAP, opcode),
+            _ => process_sparse_opcode(state, &THREE_BYTE_0F3A_MAP, opcode),
+        }
+        update_operation_for_vex(state);
+        let mmx = (0..4).any(|oper| {
+            let reg = operand_type(state, oper);
+            reg >= OperandType::REG_MM0 && reg <= OperandType::REG_MM7
+        });
+        // A `VEX.pp` that the legacy instruction didn't use is invalid.
+        if mmx || state.rep != RepPrefix::NONE {
+            set_invalid(state, InvalidReason::Opcode);
+        }
+    }
+    apply_vex_form(state);
+    if map == 3 || map == 8 {
+        decode_vex_imm_8(state);
+    }
+    if state.vex_form & VexForm::W_SWAP != 0 && state.vex_w {
+        swap_last_vex_operands(state);
     }
 }
 
-fn decode_eax_imm_8(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    set_operand_to_eax_final_op_size(state, operand0);
-    let operand1 = state.operand1;
-    set_operand_to_imm_8(state, operand1);
-}
-
-fn decode_eax_dx(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    set_operand_to_eax_final_op_size(state, operand0);
-    unsafe {
-        (*state.operand1).operand = OperandType::REG_DX;
-        (*state.operand1).size = 2;
+fn process_vex_encoding(state: &mut DecodeState, encoding: &VexInstructionEncoding) {
+    state.result.operation = encoding.operation;
+    state.flags = 0;
+    state.vex_form = encoding.form;
+    // The encoding was found by its prefix, which is now used.
+    decode_sse_prefix(state);
+    if encoding.form & VexForm::FLIP_OPERANDS != 0 {
+        state.operand0 = 1;
+        state.operand1 = 0;
+    } else {
+        state.operand0 = 0;
+        state.operand1 = 1;
+    }
+    if encoding.form & VexForm::W_OPERATION != 0 && state.vex_w {
+        state.result.operation = InstructionOperation::from_i32(state.result.operation as i32 + 1);
     }
+    (encoding.func)(state);
 }
 
-fn decode_3dnow(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    decode_rm_reg(
-        state,
-        operand1,
-        &MMX_REG_LIST,
-        8,
-        operand0,
-        &MMX_REG_LIST,
-        8,
-    );
-    let op = read_8(state);
-    state.result.operation =
-        match SPARSE_3DNOW_OPCODES.binary_search_by_key(&op, |entry| entry.opcode) {
-            Ok(idx) => SPARSE_3DNOW_OPCODES[idx].operation,
-            Err(_) => InstructionOperation::INVALID,
+fn update_operation_for_vex(state: &mut DecodeState) {
+    let operation = state.result.operation as i32;
+    match VEX_OPERATIONS.binary_search_by_key(&operation, |entry| entry.legacy as i32) {
+        Ok(idx) => {
+            state.result.operation = VEX_OPERATIONS[idx].operation;
+            state.vex_form = VEX_OPERATIONS[idx].form;
         }
+        Err(_) => {
+            state.result.operation = InstructionOperation::INVALID;
+            set_invalid(state, InvalidReason::Opcode);
+        }
+    }
 }
 
-fn decode_sse_prefix(state: &mut DecodeState) -> u8 {
-    if state.op_prefix {
-        state.op_prefix = false;
-        1
-    } else if state.rep == RepPrefix::REPNE {
-        state.rep = RepPrefix::NONE;
-        2
-    } else if state.rep == RepPrefix::REPE {
-        state.rep = RepPrefix::NONE;
-        3
-    } else {
-        0
-    }
+/// Insert the register from `VEX.vvvv` as operand `index`, moving the
+/// operands after it along.
+fn insert_vex_operand(state: &mut DecodeState, index: usize, reg_list: &[OperandType], size: u16) {
+    state.result.operands.copy_within(index..3, index + 1);
+    set_reg_operand(state, index, reg_list[usize::from(state.vex_v)], size);
+    let shift = |oper: usize| if oper >= index { oper + 1 } else { oper };
+    state.rip_rel_fixup = state.rip_rel_fixup.map(shift);
+    state.rm_operand = state.rm_operand.map(shift);
+    state.vex_v_used = true;
 }
 
-fn get_operand_for_sse_entry_type(
-    state: &DecodeState,
-    entry_type: SSETableOperandType,
-    operand_index: u8,
-) -> *mut X86Operand {
-    let operand_index = if entry_type == SSETableOperandType::SSE_128_FLIP {
-        1 - operand_index
+fn apply_vex_form(state: &mut DecodeState) {
+    let mut form = state.vex_form;
+    if form & VexForm::SHIFT != 0 {
+        form |= if operand_type(state, 1) == OperandType::IMM {
+            VexForm::NDD
+        } else {
+            VexForm::NDS | VexForm::RM_XMM
+        };
+    }
+    if state.vex_l && form & VexForm::L0 != 0 || !state.vex_l && form & VexForm::L1 != 0 ||
+        state.vex_w && form & VexForm::W0 != 0 || !state.vex_w && form & VexForm::W1 != 0
+    {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    let wide = state.vex_l && form & VexForm::LIG == 0;
+    if wide {
+        for i in 0..4 {
+            let is_rm = state.rm_operand == Some(i);
+            match &mut state.result.operands[i] {
+                X86Operand::Memory(mem) => {
+                    if form & VexForm::RM_HALF != 0 {
+                        mem.size *= 2;
+                    } else if form & VexForm::RM_XMM == 0 {
+                        mem.size = 32;
+                    }
+                }
+                X86Operand::Register(reg) if reg.register >= OperandType::REG_XMM0 &&
+                                             reg.register <= OperandType::REG_XMM15 => {
+                    let keep = if is_rm {
+                        form & (VexForm::RM_HALF | VexForm::RM_XMM) != 0
+                    } else {
+                        form & VexForm::REG_XMM != 0
+                    };
+                    if !keep {
+                        let index = reg.register as i32 - OperandType::REG_XMM0 as i32;
+                        reg.register = YMM_REG_LIST[index as usize];
+                        reg.size = 32;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    let (v_list, v_size): (&[OperandType], u16) = if form & VexForm::GPR != 0 {
+        (get_reg_list_for_final_op_size(state), state.final_op_size)
+    } else if wide {
+        (&YMM_REG_LIST, 32)
     } else {
-        operand_index
+        (&XMM_REG_LIST, 16)
     };
-    if operand_index == 0 {
-        state.operand0
-    } else {
-        state.operand1
+    let rm_is_mem = state.rm_operand.is_some_and(|oper| {
+        operand_type(state, oper) == OperandType::MEM
+    });
+    if form & VexForm::NDD != 0 {
+        insert_vex_operand(state, 0, v_list, v_size);
+    } else if form & VexForm::NDS != 0 || form & VexForm::NDS_REG != 0 && !rm_is_mem ||
+               form & VexForm::NDS_LOAD != 0 &&
+                   operand_type(state, 0) != OperandType::MEM
+    {
+        insert_vex_operand(state, 1, v_list, v_size);
+    } else if form & VexForm::NDS_LAST != 0 {
+        insert_vex_operand(state, 2, v_list, v_size);
+    }
+    // An unused vvvv must be 1111b.
+    if !state.vex_v_used && state.vex_v != 0 {
+        set_invalid(state, InvalidReason::Opcode);
     }
+    state.vex_form = form;
 }
 
-fn get_reg_list_for_sse_entry_type(
-    state: &DecodeState,
-    entry_type: SSETableOperandType,
-) -> &'static [OperandType] {
-    if entry_type == SSETableOperandType::GPR_32_OR_64 {
-        if state.op_size == 8 {
-             &REG64_LIST
-         } else {
-             &REG32_LIST
-         }
-    } else if entry_type == SSETableOperandType::MMX_64 ||
-               entry_type == SSETableOperandType::MMX_32
-    {
-        &MMX_REG_LIST
-    } else {
-        &XMM_REG_LIST
+fn decode_vex_imm_8(state: &mut DecodeState) {
+    let oper = state
+        .result
+        .operands
+        .iter()
+        .position(|operand| *operand == NO_OPERAND)
+        .unwrap_or(3);
+    set_operand_to_imm_8(state, oper);
+    if state.vex_form & VexForm::IS4 != 0 {
+        // The register is in the upper 4 bits, of which only 3 are
+        // used outside of 64-bit.
+        let mask = if state.using64 { 0x0f } else { 0x07 };
+        let reg = usize::from(last_opcode_byte(state) >> 4 & mask);
+        let wide = state.vex_l && state.vex_form & VexForm::LIG == 0;
+        let (reg_list, size): (&[OperandType], u16) = if wide {
+            (&YMM_REG_LIST, 32)
+        } else {
+            (&XMM_REG_LIST, 16)
+        };
+        set_reg_operand(state, oper, reg_list[reg], size);
     }
 }
 
-fn get_size_for_sse_entry_type(state: &DecodeState, entry_type: SSETableOperandType) -> u16 {
-    match entry_type {
-        SSETableOperandType::GPR_32_OR_64 => if state.op_size == 8 { 8 } else { 4 },
-        SSETableOperandType::MMX_64 |
-        SSETableOperandType::SSE_64 => 8,
-        SSETableOperandType::MMX_32 |
-        SSETableOperandType::SSE_32 => 4,
-        SSETableOperandType::SSE_16 => 2,
-        _ => 16,
-    }
+/// Swap the last two operands, which are always sources.
+fn swap_last_vex_operands(state: &mut DecodeState) {
+    let count = state
+        .result
+        .operands
+        .iter()
+        .position(|operand| *operand == NO_OPERAND)
+        .unwrap_or(4);
+    let (a, b) = (count - 2, count - 1);
+    state.result.operands.swap(a, b);
+    let swap = |oper: usize| if oper == a { b } else if oper == b { a } else { oper };
+    state.rip_rel_fixup = state.rip_rel_fixup.map(swap);
+    state.rm_operand = state.rm_operand.map(swap);
 }
 
-fn update_operation_for_sse_entry_type(state: &mut DecodeState, entry_type: SSETableOperandType) {
-    if entry_type == SSETableOperandType::GPR_32_OR_64 && (state.op_size == 8) {
-        state.result.operation =
-            InstructionOperation::from_i32(state.result.operation as i32 + 1);
-    }
+/// Decode an instruction on general purpose registers, which are 64
+/// bits with `VEX.W` in 64-bit and are otherwise 32 bits.
+fn decode_vex_gpr(state: &mut DecodeState) {
+    state.final_op_size = if state.op_size == 8 { 8 } else { 4 };
+    decode_reg_rm(state);
 }
 
-fn decode_sse_table(state: &mut DecodeState) {
-    let entry_type = decode_sse_prefix(state) as usize;
-    let rm: u8 = peek_8(state);
-    let mod_field: u8 = rm >> 6 & 3;
-    let entry = &SSE_TABLE[state.result.operation as usize];
-    let op_entry = if mod_field == 3 {
-        &entry.reg_ops[entry_type]
-    } else {
-        &entry.mem_ops[entry_type]
+fn decode_vex_gpr_group(state: &mut DecodeState) {
+    state.final_op_size = if state.op_size == 8 { 8 } else { 4 };
+    let reg_list = get_reg_list_for_final_op_size(state);
+    let size = state.final_op_size;
+    let mut reg_field: u8 = 0;
+    decode_rm(state, 0, reg_list, size, &mut reg_field);
+    state.result.operation = match reg_field {
+        1 => InstructionOperation::BLSR,
+        2 => InstructionOperation::BLSMSK,
+        3 => InstructionOperation::BLSI,
+        _ => InstructionOperation::INVALID,
     };
-    state.result.operation = op_entry.operation;
-    let operand1 = get_operand_for_sse_entry_type(state, op_entry.rm_type, 1);
-    let rm_reg_list = get_reg_list_for_sse_entry_type(state, op_entry.rm_type);
-    let rm_reg_size = get_size_for_sse_entry_type(state, op_entry.rm_type);
-    let operand0 = get_operand_for_sse_entry_type(state, op_entry.reg_type, 0);
-    let reg_list = get_reg_list_for_sse_entry_type(state, op_entry.reg_type);
-    let reg_size = get_size_for_sse_entry_type(state, op_entry.reg_type);
+}
+
+fn decode_vzero(state: &mut DecodeState) {
+    if state.vex_l {
+        state.result.operation = InstructionOperation::VZEROALL;
+    }
+}
+
+fn decode_vex_sse(state: &mut DecodeState) {
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
     decode_rm_reg(
         state,
         operand1,
-        rm_reg_list,
-        rm_reg_size,
+        &XMM_REG_LIST,
+        16,
         operand0,
-        reg_list,
-        reg_size,
+        &XMM_REG_LIST,
+        16,
     );
-    if state.flags & DecodeFlags::INC_OPERATION_FOR_64 != 0 {
-        update_operation_for_sse_entry_type(state, op_entry.reg_type);
-        update_operation_for_sse_entry_type(state, op_entry.rm_type);
-    }
 }
 
-fn decode_see_table_imm_8(state: &mut DecodeState) {
-    decode_sse_table(state);
-    let operand = &mut state.result.operands[2] as *mut X86Operand;
-    set_operand_to_imm_8(state, operand);
+fn decode_vex_sse_32(state: &mut DecodeState) {
+    decode_vex_sse_sized(state, 4);
 }
 
-fn decode_sse_table_mem_8(state: &mut DecodeState) {
-    decode_sse_table(state);
-    unsafe {
-        if (*state.operand0).operand == OperandType::MEM {
-            (*state.operand0).size = 1;
-        }
-        if (*state.operand1).operand == OperandType::MEM {
-            (*state.operand1).size = 1;
-        }
-    }
+fn decode_vex_sse_64(state: &mut DecodeState) {
+    decode_vex_sse_sized(state, 8);
 }
 
-fn get_size_for_sse_type(type_: u8) -> u16 {
-    match type_ {
-        2 => 8,
-        3 => 4,
-        _ => 16,
-    }
+/// Decode a scalar operation, which is on a double with `VEX.W` or `EVEX.W`.
+fn decode_vex_scalar(state: &mut DecodeState) {
+    let size = if state.vex_w { 8 } else { 4 };
+    decode_vex_sse_sized(state, size);
 }
-
-fn decode_sse(state: &mut DecodeState) {
-    let type_: u8 = decode_sse_prefix(state);
+
+/// Decode like `decode_vex_sse`, but with a memory operand of `size`
+/// bytes.
+fn decode_vex_sse_sized(state: &mut DecodeState, size: u16) {
     let rm: u8 = peek_8(state);
-    let mod_field: u8 = rm >> 6 & 3;
-    state.result.operation =
-        InstructionOperation::from_i32(state.result.operation as i32 + i32::from(type_));
-    let size: u16 = if mod_field == 3 {
-        16
-    } else {
-        get_size_for_sse_type(type_)
-    };
+    let size: u16 = if rm >> 6 == 3 { 16 } else { size };
     let operand0 = state.operand0;
     let operand1 = state.operand1;
     decode_rm_reg(
@@ -8601,41 +18012,44 @@ fn decode_sse(state: &mut DecodeState) {
     );
 }
 
-fn decode_sse_single(state: &mut DecodeState) {
-    let type_: u8 = decode_sse_prefix(state);
-    if type_ == 1 || type_ == 2 {
-        state.invalid = true;
-    } else {
-        state.result.operation =
-            InstructionOperation::from_i32(state.result.operation as i32 + i32::from((type_) & 1));
-        let operand0 = state.operand0;
-        let operand1 = state.operand1;
-        decode_rm_reg(
-            state,
-            operand1,
-            &XMM_REG_LIST,
-            16,
-            operand0,
-            &XMM_REG_LIST,
-            16,
-        );
+fn decode_vex_sse_mem(state: &mut DecodeState) {
+    decode_vex_sse(state);
+    if operand_type(state, state.operand1) != OperandType::MEM {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_sse_packed(state: &mut DecodeState) {
-    let type_: u8 = decode_sse_prefix(state);
-    if type_ == 2 || type_ == 3 {
-        state.invalid = true;
+fn decode_vex_broadcast(state: &mut DecodeState) {
+    let size: u16 = match state.result.operation {
+        InstructionOperation::VPBROADCASTB => 1,
+        InstructionOperation::VPBROADCASTW => 2,
+        InstructionOperation::VBROADCASTSS |
+        InstructionOperation::VPBROADCASTD => 4,
+        InstructionOperation::VBROADCASTSD |
+        InstructionOperation::VPBROADCASTQ |
+        InstructionOperation::VBROADCASTF32X2 |
+        InstructionOperation::VBROADCASTI32X2 => 8,
+        InstructionOperation::VBROADCASTF32X8 |
+        InstructionOperation::VBROADCASTF64X4 |
+        InstructionOperation::VBROADCASTI32X8 |
+        InstructionOperation::VBROADCASTI64X4 => 32,
+        _ => 16,
+    };
+    let rm: u8 = peek_8(state);
+    if rm >> 6 == 3 {
+        // Only the broadcasts of up to 64 bits can use a register.
+        if size >= 16 {
+            set_invalid(state, InvalidReason::Operands);
+        }
+        decode_vex_sse(state);
     } else {
-        state.result.operation =
-            InstructionOperation::from_i32(state.result.operation as i32 + i32::from((type_) & 1));
         let operand0 = state.operand0;
         let operand1 = state.operand1;
         decode_rm_reg(
             state,
             operand1,
             &XMM_REG_LIST,
-            16,
+            size,
             operand0,
             &XMM_REG_LIST,
             16,
@@ -8643,337 +18057,778 @@ fn decode_sse_packed(state: &mut DecodeState) {
     }
 }
 
-fn decode_mmx(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    if state.op_prefix {
-        decode_rm_reg(
-            state,
-            operand1,
-            &XMM_REG_LIST,
-            16,
-            operand0,
-            &XMM_REG_LIST,
-            16,
-        );
+fn decode_vex_gather(state: &mut DecodeState) {
+    let qword_index = matches!(
+        state.result.operation,
+        InstructionOperation::VPGATHERQD |
+            InstructionOperation::VPGATHERQQ |
+            InstructionOperation::VGATHERQPS |
+            InstructionOperation::VGATHERQPD
+    );
+    // With 256 bit vectors, the index or the elements may only need
+    // half of the vector.
+    let wide_index = state.vex_l && (qword_index || !state.vex_w);
+    let wide_elements = state.vex_l && (!qword_index || state.vex_w);
+    let (reg_list, reg_size): (&[OperandType], u16) = if wide_elements {
+        (&YMM_REG_LIST, 32)
     } else {
-        decode_rm_reg(
-            state,
-            operand1,
-            &MMX_REG_LIST,
-            8,
-            operand0,
-            &MMX_REG_LIST,
-            8,
-        );
-    }
+        (&XMM_REG_LIST, 16)
+    };
+    state.vsib = Some(if wide_index { &YMM_REG_LIST } else { &XMM_REG_LIST });
+    let element_size = if state.vex_w { 8 } else { 4 };
+    decode_rm_reg(
+        state,
+        1,
+        &XMM_REG_LIST,
+        element_size,
+        0,
+        reg_list,
+        reg_size,
+    );
+    state.vsib = None;
+    // The mask follows the memory operand.
+    insert_vex_operand(state, 2, reg_list, reg_size);
 }
 
-fn decode_mmx_sse_only(state: &mut DecodeState) {
-    if state.op_prefix {
-        let operand0 = state.operand0;
-        let operand1 = state.operand1;
-        decode_rm_reg(
-            state,
-            operand1,
-            &XMM_REG_LIST,
-            16,
-            operand0,
-            &XMM_REG_LIST,
-            16,
-        );
+fn decode_evex(state: &mut DecodeState) {
+    // As with VEX, the EVEX prefix replaces these prefixes.
+    if state.op_prefix || state.rep != RepPrefix::NONE || state.rex ||
+        state.result.flags & X86Flag::LOCK != 0
+    {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    state.result.flags |= X86Flag::EVEX;
+    let p0 = read_8(state);
+    let p1 = read_8(state);
+    let p2 = read_8(state);
+    // Outside of 64-bit mode, bit 3 of P0 must be 0 and bit 2 of P1
+    // must be 1. In 64-bit mode, these are the APX B4 and inverted X4
+    // bits.
+    if !state.using64 && (p0 & 0x08 != 0 || p1 & 0x04 == 0) {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    state.result.layout.opcode_offset = state.pos as u8;
+    state.result.layout.opcode_length = 1;
+    // R, X, B, R', vvvv and V' are all stored inverted.
+    state.vex_w = p1 & 0x80 != 0;
+    state.vex_v = (!p1 >> 3) & 0x0f;
+    state.evex_p2 = p2;
+    if state.using64 {
+        state.rex_reg = p0 & 0x80 == 0;
+        state.rex_rm_2 = p0 & 0x40 == 0;
+        state.rex_rm_1 = p0 & 0x20 == 0;
+        state.evex_r = p0 & 0x10 == 0;
+        // X also extends a register in `r/m` to 32 registers.
+        state.evex_x = state.rex_rm_2;
+        // R' is also R4 for a general purpose register in `reg`.
+        state.rex2_reg = state.evex_r;
+        state.rex2_rm_1 = p0 & 0x08 != 0;
+        state.rex2_rm_2 = p1 & 0x04 == 0;
+        if p2 & 0x08 == 0 {
+            state.vex_v |= 0x10;
+        }
+        if state.vex_w {
+            state.op_size = 8;
+        }
     } else {
-        state.invalid = true;
+        state.vex_v &= 7;
     }
-}
-
-fn decode_mmx_group(state: &mut DecodeState) {
-    let mut reg_field: u8 = 0;
-    if state.op_prefix {
-        let operand0 = state.operand0;
-        decode_rm(state, operand0, &XMM_REG_LIST, 16, &mut reg_field);
-        state.result.operation =
-            MMX_GROUP_OPERATIONS[state.result.operation as usize][reg_field as usize][1];
+    match p1 & 3 {
+        1 => state.op_prefix = true,
+        2 => state.rep = RepPrefix::REPE,
+        3 => state.rep = RepPrefix::REPNE,
+        _ => {}
+    }
+    let map = p0 & 7;
+    let evex_map: &'static [EvexInstructionEncoding] = match map {
+        1 => &EVEX_0F_MAP,
+        2 => &EVEX_0F38_MAP,
+        3 => &EVEX_0F3A_MAP,
+        4 if state.using64 => {
+            decode_evex_map4(state);
+            return;
+        }
+        _ => {
+            state.result.operation = InstructionOperation::INVALID;
+            set_invalid(state, InvalidReason::Opcode);
+            return;
+        }
+    };
+    let opcode = read_8(state);
+    let prefix = vex_prefix_column(p1);
+    let native = find_vex_entry(evex_map, (opcode, prefix), state.vex_w, |entry| {
+        ((entry.opcode, entry.prefix), entry.form)
+    });
+    if let Some(encoding) = native {
+        process_evex_encoding(state, encoding);
     } else {
-        let operand0 = state.operand0;
-        decode_rm(state, operand0, &MMX_REG_LIST, 8, &mut reg_field);
-        state.result.operation =
-            MMX_GROUP_OPERATIONS[state.result.operation as usize][reg_field as usize][0];
+        // Everything else is a legacy SSE instruction with a new form.
+        match map {
+            1 => process_opcode(state, &TWO_BYTE_OPCODE_MAP, opcode),
+            2 => process_sparse_opcode(state, &THREE_BYTE_0F38_MAP, opcode),
+            _ => process_sparse_opcode(state, &THREE_BYTE_0F3A_MAP, opcode),
+        }
+        update_operation_for_evex(state);
+        let mmx = (0..4).any(|oper| {
+            let reg = operand_type(state, oper);
+            reg >= OperandType::REG_MM0 && reg <= OperandType::REG_MM7
+        });
+        // An `EVEX.pp` that the legacy instruction didn't use is invalid.
+        if mmx || state.rep != RepPrefix::NONE {
+            set_invalid(state, InvalidReason::Opcode);
+        }
+    }
+    apply_evex_form(state);
+    if map == 3 {
+        decode_vex_imm_8(state);
     }
-    let operand1 = state.operand1;
-    set_operand_to_imm_8(state, operand1);
 }
 
-fn decode_pinsrw(state: &mut DecodeState) {
-    decode_see_table_imm_8(state);
-    unsafe {
-        if (*state.operand1).operand == OperandType::MEM {
-            (*state.operand1).size = 2;
+/// Decode an APX instruction from EVEX map 4. These are legacy
+/// instructions that EVEX gives a new data destination in `vvvv`,
+/// when `EVEX.ND` is set, and a form that doesn't write the flags,
+/// when `EVEX.NF` is set.
+fn decode_evex_map4(state: &mut DecodeState) {
+    if state.op_prefix && !state.vex_w {
+        state.op_size = 2;
+    }
+    let opcode = read_8(state);
+    process_sparse_opcode(state, &EVEX_MAP4, opcode);
+    let p2 = state.evex_p2;
+    let new_dest = p2 & 0x10 != 0;
+    let no_flags = p2 & 0x04 != 0;
+    // There is no masking, vector length or broadcast.
+    if p2 & 0xe3 != 0 || state.rex2_rm_2 && !rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    match apx_forms(state.result.operation, opcode) {
+        Some((new_dest_form, no_flags_form)) => {
+            if new_dest && !new_dest_form || no_flags && !no_flags_form {
+                set_invalid(state, InvalidReason::Opcode);
+            }
         }
+        None => set_invalid(state, InvalidReason::Opcode),
+    }
+    if state.rep != RepPrefix::NONE && !state.rep_used {
+        set_invalid(state, InvalidReason::Opcode);
     }
+    if new_dest {
+        let reg_list = get_reg_list_for_final_op_size(state);
+        let size = state.final_op_size;
+        insert_vex_operand(state, 0, reg_list, size);
+    }
+    if !state.vex_v_used && state.vex_v != 0 {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    // PUSH2 and POP2 use the new data destination bit for their
+    // first register, which isn't a destination.
+    let push2_pop2 = matches!(
+        state.result.operation,
+        InstructionOperation::PUSH2 |
+        InstructionOperation::PUSH2P |
+        InstructionOperation::POP2 |
+        InstructionOperation::POP2P
+    );
+    state.result.evex = Some(X86Evex {
+        no_flags,
+        new_data_dest: new_dest && !push2_pop2,
+        ..Default::default()
+    });
 }
 
-fn decode_reg_cr(state: &mut DecodeState) {
-    if state.op_size == 2 {
-        state.op_size = 4;
+/// Whether an instruction from EVEX map 4 has a new data destination
+/// form and a no flags form, or `None` if it isn't in map 4.
+fn apx_forms(operation: InstructionOperation, opcode: u8) -> Option<(bool, bool)> {
+    match operation {
+        InstructionOperation::ADD |
+        InstructionOperation::OR |
+        InstructionOperation::AND |
+        InstructionOperation::SUB |
+        InstructionOperation::XOR |
+        InstructionOperation::INC |
+        InstructionOperation::DEC |
+        InstructionOperation::NEG |
+        InstructionOperation::ROL |
+        InstructionOperation::ROR |
+        InstructionOperation::SHL |
+        InstructionOperation::SHR |
+        InstructionOperation::SAR |
+        InstructionOperation::SHLD |
+        InstructionOperation::SHRD => Some((true, true)),
+        // These read the flags, or never wrote them.
+        InstructionOperation::ADC |
+        InstructionOperation::SBB |
+        InstructionOperation::RCL |
+        InstructionOperation::RCR |
+        InstructionOperation::ADCX |
+        InstructionOperation::ADOX |
+        InstructionOperation::NOT |
+        InstructionOperation::PUSH2 |
+        InstructionOperation::PUSH2P |
+        InstructionOperation::POP2 |
+        InstructionOperation::POP2P => Some((true, false)),
+        // Only the two operand IMUL has a new data destination.
+        InstructionOperation::IMUL => Some((opcode == 0xaf, true)),
+        InstructionOperation::MUL |
+        InstructionOperation::DIV |
+        InstructionOperation::IDIV |
+        InstructionOperation::POPCNT |
+        InstructionOperation::LZCNT |
+        InstructionOperation::TZCNT => Some((false, true)),
+        InstructionOperation::WRSSD |
+        InstructionOperation::WRSSQ |
+        InstructionOperation::WRUSSD |
+        InstructionOperation::WRUSSQ => Some((false, false)),
+        _ => None,
     }
-    let reg_list = get_reg_list_for_op_size(state);
-    let reg = read_8(state);
-    if state.result.flags & X86Flag::LOCK != 0 {
-        state.result.flags &= !X86Flag::LOCK;
-        state.rex_reg = true;
+}
+
+/// Decode PUSH2 and POP2 from `FF /6` and `8F /0` in EVEX map 4. The
+/// first register is in `vvvv`, and `EVEX.W` is a hint that the push
+/// and pop are paired.
+fn decode_push2_pop2(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
+    let reg_field = rm >> 3 & 7;
+    let push = last_opcode_byte(state) == 0xff;
+    if push && reg_field != 6 {
+        decode_group_ff(state);
+        return;
     }
-    unsafe {
-        (*state.operand0).operand = reg_list[((reg & 7) + if (*state).rex_rm_1 { 8 } else { 0 }) as
-                                                 usize];
-        (*state.operand0).size = (*state).op_size;
-        (*state.operand1).operand = OperandType::from_i32(
-            state.result.operation as i32 + (i32::from(reg) >> 3 & 7) +
-                if state.rex_reg { 8 } else { 0 },
-        );
-        (*state.operand1).size = state.op_size;
+    let operation = if push {
+        InstructionOperation::PUSH2
+    } else if reg_field == 0 {
+        InstructionOperation::POP2
+    } else {
+        InstructionOperation::INVALID
+    };
+    state.result.operation = if state.vex_w {
+        InstructionOperation::from_i32(operation as i32 + 1)
+    } else {
+        operation
+    };
+    state.final_op_size = 8;
+    decode_rm_final_op_size(state);
+    let reg = operand_type(state, state.operand0);
+    let vvvv = REG64_LIST[usize::from(state.vex_v)];
+    if state.evex_p2 & 0x10 == 0 || state.op_prefix {
+        set_invalid(state, InvalidReason::Opcode);
+    } else if reg == OperandType::MEM || reg == OperandType::REG_RSP ||
+               vvvv == OperandType::REG_RSP || !push && reg == vvvv
+    {
+        set_invalid(state, InvalidReason::Operands);
     }
-    state.result.operation = InstructionOperation::MOV;
 }
 
-fn decode_mov_sxzx8(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    let rm_reg_list = get_byte_reg_list(state);
-    let reg_list = get_reg_list_for_op_size(state);
-    let reg_size = state.op_size;
-    decode_rm_reg(
-        state,
-        operand1,
-        rm_reg_list,
-        1,
-        operand0,
-        reg_list,
-        reg_size,
-    );
+fn process_evex_encoding(state: &mut DecodeState, encoding: &EvexInstructionEncoding) {
+    state.result.operation = encoding.operation;
+    state.flags = 0;
+    state.vex_form = encoding.form;
+    state.evex_form = encoding.evex;
+    // The encoding was found by its prefix, which is now used.
+    decode_sse_prefix(state);
+    if encoding.form & VexForm::FLIP_OPERANDS != 0 {
+        state.operand0 = 1;
+        state.operand1 = 0;
+    } else {
+        state.operand0 = 0;
+        state.operand1 = 1;
+    }
+    (encoding.func)(state);
 }
 
-fn decode_mov_sxzx16(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    let reg_list = get_reg_list_for_op_size(state);
-    let reg_size = state.op_size;
-    decode_rm_reg(
-        state,
-        operand1,
-        &REG16_LIST,
-        2,
-        operand0,
-        reg_list,
-        reg_size,
-    );
+fn update_operation_for_evex(state: &mut DecodeState) {
+    let operation = state.result.operation as i32;
+    let entry = find_vex_entry(&EVEX_OPERATIONS, operation, state.vex_w, |entry| {
+        (entry.legacy as i32, entry.form)
+    });
+    match entry {
+        Some(entry) => {
+            state.result.operation = entry.operation;
+            state.vex_form = entry.form;
+            state.evex_form = entry.evex;
+        }
+        None => {
+            state.result.operation = InstructionOperation::INVALID;
+            set_invalid(state, InvalidReason::Opcode);
+        }
+    }
 }
 
-fn decode_mem_16(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, &REG32_LIST, 2, &mut _ignore);
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
-        }
+/// The list of vector registers of `size` bytes.
+fn vector_reg_list(size: u16) -> &'static [OperandType] {
+    match size {
+        16 => &XMM_REG_LIST,
+        32 => &YMM_REG_LIST,
+        _ => &ZMM_REG_LIST,
     }
 }
 
-fn decode_mem_32(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, &REG32_LIST, 4, &mut _ignore);
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
-        }
+/// Replace a register that was decoded from `XMM_REG_LIST` with the
+/// opmask register of the same number. There are only 8 opmask
+/// registers, so any extension of the register number is ignored.
+fn set_mask_operand(state: &mut DecodeState, oper: usize) {
+    let reg = operand_type(state, oper);
+    if reg >= OperandType::REG_XMM0 && reg <= OperandType::REG_XMM15 {
+        let index = (reg as i32 - OperandType::REG_XMM0 as i32) & 7;
+        set_reg_operand(state, oper, MASK_REG_LIST[index as usize], 8);
     }
 }
 
-fn decode_mem_64(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, &REG32_LIST, 8, &mut _ignore);
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
+fn rm_operand_is_mem(state: &DecodeState) -> bool {
+    state
+        .rm_operand
+        .is_some_and(|oper| operand_type(state, oper) == OperandType::MEM)
+}
+
+fn apply_evex_form(state: &mut DecodeState) {
+    let mut form = state.vex_form;
+    let evex = state.evex_form;
+    let p2 = state.evex_p2;
+    if form & VexForm::SHIFT != 0 {
+        form |= if operand_type(state, 1) == OperandType::IMM {
+            VexForm::NDD
+        } else {
+            VexForm::NDS | VexForm::RM_XMM
+        };
+    }
+    let rm_is_mem = rm_operand_is_mem(state);
+    // X4 only extends an index register.
+    if state.rex2_rm_2 && !rm_is_mem {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    let length = p2 >> 5 & 3;
+    let mut vector_size: u16 = 16 << length;
+    let mut rounding = None;
+    let mut sae = false;
+    let mut element_size: u16 = 0;
+    if p2 & 0x10 != 0 {
+        if rm_is_mem {
+            if evex & EvexForm::BCST == 0 {
+                set_invalid(state, InvalidReason::Opcode);
+            }
+            element_size = if state.vex_w { 8 } else { 4 };
+        } else if evex & (EvexForm::ER | EvexForm::SAE) != 0 {
+            // `EVEX.L'L` holds the rounding mode instead of the vector
+            // length, which is then always 512 bits.
+            if evex & EvexForm::ER != 0 {
+                rounding = Some(X86Rounding::from_bits(length));
+            }
+            sae = true;
+            vector_size = 64;
+        } else {
+            set_invalid(state, InvalidReason::Opcode);
+        }
+    }
+    if form & VexForm::LIG != 0 {
+        vector_size = 16;
+    } else if vector_size > 64 {
+        set_invalid(state, InvalidReason::Opcode);
+        vector_size = 64;
+    }
+    if form & VexForm::L0 != 0 && vector_size != 16 || form & VexForm::L1 != 0 && vector_size == 16 ||
+        evex & EvexForm::L512 != 0 && vector_size != 64 || !vex_w_allowed(form, state.vex_w)
+    {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    let mut broadcast = None;
+    let mut disp8_scale: u16 = 0;
+    for i in 0..4 {
+        let is_rm = state.rm_operand == Some(i);
+        let operand = &mut state.result.operands[i];
+        if let X86Operand::Memory(mem) = operand {
+            if vector_size > 16 {
+                if form & VexForm::RM_HALF != 0 {
+                    mem.size *= vector_size / 16;
+                } else if form & VexForm::RM_XMM == 0 {
+                    mem.size = vector_size;
+                }
+            }
+            if let Some(count) = mem.size.checked_div(element_size) {
+                broadcast = Some(count as u8);
+                mem.size = element_size;
+            }
+            disp8_scale = if evex & EvexForm::T1S != 0 && element_size == 0 {
+                if state.vex_w { 8 } else { 4 }
+            } else {
+                mem.size
+            };
+        } else if let X86Operand::Register(operand) = operand {
+            if operand.register < OperandType::REG_XMM0 ||
+                operand.register > OperandType::REG_XMM15
+            {
+                continue;
+            }
+            let mut reg = (operand.register as i32 - OperandType::REG_XMM0 as i32) as usize;
+            if is_rm && state.evex_x || !is_rm && state.evex_r {
+                reg += 16;
+            }
+            let half = if vector_size > 16 { vector_size / 2 } else { 16 };
+            let size = if is_rm {
+                if form & VexForm::RM_XMM != 0 ||
+                    form & VexForm::RM_HALF != 0 && evex & EvexForm::RM_QUARTER != 0
+                {
+                    16
+                } else if form & VexForm::RM_HALF != 0 {
+                    half
+                } else {
+                    vector_size
+                }
+            } else if form & VexForm::REG_XMM != 0 {
+                half
+            } else {
+                vector_size
+            };
+            if !is_rm && evex & EvexForm::K_DEST != 0 {
+                operand.register = MASK_REG_LIST[reg & 7];
+                operand.size = 8;
+            } else {
+                operand.register = vector_reg_list(size)[reg];
+                operand.size = size;
+            }
+        }
+    }
+    let v_list = vector_reg_list(vector_size);
+    if form & VexForm::NDD != 0 {
+        insert_vex_operand(state, 0, v_list, vector_size);
+    } else if form & VexForm::NDS != 0 || form & VexForm::NDS_REG != 0 && !rm_is_mem ||
+               form & VexForm::NDS_LOAD != 0 &&
+                   operand_type(state, 0) != OperandType::MEM
+    {
+        insert_vex_operand(state, 1, v_list, vector_size);
+    }
+    // An unused vvvv must be 1111b, and V' must be 1.
+    if !state.vex_v_used && state.vex_v != 0 {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    let mask = usize::from(p2 & 7);
+    let zeroing = p2 & 0x80 != 0;
+    if evex & EvexForm::NO_MASK != 0 && (mask != 0 || zeroing) ||
+        evex & EvexForm::MASK_REQUIRED != 0 && (mask == 0 || zeroing)
+    {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    // Zeroing needs a mask, and can't be used with a store or with an
+    // opmask destination.
+    if zeroing &&
+        (mask == 0 || operand_type(state, 0) == OperandType::MEM ||
+             evex & EvexForm::K_DEST != 0)
+    {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    // An 8 bit displacement is compressed by the size of the access.
+    if state.result.layout.displacement_size == 1 {
+        if let Some(oper) = state.rm_operand {
+            if let X86Operand::Memory(mem) = &mut state.result.operands[oper] {
+                mem.displacement *= i64::from(disp8_scale);
+            }
         }
     }
+    state.vex_form = form;
+    state.result.evex = Some(X86Evex {
+        mask: if mask != 0 { Some(MASK_REG_LIST[mask]) } else { None },
+        zeroing,
+        broadcast,
+        rounding,
+        sae,
+        disp8_scale: disp8_scale as u8,
+        no_flags: false,
+        new_data_dest: false,
+    });
 }
 
-fn decode_mem_80(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, &REG32_LIST, 10, &mut _ignore);
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
+/// Replace a register that was decoded from `XMM_REG_LIST` with the
+/// tile register of the same number. There are only 8 tile registers.
+fn set_tile_operand(state: &mut DecodeState, oper: usize) {
+    let operand = operand_type(state, oper);
+    if operand >= OperandType::REG_XMM0 && operand <= OperandType::REG_XMM15 {
+        let reg = (operand as i32 - OperandType::REG_XMM0 as i32) as usize;
+        if reg >= 8 {
+            set_invalid(state, InvalidReason::Operands);
         }
+        set_reg_operand(state, oper, TMM_REG_LIST[reg & 7], 1024);
     }
 }
 
-fn decode_mem_float_env(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let rm_size = if state.op_size == 2 { 14 } else { 28 };
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, &REG32_LIST, rm_size, &mut _ignore);
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
+/// Decode `VEX 0F38 49`, which holds the AMX tile configuration
+/// instructions and TILEZERO.
+fn decode_amx_tile_config(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
+    if rm >> 6 == 3 {
+        // TILERELEASE has no operands, and shares its prefix with
+        // LDTILECFG.
+        if state.result.operation == InstructionOperation::LDTILECFG && rm == 0xc0 {
+            state.result.operation = InstructionOperation::TILERELEASE;
+            read_mod_rm(state);
+        } else if state.result.operation == InstructionOperation::TILEZERO && rm & 7 == 0 {
+            read_mod_rm(state);
+            if state.rex_reg {
+                set_invalid(state, InvalidReason::Operands);
+            }
+            set_reg_operand(state, 0, TMM_REG_LIST[usize::from(rm >> 3 & 7)], 1024);
+        } else {
+            state.result.operation = InstructionOperation::INVALID;
+            read_mod_rm(state);
         }
+    } else if state.result.operation == InstructionOperation::TILEZERO || rm >> 3 & 7 != 0 {
+        state.result.operation = InstructionOperation::INVALID;
+        decode_rm_final_op_size(state);
+    } else {
+        let mut _ignore: u8 = 0;
+        decode_rm(state, 0, &REG64_LIST, 64, &mut _ignore);
+    }
+    if !state.using64 {
+        set_invalid(state, InvalidReason::Opcode);
     }
 }
 
-fn decode_mem_float_save(state: &mut DecodeState) {
+/// Decode a tile load or store, whose memory operand must have a SIB
+/// byte. The index register is the stride between rows rather than an
+/// index into the tile.
+fn decode_amx_tile_load_store(state: &mut DecodeState) {
+    let rm: u8 = peek_8(state);
     let operand0 = state.operand0;
-    let rm_size = if state.op_size == 2 { 94 } else { 108 };
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, &REG32_LIST, rm_size, &mut _ignore);
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
-        }
+    let operand1 = state.operand1;
+    decode_rm_reg(
+        state,
+        operand1,
+        &XMM_REG_LIST,
+        1024,
+        operand0,
+        &XMM_REG_LIST,
+        1024,
+    );
+    set_tile_operand(state, operand0);
+    if !state.using64 {
+        set_invalid(state, InvalidReason::Opcode);
+    } else if rm >> 6 == 3 || rm & 7 != 4 {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_fpu_reg(state: &mut DecodeState) {
-    let operand0 = state.operand0;
-    let mut _ignore: u8 = 0;
-    decode_rm(state, operand0, &FPU_REG_LIST, 10, &mut _ignore);
+/// Decode a tile dot product, with the destination tile in `reg` and
+/// the source tiles in `r/m` and `VEX.vvvv`.
+fn decode_amx_tile_dot(state: &mut DecodeState) {
+    decode_vex_sse(state);
+    set_tile_operand(state, 0);
+    set_tile_operand(state, 1);
+    if state.vex_v >= 8 {
+        set_invalid(state, InvalidReason::Operands);
+        state.vex_v &= 7;
+    }
+    insert_vex_operand(state, 2, &TMM_REG_LIST, 1024);
+    if !state.using64 {
+        set_invalid(state, InvalidReason::Opcode);
+    } else if rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
+    }
 }
 
-fn decode_fpu_reg_st0(state: &mut DecodeState) {
-    decode_fpu_reg(state);
-    unsafe {
-        (*state.operand1).operand = OperandType::REG_ST0;
-        (*state.operand1).size = 10;
+/// Decode an opmask instruction with opmask registers in `reg` and
+/// `r/m`.
+fn decode_vex_mask(state: &mut DecodeState) {
+    decode_vex_sse(state);
+    set_mask_operand(state, 0);
+    set_mask_operand(state, 1);
+    if rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_reg_group_no_operands(state: &mut DecodeState) {
-    let rm_byte: u8 = read_8(state);
-    state.result.operation = GROUP_OPERATIONS[state.result.operation as usize][(rm_byte & 7) as
-                                                                                   usize];
+/// Like `decode_vex_mask`, with another source opmask register in
+/// `VEX.vvvv`.
+fn decode_vex_mask_nds(state: &mut DecodeState) {
+    decode_vex_mask(state);
+    state.vex_v &= 7;
+    insert_vex_operand(state, 1, &MASK_REG_LIST, 8);
 }
 
-fn decode_reg_group_ax(state: &mut DecodeState) {
-    decode_reg_group_no_operands(state);
-    unsafe {
-        (*state.operand0).operand = OperandType::REG_AX;
-        (*state.operand0).size = 2u16;
+fn decode_vex_mask_mov(state: &mut DecodeState) {
+    let size: u16 = match state.result.operation {
+        InstructionOperation::KMOVB => 1,
+        InstructionOperation::KMOVW => 2,
+        InstructionOperation::KMOVD => 4,
+        _ => 8,
+    };
+    let rm: u8 = peek_8(state);
+    let operand0 = state.operand0;
+    let operand1 = state.operand1;
+    decode_rm_reg(
+        state,
+        operand1,
+        &XMM_REG_LIST,
+        if rm >> 6 == 3 { 8 } else { size },
+        operand0,
+        &XMM_REG_LIST,
+        8,
+    );
+    set_mask_operand(state, 0);
+    set_mask_operand(state, 1);
+    // The store has no register to register form.
+    if operand0 == 1 && !rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_cmp_xch_8b(state: &mut DecodeState) {
-    let rm: u8 = peek_8(state);
-    let reg_field: u8 = rm >> 3 & 7;
-    if reg_field == 1 {
-        if state.op_size == 2 {
-            state.op_size = 4;
-        } else if state.op_size == 8 {
-            state.result.operation = InstructionOperation::CMPXCH16B;
-        }
-        let operand0 = state.operand0;
-        let rm_size = state.op_size * 2;
-        let reg_list = get_reg_list_for_op_size(state);
-        let mut _ignore: u8 = 0;
-        decode_rm(state, operand0, reg_list, rm_size, &mut _ignore);
-    } else if reg_field == 6 {
-        if state.op_prefix {
-            state.result.operation = InstructionOperation::VMCLEAR;
-        } else if state.rep == RepPrefix::REPE {
-            state.result.operation = InstructionOperation::VMXON;
-        } else {
-            state.result.operation = InstructionOperation::VMPTRLD;
-        }
-        let operand0 = state.operand0;
-        let mut _ignore: u8 = 0;
-        decode_rm(state, operand0, &REG64_LIST, 8, &mut _ignore);
-    } else if reg_field == 7 {
-        state.result.operation = InstructionOperation::VMPTRST;
-        let operand0 = state.operand0;
-        let mut _ignore: u8 = 0;
-        decode_rm(state, operand0, &REG64_LIST, 8, &mut _ignore);
+/// Decode a move between an opmask register and a general purpose
+/// register, which is 64 bits with `VEX.W`.
+fn decode_vex_mask_gpr(state: &mut DecodeState) {
+    let (gpr_list, gpr_size): (&[OperandType], u16) = if state.vex_w {
+        (&REG64_LIST, 8)
     } else {
-        state.invalid = true;
+        (&REG32_LIST, 4)
+    };
+    if last_opcode_byte(state) == 0x92 {
+        decode_rm_reg(state, 1, gpr_list, gpr_size, 0, &XMM_REG_LIST, 8);
+        set_mask_operand(state, 0);
+    } else {
+        decode_rm_reg(state, 1, &XMM_REG_LIST, 8, 0, gpr_list, gpr_size);
+        set_mask_operand(state, 1);
     }
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
-        }
+    if rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_mov_nti(state: &mut DecodeState) {
-    if state.op_size == 2 {
-        state.op_size = 4;
-    }
-    let operand0 = state.operand0;
-    let operand1 = state.operand1;
-    let op_size = state.op_size;
-    let reg_list = get_reg_list_for_op_size(state);
+fn decode_evex_sse_16(state: &mut DecodeState) {
+    decode_vex_sse_sized(state, 2);
+}
+
+/// Decode a conversion of a scalar to a general purpose register.
+fn decode_evex_to_gpr(state: &mut DecodeState) {
+    let size: u16 = match state.result.operation {
+        InstructionOperation::VCVTSS2USI | InstructionOperation::VCVTTSS2USI => 4,
+        _ => 8,
+    };
+    let rm: u8 = peek_8(state);
+    let (reg_list, reg_size): (&[OperandType], u16) = if state.vex_w {
+        (&REG64_LIST, 8)
+    } else {
+        (&REG32_LIST, 4)
+    };
     decode_rm_reg(
         state,
-        operand0,
-        reg_list,
-        op_size,
-        operand1,
+        1,
+        &XMM_REG_LIST,
+        if rm >> 6 == 3 { 16 } else { size },
+        0,
         reg_list,
-        op_size,
+        reg_size,
     );
-    unsafe {
-        if (*state.operand0).operand != OperandType::MEM {
-            state.invalid = true;
-        }
+}
+
+/// Decode a conversion from a general purpose register to a scalar.
+fn decode_evex_from_gpr(state: &mut DecodeState) {
+    let (rm_list, rm_size): (&[OperandType], u16) = if state.vex_w {
+        (&REG64_LIST, 8)
+    } else {
+        (&REG32_LIST, 4)
+    };
+    decode_rm_reg(state, 1, rm_list, rm_size, 0, &XMM_REG_LIST, 16);
+}
+
+/// Decode a broadcast from a general purpose register.
+fn decode_evex_gpr_broadcast(state: &mut DecodeState) {
+    let (rm_list, rm_size): (&[OperandType], u16) = if state.vex_w {
+        (&REG64_LIST, 8)
+    } else {
+        (&REG32_LIST, 4)
+    };
+    decode_rm_reg(state, 1, rm_list, rm_size, 0, &XMM_REG_LIST, 16);
+    if rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
     }
 }
 
-fn decode_crc_32(state: &mut DecodeState) {
-    let src_reg_list = get_reg_list_for_final_op_size(state);
-    let dest_reg_list = if (*state).op_size == 8 {
-        &REG64_LIST
+/// Decode a conversion of an opmask register in `r/m` to a vector.
+fn decode_evex_from_mask(state: &mut DecodeState) {
+    decode_vex_sse(state);
+    set_mask_operand(state, 1);
+    if rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
+    }
+}
+
+/// Decode a conversion of a vector to an opmask register in `reg`.
+fn decode_evex_to_mask(state: &mut DecodeState) {
+    decode_vex_sse(state);
+    if rm_operand_is_mem(state) {
+        set_invalid(state, InvalidReason::Operands);
+    }
+}
+
+/// Decode a shift or rotate by an immediate from groups 12 to 14,
+/// where the `reg` field selects the operation.
+fn decode_evex_shift_imm(state: &mut DecodeState) {
+    let group = usize::from(last_opcode_byte(state) - 0x71);
+    let reg_field = usize::from(peek_8(state) >> 3 & 7);
+    state.result.operation = EVEX_SHIFT_OPERATIONS[group][reg_field][usize::from(state.vex_w)];
+    if state.result.operation == InstructionOperation::INVALID {
+        set_invalid(state, InvalidReason::Opcode);
+    }
+    // Only the doubleword and quadword forms can broadcast.
+    if group != 0 && reg_field & 3 != 3 {
+        state.evex_form |= EvexForm::BCST;
+    }
+    let mut reg: u8 = 0;
+    decode_rm(state, 0, &XMM_REG_LIST, 16, &mut reg);
+    set_operand_to_imm_8(state, 1);
+}
+
+fn decode_evex_gather(state: &mut DecodeState) {
+    let qword_index = matches!(
+        state.result.operation,
+        InstructionOperation::VPGATHERQD |
+            InstructionOperation::VPGATHERQQ |
+            InstructionOperation::VGATHERQPS |
+            InstructionOperation::VGATHERQPD |
+            InstructionOperation::VPSCATTERQD |
+            InstructionOperation::VPSCATTERQQ |
+            InstructionOperation::VSCATTERQPS |
+            InstructionOperation::VSCATTERQPD
+    );
+    let vector_size = (16u16 << (state.evex_p2 >> 5 & 3)).min(64);
+    // Either the index or the elements may only need half the vector.
+    let index_size = if qword_index || !state.vex_w || vector_size == 16 {
+        vector_size
     } else {
-        &REG32_LIST
+        vector_size / 2
     };
-    let dest_size: u16 = if state.op_size == 8 { 8 } else { 4 };
+    let index_list = vector_reg_list(index_size);
+    state.vsib = Some(index_list);
+    let element_size = if state.vex_w { 8 } else { 4 };
     let operand0 = state.operand0;
     let operand1 = state.operand1;
-    let final_op_size = state.final_op_size;
     decode_rm_reg(
         state,
         operand1,
-        src_reg_list,
-        final_op_size,
+        &XMM_REG_LIST,
+        element_size,
         operand0,
-        dest_reg_list,
-        dest_size,
+        &XMM_REG_LIST,
+        16,
     );
+    state.vsib = None;
+    // V' extends the index register rather than `EVEX.vvvv`.
+    if state.vex_v & 0x10 != 0 {
+        if let X86Operand::Memory(MemRef { index: Some(index), .. }) =
+            &mut state.result.operands[operand1]
+        {
+            if let Some(pos) = index_list.iter().position(|reg| reg == index) {
+                *index = index_list[pos + 16];
+            }
+        }
+    }
+    state.vex_v &= 0x0f;
+    state.vex_form |= VexForm::RM_XMM;
+    if qword_index && !state.vex_w {
+        state.vex_form |= VexForm::REG_XMM;
+    }
 }
 
-fn decode_arpl(state: &mut DecodeState) {
-    if state.using64 {
-        // In 64-bit, ARPL is repurposed to MOVSXD
-        let reg_list = get_reg_list_for_final_op_size(state);
-        state.result.operation = InstructionOperation::MOVSXD;
-        let operand0 = state.operand0;
-        let operand1 = state.operand1;
-        let final_op_size = state.final_op_size;
-        decode_rm_reg(
-            state,
-            operand1,
-            &REG32_LIST,
-            4,
-            operand0,
-            reg_list,
-            final_op_size,
-        );
-    } else {
-        // ARPL instruction
-        state.operand0 = &mut state.result.operands[1] as *mut X86Operand;
-        state.operand1 = &mut state.result.operands[0] as *mut X86Operand;
-        state.final_op_size = 2;
-        decode_reg_rm(state);
+/// Keep the byte that was just read as part of the prefixes.
+fn record_prefix_byte(state: &mut DecodeState, byte: u8) {
+    if let Some(prefix) = state.result.prefix_bytes.get_mut(state.pos - 1) {
+        *prefix = byte;
     }
 }
 
@@ -8988,7 +18843,7 @@ fn process_prefixes(state: &mut DecodeState) {
         if state.invalid {
             break;
         }
-        if prefix >= 0x26 && (prefix <= 0x3e) && (prefix & 7 == 6) {
+        if (0x26..=0x3e).contains(&prefix) && (prefix & 7 == 6) {
             // Segment prefix
             let prefix = i32::from(prefix);
             state.result.segment =
@@ -9010,20 +18865,34 @@ fn process_prefixes(state: &mut DecodeState) {
             state.rep = RepPrefix::REPNE;
         } else if prefix == 0xf3 {
             state.rep = RepPrefix::REPE;
+        } else if state.using64 && prefix == 0xd5 {
+            // REX2 prefix, which is followed by the opcode
+            record_prefix_byte(state, prefix);
+            let payload = read_8(state);
+            record_prefix_byte(state, payload);
+            if rex != 0 {
+                set_invalid(state, InvalidReason::Opcode);
+            }
+            rex = 0;
+            state.rex2 = Some(payload);
+            break;
         } else {
-            if !(state.using64 && (prefix >= 0x40) && (prefix <= 0x4f)) {
+            if !(state.using64 && (0x40..=0x4f).contains(&prefix)) {
                 // Not a prefix, continue instruction processing.
-                state.opcode = unsafe { state.opcode.offset(-1) };
-                state.len = state.len.wrapping_add(1);
+                state.pos -= 1;
+                state.len += 1;
                 break;
             }
             // REX prefix
             rex = prefix;
+            record_prefix_byte(state, prefix);
             continue;
         }
         // Force ignore REX unless it is the last prefix
         rex = 0;
+        record_prefix_byte(state, prefix);
     }
+    state.result.layout.prefix_length = state.pos as u8;
     if state.op_prefix {
         state.op_size = if state.op_size == 2 { 4 } else { 2 };
     }
@@ -9036,69 +18905,205 @@ fn process_prefixes(state: &mut DecodeState) {
         state.rex_rm_1 = rex & 1 != 0;
         state.rex_rm_2 = rex & 2 != 0;
         state.rex_reg = rex & 4 != 0;
-        if rex & 8 != 0 {
+        state.rex_w = rex & 8 != 0;
+        if state.rex_w {
+            state.op_size = 8;
+        }
+    }
+    if let Some(payload) = state.rex2 {
+        // The low half of the payload is the same as REX, and the high
+        // half extends the registers to 32 and selects the opcode map.
+        state.rex = true;
+        state.rex_rm_1 = payload & 1 != 0;
+        state.rex_rm_2 = payload & 2 != 0;
+        state.rex_reg = payload & 4 != 0;
+        state.rex2_rm_1 = payload & 0x10 != 0;
+        state.rex2_rm_2 = payload & 0x20 != 0;
+        state.rex2_reg = payload & 0x40 != 0;
+        state.rex_w = payload & 8 != 0;
+        if state.rex_w {
             state.op_size = 8;
         }
     }
 }
 
 fn finish_disassemble(state: &mut DecodeState) {
-    state.result.length = (state.opcode as usize).wrapping_sub(state.opcode_start as usize) /
-        ::std::mem::size_of::<u8>();
-    if !state.rip_rel_fixup.is_null() {
-        unsafe {
-            *state.rip_rel_fixup = (*state.rip_rel_fixup as usize).wrapping_add(
-                state.addr.wrapping_add(
-                    state
-                        .result
-                        .length,
-                ),
-            ) as isize;
+    state.result.length = state.pos;
+    if let Some(oper) = state.rip_rel_fixup {
+        let next = state.addr.wrapping_add(state.result.length as u64);
+        if let X86Operand::Memory(mem) = &mut state.result.operands[oper] {
+            mem.displacement = (mem.displacement as u64).wrapping_add(next) as i64;
         }
     }
-    if state.insufficient_length && (state.original_length < 15) {
+    if state.insufficient_length && (state.opcode.len() < 15) {
         state.result.flags |= X86Flag::INSUFFICIENT_LENGTH;
     }
+    // The operands end at the first unused one.
+    state.result.operand_count = state
+        .result
+        .operands
+        .iter()
+        .position(|operand| *operand == NO_OPERAND)
+        .unwrap_or(4) as u8;
+    if state.rep_used {
+        state.result.prefix_usage |= PrefixUsage::REP;
+    }
+    // REX.W takes priority over an operand size prefix that isn't
+    // part of the opcode, except where the operand size defaults to
+    // 64 bits and the prefix still selects 32 bits.
+    if state.rex_w && state.op_size == 8 && !state.op_prefix_used {
+        state.result.prefix_usage |= PrefixUsage::OP_SIZE_OVERRIDDEN;
+    }
 }
 
-/// Disassemble a single x86 instruction in 16 bit mode from a stream of
-/// opcodes.
+fn get_decode_error(state: &DecodeState) -> DecodeError {
+    let length = state.result.length;
+    let operation = state.result.operation;
+    if state.insufficient_length {
+        if state.opcode.len() < 15 {
+            DecodeError::InsufficientLength { length, operation }
+        } else {
+            DecodeError::TooLong { length, operation }
+        }
+    } else {
+        match state.invalid_reason {
+            InvalidReason::Opcode => DecodeError::InvalidOpcode { length, operation },
+            InvalidReason::In64BitMode => DecodeError::InvalidIn64BitMode { length, operation },
+            InvalidReason::Lock => DecodeError::InvalidLock { length, operation },
+            InvalidReason::Operands => DecodeError::InvalidOperands { length, operation },
+        }
+    }
+}
+
+/// The processor mode that instructions are decoded for.
+///
+/// The mode determines the default operand and address sizes as well
+/// as whether the instruction set extensions of x86_64 are available.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // Choose the mode at runtime, for example from an ELF header.
+/// let e_machine = 62; // EM_X86_64
+/// let mode = if e_machine == 62 { X86Mode::Bits64 } else { X86Mode::Bits32 };
+///
+/// let data = [0x48u8, 0x89, 0xe5];
+/// let instr = disassemble(mode, &data, 0).unwrap();
+/// assert_eq!(instr.operation, InstructionOperation::MOV);
+/// match instr.operands()[0] {
+///     X86Operand::Register(reg) => assert_eq!(reg.register, OperandType::REG_RBP),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum X86Mode {
+    /// 16 bit real or protected mode.
+    Bits16,
+    /// 32 bit protected mode.
+    Bits32,
+    /// 64 bit long mode.
+    Bits64,
+}
+
+/// Disassemble a single x86 instruction in the given mode from a stream
+/// of opcodes.
+///
+/// At most 15 bytes of `opcode` are examined. If no valid instruction
+/// can be decoded, the returned [`DecodeError`] describes why.
 ///
 /// ```
 /// use burst::x86::*;
 ///
 /// let data = [0u8, 0u8];
-/// if let Ok(instr) = disassemble_16(&data, 0, data.len()) {
+/// if let Ok(instr) = disassemble(X86Mode::Bits64, &data, 0) {
 ///     // ...
 /// }
 /// ```
-pub fn disassemble_16(opcode: &[u8], addr: usize, max_length: usize) -> Result<X86Instruction, ()> {
-    let max_length = cmp::min(max_length, 15);
+///
+/// [`DecodeError`]: enum.DecodeError.html
+pub fn disassemble(
+    mode: X86Mode,
+    opcode: &[u8],
+    addr: u64,
+) -> Result<X86Instruction, DecodeError> {
+    disassemble_mode(mode, opcode, addr, opcode.len())
+}
+
+fn disassemble_mode(
+    mode: X86Mode,
+    opcode: &[u8],
+    addr: u64,
+    max_length: usize,
+) -> Result<X86Instruction, DecodeError> {
+    // Never trust max_length beyond the bytes actually available.
+    let max_length = cmp::min(cmp::min(max_length, opcode.len()), 15);
+    let (addr_size, op_size) = match mode {
+        X86Mode::Bits16 => (2, 2),
+        X86Mode::Bits32 => (4, 4),
+        X86Mode::Bits64 => (8, 4),
+    };
+    let mut result = X86Instruction {
+        mode,
+        ..Default::default()
+    };
     let mut state = DecodeState {
-        opcode_start: opcode.as_ptr(),
-        opcode: opcode.as_ptr(),
+        opcode: &opcode[..max_length],
         addr,
         len: max_length,
-        original_length: max_length,
-        addr_size: 2,
-        op_size: 2,
-        using64: false,
-        ..Default::default()
+        addr_size,
+        op_size,
+        using64: mode == X86Mode::Bits64,
+        ..DecodeState::new(&mut result)
     };
     process_prefixes(&mut state);
+    state.result.layout.opcode_offset = state.pos as u8;
+    state.result.layout.opcode_length = 1;
     let next_opcode = read_8(&mut state);
-    process_opcode(&mut state, &MAIN_OPCODE_MAP, next_opcode);
+    if let Some(payload) = state.rex2 {
+        process_rex2_opcode(&mut state, payload, next_opcode);
+    } else {
+        process_opcode(&mut state, &MAIN_OPCODE_MAP, next_opcode);
+    }
     finish_disassemble(&mut state);
     if state.invalid {
-        Err(())
+        Err(get_decode_error(&state))
     } else {
-        Ok(state.result)
+        Ok(result)
     }
 }
 
+/// Disassemble a single x86 instruction in 16 bit mode from a stream of
+/// opcodes.
+///
+/// This is the same as calling [`disassemble`] with `X86Mode::Bits16`,
+/// but also limits decoding to `max_length` bytes. A `max_length` larger
+/// than `opcode` is treated as the length of `opcode`.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// let data = [0u8, 0u8];
+/// if let Ok(instr) = disassemble_16(&data, 0, data.len()) {
+///     // ...
+/// }
+/// ```
+///
+/// [`disassemble`]: fn.disassemble.html
+pub fn disassemble_16(
+    opcode: &[u8],
+    addr: u64,
+    max_length: usize,
+) -> Result<X86Instruction, DecodeError> {
+    disassemble_mode(X86Mode::Bits16, opcode, addr, max_length)
+}
+
 /// Disassemble a single x86 instruction in 32 bit mode from a stream of
 /// opcodes.
 ///
+/// This is the same as calling [`disassemble`] with `X86Mode::Bits32`,
+/// but also limits decoding to `max_length` bytes. A `max_length` larger
+/// than `opcode` is treated as the length of `opcode`.
+///
 /// ```
 /// use burst::x86::*;
 ///
@@ -9107,33 +19112,23 @@ pub fn disassemble_16(opcode: &[u8], addr: usize, max_length: usize) -> Result<X
 ///     // ...
 /// }
 /// ```
-pub fn disassemble_32(opcode: &[u8], addr: usize, max_length: usize) -> Result<X86Instruction, ()> {
-    let max_length = cmp::min(max_length, 15);
-    let mut state = DecodeState {
-        opcode_start: opcode.as_ptr(),
-        opcode: opcode.as_ptr(),
-        addr,
-        len: max_length,
-        original_length: max_length,
-        addr_size: 4,
-        op_size: 4,
-        using64: false,
-        ..Default::default()
-    };
-    process_prefixes(&mut state);
-    let next_opcode = read_8(&mut state);
-    process_opcode(&mut state, &MAIN_OPCODE_MAP, next_opcode);
-    finish_disassemble(&mut state);
-    if state.invalid {
-        Err(())
-    } else {
-        Ok(state.result)
-    }
+///
+/// [`disassemble`]: fn.disassemble.html
+pub fn disassemble_32(
+    opcode: &[u8],
+    addr: u64,
+    max_length: usize,
+) -> Result<X86Instruction, DecodeError> {
+    disassemble_mode(X86Mode::Bits32, opcode, addr, max_length)
 }
 
 /// Disassemble a single x86 instruction in 64 bit mode from a stream of
 /// opcodes.
 ///
+/// This is the same as calling [`disassemble`] with `X86Mode::Bits64`,
+/// but also limits decoding to `max_length` bytes. A `max_length` larger
+/// than `opcode` is treated as the length of `opcode`.
+///
 /// ```
 /// use burst::x86::*;
 ///
@@ -9142,28 +19137,14 @@ pub fn disassemble_32(opcode: &[u8], addr: usize, max_length: usize) -> Result<X
 ///     // ...
 /// }
 /// ```
-pub fn disassemble_64(opcode: &[u8], addr: usize, max_length: usize) -> Result<X86Instruction, ()> {
-    let max_length = cmp::min(max_length, 15);
-    let mut state = DecodeState {
-        opcode_start: opcode.as_ptr(),
-        opcode: opcode.as_ptr(),
-        addr,
-        len: max_length,
-        original_length: max_length,
-        addr_size: 8,
-        op_size: 4,
-        using64: true,
-        ..Default::default()
-    };
-    process_prefixes(&mut state);
-    let next_opcode = read_8(&mut state);
-    process_opcode(&mut state, &MAIN_OPCODE_MAP, next_opcode);
-    finish_disassemble(&mut state);
-    if state.invalid {
-        Err(())
-    } else {
-        Ok(state.result)
-    }
+///
+/// [`disassemble`]: fn.disassemble.html
+pub fn disassemble_64(
+    opcode: &[u8],
+    addr: u64,
+    max_length: usize,
+) -> Result<X86Instruction, DecodeError> {
+    disassemble_mode(X86Mode::Bits64, opcode, addr, max_length)
 }
 
 fn write_operand(
@@ -9175,7 +19156,7 @@ fn write_operand(
     if plus {
         stream.write_char('+')?;
     }
-    stream.write_str(OPERAND_TYPE_TABLE[type_ as usize].name)?;
+    stream.write_str(type_.name())?;
     if scale != 1 {
         stream.write_char('*')?;
         stream.write_char((scale + b'0') as char)?;
@@ -9183,8 +19164,54 @@ fn write_operand(
     Ok(())
 }
 
+fn write_memory(
+    stream: &mut dyn fmt::Write,
+    instr: &X86Instruction,
+    mem: &MemRef,
+) -> fmt::Result {
+    let mut plus: bool = false;
+    stream.write_str(get_size_string(mem.size))?;
+    if instr.segment != SegmentRegister::DEFAULT || mem.segment == SegmentRegister::ES {
+        write_operand(
+            stream,
+            OperandType::from_i32(mem.segment as i32 + OperandType::REG_ES as i32),
+            1,
+            false,
+        )?;
+        stream.write_char(':')?;
+    }
+    stream.write_char('[')?;
+    if let Some(base) = mem.base {
+        write_operand(stream, base, 1, false)?;
+        plus = true;
+    }
+    if let Some(index) = mem.index {
+        write_operand(stream, index, mem.scale, plus)?;
+        plus = true;
+    }
+    if mem.displacement != 0 || mem.base.is_none() && mem.index.is_none() {
+        // An 8 bit displacement that EVEX scaled is still written as a
+        // short signed value.
+        let short = (-0x80..=0x7f).contains(&mem.displacement) ||
+            instr.evex.is_some() && instr.layout.displacement_size == 1;
+        if plus && short && (mem.displacement < 0) {
+            write!(stream, "-{:#02x}", -mem.displacement)?;
+        } else if plus && short && (mem.displacement > 0) {
+            write!(stream, "+{:#02x}", mem.displacement)?;
+        } else {
+            if plus {
+                stream.write_char('+')?;
+            }
+            write!(stream, "{:#08x}", mem.displacement)?;
+        }
+    }
+    stream.write_char(']')
+}
+
 fn get_size_string(size: u16) -> &'static str {
     match size {
+        64 => "zword ",
+        32 => "yword ",
         16 => "oword ",
         10 => "tword ",
         8 => "qword ",
@@ -9230,27 +19257,23 @@ pub fn format_instruction_string(
     stream: &mut dyn fmt::Write,
     fmt: &str,
     opcode: Option<&[u8]>,
-    addr: usize,
+    addr: u64,
     instr: &X86Instruction,
 ) -> fmt::Result {
-    let fmt = fmt.chars().collect::<Vec<_>>();
-    let mut f = 0;
-    loop {
-        if f >= fmt.len() {
-            break;
-        }
-        if fmt[f] == '%' {
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
             let mut width: usize = 0;
-            f += 1;
-            if f >= fmt.len() {
-                break;
-            }
-            if fmt[f] == 'a' {
+            let spec = match chars.next() {
+                Some(spec) => spec,
+                None => break,
+            };
+            if spec == 'a' {
                 if width == 0 {
-                    width = ::std::mem::size_of::<*mut ::std::os::raw::c_void>() * 2;
+                    width = ::core::mem::size_of::<u64>() * 2;
                 }
                 write!(stream, "{:0width$x}", addr, width = width)?;
-            } else if fmt[f] == 'b' {
+            } else if spec == 'b' {
                 if let Some(opcode) = opcode {
                     for byte in opcode.iter().take(instr.length) {
                         write!(stream, "{:02x}", byte)?;
@@ -9259,7 +19282,7 @@ pub fn format_instruction_string(
                         stream.write_str("  ")?;
                     }
                 }
-            } else if fmt[f] == 'i' {
+            } else if spec == 'i' {
                 if instr.flags & X86Flag::ANY_REP != 0 {
                     stream.write_str("rep")?;
                     if instr.flags & X86Flag::REPNE != 0 {
@@ -9273,94 +19296,62 @@ pub fn format_instruction_string(
                 if instr.flags & X86Flag::LOCK != 0 {
                     stream.write_str("lock ")?;
                 }
+                if instr.evex.is_some_and(|evex| evex.no_flags) {
+                    stream.write_str("{nf} ")?;
+                }
                 stream.write_str(instr.operation.mnemonic())?;
-            } else if fmt[f] == 'o' {
-                let mut i: usize = 0;
-                loop {
-                    if i >= 3 {
-                        break;
-                    }
-                    if instr.operands[i].operand == OperandType::NONE {
-                        break;
-                    }
+            } else if spec == 'o' {
+                for (i, operand) in instr.operands().iter().enumerate() {
                     if i != 0 {
                         stream.write_str(", ")?;
                     }
-                    if instr.operands[i].operand == OperandType::IMM {
-                        write!(
-                            stream,
-                            "{:#width$x}",
-                            instr.operands[i].immediate,
-                            width = (instr.operands[i].size * 2) as usize
-                        )?;
-                    } else if instr.operands[i].operand == OperandType::MEM {
-                        let mut plus: bool = false;
-                        stream.write_str(get_size_string(instr.operands[i].size))?;
-                        if instr.segment != SegmentRegister::DEFAULT ||
-                            instr.operands[i].segment == SegmentRegister::ES
-                        {
-                            write_operand(
-                                stream,
-                                OperandType::from_i32(
-                                    instr.operands[i].segment as i32 +
-                                        OperandType::REG_ES as i32,
-                                ),
-                                1,
-                                false,
-                            )?;
-                            stream.write_char(':')?;
-                        }
-                        stream.write_char('[')?;
-                        if instr.operands[i].components[0] != OperandType::NONE {
-                            write_operand(
-                                stream,
-                                instr.operands[i].components[0],
-                                1,
-                                false,
-                            )?;
-                            plus = true;
+                    match *operand {
+                        X86Operand::Register(reg) => {
+                            write_operand(stream, reg.register, 1, false)?;
                         }
-                        if instr.operands[i].components[1] != OperandType::NONE {
-                            write_operand(
+                        X86Operand::Immediate(imm) => {
+                            write!(
                                 stream,
-                                instr.operands[i].components[1],
-                                instr.operands[i].scale,
-                                plus,
+                                "{:#width$x}",
+                                imm.value,
+                                width = (imm.size * 2) as usize
                             )?;
-                            plus = true;
                         }
-                        if instr.operands[i].immediate != 0 ||
-                            instr.operands[i].components[0] == OperandType::NONE &&
-                                (instr.operands[i].components[1] == OperandType::NONE)
-                        {
-                            if plus && (instr.operands[i].immediate >= -0x80) &&
-                                (instr.operands[i].immediate < 0)
-                            {
-                                write!(stream, "-{:#02x}", -instr.operands[i].immediate)?;
-                            } else if plus && (instr.operands[i].immediate > 0) &&
-                                       (instr.operands[i].immediate <= 0x7f)
-                            {
-                                write!(stream, "+{:#02x}", instr.operands[i].immediate)?;
-                            } else {
-                                if plus {
-                                    stream.write_char('+')?;
-                                }
-                                write!(stream, "{:#08x}", instr.operands[i].immediate)?;
+                        X86Operand::Memory(mem) => {
+                            write_memory(stream, instr, &mem)?;
+                            if let Some(count) = instr.evex.and_then(|evex| evex.broadcast) {
+                                write!(stream, "{{1to{}}}", count)?;
                             }
                         }
-                        stream.write_char(']')?;
-                    } else {
-                        write_operand(stream, instr.operands[i].operand, 1, false)?;
+                        X86Operand::FarPointer { seg, off } => {
+                            write!(stream, "{:#x}:{:#x}", seg, off)?;
+                        }
+                        X86Operand::RelativeTarget(target) => {
+                            write!(stream, "{:#x}", target)?;
+                        }
+                    }
+                    if let (0, Some(evex)) = (i, instr.evex) {
+                        if let Some(mask) = evex.mask {
+                            write!(stream, "{{{}}}", mask.name())?;
+                        }
+                        if evex.zeroing {
+                            stream.write_str("{z}")?;
+                        }
+                    }
+                }
+                if let Some(evex) = instr.evex {
+                    if let Some(rounding) = evex.rounding {
+                        write!(stream, ", {{{}}}", rounding.name())?;
+                    } else if evex.sae {
+                        stream.write_str(", {sae}")?;
                     }
-                    i += 1;
                 }
-            } else if !(fmt[f] >= '0' && fmt[f] <= '9') {
-                stream.write_char(fmt[f])?;
+            } else if !spec.is_ascii_digit() {
+                stream.write_char(spec)?;
             }
         } else {
-            stream.write_char(fmt[f])?;
+            stream.write_char(c)?;
         }
-        f += 1;
     }
     Ok(())
 }
@@ -0,0 +1,216 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{OperandType, SegmentRegister};
+use Operand;
+
+/// How an instruction uses one of its operands.
+///
+/// The registers in the address of a memory operand are always read,
+/// whatever the access to the memory is.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // add [rax], ebx
+/// let data = [0x01u8, 0x18];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.operand_access()[..2], [OperandAccess::ReadWrite, OperandAccess::Read]);
+///
+/// // mov [rax], ebx
+/// let data = [0x89u8, 0x18];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.operand_access()[0], OperandAccess::Write);
+///
+/// // cmove eax, ebx
+/// let data = [0x0fu8, 0x44, 0xc3];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.operand_access()[0], OperandAccess::CondWrite);
+///
+/// // vmovups [rax]{k1}, zmm0
+/// let data = [0x62u8, 0xf1, 0x7c, 0x49, 0x11, 0x00];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.operand_access()[0], OperandAccess::CondWrite);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OperandAccess {
+    /// The operand is not accessed, as with the memory operand of
+    /// `LEA` or of a prefetch.
+    None,
+    /// The operand is read.
+    Read,
+    /// The operand is written.
+    Write,
+    /// The operand is read and then written.
+    ReadWrite,
+    /// The operand may be read, depending on a condition, as with a
+    /// masked load or a repeated string instruction.
+    CondRead,
+    /// The operand may be written, depending on a condition, as with
+    /// the destination of `CMOVcc` or a masked store. Its old value
+    /// may survive.
+    CondWrite,
+}
+
+impl OperandAccess {
+    /// Whether the operand is or may be read.
+    pub fn is_read(self) -> bool {
+        matches!(
+            self,
+            OperandAccess::Read | OperandAccess::ReadWrite | OperandAccess::CondRead
+        )
+    }
+
+    /// Whether the operand is or may be written.
+    pub fn is_written(self) -> bool {
+        matches!(
+            self,
+            OperandAccess::Write | OperandAccess::ReadWrite | OperandAccess::CondWrite
+        )
+    }
+
+    /// Whether the access depends on a condition.
+    pub fn is_conditional(self) -> bool {
+        matches!(self, OperandAccess::CondRead | OperandAccess::CondWrite)
+    }
+}
+
+/// A register operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reg {
+    /// The register.
+    pub register: OperandType,
+    /// The number of bytes of the register that are accessed.
+    pub size: u16,
+}
+
+/// An immediate operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Imm {
+    /// The value, extended to 64 bits as described by `signed`.
+    pub value: i64,
+    /// The size of the operand in bytes.
+    pub size: u16,
+    /// The number of bytes that the value was encoded in, or `0` if
+    /// it is implied by the opcode (as with a shift by one).
+    pub encoded_size: u8,
+    /// Whether the encoded value was sign extended to form `value`,
+    /// rather than zero extended.
+    pub signed: bool,
+}
+
+/// A memory operand.
+///
+/// The address of the memory is the following, where a missing base
+/// or index counts as zero:
+///
+/// ```math
+/// address = base + index * scale + displacement
+/// ```
+///
+/// A RIP relative operand is resolved to its absolute address, so it
+/// has neither a base nor an index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemRef {
+    /// The base register.
+    pub base: Option<OperandType>,
+    /// The index register, which is multiplied by `scale`.
+    pub index: Option<OperandType>,
+    /// The scale for the index register.
+    pub scale: u8,
+    /// The constant offset of the address.
+    pub displacement: i64,
+    /// The number of bytes that the displacement was encoded in, or
+    /// `0` if there is none.
+    pub displacement_size: u8,
+    /// The segment register used for the access. This is always a
+    /// segment register, as `SegmentRegister::DEFAULT` is resolved to
+    /// the default register.
+    pub segment: SegmentRegister,
+    /// The size of the memory access in bytes.
+    pub size: u16,
+}
+
+/// An operand for an `Instruction`.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // mov eax, [rbx+rcx*4+0x10]
+/// let data = [0x8bu8, 0x44, 0x8b, 0x10];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// match instr.operands() {
+///     [X86Operand::Register(reg), X86Operand::Memory(mem)] => {
+///         assert_eq!(reg.register, OperandType::REG_EAX);
+///         assert_eq!(mem.base, Some(OperandType::REG_RBX));
+///         assert_eq!(mem.index, Some(OperandType::REG_RCX));
+///         assert_eq!(mem.scale, 4);
+///         assert_eq!(mem.displacement, 0x10);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// // mov rax, [0x1122334455667788] has a full 64 bit address
+/// let data = [0x48u8, 0xa1, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.length, 10);
+/// assert_eq!(instruction_length(X86Mode::Bits64, &data), Ok(10));
+/// match instr.operands()[1] {
+///     X86Operand::Memory(mem) => {
+///         assert_eq!(mem.displacement, 0x1122334455667788);
+///         assert_eq!(mem.displacement_size, 8);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// // jmp far 0x1234:0x5678 in 16 bit mode
+/// let data = [0xeau8, 0x78, 0x56, 0x34, 0x12];
+/// let instr = disassemble_16(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.operands(), [X86Operand::FarPointer { seg: 0x1234, off: 0x5678 }]);
+///
+/// // call to a relative target
+/// let data = [0xe8u8, 0x10, 0x00, 0x00, 0x00];
+/// let instr = disassemble_64(&data, 0x1000, data.len()).unwrap();
+/// assert_eq!(instr.operands(), [X86Operand::RelativeTarget(0x1015)]);
+///
+/// // A 16 bit operand size wraps the target around at 0xffff
+/// let data = [0x66u8, 0xe9, 0x00, 0x10];
+/// let instr = disassemble_32(&data, 0xf000, data.len()).unwrap();
+/// assert_eq!(instr.operands(), [X86Operand::RelativeTarget(0x0004)]);
+/// let data = [0xebu8, 0x10];
+/// let instr = disassemble_16(&data, 0xfff0, data.len()).unwrap();
+/// assert_eq!(instr.operands(), [X86Operand::RelativeTarget(0x0002)]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X86Operand {
+    /// A register.
+    Register(Reg),
+    /// A constant value.
+    Immediate(Imm),
+    /// A memory reference.
+    Memory(MemRef),
+    /// The segment selector and offset of a far call or jump.
+    FarPointer {
+        /// The segment selector.
+        seg: u16,
+        /// The offset within the segment.
+        off: u64,
+    },
+    /// The target address of a relative branch, or the absolute
+    /// target of `JMPABS`.
+    RelativeTarget(u64),
+}
+
+impl Operand for X86Operand {}
+
+/// Fills the unused operand slots of an instruction. These are never
+/// visible, as only the operands before the first one are returned.
+pub(crate) const NO_OPERAND: X86Operand = X86Operand::Register(Reg {
+    register: OperandType::NONE,
+    size: 0,
+});
+
+/// The operands of an instruction before any are decoded.
+pub(crate) const NO_OPERANDS: [X86Operand; 4] = [NO_OPERAND; 4];
@@ -5,10 +5,11 @@
 
 /// The location used by an operand.
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd)]
 #[repr(i32)]
 pub enum OperandType {
     /// Invalid / no operand.
+    #[default]
     NONE = 0i32,
     /// An immediate operand.
     IMM,
@@ -324,6 +325,326 @@ pub enum OperandType {
     REG_GS,
     /// Instruction pointer. 64 bits.
     REG_RIP,
+    /// AVX register. 256 bits.
+    REG_YMM0,
+    /// AVX register. 256 bits.
+    REG_YMM1,
+    /// AVX register. 256 bits.
+    REG_YMM2,
+    /// AVX register. 256 bits.
+    REG_YMM3,
+    /// AVX register. 256 bits.
+    REG_YMM4,
+    /// AVX register. 256 bits.
+    REG_YMM5,
+    /// AVX register. 256 bits.
+    REG_YMM6,
+    /// AVX register. 256 bits.
+    REG_YMM7,
+    /// AVX register. 256 bits.
+    REG_YMM8,
+    /// AVX register. 256 bits.
+    REG_YMM9,
+    /// AVX register. 256 bits.
+    REG_YMM10,
+    /// AVX register. 256 bits.
+    REG_YMM11,
+    /// AVX register. 256 bits.
+    REG_YMM12,
+    /// AVX register. 256 bits.
+    REG_YMM13,
+    /// AVX register. 256 bits.
+    REG_YMM14,
+    /// AVX register. 256 bits.
+    REG_YMM15,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM16,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM17,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM18,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM19,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM20,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM21,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM22,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM23,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM24,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM25,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM26,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM27,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM28,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM29,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM30,
+    /// SSE register. 128 bits. Only available with AVX-512.
+    REG_XMM31,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM16,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM17,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM18,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM19,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM20,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM21,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM22,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM23,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM24,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM25,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM26,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM27,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM28,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM29,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM30,
+    /// AVX register. 256 bits. Only available with AVX-512.
+    REG_YMM31,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM0,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM1,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM2,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM3,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM4,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM5,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM6,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM7,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM8,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM9,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM10,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM11,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM12,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM13,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM14,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM15,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM16,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM17,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM18,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM19,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM20,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM21,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM22,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM23,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM24,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM25,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM26,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM27,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM28,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM29,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM30,
+    /// AVX-512 register. 512 bits.
+    REG_ZMM31,
+    /// AVX-512 opmask register. Means no masking when used as a mask.
+    REG_K0,
+    /// AVX-512 opmask register.
+    REG_K1,
+    /// AVX-512 opmask register.
+    REG_K2,
+    /// AVX-512 opmask register.
+    REG_K3,
+    /// AVX-512 opmask register.
+    REG_K4,
+    /// AVX-512 opmask register.
+    REG_K5,
+    /// AVX-512 opmask register.
+    REG_K6,
+    /// AVX-512 opmask register.
+    REG_K7,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R16B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R17B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R18B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R19B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R20B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R21B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R22B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R23B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R24B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R25B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R26B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R27B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R28B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R29B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R30B,
+    /// APX GPR. Lowermost 8 bits of 64.
+    REG_R31B,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R16W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R17W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R18W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R19W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R20W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R21W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R22W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R23W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R24W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R25W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R26W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R27W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R28W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R29W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R30W,
+    /// APX GPR. Lowermost 16 bits of 64.
+    REG_R31W,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R16D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R17D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R18D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R19D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R20D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R21D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R22D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R23D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R24D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R25D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R26D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R27D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R28D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R29D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R30D,
+    /// APX GPR. Lowermost 32 bits of 64.
+    REG_R31D,
+    /// APX GPR. 64 bits.
+    REG_R16,
+    /// APX GPR. 64 bits.
+    REG_R17,
+    /// APX GPR. 64 bits.
+    REG_R18,
+    /// APX GPR. 64 bits.
+    REG_R19,
+    /// APX GPR. 64 bits.
+    REG_R20,
+    /// APX GPR. 64 bits.
+    REG_R21,
+    /// APX GPR. 64 bits.
+    REG_R22,
+    /// APX GPR. 64 bits.
+    REG_R23,
+    /// APX GPR. 64 bits.
+    REG_R24,
+    /// APX GPR. 64 bits.
+    REG_R25,
+    /// APX GPR. 64 bits.
+    REG_R26,
+    /// APX GPR. 64 bits.
+    REG_R27,
+    /// APX GPR. 64 bits.
+    REG_R28,
+    /// APX GPR. 64 bits.
+    REG_R29,
+    /// APX GPR. 64 bits.
+    REG_R30,
+    /// APX GPR. 64 bits.
+    REG_R31,
+    /// AMX tile register.
+    REG_TMM0,
+    /// AMX tile register.
+    REG_TMM1,
+    /// AMX tile register.
+    REG_TMM2,
+    /// AMX tile register.
+    REG_TMM3,
+    /// AMX tile register.
+    REG_TMM4,
+    /// AMX tile register.
+    REG_TMM5,
+    /// AMX tile register.
+    REG_TMM6,
+    /// AMX tile register.
+    REG_TMM7,
 }
 
 impl OperandType {
@@ -331,650 +652,1982 @@ impl OperandType {
     pub fn from_i32(i: i32) -> Self {
         OPERAND_TYPE_TABLE[i as usize].value
     }
-}
 
-impl Default for OperandType {
-    fn default() -> Self {
-        OperandType::NONE
+    /// The name of the register, or an empty string when this is not
+    /// a register.
+    pub fn name(self) -> &'static str {
+        OPERAND_TYPE_TABLE[self as usize].name
+    }
+
+    /// Whether this is a register rather than `NONE`, `IMM` or `MEM`.
+    pub fn is_register(self) -> bool {
+        self > OperandType::MEM
+    }
+
+    /// The size of the register in bytes, or 0 when this is not a
+    /// register. Control and debug registers have their 64 bit size.
+    pub fn size(self) -> u16 {
+        OPERAND_TYPE_TABLE[self as usize].size
+    }
+
+    /// The largest register that contains this one, such as `REG_RAX`
+    /// for `REG_AL`, `REG_AH` and `REG_EAX`, or `REG_ZMM3` for
+    /// `REG_XMM3` and `REG_YMM3`. Registers that are not part of a
+    /// larger one are their own family.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// assert_eq!(OperandType::REG_XMM3.family(), OperandType::REG_ZMM3);
+    /// assert_eq!(OperandType::REG_YMM3.family(), OperandType::REG_ZMM3);
+    /// assert_eq!(OperandType::REG_R9W.family(), OperandType::REG_R9);
+    /// assert_eq!(OperandType::REG_K1.family(), OperandType::REG_K1);
+    /// assert_eq!(OperandType::REG_YMM17.size(), 32);
+    /// assert_eq!(OperandType::REG_ZMM31.name(), "zmm31");
+    /// ```
+    pub fn family(self) -> OperandType {
+        OPERAND_TYPE_TABLE[self as usize].family
+    }
+
+    /// Whether this register is part of `other` or is `other`, such
+    /// as `REG_XMM3` within `REG_YMM3`. `REG_AH` is not within `REG_AL`.
+    pub fn is_within(self, other: OperandType) -> bool {
+        if !self.is_register() || self.family() != other.family() {
+            return false;
+        }
+        match (self, other) {
+            (a, b) if a == b => true,
+            (OperandType::REG_AH, _) |
+            (OperandType::REG_CH, _) |
+            (OperandType::REG_DH, _) |
+            (OperandType::REG_BH, _) => other.size() > 1,
+            (_, OperandType::REG_AH) |
+            (_, OperandType::REG_CH) |
+            (_, OperandType::REG_DH) |
+            (_, OperandType::REG_BH) => false,
+            _ => self.size() <= other.size(),
+        }
     }
 }
 
+
 pub(crate) struct OperandTypeInfo {
     pub name: &'static str,
     pub value: OperandType,
+    pub size: u16,
+    pub family: OperandType,
 }
 
-pub(crate) static OPERAND_TYPE_TABLE: [OperandTypeInfo; 158] = [
+/// The number of `OperandType` values.
+pub(crate) const OPERAND_TYPE_COUNT: usize = 318;
+
+pub(crate) static OPERAND_TYPE_TABLE: [OperandTypeInfo; OPERAND_TYPE_COUNT] = [
     OperandTypeInfo {
         name: "",
         value: OperandType::NONE,
+        size: 0,
+        family: OperandType::NONE,
     },
     OperandTypeInfo {
         name: "",
         value: OperandType::IMM,
+        size: 0,
+        family: OperandType::IMM,
     },
     OperandTypeInfo {
         name: "",
         value: OperandType::MEM,
+        size: 0,
+        family: OperandType::MEM,
     },
     OperandTypeInfo {
         name: "al",
         value: OperandType::REG_AL,
+        size: 1,
+        family: OperandType::REG_RAX,
     },
     OperandTypeInfo {
         name: "cl",
         value: OperandType::REG_CL,
+        size: 1,
+        family: OperandType::REG_RCX,
     },
     OperandTypeInfo {
         name: "dl",
         value: OperandType::REG_DL,
+        size: 1,
+        family: OperandType::REG_RDX,
     },
     OperandTypeInfo {
         name: "bl",
         value: OperandType::REG_BL,
+        size: 1,
+        family: OperandType::REG_RBX,
     },
     OperandTypeInfo {
         name: "ah",
         value: OperandType::REG_AH,
+        size: 1,
+        family: OperandType::REG_RAX,
     },
     OperandTypeInfo {
         name: "ch",
         value: OperandType::REG_CH,
+        size: 1,
+        family: OperandType::REG_RCX,
     },
     OperandTypeInfo {
         name: "dh",
         value: OperandType::REG_DH,
+        size: 1,
+        family: OperandType::REG_RDX,
     },
     OperandTypeInfo {
         name: "bh",
         value: OperandType::REG_BH,
+        size: 1,
+        family: OperandType::REG_RBX,
     },
     OperandTypeInfo {
         name: "spl",
         value: OperandType::REG_SPL,
+        size: 1,
+        family: OperandType::REG_RSP,
     },
     OperandTypeInfo {
         name: "bpl",
         value: OperandType::REG_BPL,
+        size: 1,
+        family: OperandType::REG_RBP,
     },
     OperandTypeInfo {
         name: "sil",
         value: OperandType::REG_SIL,
+        size: 1,
+        family: OperandType::REG_RSI,
     },
     OperandTypeInfo {
         name: "dil",
         value: OperandType::REG_DIL,
+        size: 1,
+        family: OperandType::REG_RDI,
     },
     OperandTypeInfo {
         name: "r8b",
         value: OperandType::REG_R8B,
+        size: 1,
+        family: OperandType::REG_R8,
     },
     OperandTypeInfo {
         name: "r9b",
         value: OperandType::REG_R9B,
+        size: 1,
+        family: OperandType::REG_R9,
     },
     OperandTypeInfo {
         name: "r10b",
         value: OperandType::REG_R10B,
+        size: 1,
+        family: OperandType::REG_R10,
     },
     OperandTypeInfo {
         name: "r11b",
         value: OperandType::REG_R11B,
+        size: 1,
+        family: OperandType::REG_R11,
     },
     OperandTypeInfo {
         name: "r12b",
         value: OperandType::REG_R12B,
+        size: 1,
+        family: OperandType::REG_R12,
     },
     OperandTypeInfo {
         name: "r13b",
         value: OperandType::REG_R13B,
+        size: 1,
+        family: OperandType::REG_R13,
     },
     OperandTypeInfo {
         name: "r14b",
         value: OperandType::REG_R14B,
+        size: 1,
+        family: OperandType::REG_R14,
     },
     OperandTypeInfo {
         name: "r15b",
         value: OperandType::REG_R15B,
+        size: 1,
+        family: OperandType::REG_R15,
     },
     OperandTypeInfo {
         name: "ax",
         value: OperandType::REG_AX,
+        size: 2,
+        family: OperandType::REG_RAX,
     },
     OperandTypeInfo {
         name: "cx",
         value: OperandType::REG_CX,
+        size: 2,
+        family: OperandType::REG_RCX,
     },
     OperandTypeInfo {
         name: "dx",
         value: OperandType::REG_DX,
+        size: 2,
+        family: OperandType::REG_RDX,
     },
     OperandTypeInfo {
         name: "bx",
         value: OperandType::REG_BX,
+        size: 2,
+        family: OperandType::REG_RBX,
     },
     OperandTypeInfo {
         name: "sp",
         value: OperandType::REG_SP,
+        size: 2,
+        family: OperandType::REG_RSP,
     },
     OperandTypeInfo {
         name: "bp",
         value: OperandType::REG_BP,
+        size: 2,
+        family: OperandType::REG_RBP,
     },
     OperandTypeInfo {
         name: "si",
         value: OperandType::REG_SI,
+        size: 2,
+        family: OperandType::REG_RSI,
     },
     OperandTypeInfo {
         name: "di",
         value: OperandType::REG_DI,
+        size: 2,
+        family: OperandType::REG_RDI,
     },
     OperandTypeInfo {
         name: "r8w",
         value: OperandType::REG_R8W,
+        size: 2,
+        family: OperandType::REG_R8,
     },
     OperandTypeInfo {
         name: "r9w",
         value: OperandType::REG_R9W,
+        size: 2,
+        family: OperandType::REG_R9,
     },
     OperandTypeInfo {
         name: "r10w",
         value: OperandType::REG_R10W,
+        size: 2,
+        family: OperandType::REG_R10,
     },
     OperandTypeInfo {
         name: "r11w",
         value: OperandType::REG_R11W,
+        size: 2,
+        family: OperandType::REG_R11,
     },
     OperandTypeInfo {
         name: "r12w",
         value: OperandType::REG_R12W,
+        size: 2,
+        family: OperandType::REG_R12,
     },
     OperandTypeInfo {
         name: "r13w",
         value: OperandType::REG_R13W,
+        size: 2,
+        family: OperandType::REG_R13,
     },
     OperandTypeInfo {
         name: "r14w",
         value: OperandType::REG_R14W,
+        size: 2,
+        family: OperandType::REG_R14,
     },
     OperandTypeInfo {
         name: "r15w",
         value: OperandType::REG_R15W,
+        size: 2,
+        family: OperandType::REG_R15,
     },
     OperandTypeInfo {
         name: "eax",
         value: OperandType::REG_EAX,
+        size: 4,
+        family: OperandType::REG_RAX,
     },
     OperandTypeInfo {
         name: "ecx",
         value: OperandType::REG_ECX,
+        size: 4,
+        family: OperandType::REG_RCX,
     },
     OperandTypeInfo {
         name: "edx",
         value: OperandType::REG_EDX,
+        size: 4,
+        family: OperandType::REG_RDX,
     },
     OperandTypeInfo {
         name: "ebx",
         value: OperandType::REG_EBX,
+        size: 4,
+        family: OperandType::REG_RBX,
     },
     OperandTypeInfo {
         name: "esp",
         value: OperandType::REG_ESP,
+        size: 4,
+        family: OperandType::REG_RSP,
     },
     OperandTypeInfo {
         name: "ebp",
         value: OperandType::REG_EBP,
+        size: 4,
+        family: OperandType::REG_RBP,
     },
     OperandTypeInfo {
         name: "esi",
         value: OperandType::REG_ESI,
+        size: 4,
+        family: OperandType::REG_RSI,
     },
     OperandTypeInfo {
         name: "edi",
         value: OperandType::REG_EDI,
+        size: 4,
+        family: OperandType::REG_RDI,
     },
     OperandTypeInfo {
         name: "r8d",
         value: OperandType::REG_R8D,
+        size: 4,
+        family: OperandType::REG_R8,
     },
     OperandTypeInfo {
         name: "r9d",
         value: OperandType::REG_R9D,
+        size: 4,
+        family: OperandType::REG_R9,
     },
     OperandTypeInfo {
         name: "r10d",
         value: OperandType::REG_R10D,
+        size: 4,
+        family: OperandType::REG_R10,
     },
     OperandTypeInfo {
         name: "r11d",
         value: OperandType::REG_R11D,
+        size: 4,
+        family: OperandType::REG_R11,
     },
     OperandTypeInfo {
         name: "r12d",
         value: OperandType::REG_R12D,
+        size: 4,
+        family: OperandType::REG_R12,
     },
     OperandTypeInfo {
         name: "r13d",
         value: OperandType::REG_R13D,
+        size: 4,
+        family: OperandType::REG_R13,
     },
     OperandTypeInfo {
         name: "r14d",
         value: OperandType::REG_R14D,
+        size: 4,
+        family: OperandType::REG_R14,
     },
     OperandTypeInfo {
         name: "r15d",
         value: OperandType::REG_R15D,
+        size: 4,
+        family: OperandType::REG_R15,
     },
     OperandTypeInfo {
         name: "rax",
         value: OperandType::REG_RAX,
+        size: 8,
+        family: OperandType::REG_RAX,
     },
     OperandTypeInfo {
         name: "rcx",
         value: OperandType::REG_RCX,
+        size: 8,
+        family: OperandType::REG_RCX,
     },
     OperandTypeInfo {
         name: "rdx",
         value: OperandType::REG_RDX,
+        size: 8,
+        family: OperandType::REG_RDX,
     },
     OperandTypeInfo {
         name: "rbx",
         value: OperandType::REG_RBX,
+        size: 8,
+        family: OperandType::REG_RBX,
     },
     OperandTypeInfo {
         name: "rsp",
         value: OperandType::REG_RSP,
+        size: 8,
+        family: OperandType::REG_RSP,
     },
     OperandTypeInfo {
         name: "rbp",
         value: OperandType::REG_RBP,
+        size: 8,
+        family: OperandType::REG_RBP,
     },
     OperandTypeInfo {
         name: "rsi",
         value: OperandType::REG_RSI,
+        size: 8,
+        family: OperandType::REG_RSI,
     },
     OperandTypeInfo {
         name: "rdi",
         value: OperandType::REG_RDI,
+        size: 8,
+        family: OperandType::REG_RDI,
     },
     OperandTypeInfo {
         name: "r8",
         value: OperandType::REG_R8,
+        size: 8,
+        family: OperandType::REG_R8,
     },
     OperandTypeInfo {
         name: "r9",
         value: OperandType::REG_R9,
+        size: 8,
+        family: OperandType::REG_R9,
     },
     OperandTypeInfo {
         name: "r10",
         value: OperandType::REG_R10,
+        size: 8,
+        family: OperandType::REG_R10,
     },
     OperandTypeInfo {
         name: "r11",
         value: OperandType::REG_R11,
+        size: 8,
+        family: OperandType::REG_R11,
     },
     OperandTypeInfo {
         name: "r12",
         value: OperandType::REG_R12,
+        size: 8,
+        family: OperandType::REG_R12,
     },
     OperandTypeInfo {
         name: "r13",
         value: OperandType::REG_R13,
+        size: 8,
+        family: OperandType::REG_R13,
     },
     OperandTypeInfo {
         name: "r14",
         value: OperandType::REG_R14,
+        size: 8,
+        family: OperandType::REG_R14,
     },
     OperandTypeInfo {
         name: "r15",
         value: OperandType::REG_R15,
+        size: 8,
+        family: OperandType::REG_R15,
     },
     OperandTypeInfo {
         name: "st0",
         value: OperandType::REG_ST0,
+        size: 10,
+        family: OperandType::REG_ST0,
     },
     OperandTypeInfo {
         name: "st1",
         value: OperandType::REG_ST1,
+        size: 10,
+        family: OperandType::REG_ST1,
     },
     OperandTypeInfo {
         name: "st2",
         value: OperandType::REG_ST2,
+        size: 10,
+        family: OperandType::REG_ST2,
     },
     OperandTypeInfo {
         name: "st3",
         value: OperandType::REG_ST3,
+        size: 10,
+        family: OperandType::REG_ST3,
     },
     OperandTypeInfo {
         name: "st4",
         value: OperandType::REG_ST4,
+        size: 10,
+        family: OperandType::REG_ST4,
     },
     OperandTypeInfo {
         name: "st5",
         value: OperandType::REG_ST5,
+        size: 10,
+        family: OperandType::REG_ST5,
     },
     OperandTypeInfo {
         name: "st6",
         value: OperandType::REG_ST6,
+        size: 10,
+        family: OperandType::REG_ST6,
     },
     OperandTypeInfo {
         name: "st7",
         value: OperandType::REG_ST7,
+        size: 10,
+        family: OperandType::REG_ST7,
     },
     OperandTypeInfo {
         name: "mm0",
         value: OperandType::REG_MM0,
+        size: 8,
+        family: OperandType::REG_MM0,
     },
     OperandTypeInfo {
         name: "mm1",
         value: OperandType::REG_MM1,
+        size: 8,
+        family: OperandType::REG_MM1,
     },
     OperandTypeInfo {
         name: "mm2",
         value: OperandType::REG_MM2,
+        size: 8,
+        family: OperandType::REG_MM2,
     },
     OperandTypeInfo {
         name: "mm3",
         value: OperandType::REG_MM3,
+        size: 8,
+        family: OperandType::REG_MM3,
     },
     OperandTypeInfo {
         name: "mm4",
         value: OperandType::REG_MM4,
+        size: 8,
+        family: OperandType::REG_MM4,
     },
     OperandTypeInfo {
         name: "mm5",
         value: OperandType::REG_MM5,
+        size: 8,
+        family: OperandType::REG_MM5,
     },
     OperandTypeInfo {
         name: "mm6",
         value: OperandType::REG_MM6,
+        size: 8,
+        family: OperandType::REG_MM6,
     },
     OperandTypeInfo {
         name: "mm7",
         value: OperandType::REG_MM7,
+        size: 8,
+        family: OperandType::REG_MM7,
     },
     OperandTypeInfo {
         name: "xmm0",
         value: OperandType::REG_XMM0,
+        size: 16,
+        family: OperandType::REG_ZMM0,
     },
     OperandTypeInfo {
         name: "xmm1",
         value: OperandType::REG_XMM1,
+        size: 16,
+        family: OperandType::REG_ZMM1,
     },
     OperandTypeInfo {
         name: "xmm2",
         value: OperandType::REG_XMM2,
+        size: 16,
+        family: OperandType::REG_ZMM2,
     },
     OperandTypeInfo {
         name: "xmm3",
         value: OperandType::REG_XMM3,
+        size: 16,
+        family: OperandType::REG_ZMM3,
     },
     OperandTypeInfo {
         name: "xmm4",
         value: OperandType::REG_XMM4,
+        size: 16,
+        family: OperandType::REG_ZMM4,
     },
     OperandTypeInfo {
         name: "xmm5",
         value: OperandType::REG_XMM5,
+        size: 16,
+        family: OperandType::REG_ZMM5,
     },
     OperandTypeInfo {
         name: "xmm6",
         value: OperandType::REG_XMM6,
+        size: 16,
+        family: OperandType::REG_ZMM6,
     },
     OperandTypeInfo {
         name: "xmm7",
         value: OperandType::REG_XMM7,
+        size: 16,
+        family: OperandType::REG_ZMM7,
     },
     OperandTypeInfo {
         name: "xmm8",
         value: OperandType::REG_XMM8,
+        size: 16,
+        family: OperandType::REG_ZMM8,
     },
     OperandTypeInfo {
         name: "xmm9",
         value: OperandType::REG_XMM9,
+        size: 16,
+        family: OperandType::REG_ZMM9,
     },
     OperandTypeInfo {
         name: "xmm10",
         value: OperandType::REG_XMM10,
+        size: 16,
+        family: OperandType::REG_ZMM10,
     },
     OperandTypeInfo {
         name: "xmm11",
         value: OperandType::REG_XMM11,
+        size: 16,
+        family: OperandType::REG_ZMM11,
     },
     OperandTypeInfo {
         name: "xmm12",
         value: OperandType::REG_XMM12,
+        size: 16,
+        family: OperandType::REG_ZMM12,
     },
     OperandTypeInfo {
         name: "xmm13",
         value: OperandType::REG_XMM13,
+        size: 16,
+        family: OperandType::REG_ZMM13,
     },
     OperandTypeInfo {
         name: "xmm14",
         value: OperandType::REG_XMM14,
+        size: 16,
+        family: OperandType::REG_ZMM14,
     },
     OperandTypeInfo {
         name: "xmm15",
         value: OperandType::REG_XMM15,
+        size: 16,
+        family: OperandType::REG_ZMM15,
     },
     OperandTypeInfo {
         name: "cr0",
         value: OperandType::REG_CR0,
+        size: 8,
+        family: OperandType::REG_CR0,
     },
     OperandTypeInfo {
         name: "cr1",
         value: OperandType::REG_CR1,
+        size: 8,
+        family: OperandType::REG_CR1,
     },
     OperandTypeInfo {
         name: "cr2",
         value: OperandType::REG_CR2,
+        size: 8,
+        family: OperandType::REG_CR2,
     },
     OperandTypeInfo {
         name: "cr3",
         value: OperandType::REG_CR3,
+        size: 8,
+        family: OperandType::REG_CR3,
     },
     OperandTypeInfo {
         name: "cr4",
         value: OperandType::REG_CR4,
+        size: 8,
+        family: OperandType::REG_CR4,
     },
     OperandTypeInfo {
         name: "cr5",
         value: OperandType::REG_CR5,
+        size: 8,
+        family: OperandType::REG_CR5,
     },
     OperandTypeInfo {
         name: "cr6",
         value: OperandType::REG_CR6,
+        size: 8,
+        family: OperandType::REG_CR6,
     },
     OperandTypeInfo {
         name: "cr7",
         value: OperandType::REG_CR7,
+        size: 8,
+        family: OperandType::REG_CR7,
     },
     OperandTypeInfo {
         name: "cr8",
         value: OperandType::REG_CR8,
+        size: 8,
+        family: OperandType::REG_CR8,
     },
     OperandTypeInfo {
         name: "cr9",
         value: OperandType::REG_CR9,
+        size: 8,
+        family: OperandType::REG_CR9,
     },
     OperandTypeInfo {
         name: "cr10",
         value: OperandType::REG_CR10,
+        size: 8,
+        family: OperandType::REG_CR10,
     },
     OperandTypeInfo {
         name: "cr11",
         value: OperandType::REG_CR11,
+        size: 8,
+        family: OperandType::REG_CR11,
     },
     OperandTypeInfo {
         name: "cr12",
         value: OperandType::REG_CR12,
+        size: 8,
+        family: OperandType::REG_CR12,
     },
     OperandTypeInfo {
         name: "cr13",
         value: OperandType::REG_CR13,
+        size: 8,
+        family: OperandType::REG_CR13,
     },
     OperandTypeInfo {
         name: "cr14",
         value: OperandType::REG_CR14,
+        size: 8,
+        family: OperandType::REG_CR14,
     },
     OperandTypeInfo {
         name: "cr15",
         value: OperandType::REG_CR15,
+        size: 8,
+        family: OperandType::REG_CR15,
     },
     OperandTypeInfo {
         name: "dr0",
         value: OperandType::REG_DR0,
+        size: 8,
+        family: OperandType::REG_DR0,
     },
     OperandTypeInfo {
         name: "dr1",
         value: OperandType::REG_DR1,
+        size: 8,
+        family: OperandType::REG_DR1,
     },
     OperandTypeInfo {
         name: "dr2",
         value: OperandType::REG_DR2,
+        size: 8,
+        family: OperandType::REG_DR2,
     },
     OperandTypeInfo {
         name: "dr3",
         value: OperandType::REG_DR3,
+        size: 8,
+        family: OperandType::REG_DR3,
     },
     OperandTypeInfo {
         name: "dr4",
         value: OperandType::REG_DR4,
+        size: 8,
+        family: OperandType::REG_DR4,
     },
     OperandTypeInfo {
         name: "dr5",
         value: OperandType::REG_DR5,
+        size: 8,
+        family: OperandType::REG_DR5,
     },
     OperandTypeInfo {
         name: "dr6",
         value: OperandType::REG_DR6,
+        size: 8,
+        family: OperandType::REG_DR6,
     },
     OperandTypeInfo {
         name: "dr7",
         value: OperandType::REG_DR7,
+        size: 8,
+        family: OperandType::REG_DR7,
     },
     OperandTypeInfo {
         name: "dr8",
         value: OperandType::REG_DR8,
+        size: 8,
+        family: OperandType::REG_DR8,
     },
     OperandTypeInfo {
         name: "dr9",
         value: OperandType::REG_DR9,
+        size: 8,
+        family: OperandType::REG_DR9,
     },
     OperandTypeInfo {
         name: "dr10",
         value: OperandType::REG_DR10,
+        size: 8,
+        family: OperandType::REG_DR10,
     },
     OperandTypeInfo {
         name: "dr11",
         value: OperandType::REG_DR11,
+        size: 8,
+        family: OperandType::REG_DR11,
     },
     OperandTypeInfo {
         name: "dr12",
         value: OperandType::REG_DR12,
+        size: 8,
+        family: OperandType::REG_DR12,
     },
     OperandTypeInfo {
         name: "dr13",
         value: OperandType::REG_DR13,
+        size: 8,
+        family: OperandType::REG_DR13,
     },
     OperandTypeInfo {
         name: "dr14",
         value: OperandType::REG_DR14,
+        size: 8,
+        family: OperandType::REG_DR14,
     },
     OperandTypeInfo {
         name: "dr15",
         value: OperandType::REG_DR15,
+        size: 8,
+        family: OperandType::REG_DR15,
     },
     OperandTypeInfo {
         name: "tr0",
         value: OperandType::REG_TR0,
+        size: 4,
+        family: OperandType::REG_TR0,
     },
     OperandTypeInfo {
         name: "tr1",
         value: OperandType::REG_TR1,
+        size: 4,
+        family: OperandType::REG_TR1,
     },
     OperandTypeInfo {
         name: "tr2",
         value: OperandType::REG_TR2,
+        size: 4,
+        family: OperandType::REG_TR2,
     },
     OperandTypeInfo {
         name: "tr3",
         value: OperandType::REG_TR3,
+        size: 4,
+        family: OperandType::REG_TR3,
     },
     OperandTypeInfo {
         name: "tr4",
         value: OperandType::REG_TR4,
+        size: 4,
+        family: OperandType::REG_TR4,
     },
     OperandTypeInfo {
         name: "tr5",
         value: OperandType::REG_TR5,
+        size: 4,
+        family: OperandType::REG_TR5,
     },
     OperandTypeInfo {
         name: "tr6",
         value: OperandType::REG_TR6,
+        size: 4,
+        family: OperandType::REG_TR6,
     },
     OperandTypeInfo {
         name: "tr7",
         value: OperandType::REG_TR7,
+        size: 4,
+        family: OperandType::REG_TR7,
     },
     OperandTypeInfo {
         name: "tr8",
         value: OperandType::REG_TR8,
+        size: 4,
+        family: OperandType::REG_TR8,
     },
     OperandTypeInfo {
         name: "tr9",
         value: OperandType::REG_TR9,
+        size: 4,
+        family: OperandType::REG_TR9,
     },
     OperandTypeInfo {
         name: "tr10",
         value: OperandType::REG_TR10,
+        size: 4,
+        family: OperandType::REG_TR10,
     },
     OperandTypeInfo {
         name: "tr11",
         value: OperandType::REG_TR11,
+        size: 4,
+        family: OperandType::REG_TR11,
     },
     OperandTypeInfo {
         name: "tr12",
         value: OperandType::REG_TR12,
+        size: 4,
+        family: OperandType::REG_TR12,
     },
     OperandTypeInfo {
         name: "tr13",
         value: OperandType::REG_TR13,
+        size: 4,
+        family: OperandType::REG_TR13,
     },
     OperandTypeInfo {
         name: "tr14",
         value: OperandType::REG_TR14,
+        size: 4,
+        family: OperandType::REG_TR14,
     },
     OperandTypeInfo {
         name: "tr15",
         value: OperandType::REG_TR15,
+        size: 4,
+        family: OperandType::REG_TR15,
     },
     OperandTypeInfo {
         name: "es",
         value: OperandType::REG_ES,
+        size: 2,
+        family: OperandType::REG_ES,
     },
     OperandTypeInfo {
         name: "cs",
         value: OperandType::REG_CS,
+        size: 2,
+        family: OperandType::REG_CS,
     },
     OperandTypeInfo {
         name: "ss",
         value: OperandType::REG_SS,
+        size: 2,
+        family: OperandType::REG_SS,
     },
     OperandTypeInfo {
         name: "ds",
         value: OperandType::REG_DS,
+        size: 2,
+        family: OperandType::REG_DS,
     },
     OperandTypeInfo {
         name: "fs",
         value: OperandType::REG_FS,
+        size: 2,
+        family: OperandType::REG_FS,
     },
     OperandTypeInfo {
         name: "gs",
         value: OperandType::REG_GS,
+        size: 2,
+        family: OperandType::REG_GS,
     },
     OperandTypeInfo {
         name: "rip",
         value: OperandType::REG_RIP,
+        size: 8,
+        family: OperandType::REG_RIP,
+    },
+    OperandTypeInfo {
+        name: "ymm0",
+        value: OperandType::REG_YMM0,
+        size: 32,
+        family: OperandType::REG_ZMM0,
+    },
+    OperandTypeInfo {
+        name: "ymm1",
+        value: OperandType::REG_YMM1,
+        size: 32,
+        family: OperandType::REG_ZMM1,
+    },
+    OperandTypeInfo {
+        name: "ymm2",
+        value: OperandType::REG_YMM2,
+        size: 32,
+        family: OperandType::REG_ZMM2,
+    },
+    OperandTypeInfo {
+        name: "ymm3",
+        value: OperandType::REG_YMM3,
+        size: 32,
+        family: OperandType::REG_ZMM3,
+    },
+    OperandTypeInfo {
+        name: "ymm4",
+        value: OperandType::REG_YMM4,
+        size: 32,
+        family: OperandType::REG_ZMM4,
+    },
+    OperandTypeInfo {
+        name: "ymm5",
+        value: OperandType::REG_YMM5,
+        size: 32,
+        family: OperandType::REG_ZMM5,
+    },
+    OperandTypeInfo {
+        name: "ymm6",
+        value: OperandType::REG_YMM6,
+        size: 32,
+        family: OperandType::REG_ZMM6,
+    },
+    OperandTypeInfo {
+        name: "ymm7",
+        value: OperandType::REG_YMM7,
+        size: 32,
+        family: OperandType::REG_ZMM7,
+    },
+    OperandTypeInfo {
+        name: "ymm8",
+        value: OperandType::REG_YMM8,
+        size: 32,
+        family: OperandType::REG_ZMM8,
+    },
+    OperandTypeInfo {
+        name: "ymm9",
+        value: OperandType::REG_YMM9,
+        size: 32,
+        family: OperandType::REG_ZMM9,
+    },
+    OperandTypeInfo {
+        name: "ymm10",
+        value: OperandType::REG_YMM10,
+        size: 32,
+        family: OperandType::REG_ZMM10,
+    },
+    OperandTypeInfo {
+        name: "ymm11",
+        value: OperandType::REG_YMM11,
+        size: 32,
+        family: OperandType::REG_ZMM11,
+    },
+    OperandTypeInfo {
+        name: "ymm12",
+        value: OperandType::REG_YMM12,
+        size: 32,
+        family: OperandType::REG_ZMM12,
+    },
+    OperandTypeInfo {
+        name: "ymm13",
+        value: OperandType::REG_YMM13,
+        size: 32,
+        family: OperandType::REG_ZMM13,
+    },
+    OperandTypeInfo {
+        name: "ymm14",
+        value: OperandType::REG_YMM14,
+        size: 32,
+        family: OperandType::REG_ZMM14,
+    },
+    OperandTypeInfo {
+        name: "ymm15",
+        value: OperandType::REG_YMM15,
+        size: 32,
+        family: OperandType::REG_ZMM15,
+    },
+    OperandTypeInfo {
+        name: "xmm16",
+        value: OperandType::REG_XMM16,
+        size: 16,
+        family: OperandType::REG_ZMM16,
+    },
+    OperandTypeInfo {
+        name: "xmm17",
+        value: OperandType::REG_XMM17,
+        size: 16,
+        family: OperandType::REG_ZMM17,
+    },
+    OperandTypeInfo {
+        name: "xmm18",
+        value: OperandType::REG_XMM18,
+        size: 16,
+        family: OperandType::REG_ZMM18,
+    },
+    OperandTypeInfo {
+        name: "xmm19",
+        value: OperandType::REG_XMM19,
+        size: 16,
+        family: OperandType::REG_ZMM19,
+    },
+    OperandTypeInfo {
+        name: "xmm20",
+        value: OperandType::REG_XMM20,
+        size: 16,
+        family: OperandType::REG_ZMM20,
+    },
+    OperandTypeInfo {
+        name: "xmm21",
+        value: OperandType::REG_XMM21,
+        size: 16,
+        family: OperandType::REG_ZMM21,
+    },
+    OperandTypeInfo {
+        name: "xmm22",
+        value: OperandType::REG_XMM22,
+        size: 16,
+        family: OperandType::REG_ZMM22,
+    },
+    OperandTypeInfo {
+        name: "xmm23",
+        value: OperandType::REG_XMM23,
+        size: 16,
+        family: OperandType::REG_ZMM23,
+    },
+    OperandTypeInfo {
+        name: "xmm24",
+        value: OperandType::REG_XMM24,
+        size: 16,
+        family: OperandType::REG_ZMM24,
+    },
+    OperandTypeInfo {
+        name: "xmm25",
+        value: OperandType::REG_XMM25,
+        size: 16,
+        family: OperandType::REG_ZMM25,
+    },
+    OperandTypeInfo {
+        name: "xmm26",
+        value: OperandType::REG_XMM26,
+        size: 16,
+        family: OperandType::REG_ZMM26,
+    },
+    OperandTypeInfo {
+        name: "xmm27",
+        value: OperandType::REG_XMM27,
+        size: 16,
+        family: OperandType::REG_ZMM27,
+    },
+    OperandTypeInfo {
+        name: "xmm28",
+        value: OperandType::REG_XMM28,
+        size: 16,
+        family: OperandType::REG_ZMM28,
+    },
+    OperandTypeInfo {
+        name: "xmm29",
+        value: OperandType::REG_XMM29,
+        size: 16,
+        family: OperandType::REG_ZMM29,
+    },
+    OperandTypeInfo {
+        name: "xmm30",
+        value: OperandType::REG_XMM30,
+        size: 16,
+        family: OperandType::REG_ZMM30,
+    },
+    OperandTypeInfo {
+        name: "xmm31",
+        value: OperandType::REG_XMM31,
+        size: 16,
+        family: OperandType::REG_ZMM31,
+    },
+    OperandTypeInfo {
+        name: "ymm16",
+        value: OperandType::REG_YMM16,
+        size: 32,
+        family: OperandType::REG_ZMM16,
+    },
+    OperandTypeInfo {
+        name: "ymm17",
+        value: OperandType::REG_YMM17,
+        size: 32,
+        family: OperandType::REG_ZMM17,
+    },
+    OperandTypeInfo {
+        name: "ymm18",
+        value: OperandType::REG_YMM18,
+        size: 32,
+        family: OperandType::REG_ZMM18,
+    },
+    OperandTypeInfo {
+        name: "ymm19",
+        value: OperandType::REG_YMM19,
+        size: 32,
+        family: OperandType::REG_ZMM19,
+    },
+    OperandTypeInfo {
+        name: "ymm20",
+        value: OperandType::REG_YMM20,
+        size: 32,
+        family: OperandType::REG_ZMM20,
+    },
+    OperandTypeInfo {
+        name: "ymm21",
+        value: OperandType::REG_YMM21,
+        size: 32,
+        family: OperandType::REG_ZMM21,
+    },
+    OperandTypeInfo {
+        name: "ymm22",
+        value: OperandType::REG_YMM22,
+        size: 32,
+        family: OperandType::REG_ZMM22,
+    },
+    OperandTypeInfo {
+        name: "ymm23",
+        value: OperandType::REG_YMM23,
+        size: 32,
+        family: OperandType::REG_ZMM23,
+    },
+    OperandTypeInfo {
+        name: "ymm24",
+        value: OperandType::REG_YMM24,
+        size: 32,
+        family: OperandType::REG_ZMM24,
+    },
+    OperandTypeInfo {
+        name: "ymm25",
+        value: OperandType::REG_YMM25,
+        size: 32,
+        family: OperandType::REG_ZMM25,
+    },
+    OperandTypeInfo {
+        name: "ymm26",
+        value: OperandType::REG_YMM26,
+        size: 32,
+        family: OperandType::REG_ZMM26,
+    },
+    OperandTypeInfo {
+        name: "ymm27",
+        value: OperandType::REG_YMM27,
+        size: 32,
+        family: OperandType::REG_ZMM27,
+    },
+    OperandTypeInfo {
+        name: "ymm28",
+        value: OperandType::REG_YMM28,
+        size: 32,
+        family: OperandType::REG_ZMM28,
+    },
+    OperandTypeInfo {
+        name: "ymm29",
+        value: OperandType::REG_YMM29,
+        size: 32,
+        family: OperandType::REG_ZMM29,
+    },
+    OperandTypeInfo {
+        name: "ymm30",
+        value: OperandType::REG_YMM30,
+        size: 32,
+        family: OperandType::REG_ZMM30,
+    },
+    OperandTypeInfo {
+        name: "ymm31",
+        value: OperandType::REG_YMM31,
+        size: 32,
+        family: OperandType::REG_ZMM31,
+    },
+    OperandTypeInfo {
+        name: "zmm0",
+        value: OperandType::REG_ZMM0,
+        size: 64,
+        family: OperandType::REG_ZMM0,
+    },
+    OperandTypeInfo {
+        name: "zmm1",
+        value: OperandType::REG_ZMM1,
+        size: 64,
+        family: OperandType::REG_ZMM1,
+    },
+    OperandTypeInfo {
+        name: "zmm2",
+        value: OperandType::REG_ZMM2,
+        size: 64,
+        family: OperandType::REG_ZMM2,
+    },
+    OperandTypeInfo {
+        name: "zmm3",
+        value: OperandType::REG_ZMM3,
+        size: 64,
+        family: OperandType::REG_ZMM3,
+    },
+    OperandTypeInfo {
+        name: "zmm4",
+        value: OperandType::REG_ZMM4,
+        size: 64,
+        family: OperandType::REG_ZMM4,
+    },
+    OperandTypeInfo {
+        name: "zmm5",
+        value: OperandType::REG_ZMM5,
+        size: 64,
+        family: OperandType::REG_ZMM5,
+    },
+    OperandTypeInfo {
+        name: "zmm6",
+        value: OperandType::REG_ZMM6,
+        size: 64,
+        family: OperandType::REG_ZMM6,
+    },
+    OperandTypeInfo {
+        name: "zmm7",
+        value: OperandType::REG_ZMM7,
+        size: 64,
+        family: OperandType::REG_ZMM7,
+    },
+    OperandTypeInfo {
+        name: "zmm8",
+        value: OperandType::REG_ZMM8,
+        size: 64,
+        family: OperandType::REG_ZMM8,
+    },
+    OperandTypeInfo {
+        name: "zmm9",
+        value: OperandType::REG_ZMM9,
+        size: 64,
+        family: OperandType::REG_ZMM9,
+    },
+    OperandTypeInfo {
+        name: "zmm10",
+        value: OperandType::REG_ZMM10,
+        size: 64,
+        family: OperandType::REG_ZMM10,
+    },
+    OperandTypeInfo {
+        name: "zmm11",
+        value: OperandType::REG_ZMM11,
+        size: 64,
+        family: OperandType::REG_ZMM11,
+    },
+    OperandTypeInfo {
+        name: "zmm12",
+        value: OperandType::REG_ZMM12,
+        size: 64,
+        family: OperandType::REG_ZMM12,
+    },
+    OperandTypeInfo {
+        name: "zmm13",
+        value: OperandType::REG_ZMM13,
+        size: 64,
+        family: OperandType::REG_ZMM13,
+    },
+    OperandTypeInfo {
+        name: "zmm14",
+        value: OperandType::REG_ZMM14,
+        size: 64,
+        family: OperandType::REG_ZMM14,
+    },
+    OperandTypeInfo {
+        name: "zmm15",
+        value: OperandType::REG_ZMM15,
+        size: 64,
+        family: OperandType::REG_ZMM15,
+    },
+    OperandTypeInfo {
+        name: "zmm16",
+        value: OperandType::REG_ZMM16,
+        size: 64,
+        family: OperandType::REG_ZMM16,
+    },
+    OperandTypeInfo {
+        name: "zmm17",
+        value: OperandType::REG_ZMM17,
+        size: 64,
+        family: OperandType::REG_ZMM17,
+    },
+    OperandTypeInfo {
+        name: "zmm18",
+        value: OperandType::REG_ZMM18,
+        size: 64,
+        family: OperandType::REG_ZMM18,
+    },
+    OperandTypeInfo {
+        name: "zmm19",
+        value: OperandType::REG_ZMM19,
+        size: 64,
+        family: OperandType::REG_ZMM19,
+    },
+    OperandTypeInfo {
+        name: "zmm20",
+        value: OperandType::REG_ZMM20,
+        size: 64,
+        family: OperandType::REG_ZMM20,
+    },
+    OperandTypeInfo {
+        name: "zmm21",
+        value: OperandType::REG_ZMM21,
+        size: 64,
+        family: OperandType::REG_ZMM21,
+    },
+    OperandTypeInfo {
+        name: "zmm22",
+        value: OperandType::REG_ZMM22,
+        size: 64,
+        family: OperandType::REG_ZMM22,
+    },
+    OperandTypeInfo {
+        name: "zmm23",
+        value: OperandType::REG_ZMM23,
+        size: 64,
+        family: OperandType::REG_ZMM23,
+    },
+    OperandTypeInfo {
+        name: "zmm24",
+        value: OperandType::REG_ZMM24,
+        size: 64,
+        family: OperandType::REG_ZMM24,
+    },
+    OperandTypeInfo {
+        name: "zmm25",
+        value: OperandType::REG_ZMM25,
+        size: 64,
+        family: OperandType::REG_ZMM25,
+    },
+    OperandTypeInfo {
+        name: "zmm26",
+        value: OperandType::REG_ZMM26,
+        size: 64,
+        family: OperandType::REG_ZMM26,
+    },
+    OperandTypeInfo {
+        name: "zmm27",
+        value: OperandType::REG_ZMM27,
+        size: 64,
+        family: OperandType::REG_ZMM27,
+    },
+    OperandTypeInfo {
+        name: "zmm28",
+        value: OperandType::REG_ZMM28,
+        size: 64,
+        family: OperandType::REG_ZMM28,
+    },
+    OperandTypeInfo {
+        name: "zmm29",
+        value: OperandType::REG_ZMM29,
+        size: 64,
+        family: OperandType::REG_ZMM29,
+    },
+    OperandTypeInfo {
+        name: "zmm30",
+        value: OperandType::REG_ZMM30,
+        size: 64,
+        family: OperandType::REG_ZMM30,
+    },
+    OperandTypeInfo {
+        name: "zmm31",
+        value: OperandType::REG_ZMM31,
+        size: 64,
+        family: OperandType::REG_ZMM31,
+    },
+    OperandTypeInfo {
+        name: "k0",
+        value: OperandType::REG_K0,
+        size: 8,
+        family: OperandType::REG_K0,
+    },
+    OperandTypeInfo {
+        name: "k1",
+        value: OperandType::REG_K1,
+        size: 8,
+        family: OperandType::REG_K1,
+    },
+    OperandTypeInfo {
+        name: "k2",
+        value: OperandType::REG_K2,
+        size: 8,
+        family: OperandType::REG_K2,
+    },
+    OperandTypeInfo {
+        name: "k3",
+        value: OperandType::REG_K3,
+        size: 8,
+        family: OperandType::REG_K3,
+    },
+    OperandTypeInfo {
+        name: "k4",
+        value: OperandType::REG_K4,
+        size: 8,
+        family: OperandType::REG_K4,
+    },
+    OperandTypeInfo {
+        name: "k5",
+        value: OperandType::REG_K5,
+        size: 8,
+        family: OperandType::REG_K5,
+    },
+    OperandTypeInfo {
+        name: "k6",
+        value: OperandType::REG_K6,
+        size: 8,
+        family: OperandType::REG_K6,
+    },
+    OperandTypeInfo {
+        name: "k7",
+        value: OperandType::REG_K7,
+        size: 8,
+        family: OperandType::REG_K7,
+    },
+    OperandTypeInfo {
+        name: "r16b",
+        value: OperandType::REG_R16B,
+        size: 1,
+        family: OperandType::REG_R16,
+    },
+    OperandTypeInfo {
+        name: "r17b",
+        value: OperandType::REG_R17B,
+        size: 1,
+        family: OperandType::REG_R17,
+    },
+    OperandTypeInfo {
+        name: "r18b",
+        value: OperandType::REG_R18B,
+        size: 1,
+        family: OperandType::REG_R18,
+    },
+    OperandTypeInfo {
+        name: "r19b",
+        value: OperandType::REG_R19B,
+        size: 1,
+        family: OperandType::REG_R19,
+    },
+    OperandTypeInfo {
+        name: "r20b",
+        value: OperandType::REG_R20B,
+        size: 1,
+        family: OperandType::REG_R20,
+    },
+    OperandTypeInfo {
+        name: "r21b",
+        value: OperandType::REG_R21B,
+        size: 1,
+        family: OperandType::REG_R21,
+    },
+    OperandTypeInfo {
+        name: "r22b",
+        value: OperandType::REG_R22B,
+        size: 1,
+        family: OperandType::REG_R22,
+    },
+    OperandTypeInfo {
+        name: "r23b",
+        value: OperandType::REG_R23B,
+        size: 1,
+        family: OperandType::REG_R23,
+    },
+    OperandTypeInfo {
+        name: "r24b",
+        value: OperandType::REG_R24B,
+        size: 1,
+        family: OperandType::REG_R24,
+    },
+    OperandTypeInfo {
+        name: "r25b",
+        value: OperandType::REG_R25B,
+        size: 1,
+        family: OperandType::REG_R25,
+    },
+    OperandTypeInfo {
+        name: "r26b",
+        value: OperandType::REG_R26B,
+        size: 1,
+        family: OperandType::REG_R26,
+    },
+    OperandTypeInfo {
+        name: "r27b",
+        value: OperandType::REG_R27B,
+        size: 1,
+        family: OperandType::REG_R27,
+    },
+    OperandTypeInfo {
+        name: "r28b",
+        value: OperandType::REG_R28B,
+        size: 1,
+        family: OperandType::REG_R28,
+    },
+    OperandTypeInfo {
+        name: "r29b",
+        value: OperandType::REG_R29B,
+        size: 1,
+        family: OperandType::REG_R29,
+    },
+    OperandTypeInfo {
+        name: "r30b",
+        value: OperandType::REG_R30B,
+        size: 1,
+        family: OperandType::REG_R30,
+    },
+    OperandTypeInfo {
+        name: "r31b",
+        value: OperandType::REG_R31B,
+        size: 1,
+        family: OperandType::REG_R31,
+    },
+    OperandTypeInfo {
+        name: "r16w",
+        value: OperandType::REG_R16W,
+        size: 2,
+        family: OperandType::REG_R16,
+    },
+    OperandTypeInfo {
+        name: "r17w",
+        value: OperandType::REG_R17W,
+        size: 2,
+        family: OperandType::REG_R17,
+    },
+    OperandTypeInfo {
+        name: "r18w",
+        value: OperandType::REG_R18W,
+        size: 2,
+        family: OperandType::REG_R18,
+    },
+    OperandTypeInfo {
+        name: "r19w",
+        value: OperandType::REG_R19W,
+        size: 2,
+        family: OperandType::REG_R19,
+    },
+    OperandTypeInfo {
+        name: "r20w",
+        value: OperandType::REG_R20W,
+        size: 2,
+        family: OperandType::REG_R20,
+    },
+    OperandTypeInfo {
+        name: "r21w",
+        value: OperandType::REG_R21W,
+        size: 2,
+        family: OperandType::REG_R21,
+    },
+    OperandTypeInfo {
+        name: "r22w",
+        value: OperandType::REG_R22W,
+        size: 2,
+        family: OperandType::REG_R22,
+    },
+    OperandTypeInfo {
+        name: "r23w",
+        value: OperandType::REG_R23W,
+        size: 2,
+        family: OperandType::REG_R23,
+    },
+    OperandTypeInfo {
+        name: "r24w",
+        value: OperandType::REG_R24W,
+        size: 2,
+        family: OperandType::REG_R24,
+    },
+    OperandTypeInfo {
+        name: "r25w",
+        value: OperandType::REG_R25W,
+        size: 2,
+        family: OperandType::REG_R25,
+    },
+    OperandTypeInfo {
+        name: "r26w",
+        value: OperandType::REG_R26W,
+        size: 2,
+        family: OperandType::REG_R26,
+    },
+    OperandTypeInfo {
+        name: "r27w",
+        value: OperandType::REG_R27W,
+        size: 2,
+        family: OperandType::REG_R27,
+    },
+    OperandTypeInfo {
+        name: "r28w",
+        value: OperandType::REG_R28W,
+        size: 2,
+        family: OperandType::REG_R28,
+    },
+    OperandTypeInfo {
+        name: "r29w",
+        value: OperandType::REG_R29W,
+        size: 2,
+        family: OperandType::REG_R29,
+    },
+    OperandTypeInfo {
+        name: "r30w",
+        value: OperandType::REG_R30W,
+        size: 2,
+        family: OperandType::REG_R30,
+    },
+    OperandTypeInfo {
+        name: "r31w",
+        value: OperandType::REG_R31W,
+        size: 2,
+        family: OperandType::REG_R31,
+    },
+    OperandTypeInfo {
+        name: "r16d",
+        value: OperandType::REG_R16D,
+        size: 4,
+        family: OperandType::REG_R16,
+    },
+    OperandTypeInfo {
+        name: "r17d",
+        value: OperandType::REG_R17D,
+        size: 4,
+        family: OperandType::REG_R17,
+    },
+    OperandTypeInfo {
+        name: "r18d",
+        value: OperandType::REG_R18D,
+        size: 4,
+        family: OperandType::REG_R18,
+    },
+    OperandTypeInfo {
+        name: "r19d",
+        value: OperandType::REG_R19D,
+        size: 4,
+        family: OperandType::REG_R19,
+    },
+    OperandTypeInfo {
+        name: "r20d",
+        value: OperandType::REG_R20D,
+        size: 4,
+        family: OperandType::REG_R20,
+    },
+    OperandTypeInfo {
+        name: "r21d",
+        value: OperandType::REG_R21D,
+        size: 4,
+        family: OperandType::REG_R21,
+    },
+    OperandTypeInfo {
+        name: "r22d",
+        value: OperandType::REG_R22D,
+        size: 4,
+        family: OperandType::REG_R22,
+    },
+    OperandTypeInfo {
+        name: "r23d",
+        value: OperandType::REG_R23D,
+        size: 4,
+        family: OperandType::REG_R23,
+    },
+    OperandTypeInfo {
+        name: "r24d",
+        value: OperandType::REG_R24D,
+        size: 4,
+        family: OperandType::REG_R24,
+    },
+    OperandTypeInfo {
+        name: "r25d",
+        value: OperandType::REG_R25D,
+        size: 4,
+        family: OperandType::REG_R25,
+    },
+    OperandTypeInfo {
+        name: "r26d",
+        value: OperandType::REG_R26D,
+        size: 4,
+        family: OperandType::REG_R26,
+    },
+    OperandTypeInfo {
+        name: "r27d",
+        value: OperandType::REG_R27D,
+        size: 4,
+        family: OperandType::REG_R27,
+    },
+    OperandTypeInfo {
+        name: "r28d",
+        value: OperandType::REG_R28D,
+        size: 4,
+        family: OperandType::REG_R28,
+    },
+    OperandTypeInfo {
+        name: "r29d",
+        value: OperandType::REG_R29D,
+        size: 4,
+        family: OperandType::REG_R29,
+    },
+    OperandTypeInfo {
+        name: "r30d",
+        value: OperandType::REG_R30D,
+        size: 4,
+        family: OperandType::REG_R30,
+    },
+    OperandTypeInfo {
+        name: "r31d",
+        value: OperandType::REG_R31D,
+        size: 4,
+        family: OperandType::REG_R31,
+    },
+    OperandTypeInfo {
+        name: "r16",
+        value: OperandType::REG_R16,
+        size: 8,
+        family: OperandType::REG_R16,
+    },
+    OperandTypeInfo {
+        name: "r17",
+        value: OperandType::REG_R17,
+        size: 8,
+        family: OperandType::REG_R17,
+    },
+    OperandTypeInfo {
+        name: "r18",
+        value: OperandType::REG_R18,
+        size: 8,
+        family: OperandType::REG_R18,
+    },
+    OperandTypeInfo {
+        name: "r19",
+        value: OperandType::REG_R19,
+        size: 8,
+        family: OperandType::REG_R19,
+    },
+    OperandTypeInfo {
+        name: "r20",
+        value: OperandType::REG_R20,
+        size: 8,
+        family: OperandType::REG_R20,
+    },
+    OperandTypeInfo {
+        name: "r21",
+        value: OperandType::REG_R21,
+        size: 8,
+        family: OperandType::REG_R21,
+    },
+    OperandTypeInfo {
+        name: "r22",
+        value: OperandType::REG_R22,
+        size: 8,
+        family: OperandType::REG_R22,
+    },
+    OperandTypeInfo {
+        name: "r23",
+        value: OperandType::REG_R23,
+        size: 8,
+        family: OperandType::REG_R23,
+    },
+    OperandTypeInfo {
+        name: "r24",
+        value: OperandType::REG_R24,
+        size: 8,
+        family: OperandType::REG_R24,
+    },
+    OperandTypeInfo {
+        name: "r25",
+        value: OperandType::REG_R25,
+        size: 8,
+        family: OperandType::REG_R25,
+    },
+    OperandTypeInfo {
+        name: "r26",
+        value: OperandType::REG_R26,
+        size: 8,
+        family: OperandType::REG_R26,
+    },
+    OperandTypeInfo {
+        name: "r27",
+        value: OperandType::REG_R27,
+        size: 8,
+        family: OperandType::REG_R27,
+    },
+    OperandTypeInfo {
+        name: "r28",
+        value: OperandType::REG_R28,
+        size: 8,
+        family: OperandType::REG_R28,
+    },
+    OperandTypeInfo {
+        name: "r29",
+        value: OperandType::REG_R29,
+        size: 8,
+        family: OperandType::REG_R29,
+    },
+    OperandTypeInfo {
+        name: "r30",
+        value: OperandType::REG_R30,
+        size: 8,
+        family: OperandType::REG_R30,
+    },
+    OperandTypeInfo {
+        name: "r31",
+        value: OperandType::REG_R31,
+        size: 8,
+        family: OperandType::REG_R31,
+    },
+    OperandTypeInfo {
+        name: "tmm0",
+        value: OperandType::REG_TMM0,
+        size: 1024,
+        family: OperandType::REG_TMM0,
+    },
+    OperandTypeInfo {
+        name: "tmm1",
+        value: OperandType::REG_TMM1,
+        size: 1024,
+        family: OperandType::REG_TMM1,
+    },
+    OperandTypeInfo {
+        name: "tmm2",
+        value: OperandType::REG_TMM2,
+        size: 1024,
+        family: OperandType::REG_TMM2,
+    },
+    OperandTypeInfo {
+        name: "tmm3",
+        value: OperandType::REG_TMM3,
+        size: 1024,
+        family: OperandType::REG_TMM3,
+    },
+    OperandTypeInfo {
+        name: "tmm4",
+        value: OperandType::REG_TMM4,
+        size: 1024,
+        family: OperandType::REG_TMM4,
+    },
+    OperandTypeInfo {
+        name: "tmm5",
+        value: OperandType::REG_TMM5,
+        size: 1024,
+        family: OperandType::REG_TMM5,
+    },
+    OperandTypeInfo {
+        name: "tmm6",
+        value: OperandType::REG_TMM6,
+        size: 1024,
+        family: OperandType::REG_TMM6,
+    },
+    OperandTypeInfo {
+        name: "tmm7",
+        value: OperandType::REG_TMM7,
+        size: 1024,
+        family: OperandType::REG_TMM7,
     },
 ];
@@ -0,0 +1,266 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use core::ops::Deref;
+
+use super::SegmentRegister;
+
+/// The most prefixes that fit in an instruction, which is limited to
+/// 15 bytes including at least one opcode byte.
+pub const MAX_PREFIXES: usize = 14;
+
+/// What a prefix byte does.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // add r16d, eax
+/// let data = [0xd5u8, 0x10, 0x01, 0xc0];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.prefixes()[0].kind, X86PrefixKind::Rex2(0x10));
+/// match instr.operands()[0] {
+///     X86Operand::Register(reg) => assert_eq!(reg.register, OperandType::REG_R16D),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X86PrefixKind {
+    /// A segment override prefix: `0x26`, `0x2e`, `0x36`, `0x3e`,
+    /// `0x64` or `0x65`.
+    Segment(SegmentRegister),
+    /// The operand size prefix, `0x66`.
+    OperandSize,
+    /// The address size prefix, `0x67`.
+    AddressSize,
+    /// The `LOCK` prefix, `0xf0`.
+    Lock,
+    /// The `REPNE` prefix, `0xf2`.
+    Repne,
+    /// The `REPE` or `REP` prefix, `0xf3`.
+    Repe,
+    /// A REX prefix, `0x40` to `0x4f`. These are only prefixes in
+    /// 64 bit mode.
+    Rex,
+    /// An APX REX2 prefix, `0xd5`, with its payload byte. This is only
+    /// a prefix in 64 bit mode, and is always the last prefix.
+    Rex2(u8),
+}
+
+impl X86PrefixKind {
+    /// The kind of a prefix byte, given the byte after it for REX2.
+    fn from_byte(byte: u8, next: u8) -> Self {
+        match byte {
+            0x26 => X86PrefixKind::Segment(SegmentRegister::ES),
+            0x2e => X86PrefixKind::Segment(SegmentRegister::CS),
+            0x36 => X86PrefixKind::Segment(SegmentRegister::SS),
+            0x3e => X86PrefixKind::Segment(SegmentRegister::DS),
+            0x64 => X86PrefixKind::Segment(SegmentRegister::FS),
+            0x65 => X86PrefixKind::Segment(SegmentRegister::GS),
+            0x66 => X86PrefixKind::OperandSize,
+            0x67 => X86PrefixKind::AddressSize,
+            0xf0 => X86PrefixKind::Lock,
+            0xf2 => X86PrefixKind::Repne,
+            0xf3 => X86PrefixKind::Repe,
+            0xd5 => X86PrefixKind::Rex2(next),
+            _ => X86PrefixKind::Rex,
+        }
+    }
+
+    /// The kinds of prefix that can't be combined, where only the last
+    /// one of a group takes effect.
+    fn group(self) -> u8 {
+        match self {
+            X86PrefixKind::Segment(_) => 0,
+            X86PrefixKind::OperandSize => 1,
+            X86PrefixKind::AddressSize => 2,
+            X86PrefixKind::Lock => 3,
+            X86PrefixKind::Repne | X86PrefixKind::Repe => 4,
+            X86PrefixKind::Rex | X86PrefixKind::Rex2(_) => 5,
+        }
+    }
+}
+
+/// Whether a prefix had an effect on the instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X86PrefixStatus {
+    /// The prefix applies to the instruction.
+    Effective,
+    /// The prefix is repeated by a later identical prefix, which is
+    /// the one that applies.
+    Redundant,
+    /// A later prefix of the same group, such as a different segment
+    /// override or `REPE` after `REPNE`, applies instead. This is also
+    /// an operand size prefix when REX.W sets the operand size.
+    Overridden,
+    /// The prefix has no effect on this instruction. This is a REX
+    /// prefix that is not immediately before the opcode, a segment
+    /// override on an instruction without a memory operand that uses
+    /// it (or an `ES`, `CS`, `SS` or `DS` override in 64 bit mode), or
+    /// a `REPNE` or `REPE` that the instruction does not use.
+    Ignored,
+}
+
+/// A single prefix byte of an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct X86Prefix {
+    /// The prefix byte.
+    pub byte: u8,
+    /// The offset of the prefix from the start of the instruction.
+    pub offset: u8,
+    /// What the prefix does.
+    pub kind: X86PrefixKind,
+    /// Whether the prefix had an effect.
+    pub status: X86PrefixStatus,
+}
+
+const EMPTY_PREFIX: X86Prefix = X86Prefix {
+    byte: 0,
+    offset: 0,
+    kind: X86PrefixKind::Lock,
+    status: X86PrefixStatus::Ignored,
+};
+
+/// Every prefix of an instruction, in the order that they were given.
+///
+/// These are worked out from the prefix bytes of the instruction each
+/// time that `X86Instruction::prefixes` is called.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// // A REX prefix is discarded when it isn't right before the opcode.
+/// let data = [0x2eu8, 0x48, 0x64, 0x64, 0x66, 0x89, 0x00];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// let prefixes = instr.prefixes();
+/// let statuses = prefixes.iter().map(|p| p.status).collect::<Vec<_>>();
+/// assert_eq!(statuses, [
+///     X86PrefixStatus::Overridden,
+///     X86PrefixStatus::Ignored,
+///     X86PrefixStatus::Redundant,
+///     X86PrefixStatus::Effective,
+///     X86PrefixStatus::Effective,
+/// ]);
+/// assert_eq!(prefixes[3].kind, X86PrefixKind::Segment(SegmentRegister::FS));
+/// assert!(prefixes.rex_discarded());
+///
+/// // REX.W takes priority over the operand size prefix.
+/// let data = [0x66u8, 0x48, 0x01, 0xc0];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.prefixes()[0].status, X86PrefixStatus::Overridden);
+/// assert_eq!(instr.prefixes()[1].status, X86PrefixStatus::Effective);
+///
+/// // Unless it is part of the opcode, as for `movq rax, xmm0`.
+/// let data = [0x66u8, 0x48, 0x0f, 0x7e, 0xc0];
+/// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+/// assert_eq!(instr.prefixes()[0].status, X86PrefixStatus::Effective);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct X86Prefixes {
+    prefixes: [X86Prefix; MAX_PREFIXES],
+    count: u8,
+    rex_discarded: bool,
+}
+
+impl X86Prefixes {
+    /// The prefixes as a slice.
+    pub fn as_slice(&self) -> &[X86Prefix] {
+        &self.prefixes[..usize::from(self.count)]
+    }
+
+    /// Whether a REX prefix was discarded because another prefix
+    /// followed it.
+    pub fn rex_discarded(&self) -> bool {
+        self.rex_discarded
+    }
+
+    /// The prefixes given by `bytes`, which must hold only prefixes,
+    /// with a REX2 prefix followed by its payload.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+        let mut prefixes = X86Prefixes::default();
+        for (offset, &byte) in bytes.iter().enumerate() {
+            let next = bytes.get(offset + 1).cloned().unwrap_or(0);
+            let kind = X86PrefixKind::from_byte(byte, next);
+            prefixes.prefixes[offset] = X86Prefix {
+                byte,
+                offset: offset as u8,
+                kind,
+                status: X86PrefixStatus::Effective,
+            };
+            prefixes.count += 1;
+            if let X86PrefixKind::Rex2(_) = kind {
+                break;
+            }
+        }
+        prefixes.resolve();
+        prefixes
+    }
+
+    /// Mark prefixes that are superseded by a later prefix.
+    fn resolve(&mut self) {
+        let count = usize::from(self.count);
+        for i in 0..count {
+            let prefix = self.prefixes[i];
+            if prefix.kind == X86PrefixKind::Rex {
+                if i + 1 != count {
+                    self.prefixes[i].status = X86PrefixStatus::Ignored;
+                    self.rex_discarded = true;
+                }
+                continue;
+            }
+            let last = self.prefixes[i + 1..count]
+                .iter()
+                .rev()
+                .find(|later| later.kind.group() == prefix.kind.group());
+            if let Some(last) = last {
+                self.prefixes[i].status = if last.byte == prefix.byte {
+                    X86PrefixStatus::Redundant
+                } else {
+                    X86PrefixStatus::Overridden
+                };
+            }
+        }
+    }
+
+    /// Give the effective prefixes of the given kinds a new status.
+    pub(crate) fn mark<F>(&mut self, status: X86PrefixStatus, matches: F)
+    where
+        F: Fn(X86PrefixKind) -> bool,
+    {
+        let count = usize::from(self.count);
+        for prefix in &mut self.prefixes[..count] {
+            if prefix.status == X86PrefixStatus::Effective && matches(prefix.kind) {
+                prefix.status = status;
+            }
+        }
+    }
+}
+
+impl Default for X86Prefixes {
+    fn default() -> Self {
+        X86Prefixes {
+            prefixes: [EMPTY_PREFIX; MAX_PREFIXES],
+            count: 0,
+            rex_discarded: false,
+        }
+    }
+}
+
+impl fmt::Debug for X86Prefixes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("X86Prefixes")
+            .field("prefixes", &self.as_slice())
+            .field("rex_discarded", &self.rex_discarded)
+            .finish()
+    }
+}
+
+impl Deref for X86Prefixes {
+    type Target = [X86Prefix];
+
+    fn deref(&self) -> &[X86Prefix] {
+        self.as_slice()
+    }
+}
@@ -0,0 +1,4131 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+
+use super::{InstructionOperation, OperandAccess, OperandType, X86Flag, X86Instruction, X86Mode};
+use super::X86Operand;
+use super::{OPERAND_TYPE_COUNT, REG16_LIST, REG32_LIST, REG64_LIST, REG8_LIST64};
+
+const SET_WORDS: usize = OPERAND_TYPE_COUNT.div_ceil(64);
+
+/// A set of registers, such as the registers that an instruction
+/// reads or writes.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// let mut regs = X86RegSet::new();
+/// regs.insert(OperandType::REG_AL);
+/// assert!(regs.contains(OperandType::REG_AL));
+/// assert!(!regs.contains(OperandType::REG_RAX));
+/// // al shares bits with eax and rax, but not with ah.
+/// assert!(regs.overlaps(OperandType::REG_EAX));
+/// assert!(regs.overlaps(OperandType::REG_RAX));
+/// assert!(!regs.overlaps(OperandType::REG_AH));
+/// assert_eq!(regs.iter().collect::<Vec<_>>(), [OperandType::REG_AL]);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct X86RegSet {
+    bits: [u64; SET_WORDS],
+}
+
+impl X86RegSet {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        X86RegSet::default()
+    }
+
+    /// Add a register to the set. Anything that isn't a register is
+    /// ignored.
+    pub fn insert(&mut self, reg: OperandType) {
+        if reg.is_register() {
+            let i = reg as usize;
+            self.bits[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    /// Remove a register from the set.
+    pub fn remove(&mut self, reg: OperandType) {
+        let i = reg as usize;
+        self.bits[i / 64] &= !(1 << (i % 64));
+    }
+
+    /// Whether the set holds exactly this register.
+    pub fn contains(&self, reg: OperandType) -> bool {
+        let i = reg as usize;
+        self.bits[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Whether the set holds a register that shares any bits with
+    /// `reg`, which is when either of them is within the other.
+    pub fn overlaps(&self, reg: OperandType) -> bool {
+        self.iter().any(|other| reg.is_within(other) || other.is_within(reg))
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// The number of registers in the set.
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// The registers in the set, in the order of `OperandType`.
+    pub fn iter(&self) -> impl Iterator<Item = OperandType> {
+        let bits = self.bits;
+        (0..OPERAND_TYPE_COUNT)
+            .filter(move |&i| bits[i / 64] & (1 << (i % 64)) != 0)
+            .map(|i| OperandType::from_i32(i as i32))
+    }
+}
+
+impl fmt::Debug for X86RegSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter().map(OperandType::name)).finish()
+    }
+}
+
+const R: OperandAccess = OperandAccess::Read;
+const W: OperandAccess = OperandAccess::Write;
+const RW: OperandAccess = OperandAccess::ReadWrite;
+const CW: OperandAccess = OperandAccess::CondWrite;
+const N: OperandAccess = OperandAccess::None;
+
+const USE_R: [OperandAccess; 4] = [R, R, R, R];
+const USE_W: [OperandAccess; 4] = [W, R, R, R];
+const USE_RW: [OperandAccess; 4] = [RW, R, R, R];
+const USE_RW_RW: [OperandAccess; 4] = [RW, RW, R, R];
+const USE_W_W: [OperandAccess; 4] = [W, W, R, R];
+const USE_CW: [OperandAccess; 4] = [CW, R, R, R];
+const USE_N: [OperandAccess; 4] = [N, N, N, N];
+const USE_W_N: [OperandAccess; 4] = [W, N, R, R];
+const USE_RW_R_RW: [OperandAccess; 4] = [RW, R, RW, R];
+
+/// A register that an instruction uses without naming it as an
+/// operand.
+#[derive(Clone, Copy)]
+enum Implicit {
+    /// Always this register.
+    Reg(OperandType),
+    /// The part of this 64 bit register with the size of the first
+    /// operand.
+    OpSize(OperandType),
+    /// The double sized accumulator of a multiply or divide: `ax`
+    /// for a byte operand and `dx:ax`, `edx:eax` or `rdx:rax`
+    /// otherwise.
+    AccPair,
+    /// The part of this 64 bit register with the address size.
+    AddrSize(OperandType),
+    /// The part of this 64 bit register with the stack size.
+    StackSize(OperandType),
+    /// The first 16 registers of the kind of this register, or the
+    /// first 8 outside of 64 bit mode.
+    Vectors(OperandType),
+    /// The 8 AMX tile registers.
+    Tiles,
+}
+
+/// How an operation uses its operands, and the registers that it
+/// uses beyond them.
+struct OperationSemantics {
+    operation: InstructionOperation,
+    /// The number of operands that this entry applies to, or `0` for
+    /// any number.
+    operands: u8,
+    access: [OperandAccess; 4],
+    read: &'static [Implicit],
+    written: &'static [Implicit],
+}
+
+/// Find the entry for an operation with a number of operands.
+fn find_semantics(
+    operation: InstructionOperation,
+    operands: usize,
+) -> Option<&'static OperationSemantics> {
+    let find = |operands: u8| {
+        OPERATION_SEMANTICS
+            .binary_search_by_key(&(operation as i32, operands), |entry| {
+                (entry.operation as i32, entry.operands)
+            })
+            .ok()
+            .map(|i| &OPERATION_SEMANTICS[i])
+    };
+    find(operands as u8).or_else(|| find(0))
+}
+
+/// Whether a register is an MMX or XMM register.
+fn is_legacy_vector(reg: OperandType) -> bool {
+    (reg >= OperandType::REG_MM0 && reg <= OperandType::REG_MM7) ||
+    (reg >= OperandType::REG_XMM0 && reg <= OperandType::REG_XMM15)
+}
+
+/// Whether a register is an XMM, YMM or ZMM register.
+fn is_vector(reg: OperandType) -> bool {
+    reg.family() >= OperandType::REG_ZMM0 && reg.family() <= OperandType::REG_ZMM31
+}
+
+/// The part of a 64 bit general purpose register with a size.
+fn gpr_of_size(reg: OperandType, size: u16) -> OperandType {
+    let i = REG64_LIST.iter().position(|&r| r == reg).unwrap_or(0);
+    match size {
+        1 => REG8_LIST64[i],
+        2 => REG16_LIST[i],
+        4 => REG32_LIST[i],
+        _ => REG64_LIST[i],
+    }
+}
+
+impl X86Instruction {
+    /// The registers that this instruction reads.
+    ///
+    /// This includes registers that are used implicitly, such as
+    /// `rsp` for `PUSH` and `rcx` for a `REP` prefix, and the base
+    /// and index registers of memory operands. A register that is
+    /// only written when a condition holds, such as the destination
+    /// of `CMOVcc`, is also read, as its old value may survive.
+    ///
+    /// Registers are reported as they are named, so `add al, bl`
+    /// reads `al` and `bl` but not `rax`. Flags, segment registers
+    /// used for addressing and the renaming of the x87 register stack
+    /// by pushes and pops are not included.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// // mul ecx
+    /// let data = [0xf7u8, 0xe1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let read = instr.regs_read();
+    /// assert!(read.contains(OperandType::REG_EAX));
+    /// assert!(read.contains(OperandType::REG_ECX));
+    /// assert_eq!(read.len(), 2);
+    ///
+    /// // rep movsb in 32 bit mode
+    /// let data = [0xf3u8, 0xa4];
+    /// let instr = disassemble_32(&data, 0, data.len()).unwrap();
+    /// let read: Vec<_> = instr.regs_read().iter().collect();
+    /// assert_eq!(read, [OperandType::REG_ECX, OperandType::REG_ESI, OperandType::REG_EDI]);
+    ///
+    /// // mov eax, [rbx+rsi*4]
+    /// let data = [0x8bu8, 0x04, 0xb3];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let read: Vec<_> = instr.regs_read().iter().collect();
+    /// assert_eq!(read, [OperandType::REG_RBX, OperandType::REG_RSI]);
+    /// ```
+    pub fn regs_read(&self) -> X86RegSet {
+        let mut regs = X86RegSet::new();
+        for (operand, access) in self.operands().iter().zip(self.operand_access()) {
+            match *operand {
+                X86Operand::Register(reg)
+                    if access.is_read() || access == OperandAccess::CondWrite =>
+                {
+                    regs.insert(reg.register);
+                }
+                X86Operand::Memory(mem) => {
+                    regs.insert(mem.base.unwrap_or(OperandType::NONE));
+                    regs.insert(mem.index.unwrap_or(OperandType::NONE));
+                }
+                _ => {}
+            }
+        }
+        if let Some(semantics) = self.semantics() {
+            for &implicit in semantics.read {
+                self.insert_implicit(&mut regs, implicit, false);
+            }
+        }
+        if self.flags & X86Flag::ANY_REP != 0 {
+            self.insert_implicit(&mut regs, Implicit::AddrSize(OperandType::REG_RCX), false);
+        }
+        if let Some(mask) = self.evex.and_then(|evex| evex.mask) {
+            regs.insert(mask);
+        }
+        regs
+    }
+
+    /// The registers that this instruction writes.
+    ///
+    /// This includes registers that are written implicitly, such as
+    /// `rdx` for `MUL` and `rsi` and `rdi` for `MOVSB`. A write is
+    /// reported as the whole register that it changes: in 64 bit mode,
+    /// writing a 32 bit register zeroes the upper half of the 64 bit
+    /// register, so `mov eax, ebx` writes `rax`, and a VEX or EVEX
+    /// instruction that writes a vector register zeroes the bits above
+    /// the destination, so it writes the whole `zmm` register. Other
+    /// writes, such as to `al`, leave the rest of the register
+    /// unchanged and are reported as they are named.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// // mul ecx
+    /// let data = [0xf7u8, 0xe1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let written: Vec<_> = instr.regs_written().iter().collect();
+    /// assert_eq!(written, [OperandType::REG_RAX, OperandType::REG_RDX]);
+    ///
+    /// // push rbp
+    /// let data = [0x55u8];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let written: Vec<_> = instr.regs_written().iter().collect();
+    /// assert_eq!(written, [OperandType::REG_RSP]);
+    ///
+    /// // vaddps xmm0, xmm1, xmm2
+    /// let data = [0xc5u8, 0xf0, 0x58, 0xc2];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert!(instr.regs_written().contains(OperandType::REG_ZMM0));
+    /// ```
+    pub fn regs_written(&self) -> X86RegSet {
+        let mut regs = X86RegSet::new();
+        for (operand, access) in self.operands().iter().zip(self.operand_access()) {
+            if let X86Operand::Register(reg) = *operand {
+                if access.is_written() {
+                    regs.insert(self.written_register(reg.register));
+                }
+            }
+        }
+        if let Some(semantics) = self.semantics() {
+            for &implicit in semantics.written {
+                self.insert_implicit(&mut regs, implicit, true);
+            }
+        }
+        if self.flags & X86Flag::ANY_REP != 0 {
+            self.insert_implicit(&mut regs, Implicit::AddrSize(OperandType::REG_RCX), true);
+        }
+        if let Some(mask) = self.evex.and_then(|evex| evex.mask) {
+            // A gather or scatter clears each mask bit as it finishes
+            // that element.
+            if self.operands().iter().any(is_vsib_memory) {
+                regs.insert(mask);
+            }
+        }
+        regs
+    }
+
+    /// Whether this is the SSE instruction `MOVSD` or `CMPSD`, which
+    /// shares its operation with a string instruction but is nothing
+    /// like it.
+    pub(crate) fn is_sse_string_alias(&self) -> bool {
+        match self.operation {
+            InstructionOperation::MOVSD | InstructionOperation::CMPSD => self
+                .operands()
+                .iter()
+                .any(|operand| matches!(*operand, X86Operand::Register(_))),
+            _ => false,
+        }
+    }
+
+    /// The entry for this instruction, if it has one.
+    fn semantics(&self) -> Option<&'static OperationSemantics> {
+        if self.is_sse_string_alias() {
+            None
+        } else {
+            find_semantics(self.operation, self.source_operand_count())
+        }
+    }
+
+    /// The number of operands, without the new destination of an APX
+    /// instruction.
+    fn source_operand_count(&self) -> usize {
+        match self.evex {
+            Some(evex) if evex.new_data_dest => self.operands().len() - 1,
+            _ => self.operands().len(),
+        }
+    }
+
+    /// How this instruction uses each of its operands, in the same
+    /// order as `operands`. The entries past the last operand are
+    /// `OperandAccess::None`.
+    ///
+    /// This is worked out from the operation on each call, so that
+    /// disassembly doesn't pay for it when it isn't needed.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// // lea rax, [rbx+8] doesn't access the memory
+    /// let data = [0x48u8, 0x8d, 0x43, 0x08];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let access = instr.operand_access();
+    /// assert_eq!(access, [
+    ///     OperandAccess::Write,
+    ///     OperandAccess::None,
+    ///     OperandAccess::None,
+    ///     OperandAccess::None,
+    /// ]);
+    ///
+    /// // Count the operands that are stored to.
+    /// let stores = instr
+    ///     .operands()
+    ///     .iter()
+    ///     .zip(instr.operand_access())
+    ///     .filter(|&(operand, access)| match *operand {
+    ///         X86Operand::Memory(_) => access.is_written(),
+    ///         _ => false,
+    ///     })
+    ///     .count();
+    /// assert_eq!(stores, 0);
+    /// ```
+    pub fn operand_access(&self) -> [OperandAccess; 4] {
+        let operands = self.operands();
+        let first_reg = match operands.first() {
+            Some(&X86Operand::Register(reg)) => Some(reg.register),
+            _ => None,
+        };
+        let legacy_vector_dest = self.flags & (X86Flag::VEX | X86Flag::EVEX | X86Flag::XOP) == 0 &&
+                                 first_reg.is_some_and(is_legacy_vector);
+        let mut access = match self.semantics() {
+            Some(semantics) => semantics.access,
+            None if self.source_operand_count() == 1 => USE_R,
+            // Most SSE and MMX instructions combine the destination
+            // with the source.
+            None if legacy_vector_dest => USE_RW,
+            None => USE_W,
+        };
+        match self.operation {
+            // A move from memory zeroes the rest of the register, but
+            // a move from a register merges with it.
+            InstructionOperation::MOVSS | InstructionOperation::MOVSD
+                if legacy_vector_dest =>
+            {
+                access[0] = match operands[1] {
+                    X86Operand::Memory(_) => W,
+                    _ => RW,
+                };
+            }
+            _ => {}
+        }
+        // Memory is only accessed where a mask selects an element, or
+        // for as many times as a string instruction repeats.
+        let mut masked_memory = self.flags & X86Flag::ANY_REP != 0 ||
+                                operands.iter().any(is_vsib_memory);
+        match self.operation {
+            InstructionOperation::VMASKMOVPS |
+            InstructionOperation::VMASKMOVPD |
+            InstructionOperation::VPMASKMOVD |
+            InstructionOperation::VPMASKMOVQ => masked_memory = true,
+            _ => {}
+        }
+        if let Some(evex) = self.evex {
+            if evex.new_data_dest {
+                // The result goes to a new register, and what would
+                // have been the destination is only read.
+                for i in (1..4).rev() {
+                    let old = access[i - 1];
+                    access[i] = if old.is_read() || old == CW { R } else { N };
+                }
+                access[0] = W;
+            } else if evex.mask.is_some() && !evex.zeroing && first_reg.is_some_and(is_vector) {
+                // Merge masking keeps the elements that aren't written.
+                access[0] = RW;
+            }
+            masked_memory |= evex.mask.is_some();
+        }
+        for (i, access) in access.iter_mut().enumerate() {
+            *access = match operands.get(i) {
+                Some(&X86Operand::Register(_)) => *access,
+                Some(&X86Operand::Memory(_)) => match *access {
+                    R if masked_memory => OperandAccess::CondRead,
+                    W if masked_memory => CW,
+                    access => access,
+                },
+                // Immediates, far pointers and relative targets.
+                Some(_) => R,
+                None => N,
+            };
+        }
+        access
+    }
+
+    /// The whole register that is changed by writing `reg`.
+    fn written_register(&self, reg: OperandType) -> OperandType {
+        let family = reg.family();
+        let zero_extended = if is_vector(reg) {
+            self.flags & (X86Flag::VEX | X86Flag::EVEX | X86Flag::XOP) != 0
+        } else {
+            self.mode == X86Mode::Bits64 && reg.size() == 4 && REG64_LIST.contains(&family)
+        };
+        if zero_extended {
+            family
+        } else {
+            reg
+        }
+    }
+
+    fn insert_implicit(&self, regs: &mut X86RegSet, implicit: Implicit, written: bool) {
+        let op_size = match self.operands().first() {
+            Some(&X86Operand::Register(reg)) => reg.register.size(),
+            Some(&X86Operand::Memory(mem)) => mem.size,
+            _ => 0,
+        };
+        let addr_size = match (self.mode, self.flags & X86Flag::ADDRSIZE != 0) {
+            (X86Mode::Bits16, false) | (X86Mode::Bits32, true) => 2,
+            (X86Mode::Bits64, false) => 8,
+            _ => 4,
+        };
+        let stack_size = match self.mode {
+            X86Mode::Bits16 => 2,
+            X86Mode::Bits32 => 4,
+            X86Mode::Bits64 => 8,
+        };
+        let mut insert = |reg: OperandType| {
+            regs.insert(if written { self.written_register(reg) } else { reg });
+        };
+        match implicit {
+            Implicit::Reg(reg) => insert(reg),
+            Implicit::OpSize(reg) => insert(gpr_of_size(reg, op_size)),
+            Implicit::AccPair if op_size == 1 => insert(OperandType::REG_AX),
+            Implicit::AccPair => {
+                insert(gpr_of_size(OperandType::REG_RAX, op_size));
+                insert(gpr_of_size(OperandType::REG_RDX, op_size));
+            }
+            Implicit::AddrSize(reg) => insert(gpr_of_size(reg, addr_size)),
+            Implicit::StackSize(reg) => insert(gpr_of_size(reg, stack_size)),
+            Implicit::Vectors(first) => {
+                let count = if self.mode == X86Mode::Bits64 { 16 } else { 8 };
+                for i in 0..count {
+                    // Implicit vector writes are exactly as listed.
+                    regs.insert(OperandType::from_i32(first as i32 + i));
+                }
+            }
+            Implicit::Tiles => {
+                for i in 0..8 {
+                    regs.insert(OperandType::from_i32(OperandType::REG_TMM0 as i32 + i));
+                }
+            }
+        }
+    }
+}
+
+fn is_vsib_memory(operand: &X86Operand) -> bool {
+    match *operand {
+        X86Operand::Memory(mem) => mem.index.is_some_and(is_vector),
+        _ => false,
+    }
+}
+
+static OPERATION_SEMANTICS: [OperationSemantics; 503] = [
+    OperationSemantics {
+        operation: InstructionOperation::AAA,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AX)],
+        written: &[Implicit::Reg(OperandType::REG_AX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::AAD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AX)],
+        written: &[Implicit::Reg(OperandType::REG_AX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::AAM,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AL)],
+        written: &[Implicit::Reg(OperandType::REG_AX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::AAS,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AX)],
+        written: &[Implicit::Reg(OperandType::REG_AX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ADD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ADC,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::AND,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ARPL,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BLENDVPD,
+        operands: 0,
+        access: USE_RW,
+        read: &[Implicit::Reg(OperandType::REG_XMM0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BLENDVPS,
+        operands: 0,
+        access: USE_RW,
+        read: &[Implicit::Reg(OperandType::REG_XMM0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BOUND,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BSF,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BSR,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BSWAP,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BT,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BTC,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BTR,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::BTS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CALLF,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CALL,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CLFLUSH,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMP,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMPXCH16B,
+        operands: 0,
+        access: USE_RW,
+        read: &[
+            Implicit::Reg(OperandType::REG_RDX),
+            Implicit::Reg(OperandType::REG_RAX),
+            Implicit::Reg(OperandType::REG_RCX),
+            Implicit::Reg(OperandType::REG_RBX),
+        ],
+        written: &[Implicit::Reg(OperandType::REG_RDX), Implicit::Reg(OperandType::REG_RAX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMPXCH8B,
+        operands: 0,
+        access: USE_RW,
+        read: &[
+            Implicit::Reg(OperandType::REG_EDX),
+            Implicit::Reg(OperandType::REG_EAX),
+            Implicit::Reg(OperandType::REG_ECX),
+            Implicit::Reg(OperandType::REG_EBX),
+        ],
+        written: &[Implicit::Reg(OperandType::REG_EDX), Implicit::Reg(OperandType::REG_EAX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMPXCHG,
+        operands: 0,
+        access: USE_RW,
+        read: &[Implicit::OpSize(OperandType::REG_RAX)],
+        written: &[Implicit::OpSize(OperandType::REG_RAX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CPUID,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_ECX)],
+        written: &[
+            Implicit::Reg(OperandType::REG_EAX),
+            Implicit::Reg(OperandType::REG_EBX),
+            Implicit::Reg(OperandType::REG_ECX),
+            Implicit::Reg(OperandType::REG_EDX),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CRC32,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::DAA,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AL)],
+        written: &[Implicit::Reg(OperandType::REG_AL)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::DAS,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AL)],
+        written: &[Implicit::Reg(OperandType::REG_AL)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::DEC,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::DIV,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AccPair],
+        written: &[Implicit::AccPair],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ENTER,
+        operands: 0,
+        access: USE_R,
+        read: &[
+            Implicit::StackSize(OperandType::REG_RSP),
+            Implicit::StackSize(OperandType::REG_RBP),
+        ],
+        written: &[
+            Implicit::StackSize(OperandType::REG_RSP),
+            Implicit::StackSize(OperandType::REG_RBP),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::F2XM1,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FABS,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FADD,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FADD,
+        operands: 2,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FADDP,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FBLD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FBSTP,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCHS,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVB,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVBE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVNB,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVNBE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVNE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVNU,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCMOVU,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOM,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOM,
+        operands: 2,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOMI,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOMIP,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOMP,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOMP,
+        operands: 2,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOMPP,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FCOS,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FDIV,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FDIV,
+        operands: 2,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FDIVP,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FDIVR,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FDIVR,
+        operands: 2,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FDIVRP,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FFREE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FFREEP,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FIADD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FICOM,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FICOMP,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FIDIV,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FIDIVR,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FILD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FIMUL,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FIST,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FISTP,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FISTTP,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FISUB,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FISUBR,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLD1,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLDL2E,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLDL2T,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLDLG2,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLDLN2,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLDPI,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FLDZ,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FMUL,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FMUL,
+        operands: 2,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FMULP,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FPATAN,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FPREM,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FPREM1,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FPTAN,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FRNDINT,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSAVE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSCALE,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSIN,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSINCOS,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSQRT,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FST,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSTCW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSTDW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSTENV,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSTP,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSTSG,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSTSW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSUB,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSUB,
+        operands: 2,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSUBP,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSUBR,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSUBR,
+        operands: 2,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FSUBRP,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FTST,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FUCOM,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FUCOM,
+        operands: 2,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FUCOMI,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FUCOMIP,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FUCOMP,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FUCOMP,
+        operands: 2,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FUCOMPP,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FXAM,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FXCH,
+        operands: 0,
+        access: USE_RW_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FXSAVE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FXTRACT,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0)],
+        written: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FYL2X,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::FYL2XP1,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ST0), Implicit::Reg(OperandType::REG_ST1)],
+        written: &[Implicit::Reg(OperandType::REG_ST0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::IDIV,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AccPair],
+        written: &[Implicit::AccPair],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::IMUL,
+        operands: 1,
+        access: USE_R,
+        read: &[Implicit::OpSize(OperandType::REG_RAX)],
+        written: &[Implicit::AccPair],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::IMUL,
+        operands: 2,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INC,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INT,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INT1,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INT3,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INTO,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INVLPG,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::IRET,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LAHF,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_AH)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LEA,
+        operands: 0,
+        access: USE_W_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LEAVE,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RBP)],
+        written: &[
+            Implicit::StackSize(OperandType::REG_RSP),
+            Implicit::StackSize(OperandType::REG_RBP),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LOOP,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RCX)],
+        written: &[Implicit::AddrSize(OperandType::REG_RCX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LOOPE,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RCX)],
+        written: &[Implicit::AddrSize(OperandType::REG_RCX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LOOPNE,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RCX)],
+        written: &[Implicit::AddrSize(OperandType::REG_RCX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVUPD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVUPS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MUL,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::OpSize(OperandType::REG_RAX)],
+        written: &[Implicit::AccPair],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::NEG,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::NOP,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::NOT,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::OR,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::OUT,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PABSB,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PABSD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PABSW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PBLENDVB,
+        operands: 0,
+        access: USE_RW,
+        read: &[Implicit::Reg(OperandType::REG_XMM0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PCMPESTRI,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[Implicit::Reg(OperandType::REG_ECX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PCMPESTRM,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[Implicit::Reg(OperandType::REG_XMM0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PCMPISTRI,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ECX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PCMPISTRM,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_XMM0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PF2ID,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PF2IW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PFRCP,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PFRSQRT,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PHMINPOSUW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PI2FD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PI2FW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POP,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PSWAPD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PTEST,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSH,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDMSR,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ECX)],
+        written: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDPMC,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ECX)],
+        written: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDTSC,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RETF,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RETN,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RCL,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RCR,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ROL,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ROR,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ROUNDPS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ROUNDPD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SAHF,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AH)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SALC,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_AL)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SAR,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SBB,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SHL,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SHLD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SHR,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SHRD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SUB,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::STMXCSR,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SYSCALL,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_RCX), Implicit::Reg(OperandType::REG_R11)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SYSENTER,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SYSEXIT,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ECX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SYSRET,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_RCX), Implicit::Reg(OperandType::REG_R11)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TEST,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VMWRITE,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::WRMSR,
+        operands: 0,
+        access: USE_R,
+        read: &[
+            Implicit::Reg(OperandType::REG_ECX),
+            Implicit::Reg(OperandType::REG_EAX),
+            Implicit::Reg(OperandType::REG_EDX),
+        ],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XCHG,
+        operands: 0,
+        access: USE_RW_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XADD,
+        operands: 0,
+        access: USE_RW_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XOR,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XRSTOR,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XSAVE,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CBW,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AL)],
+        written: &[Implicit::Reg(OperandType::REG_AX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CWDE,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AX)],
+        written: &[Implicit::Reg(OperandType::REG_EAX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CDQE,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX)],
+        written: &[Implicit::Reg(OperandType::REG_RAX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMPSB,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMPSW,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMPSD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMPSQ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVO,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVNO,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVB,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVAE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVNE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVBE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVA,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVS,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVNS,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVPE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVPO,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVL,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVGE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVLE,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CMOVG,
+        operands: 0,
+        access: USE_CW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CWD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_AX)],
+        written: &[Implicit::Reg(OperandType::REG_DX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CDQ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX)],
+        written: &[Implicit::Reg(OperandType::REG_EDX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CQO,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_RAX)],
+        written: &[Implicit::Reg(OperandType::REG_RDX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INSB,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INSW,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INSD,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INSQ,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::JCXZ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_CX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::JECXZ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ECX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::JRCXZ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_RCX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LODSB,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LODSW,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LODSD,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LODSQ,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVSB,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVSW,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVSD,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVSQ,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI), Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[
+            Implicit::AddrSize(OperandType::REG_RSI),
+            Implicit::AddrSize(OperandType::REG_RDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::OUTSB,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::OUTSW,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::OUTSD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::OUTSQ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RSI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RSI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POPA,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[
+            Implicit::StackSize(OperandType::REG_RSP),
+            Implicit::Reg(OperandType::REG_AX),
+            Implicit::Reg(OperandType::REG_CX),
+            Implicit::Reg(OperandType::REG_DX),
+            Implicit::Reg(OperandType::REG_BX),
+            Implicit::Reg(OperandType::REG_BP),
+            Implicit::Reg(OperandType::REG_SI),
+            Implicit::Reg(OperandType::REG_DI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POPAD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[
+            Implicit::StackSize(OperandType::REG_RSP),
+            Implicit::Reg(OperandType::REG_EAX),
+            Implicit::Reg(OperandType::REG_ECX),
+            Implicit::Reg(OperandType::REG_EDX),
+            Implicit::Reg(OperandType::REG_EBX),
+            Implicit::Reg(OperandType::REG_EBP),
+            Implicit::Reg(OperandType::REG_ESI),
+            Implicit::Reg(OperandType::REG_EDI),
+        ],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POPF,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POPFD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POPFQ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSHA,
+        operands: 0,
+        access: USE_R,
+        read: &[
+            Implicit::StackSize(OperandType::REG_RSP),
+            Implicit::Reg(OperandType::REG_AX),
+            Implicit::Reg(OperandType::REG_CX),
+            Implicit::Reg(OperandType::REG_DX),
+            Implicit::Reg(OperandType::REG_BX),
+            Implicit::Reg(OperandType::REG_SP),
+            Implicit::Reg(OperandType::REG_BP),
+            Implicit::Reg(OperandType::REG_SI),
+            Implicit::Reg(OperandType::REG_DI),
+        ],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSHAD,
+        operands: 0,
+        access: USE_R,
+        read: &[
+            Implicit::StackSize(OperandType::REG_RSP),
+            Implicit::Reg(OperandType::REG_EAX),
+            Implicit::Reg(OperandType::REG_ECX),
+            Implicit::Reg(OperandType::REG_EDX),
+            Implicit::Reg(OperandType::REG_EBX),
+            Implicit::Reg(OperandType::REG_ESP),
+            Implicit::Reg(OperandType::REG_EBP),
+            Implicit::Reg(OperandType::REG_ESI),
+            Implicit::Reg(OperandType::REG_EDI),
+        ],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSHF,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSHFD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSHFQ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RCPPS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RSQRTPS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SCASB,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SCASW,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SCASD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SCASQ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETO,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETNO,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETB,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETAE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETNE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETBE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETA,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETNS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETPE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETPO,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETL,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETGE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETLE,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SETG,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SQRTPS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SQRTPD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::STOSB,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::STOSW,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::STOSD,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::STOSQ,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[Implicit::AddrSize(OperandType::REG_RDI)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::COMISD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::COMISS,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTDQ2PD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTDQ2PS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTPD2DQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTPD2PI,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTPD2PS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTPI2PD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTPS2DQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTPS2PD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTPS2PI,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTTPD2DQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTTPD2PI,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTTPS2DQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CVTTPS2PI,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LDDQU,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MASKMOVQ,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MASKMOVDQU,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MMXNOP,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MONITOR,
+        operands: 0,
+        access: USE_R,
+        read: &[
+            Implicit::AddrSize(OperandType::REG_RAX),
+            Implicit::Reg(OperandType::REG_ECX),
+            Implicit::Reg(OperandType::REG_EDX),
+        ],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVAPD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVAPS,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVDDUP,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVDQ2Q,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVDQA,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVDQU,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVSHDUP,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVSLDUP,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVNTDQA,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MOVQ2DQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MWAIT,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_ECX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVSXBD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVSXBQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVSXDQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVSXBW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVSXWD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVSXWQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVZXBD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVZXBQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVZXDQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVZXBW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVZXWD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PMOVZXWQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PREFETCH,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PREFETCHNTA,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PREFETCHT0,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PREFETCHT1,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PREFETCHT2,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PREFETCHW,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PSHUFD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PSHUFHW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PSHUFLW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PSHUFW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SGDT,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SIDT,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SLDT,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SMSW,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::STR,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::UCOMISD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::UCOMISS,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VERR,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VERW,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VMPTRST,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XGETBV,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_ECX)],
+        written: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XSETBV,
+        operands: 0,
+        access: USE_R,
+        read: &[
+            Implicit::Reg(OperandType::REG_ECX),
+            Implicit::Reg(OperandType::REG_EAX),
+            Implicit::Reg(OperandType::REG_EDX),
+        ],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPCMPESTRI,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[Implicit::Reg(OperandType::REG_ECX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPCMPESTRM,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[Implicit::Reg(OperandType::REG_XMM0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPCMPISTRI,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_ECX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPCMPISTRM,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_XMM0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPTEST,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VSTMXCSR,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VCOMISD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VCOMISS,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VMASKMOVDQU,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RDI)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VUCOMISD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VUCOMISS,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VZEROUPPER,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Vectors(OperandType::REG_YMM0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VZEROALL,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Vectors(OperandType::REG_ZMM0)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VTESTPS,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VTESTPD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPGATHERDD,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPGATHERDQ,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPGATHERQD,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPGATHERQQ,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VGATHERDPS,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VGATHERDPD,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VGATHERQPS,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VGATHERQPD,
+        operands: 3,
+        access: USE_RW_R_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMI2W,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMI2D,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMI2Q,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMI2PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMI2PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMT2W,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMT2D,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMT2Q,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMT2PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPERMT2PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPTERNLOGD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VPTERNLOGQ,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFIXUPIMMPS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFIXUPIMMPD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFIXUPIMMSS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFIXUPIMMSD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KORTESTW,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KORTESTQ,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KORTESTB,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KORTESTD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KTESTW,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KTESTQ,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KTESTB,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::KTESTD,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADDSUB132PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADDSUB132PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUBADD132PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUBADD132PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD132PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD132PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD132SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD132SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB132PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB132PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB132SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB132SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD132PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD132PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD132SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD132SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB132PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB132PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB132SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB132SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADDSUB213PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADDSUB213PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUBADD213PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUBADD213PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD213PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD213PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD213SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD213SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB213PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB213PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB213SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB213SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD213PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD213PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD213SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD213SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB213PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB213PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB213SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB213SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADDSUB231PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADDSUB231PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUBADD231PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUBADD231PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD231PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD231PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD231SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMADD231SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB231PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB231PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB231SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFMSUB231SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD231PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD231PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD231SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMADD231SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB231PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB231PD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB231SS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VFNMSUB231SD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ADCX,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::ADOX,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::MULX,
+        operands: 0,
+        access: USE_W_W,
+        read: &[Implicit::OpSize(OperandType::REG_RDX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::AESIMC,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::AESKEYGENASSIST,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SHA256RNDS2,
+        operands: 0,
+        access: USE_RW,
+        read: &[Implicit::Reg(OperandType::REG_XMM0)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDSSPD,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDSSPQ,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XBEGIN,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Reg(OperandType::REG_EAX)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDRAND,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDSEED,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::RDPID,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XSAVEOPT,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XSAVEC,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XSAVES,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::XRSTORS,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX), Implicit::Reg(OperandType::REG_EDX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CLFLUSHOPT,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::CLWB,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PREFETCHWT1,
+        operands: 0,
+        access: USE_N,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INVEPT,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INVVPID,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VMRUN,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RAX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VMLOAD,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RAX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::VMSAVE,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RAX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::SKINIT,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::Reg(OperandType::REG_EAX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::INVLPGA,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::AddrSize(OperandType::REG_RAX), Implicit::Reg(OperandType::REG_ECX)],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSHP,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POPP,
+        operands: 0,
+        access: USE_W,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSH2,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::PUSH2P,
+        operands: 0,
+        access: USE_R,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POP2,
+        operands: 0,
+        access: USE_W_W,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::POP2P,
+        operands: 0,
+        access: USE_W_W,
+        read: &[Implicit::StackSize(OperandType::REG_RSP)],
+        written: &[Implicit::StackSize(OperandType::REG_RSP)],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::LDTILECFG,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Tiles],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::STTILECFG,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TILERELEASE,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[Implicit::Tiles],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TILEZERO,
+        operands: 0,
+        access: USE_W,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TDPBSSD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TDPBSUD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TDPBUSD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TDPBUUD,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TDPBF16PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TDPFP16PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TCMMIMFP16PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::TCMMRLFP16PS,
+        operands: 0,
+        access: USE_RW,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::UD0,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+    OperationSemantics {
+        operation: InstructionOperation::UD1,
+        operands: 0,
+        access: USE_R,
+        read: &[],
+        written: &[],
+    },
+];
@@ -0,0 +1,1298 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+use super::{InstructionOperation, X86Instruction, X86Operand};
+
+const CF: u32 = 1 << 0;
+const PF: u32 = 1 << 2;
+const AF: u32 = 1 << 4;
+const ZF: u32 = 1 << 6;
+const SF: u32 = 1 << 7;
+const TF: u32 = 1 << 8;
+const IF: u32 = 1 << 9;
+const DF: u32 = 1 << 10;
+const OF: u32 = 1 << 11;
+const IOPL: u32 = 3 << 12;
+const NT: u32 = 1 << 14;
+const RF: u32 = 1 << 16;
+const VM: u32 = 1 << 17;
+const AC: u32 = 1 << 18;
+const VIF: u32 = 1 << 19;
+const VIP: u32 = 1 << 20;
+const ID: u32 = 1 << 21;
+
+const STATUS: u32 = CF | PF | AF | ZF | SF | OF;
+const ALL: u32 = STATUS | TF | IF | DF | IOPL | NT | RF | VM | AC | VIF | VIP | ID;
+
+/// A set of bits in the `RFLAGS` register. Each flag has the value
+/// of its bit in the register.
+///
+/// ```
+/// use burst::x86::*;
+///
+/// let flags = Rflags::CF | Rflags::ZF;
+/// assert!(flags.contains(Rflags::ZF));
+/// assert!(!flags.contains(Rflags::CF | Rflags::OF));
+/// assert!(flags.intersects(Rflags::CF | Rflags::OF));
+/// assert_eq!(flags.bits(), 0x41);
+/// assert_eq!(format!("{:?}", flags), "Rflags(CF | ZF)");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rflags(u32);
+
+impl Rflags {
+    /// No flags.
+    pub const NONE: Rflags = Rflags(0);
+    /// The carry flag.
+    pub const CF: Rflags = Rflags(CF);
+    /// The parity flag.
+    pub const PF: Rflags = Rflags(PF);
+    /// The auxiliary carry flag.
+    pub const AF: Rflags = Rflags(AF);
+    /// The zero flag.
+    pub const ZF: Rflags = Rflags(ZF);
+    /// The sign flag.
+    pub const SF: Rflags = Rflags(SF);
+    /// The trap flag.
+    pub const TF: Rflags = Rflags(TF);
+    /// The interrupt enable flag.
+    pub const IF: Rflags = Rflags(IF);
+    /// The direction flag.
+    pub const DF: Rflags = Rflags(DF);
+    /// The overflow flag.
+    pub const OF: Rflags = Rflags(OF);
+    /// The two bits of the I/O privilege level.
+    pub const IOPL: Rflags = Rflags(IOPL);
+    /// The nested task flag.
+    pub const NT: Rflags = Rflags(NT);
+    /// The resume flag.
+    pub const RF: Rflags = Rflags(RF);
+    /// The virtual 8086 mode flag.
+    pub const VM: Rflags = Rflags(VM);
+    /// The alignment check flag.
+    pub const AC: Rflags = Rflags(AC);
+    /// The virtual interrupt flag.
+    pub const VIF: Rflags = Rflags(VIF);
+    /// The virtual interrupt pending flag.
+    pub const VIP: Rflags = Rflags(VIP);
+    /// The `CPUID` available flag.
+    pub const ID: Rflags = Rflags(ID);
+    /// The status flags that arithmetic sets: `CF`, `PF`, `AF`, `ZF`,
+    /// `SF` and `OF`.
+    pub const STATUS: Rflags = Rflags(STATUS);
+    /// Every defined flag.
+    pub const ALL: Rflags = Rflags(ALL);
+
+    /// The flags with these bits in `RFLAGS`. Undefined bits are
+    /// dropped.
+    pub fn from_bits(bits: u32) -> Self {
+        Rflags(bits & ALL)
+    }
+
+    /// The bits of these flags in `RFLAGS`.
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Whether there are no flags.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every flag in `other` is here.
+    pub fn contains(self, other: Rflags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether any flag in `other` is here.
+    pub fn intersects(self, other: Rflags) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for Rflags {
+    type Output = Rflags;
+
+    fn bitor(self, other: Rflags) -> Rflags {
+        Rflags(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Rflags {
+    fn bitor_assign(&mut self, other: Rflags) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitAnd for Rflags {
+    type Output = Rflags;
+
+    fn bitand(self, other: Rflags) -> Rflags {
+        Rflags(self.0 & other.0)
+    }
+}
+
+impl BitAndAssign for Rflags {
+    fn bitand_assign(&mut self, other: Rflags) {
+        self.0 &= other.0;
+    }
+}
+
+impl Not for Rflags {
+    type Output = Rflags;
+
+    fn not(self) -> Rflags {
+        Rflags(!self.0 & ALL)
+    }
+}
+
+static FLAG_NAMES: [(u32, &str); 17] = [
+    (CF, "CF"),
+    (PF, "PF"),
+    (AF, "AF"),
+    (ZF, "ZF"),
+    (SF, "SF"),
+    (TF, "TF"),
+    (IF, "IF"),
+    (DF, "DF"),
+    (OF, "OF"),
+    (IOPL, "IOPL"),
+    (NT, "NT"),
+    (RF, "RF"),
+    (VM, "VM"),
+    (AC, "AC"),
+    (VIF, "VIF"),
+    (VIP, "VIP"),
+    (ID, "ID"),
+];
+
+impl fmt::Debug for Rflags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Rflags(")?;
+        let mut first = true;
+        for &(bits, name) in FLAG_NAMES.iter() {
+            if self.0 & bits != 0 {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+/// How an instruction uses the flags.
+///
+/// The flags that the instruction changes are split between
+/// `written`, `set`, `cleared` and `undefined`, so each flag is in
+/// at most one of them. A flag that is in none of them is unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct X86FlagsEffect {
+    /// The flags whose values the instruction uses.
+    pub read: Rflags,
+    /// The flags that are given a value that depends on the
+    /// instruction's operands.
+    pub written: Rflags,
+    /// The flags that are always set to 1.
+    pub set: Rflags,
+    /// The flags that are always set to 0.
+    pub cleared: Rflags,
+    /// The flags that are left with an undefined value.
+    pub undefined: Rflags,
+}
+
+impl X86FlagsEffect {
+    /// Every flag that the instruction may change.
+    pub fn modified(&self) -> Rflags {
+        self.written | self.set | self.cleared | self.undefined
+    }
+}
+
+/// Build an effect in a constant.
+const fn effect(
+    read: u32,
+    written: u32,
+    set: u32,
+    cleared: u32,
+    undefined: u32,
+) -> X86FlagsEffect {
+    X86FlagsEffect {
+        read: Rflags(read),
+        written: Rflags(written),
+        set: Rflags(set),
+        cleared: Rflags(cleared),
+        undefined: Rflags(undefined),
+    }
+}
+
+struct FlagsEntry {
+    operation: InstructionOperation,
+    effect: X86FlagsEffect,
+}
+
+impl X86Instruction {
+    /// How this instruction reads and changes the flags.
+    ///
+    /// A shift or rotate only defines `OF` for a count of one, so a
+    /// count in `cl` leaves `OF` undefined, and a constant count of
+    /// zero changes nothing. As a count in `cl` may also be zero, the
+    /// flags that it changes are read as well, since their old values
+    /// are kept in that case. An APX instruction with `{nf}` doesn't
+    /// change the flags.
+    ///
+    /// ```
+    /// use burst::x86::*;
+    ///
+    /// // jbe 0x1000
+    /// let data = [0x76u8, 0xfe];
+    /// let instr = disassemble_64(&data, 0x1000, data.len()).unwrap();
+    /// assert_eq!(instr.flags_effect().read, Rflags::CF | Rflags::ZF);
+    ///
+    /// // mul ecx
+    /// let data = [0xf7u8, 0xe1];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let effect = instr.flags_effect();
+    /// assert_eq!(effect.written, Rflags::CF | Rflags::OF);
+    /// assert_eq!(effect.undefined, Rflags::SF | Rflags::ZF | Rflags::AF | Rflags::PF);
+    ///
+    /// // xor eax, eax
+    /// let data = [0x31u8, 0xc0];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let effect = instr.flags_effect();
+    /// assert_eq!(effect.written, Rflags::SF | Rflags::ZF | Rflags::PF);
+    /// assert_eq!(effect.cleared, Rflags::CF | Rflags::OF);
+    /// assert_eq!(effect.modified(), Rflags::STATUS);
+    ///
+    /// // shl eax, 4
+    /// let data = [0xc1u8, 0xe0, 0x04];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let effect = instr.flags_effect();
+    /// assert_eq!(effect.read, Rflags::NONE);
+    /// assert_eq!(effect.written, Rflags::CF | Rflags::SF | Rflags::ZF | Rflags::PF);
+    ///
+    /// // shl eax, cl
+    /// let data = [0xd3u8, 0xe0];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// let effect = instr.flags_effect();
+    /// assert_eq!(effect.read, Rflags::STATUS);
+    /// assert_eq!(effect.written, Rflags::CF | Rflags::SF | Rflags::ZF | Rflags::PF);
+    /// assert_eq!(effect.undefined, Rflags::OF | Rflags::AF);
+    ///
+    /// // rol al, cl
+    /// let data = [0xd2u8, 0xc0];
+    /// let instr = disassemble_64(&data, 0, data.len()).unwrap();
+    /// assert_eq!(instr.flags_effect().read, Rflags::CF | Rflags::OF);
+    /// ```
+    pub fn flags_effect(&self) -> X86FlagsEffect {
+        if self.is_sse_string_alias() {
+            return X86FlagsEffect::default();
+        }
+        let mut effect = match FLAGS_EFFECTS
+            .binary_search_by_key(&(self.operation as i32), |entry| entry.operation as i32)
+        {
+            Ok(i) => FLAGS_EFFECTS[i].effect,
+            Err(_) => return X86FlagsEffect::default(),
+        };
+        if self.evex.is_some_and(|evex| evex.no_flags) {
+            return X86FlagsEffect {
+                read: effect.read,
+                ..Default::default()
+            };
+        }
+        if is_shift(self.operation) {
+            match self.constant_shift_count() {
+                Some(0) => return X86FlagsEffect::default(),
+                Some(1) => {}
+                count => {
+                    if effect.written.intersects(Rflags::OF) {
+                        effect.written.0 &= !OF;
+                        effect.undefined.0 |= OF;
+                    }
+                    // A count of zero in `cl` keeps the old flags.
+                    if count.is_none() {
+                        effect.read |= effect.modified();
+                    }
+                }
+            }
+        }
+        effect
+    }
+
+    /// The count of a shift or rotate as the processor masks it, or
+    /// `None` when the count is in a register.
+    fn constant_shift_count(&self) -> Option<u64> {
+        let operands = self.operands();
+        let size = match operands.first() {
+            Some(&X86Operand::Register(reg)) => reg.size,
+            Some(&X86Operand::Memory(mem)) => mem.size,
+            _ => 0,
+        };
+        let mask = if size == 8 { 0x3f } else { 0x1f };
+        match operands.last() {
+            Some(&X86Operand::Immediate(imm)) => Some(imm.value as u64 & mask),
+            _ => None,
+        }
+    }
+}
+
+fn is_shift(operation: InstructionOperation) -> bool {
+    matches!(
+        operation,
+        InstructionOperation::ROL |
+        InstructionOperation::ROR |
+        InstructionOperation::RCL |
+        InstructionOperation::RCR |
+        InstructionOperation::SHL |
+        InstructionOperation::SHR |
+        InstructionOperation::SAR |
+        InstructionOperation::SHLD |
+        InstructionOperation::SHRD
+    )
+}
+
+// Addition, subtraction and comparison.
+const ARITH: X86FlagsEffect = effect(0, STATUS, 0, 0, 0);
+// Addition and subtraction with a carry in.
+const ARITH_CARRY: X86FlagsEffect = effect(CF, STATUS, 0, 0, 0);
+// Increments and decrements leave `CF` alone.
+const INC_DEC: X86FlagsEffect = effect(0, OF | SF | ZF | AF | PF, 0, 0, 0);
+// Bitwise logic.
+const LOGIC: X86FlagsEffect = effect(0, SF | ZF | PF, 0, OF | CF, AF);
+const MULTIPLY: X86FlagsEffect = effect(0, OF | CF, 0, 0, SF | ZF | AF | PF);
+const DIVIDE: X86FlagsEffect = effect(0, 0, 0, 0, STATUS);
+// Shifts, where `OF` is only defined for a count of one.
+const SHIFT: X86FlagsEffect = effect(0, CF | OF | SF | ZF | PF, 0, 0, AF);
+const ROTATE: X86FlagsEffect = effect(0, CF | OF, 0, 0, 0);
+const ROTATE_CARRY: X86FlagsEffect = effect(CF, CF | OF, 0, 0, 0);
+const BIT_TEST: X86FlagsEffect = effect(0, CF, 0, 0, OF | SF | AF | PF);
+const BIT_SCAN: X86FlagsEffect = effect(0, ZF, 0, 0, CF | OF | SF | AF | PF);
+const COUNT_ZEROS: X86FlagsEffect = effect(0, CF | ZF, 0, 0, OF | SF | AF | PF);
+const POPULATION: X86FlagsEffect = effect(0, ZF, 0, CF | OF | SF | AF | PF, 0);
+// Only `ZF` reports the result.
+const ZERO_ONLY: X86FlagsEffect = effect(0, ZF, 0, 0, 0);
+const BMI_ANDN: X86FlagsEffect = effect(0, SF | ZF, 0, OF | CF, AF | PF);
+const BMI_BLS: X86FlagsEffect = effect(0, SF | ZF | CF, 0, OF, AF | PF);
+const BMI_BLSMSK: X86FlagsEffect = effect(0, SF | CF, 0, ZF | OF, AF | PF);
+const BMI_BEXTR: X86FlagsEffect = effect(0, ZF, 0, CF | OF, AF | SF | PF);
+const BMI_BZHI: X86FlagsEffect = effect(0, ZF | CF | SF, 0, OF, AF | PF);
+const CARRY_ONLY: X86FlagsEffect = effect(CF, CF, 0, 0, 0);
+// `ADOX` uses the overflow flag as a carry.
+const OVERFLOW_CARRY: X86FlagsEffect = effect(OF, OF, 0, 0, 0);
+const CLEAR_CARRY: X86FlagsEffect = effect(0, 0, 0, CF, 0);
+const SET_CARRY: X86FlagsEffect = effect(0, 0, CF, 0, 0);
+const CLEAR_DIRECTION: X86FlagsEffect = effect(0, 0, 0, DF, 0);
+const SET_DIRECTION: X86FlagsEffect = effect(0, 0, DF, 0, 0);
+const CLEAR_INTERRUPT: X86FlagsEffect = effect(0, 0, 0, IF, 0);
+const SET_INTERRUPT: X86FlagsEffect = effect(0, 0, IF, 0, 0);
+// `SAHF` loads the low byte of the flags from `ah`.
+const STORE_AH: X86FlagsEffect = effect(0, SF | ZF | AF | PF | CF, 0, 0, 0);
+const LOAD_AH: X86FlagsEffect = effect(SF | ZF | AF | PF | CF, 0, 0, 0, 0);
+const SAVE_ALL: X86FlagsEffect = effect(ALL, 0, 0, 0, 0);
+const RESTORE_ALL: X86FlagsEffect = effect(0, ALL, 0, 0, 0);
+const SAVE_RESTORE_ALL: X86FlagsEffect = effect(ALL, ALL, 0, 0, 0);
+// Interrupts save the flags and may clear some of them.
+const INTERRUPT: X86FlagsEffect = effect(ALL, TF | IF | NT | RF | VM | AC, 0, 0, 0);
+const FAST_SYSTEM_CALL: X86FlagsEffect = effect(0, IF | VM | RF, 0, 0, 0);
+// String instructions step by the direction flag.
+const STRING: X86FlagsEffect = effect(DF, 0, 0, 0, 0);
+const STRING_COMPARE: X86FlagsEffect = effect(DF, STATUS, 0, 0, 0);
+const ASCII_ADJUST: X86FlagsEffect = effect(AF, AF | CF, 0, 0, OF | SF | ZF | PF);
+const DECIMAL_ADJUST: X86FlagsEffect = effect(AF | CF, SF | ZF | AF | PF | CF, 0, 0, OF);
+const ASCII_ADJUST_MULTIPLY: X86FlagsEffect = effect(0, SF | ZF | PF, 0, 0, OF | AF | CF);
+const SET_AL_FROM_CARRY: X86FlagsEffect = effect(CF, 0, 0, 0, 0);
+// Comparisons of floating point values and vector tests.
+const ORDERED_COMPARE: X86FlagsEffect = effect(0, ZF | PF | CF, 0, OF | SF | AF, 0);
+const VECTOR_TEST: X86FlagsEffect = effect(0, ZF | CF, 0, OF | SF | AF | PF, 0);
+const STRING_SEARCH: X86FlagsEffect = effect(0, CF | ZF | SF | OF, 0, AF | PF, 0);
+const RANDOM: X86FlagsEffect = effect(0, CF, 0, OF | SF | ZF | AF | PF, 0);
+// VMX instructions report failure in `CF` or `ZF`.
+const VMX_RESULT: X86FlagsEffect = effect(0, CF | ZF, 0, PF | AF | SF | OF, 0);
+const CARRY_RESULT: X86FlagsEffect = effect(0, CF, 0, ZF | PF | AF | OF | SF, 0);
+const TRANSACTION_TEST: X86FlagsEffect = effect(0, ZF, 0, CF | OF | SF | PF | AF, 0);
+// Conditions.
+const TEST_OF: X86FlagsEffect = effect(OF, 0, 0, 0, 0);
+const TEST_CF: X86FlagsEffect = effect(CF, 0, 0, 0, 0);
+const TEST_ZF: X86FlagsEffect = effect(ZF, 0, 0, 0, 0);
+const TEST_CF_ZF: X86FlagsEffect = effect(CF | ZF, 0, 0, 0, 0);
+const TEST_SF: X86FlagsEffect = effect(SF, 0, 0, 0, 0);
+const TEST_PF: X86FlagsEffect = effect(PF, 0, 0, 0, 0);
+const TEST_SF_OF: X86FlagsEffect = effect(SF | OF, 0, 0, 0, 0);
+const TEST_ZF_SF_OF: X86FlagsEffect = effect(ZF | SF | OF, 0, 0, 0, 0);
+
+static FLAGS_EFFECTS: [FlagsEntry; 217] = [
+    FlagsEntry {
+        operation: InstructionOperation::AAA,
+        effect: ASCII_ADJUST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::AAD,
+        effect: ASCII_ADJUST_MULTIPLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::AAM,
+        effect: ASCII_ADJUST_MULTIPLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::AAS,
+        effect: ASCII_ADJUST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ADD,
+        effect: ARITH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ADC,
+        effect: ARITH_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::AND,
+        effect: LOGIC,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ARPL,
+        effect: ZERO_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BSF,
+        effect: BIT_SCAN,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BSR,
+        effect: BIT_SCAN,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BT,
+        effect: BIT_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BTC,
+        effect: BIT_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BTR,
+        effect: BIT_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BTS,
+        effect: BIT_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CLC,
+        effect: CLEAR_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CLD,
+        effect: CLEAR_DIRECTION,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CLI,
+        effect: CLEAR_INTERRUPT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMC,
+        effect: CARRY_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMP,
+        effect: ARITH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMPXCH16B,
+        effect: ZERO_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMPXCH8B,
+        effect: ZERO_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMPXCHG,
+        effect: ARITH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::DAA,
+        effect: DECIMAL_ADJUST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::DAS,
+        effect: DECIMAL_ADJUST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::DEC,
+        effect: INC_DEC,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::DIV,
+        effect: DIVIDE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVB,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVBE,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVNB,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVNBE,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVNE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVNU,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCMOVU,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCOMI,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FCOMIP,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FUCOMI,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::FUCOMIP,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::IDIV,
+        effect: DIVIDE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::IMUL,
+        effect: MULTIPLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INC,
+        effect: INC_DEC,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INT,
+        effect: INTERRUPT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INT1,
+        effect: INTERRUPT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INT3,
+        effect: INTERRUPT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INTO,
+        effect: INTERRUPT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::IRET,
+        effect: RESTORE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LAHF,
+        effect: LOAD_AH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LAR,
+        effect: ZERO_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LOOPE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LOOPNE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LSL,
+        effect: ZERO_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::MUL,
+        effect: MULTIPLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::NEG,
+        effect: ARITH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::OR,
+        effect: LOGIC,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PCMPESTRI,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PCMPESTRM,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PCMPISTRI,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PCMPISTRM,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::POPCNT,
+        effect: POPULATION,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PTEST,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::RCL,
+        effect: ROTATE_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::RCR,
+        effect: ROTATE_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ROL,
+        effect: ROTATE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ROR,
+        effect: ROTATE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::RSM,
+        effect: RESTORE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SAHF,
+        effect: STORE_AH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SALC,
+        effect: SET_AL_FROM_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SAR,
+        effect: SHIFT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SBB,
+        effect: ARITH_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SHL,
+        effect: SHIFT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SHLD,
+        effect: SHIFT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SHR,
+        effect: SHIFT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SHRD,
+        effect: SHIFT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SUB,
+        effect: ARITH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::STC,
+        effect: SET_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::STD,
+        effect: SET_DIRECTION,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::STI,
+        effect: SET_INTERRUPT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SYSCALL,
+        effect: SAVE_RESTORE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SYSENTER,
+        effect: FAST_SYSTEM_CALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SYSRET,
+        effect: RESTORE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::TEST,
+        effect: LOGIC,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMREAD,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMWRITE,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::XADD,
+        effect: ARITH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::XOR,
+        effect: LOGIC,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMPSB,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMPSW,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMPSD,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMPSQ,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVO,
+        effect: TEST_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVNO,
+        effect: TEST_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVB,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVAE,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVNE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVBE,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVA,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVS,
+        effect: TEST_SF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVNS,
+        effect: TEST_SF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVPE,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVPO,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVL,
+        effect: TEST_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVGE,
+        effect: TEST_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVLE,
+        effect: TEST_ZF_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CMOVG,
+        effect: TEST_ZF_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INSB,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INSW,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INSD,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INSQ,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JO,
+        effect: TEST_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JNO,
+        effect: TEST_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JB,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JAE,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JNE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JBE,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JA,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JS,
+        effect: TEST_SF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JNS,
+        effect: TEST_SF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JPE,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JPO,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JL,
+        effect: TEST_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JGE,
+        effect: TEST_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JLE,
+        effect: TEST_ZF_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::JG,
+        effect: TEST_ZF_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LODSB,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LODSW,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LODSD,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LODSQ,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::MOVSB,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::MOVSW,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::MOVSD,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::MOVSQ,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::OUTSB,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::OUTSW,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::OUTSD,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::OUTSQ,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::POPF,
+        effect: RESTORE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::POPFD,
+        effect: RESTORE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::POPFQ,
+        effect: RESTORE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PUSHF,
+        effect: SAVE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PUSHFD,
+        effect: SAVE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::PUSHFQ,
+        effect: SAVE_ALL,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SCASB,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SCASW,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SCASD,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SCASQ,
+        effect: STRING_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETO,
+        effect: TEST_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETNO,
+        effect: TEST_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETB,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETAE,
+        effect: TEST_CF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETNE,
+        effect: TEST_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETBE,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETA,
+        effect: TEST_CF_ZF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETS,
+        effect: TEST_SF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETNS,
+        effect: TEST_SF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETPE,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETPO,
+        effect: TEST_PF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETL,
+        effect: TEST_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETGE,
+        effect: TEST_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETLE,
+        effect: TEST_ZF_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::SETG,
+        effect: TEST_ZF_SF_OF,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::STOSB,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::STOSW,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::STOSD,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::STOSQ,
+        effect: STRING,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::COMISD,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::COMISS,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::UCOMISD,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::UCOMISS,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VERR,
+        effect: ZERO_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VERW,
+        effect: ZERO_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMCALL,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMCLEAR,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMLAUNCH,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMPTRLD,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMPTRST,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMRESUME,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMXOFF,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VMXON,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VPCMPESTRI,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VPCMPESTRM,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VPCMPISTRI,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VPCMPISTRM,
+        effect: STRING_SEARCH,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VPTEST,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VCOMISD,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VCOMISS,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VUCOMISD,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VUCOMISS,
+        effect: ORDERED_COMPARE,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VTESTPS,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::VTESTPD,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KORTESTW,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KORTESTQ,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KORTESTB,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KORTESTD,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KTESTW,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KTESTQ,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KTESTB,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::KTESTD,
+        effect: VECTOR_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::TZCNT,
+        effect: COUNT_ZEROS,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::LZCNT,
+        effect: COUNT_ZEROS,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ADCX,
+        effect: CARRY_ONLY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ADOX,
+        effect: OVERFLOW_CARRY,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::ANDN,
+        effect: BMI_ANDN,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BEXTR,
+        effect: BMI_BEXTR,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BLSR,
+        effect: BMI_BLS,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BLSMSK,
+        effect: BMI_BLSMSK,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BLSI,
+        effect: BMI_BLS,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::BZHI,
+        effect: BMI_BZHI,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::RSTORSSP,
+        effect: CARRY_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::CLRSSBSY,
+        effect: CARRY_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::XTEST,
+        effect: TRANSACTION_TEST,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::RDRAND,
+        effect: RANDOM,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::RDSEED,
+        effect: RANDOM,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INVEPT,
+        effect: VMX_RESULT,
+    },
+    FlagsEntry {
+        operation: InstructionOperation::INVVPID,
+        effect: VMX_RESULT,
+    },
+];
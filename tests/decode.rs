@@ -0,0 +1,95 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Regression tests for encodings that used to be misdecoded.
+
+extern crate burst;
+
+use burst::x86::*;
+
+fn decode(data: &[u8]) -> X86Instruction {
+    disassemble(X86Mode::Bits64, data, 0).unwrap()
+}
+
+fn reg(register: OperandType, size: u16) -> X86Operand {
+    X86Operand::Register(Reg { register, size })
+}
+
+fn immediate(instr: &X86Instruction, oper: usize) -> Imm {
+    match instr.operands()[oper] {
+        X86Operand::Immediate(imm) => imm,
+        other => panic!("{:?} is not an immediate", other),
+    }
+}
+
+// A 64 bit immediate was read as a single byte.
+#[test]
+fn mov_imm64_reads_eight_bytes() {
+    let instr = decode(&[0x48, 0xb8, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]);
+    assert_eq!(instr.length, 10);
+    assert_eq!(instr.operands()[0], reg(OperandType::REG_RAX, 8));
+    let imm = immediate(&instr, 1);
+    assert_eq!(imm.value, 0x1122_3344_5566_7788);
+    assert_eq!(imm.encoded_size, 8);
+}
+
+// REX.W made the 8 bit immediate of mov r8, imm8 take 8 bytes.
+#[test]
+fn mov_r8_imm8_ignores_rex_w() {
+    let instr = decode(&[0x48, 0xb0, 0x12, 0x90, 0x90, 0x90, 0x90, 0x90, 0x90, 0x90]);
+    assert_eq!(instr.length, 3);
+    assert_eq!(instr.operands()[0], reg(OperandType::REG_AL, 1));
+    let imm = immediate(&instr, 1);
+    assert_eq!(imm.value, 0x12);
+    assert_eq!(imm.encoded_size, 1);
+}
+
+// The scale was shifted by the whole SIB byte rather than by its top two
+// bits, which overflowed.
+#[test]
+fn sib_scale_uses_top_bits() {
+    for &(sib, scale) in &[(0x08u8, 1u8), (0x48, 2), (0x88, 4), (0xc8, 8)] {
+        let instr = decode(&[0x8b, 0x04, sib]);
+        match instr.operands()[1] {
+            X86Operand::Memory(mem) => {
+                assert_eq!(mem.base, Some(OperandType::REG_RAX));
+                assert_eq!(mem.index, Some(OperandType::REG_RCX));
+                assert_eq!(mem.scale, scale, "sib {:02x}", sib);
+            }
+            other => panic!("{:?} is not a memory operand", other),
+        }
+    }
+}
+
+// The reg field of 8c and 8e indexed past the segment registers.
+#[test]
+fn mov_segment_rejects_invalid_registers() {
+    for &opcode in &[0x8cu8, 0x8e] {
+        for &mod_rm in &[0xf0u8, 0xf8] {
+            match disassemble(X86Mode::Bits64, &[opcode, mod_rm], 0) {
+                Err(DecodeError::InvalidOperands { .. }) => {}
+                other => panic!("{:02x} {:02x} gave {:?}", opcode, mod_rm, other),
+            }
+        }
+    }
+    let instr = decode(&[0x8c, 0xd8]);
+    assert_eq!(instr.operands(), [reg(OperandType::REG_EAX, 4), reg(OperandType::REG_DS, 2)]);
+}
+
+// pinsrd and pinsrq indexed past the SSE table.
+#[test]
+fn pinsrd_pinsrq() {
+    let instr = decode(&[0x66, 0x0f, 0x3a, 0x22, 0xc1, 0x05]);
+    assert_eq!(instr.operation, InstructionOperation::PINSRD);
+    assert_eq!(instr.length, 6);
+    assert_eq!(&instr.operands()[..2], [reg(OperandType::REG_XMM0, 16), reg(OperandType::REG_ECX, 4)]);
+    assert_eq!(immediate(&instr, 2).value, 5);
+
+    let instr = decode(&[0x66, 0x48, 0x0f, 0x3a, 0x22, 0xc1, 0x05]);
+    assert_eq!(instr.operation, InstructionOperation::PINSRQ);
+    assert_eq!(instr.length, 7);
+    assert_eq!(&instr.operands()[..2], [reg(OperandType::REG_XMM0, 16), reg(OperandType::REG_RCX, 8)]);
+    assert_eq!(immediate(&instr, 2).value, 5);
+}
@@ -0,0 +1,101 @@
+// Licensed under the 2-Clause BSD license <LICENSE or
+// https://opensource.org/licenses/BSD-2-Clause>. This
+// file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Check that `instruction_length` agrees with a full `disassemble`
+//! for every one byte and two byte opcode under each combination of
+//! prefixes, so that the length tables cannot drift from the decoder.
+
+extern crate burst;
+
+use burst::x86::*;
+
+static MODES: [X86Mode; 3] = [X86Mode::Bits16, X86Mode::Bits32, X86Mode::Bits64];
+
+static PREFIXES: &[&[u8]] = &[
+    &[],
+    &[0x66],
+    &[0x67],
+    &[0xf0],
+    &[0xf2],
+    &[0xf3],
+    &[0x2e],
+    &[0x64],
+    &[0x66, 0x67],
+    &[0x66, 0xf2],
+    &[0x66, 0xf3],
+    &[0xf2, 0x66],
+    &[0xf3, 0x66],
+    &[0xf2, 0xf3],
+    &[0xf0, 0x66],
+    &[0x40],
+    &[0x41],
+    &[0x48],
+    &[0x4c],
+    &[0x66, 0x48],
+    &[0x67, 0x48],
+    &[0xf2, 0x48],
+    &[0xf3, 0x48],
+    &[0x66, 0xf2, 0x48],
+    // A REX prefix that does not immediately precede the opcode.
+    &[0x48, 0x66],
+];
+
+// Each addressing form for each value of the reg field.
+fn mod_rm_bytes() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for reg in 0..8 {
+        for &form in &[0x00u8, 0x04, 0x05, 0x06, 0x44, 0x84, 0xc0, 0xc7] {
+            bytes.push(form | reg << 3);
+        }
+    }
+    bytes
+}
+
+fn check(mode: X86Mode, data: &[u8]) {
+    let expected = disassemble(mode, data, 0).map(|instr| instr.length);
+    assert_eq!(
+        instruction_length(mode, data),
+        expected,
+        "{:?} {:02x?}",
+        mode,
+        data
+    );
+    // And again when the instruction is cut short.
+    if let Ok(length) = expected {
+        let data = &data[..length - 1];
+        assert_eq!(
+            instruction_length(mode, data),
+            disassemble(mode, data, 0).map(|instr| instr.length),
+            "{:?} {:02x?}",
+            mode,
+            data
+        );
+    }
+}
+
+#[test]
+fn length_matches_disassemble() {
+    // A SIB byte with no base, then displacement and immediate bytes.
+    let tail = [0x25, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc];
+    let mod_rms = mod_rm_bytes();
+    let mut data = Vec::with_capacity(32);
+    for &mode in &MODES {
+        for prefix in PREFIXES {
+            for &escape in &[&[][..], &[0x0f][..]] {
+                for opcode in 0..=255u8 {
+                    for &mod_rm in &mod_rms {
+                        data.clear();
+                        data.extend_from_slice(prefix);
+                        data.extend_from_slice(escape);
+                        data.push(opcode);
+                        data.push(mod_rm);
+                        data.extend_from_slice(&tail);
+                        check(mode, &data);
+                    }
+                }
+            }
+        }
+    }
+}